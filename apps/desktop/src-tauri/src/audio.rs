@@ -1,9 +1,74 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{Device, SampleFormat, SupportedStreamConfig};
+use cpal::{
+    BufferSize, Device, FromSample, Sample, SampleFormat, SizedSample, SupportedStreamConfig,
+};
 use serde::{Deserialize, Serialize};
+use chrono::Utc;
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender};
+use std::sync::{Arc, Mutex};
 use std::thread;
-use tauri::{AppHandle, Emitter};
+use std::time::Duration;
+use tauri::ipc::{Channel, InvokeResponseBody};
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+mod bluetooth;
+mod bus;
+mod caps;
+mod chain;
+mod check;
+mod chunk;
+mod clipping;
+mod denoise;
+mod gain;
+mod manager;
+mod meter;
+mod monitor;
+mod outbox;
+#[cfg(target_os = "linux")]
+mod pulse;
+mod replay;
+mod resample;
+mod stats;
+mod summary;
+mod vad;
+mod watchdog;
+
+use bus::{AudioBus, AudioConsumer, BusWriter, DEFAULT_BUS_CAPACITY_MS, DURABLE_BUS_CAPACITY_MS};
+use caps::SelectedConfig;
+pub use caps::DeviceCapabilities;
+use chain::{ChannelChain, ChannelSource};
+use check::{DEFAULT_MIC_CHECK_SECS, MIC_CHECK_SECS_RANGE};
+pub use check::MicCheckReport;
+use chunk::{ChunkAccumulator, DEFAULT_CHUNK_MS};
+use clipping::ClipDetector;
+use gain::{DEFAULT_AGC_TARGET_DB, INPUT_GAIN_RANGE};
+pub(crate) use chunk::CHUNK_MS_RANGE;
+use manager::AudioSubscribers;
+use meter::LevelMeter;
+use monitor::{DEFAULT_MONITOR_LATENCY_MS, MONITOR_LATENCY_RANGE, MONITOR_VOLUME_RANGE};
+pub use monitor::{Monitor, MonitorStatus};
+use watchdog::{SilenceEvent, SilenceWatchdog, DEFAULT_SILENCE_WARNING_SECS};
+pub use watchdog::SILENCE_WARNING_SECS_RANGE;
+use outbox::Outbox;
+pub use manager::{CaptureManager, CaptureSessionInfo};
+pub(crate) use resample::MonoResampler;
+pub use replay::{ReplayClip, REPLAY_SECS_RANGE};
+use replay::ReplayBuffer;
+pub use stats::CaptureStats;
+use stats::StreamClock;
+pub use summary::SessionAudioStats;
+use summary::StatsCollector;
+use vad::{VadEvent, VoiceDetector};
+
+use crate::error::TeuImError;
+use crate::recording::Recorder;
+use crate::remote_mic::RemoteTap;
+use crate::settings::SettingsStore;
+use crate::storage::{MarkKind, Storage};
+use crate::transcribe::TranscriptionFeed;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioDevice {
@@ -11,20 +76,342 @@ pub struct AudioDevice {
     pub name: String,
 }
 
+/// 캡처 스트림 종류 (내 목소리 / 원격 참가자)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureSource {
+    Microphone,
+    System,
+    /// LAN으로 연결된 다른 인스턴스의 마이크
+    Remote,
+}
+
+impl CaptureSource {
+    /// 바이너리 프레임 헤더에 쓰는 소스 코드
+    fn code(self) -> u8 {
+        match self {
+            CaptureSource::Microphone => 0,
+            CaptureSource::System => 1,
+            CaptureSource::Remote => 2,
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            CaptureSource::Microphone => "microphone",
+            CaptureSource::System => "system",
+            CaptureSource::Remote => "remote",
+        }
+    }
+
+    pub(crate) fn parse(source: &str) -> Option<Self> {
+        match source {
+            "microphone" => Some(CaptureSource::Microphone),
+            "system" => Some(CaptureSource::System),
+            "remote" => Some(CaptureSource::Remote),
+            _ => None,
+        }
+    }
+}
+
+/// 프론트엔드로 내보내는 샘플 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputSampleFormat {
+    #[default]
+    I16,
+    F32,
+}
+
+impl OutputSampleFormat {
+    /// 바이너리 프레임 헤더에 쓰는 형식 코드
+    fn code(self) -> u8 {
+        match self {
+            OutputSampleFormat::I16 => 0,
+            OutputSampleFormat::F32 => 1,
+        }
+    }
+}
+
+/// 채널을 지정하지 않았을 때 모노로 바꾸는 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownmixMode {
+    /// 모든 채널 평균
+    #[default]
+    Average,
+    /// 첫 번째 채널만 사용 (이전 동작)
+    FirstChannel,
+}
+
+/// `capture-config-fallback` 이벤트 페이로드
+///
+/// 요청한 샘플레이트나 버퍼 크기를 장치가 지원하지 않아 기본값으로 캡처할 때 보낸다.
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureConfigFallback {
+    pub session_id: String,
+    pub device_name: String,
+    pub requested_sample_rate: Option<u32>,
+    pub requested_buffer_size: Option<u32>,
+    /// 실제로 연 샘플레이트
+    pub sample_rate: u32,
+    /// 실제로 연 버퍼 크기 (호스트 기본값이면 없음)
+    pub buffer_size: Option<u32>,
+    pub message: String,
+}
+
+/// `capture-quality-warning` 이벤트 페이로드
+///
+/// 블루투스 헤드셋 마이크가 통화 품질(HFP)로 캡처될 때 보낸다.
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureQualityWarning {
+    pub session_id: String,
+    /// 요청한 헤드셋 장치 이름
+    pub device_name: String,
+    /// 실제로 캡처하는 샘플레이트
+    pub sample_rate: u32,
+    /// 헤드셋 대신 캡처 중인 장치 (`avoid_bluetooth_input`으로 바꾼 경우)
+    pub substitute_device_name: Option<String>,
+    pub message: String,
+}
+
+/// 장치 오류 시 복구 정책
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RecoveryPolicy {
+    /// 스트림 오류가 나면 장치를 다시 열어 같은 세션으로 계속 캡처
+    pub enabled: bool,
+    /// 원래 장치를 찾을 수 없으면 기본 입력 장치로 전환
+    pub fallback_to_default: bool,
+    /// 최대 재시도 횟수 (생략하면 중지할 때까지)
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for RecoveryPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            fallback_to_default: true,
+            max_attempts: None,
+        }
+    }
+}
+
+/// 캡처 출력 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CaptureOptions {
+    /// 오디오 호스트 ID (`list_audio_hosts` 참고, 생략하면 장치 ID에서 추론)
+    pub host: Option<String>,
+    /// 사용할 입력 채널 (0부터, 생략하면 `downmix`로 모노 변환)
+    ///
+    /// 녹음과 전사는 항상 이 채널 하나만 사용한다.
+    pub channel: Option<usize>,
+    /// 선택한 채널(생략하면 첫 채널)과 다음 채널을 스테레오로 그대로 내보냄
+    pub stereo: bool,
+    pub downmix: DownmixMode,
+    pub sample_format: OutputSampleFormat,
+    pub recovery: RecoveryPolicy,
+    /// 장치에서 받을 샘플레이트 (생략하면 장치 기본값, 마이크 캡처에만 적용)
+    pub device_sample_rate: Option<u32>,
+    /// 스트림 버퍼 크기 (프레임 수, 생략하면 호스트 기본값, 마이크 캡처에만 적용)
+    pub buffer_size: Option<u32>,
+    /// 블루투스 헤드셋 마이크 대신 다른 입력 장치로 캡처
+    ///
+    /// 헤드셋 마이크를 열지 않으면 헤드셋이 고음질 출력(A2DP)으로 남아
+    /// 모니터링은 헤드셋으로 계속할 수 있다.
+    pub avoid_bluetooth_input: bool,
+}
+
+/// `audio-data` 샘플 (형식에 따라 정수 또는 실수 배열)
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AudioSamples {
+    I16(Vec<i16>),
+    F32(Vec<f32>),
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct AudioData {
-    pub samples: Vec<i16>,
+    /// 다채널이면 프레임 단위로 인터리브
+    pub samples: AudioSamples,
     pub sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: OutputSampleFormat,
+    pub source: CaptureSource,
+    pub session_id: String,
+}
+
+/// `audio-level` 이벤트 페이로드 (채널별 dBFS)
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioLevel {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub rms_db: Vec<f32>,
+    pub peak_db: Vec<f32>,
+}
+
+/// `capture-error` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureError {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub message: String,
+    /// 재시도 횟수 (스트림 오류 자체는 0)
+    pub attempt: u32,
+    /// 복구를 계속 시도하는지 (false면 세션을 중지해야 함)
+    pub recovering: bool,
+}
+
+/// `capture-recovered` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureRecovered {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub device_name: String,
+    /// 원래 장치 대신 기본 장치로 전환했는지
+    pub fallback: bool,
+}
+
+/// 장치를 다시 열 때 첫 대기 시간 (시도마다 두 배)
+const RESTART_INITIAL_DELAY: Duration = Duration::from_millis(500);
+
+/// 재시도 대기 시간 상한
+const RESTART_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// `speech-start` / `speech-end` 이벤트 페이로드
+///
+/// 시간은 캡처 시작 시점부터의 경과 시간(ms)이며 `end_ms`는 종료 이벤트에만 있다.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpeechEvent {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub start_ms: i64,
+    pub end_ms: Option<i64>,
+}
+
+/// 오디오 호스트(백엔드) 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioHost {
+    /// `host` 옵션에 넘기는 ID (예: `wasapi`, `asio`, `jack`, `coreaudio`)
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
+}
+
+/// 이 빌드에서 사용할 수 있는 오디오 호스트 목록 조회
+///
+/// ASIO와 JACK은 각각 `asio`, `jack` 기능을 켜고 빌드해야 나타난다.
+#[tauri::command]
+pub fn list_audio_hosts() -> Vec<AudioHost> {
+    let default = cpal::default_host().id();
+    cpal::available_hosts()
+        .into_iter()
+        .map(|id| AudioHost {
+            id: id.name().to_lowercase(),
+            name: id.name().to_string(),
+            is_default: id == default,
+        })
+        .collect()
+}
+
+/// 오디오 입력 장치 목록 조회 (`host`를 생략하면 기본 호스트)
+#[tauri::command]
+pub fn list_audio_devices(host: Option<String>) -> Result<Vec<AudioDevice>, TeuImError> {
+    Ok(collect_devices(&resolve_host(host.as_deref(), None)?))
+}
+
+/// 오디오 출력 장치 목록 조회 (모니터링용, `host`를 생략하면 기본 호스트)
+#[tauri::command]
+pub fn list_output_devices(host: Option<String>) -> Result<Vec<AudioDevice>, TeuImError> {
+    let host = resolve_host(host.as_deref(), None)?;
+    Ok(enumerate_output_devices(&host)
+        .into_iter()
+        .filter_map(|(id, device)| Some(AudioDevice { id, name: device.name().ok()? }))
+        .collect())
 }
 
-// 전역 중지 플래그
-static STOP_FLAG: AtomicBool = AtomicBool::new(false);
-static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+/// 출력 장치로 테스트 톤 재생 (1kHz, 1.5초, 끝나면 반환)
+#[tauri::command]
+pub async fn play_test_tone(device_id: String) -> Result<(), TeuImError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let host = resolve_host(None, Some(&device_id))?;
+        check::play_test_tone(find_output_device(&host, &device_id)?)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 마이크 점검 (`seconds`초 녹음 후 잡음 바닥, 클리핑, 말소리 레벨 측정)
+///
+/// 캡처 세션을 만들지 않고 장치를 직접 열어 짧게 녹음한다. 기본 5초.
+#[tauri::command]
+pub async fn run_mic_check(
+    device_id: String,
+    seconds: Option<u32>,
+) -> Result<MicCheckReport, TeuImError> {
+    let seconds = seconds.unwrap_or(DEFAULT_MIC_CHECK_SECS);
+    if !MIC_CHECK_SECS_RANGE.contains(&seconds) {
+        return Err(TeuImError::invalid_argument(format!(
+            "점검 길이는 {}~{}초 사이여야 합니다: {}",
+            MIC_CHECK_SECS_RANGE.start(),
+            MIC_CHECK_SECS_RANGE.end(),
+            seconds
+        )));
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let host = resolve_host(None, Some(&device_id))?;
+        check::run_mic_check(find_input_device(&host, &device_id)?, seconds)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
 
-/// 오디오 입력 장치 목록 조회
+/// 입력 장치가 지원하는 샘플레이트, 채널 수, 샘플 형식, 버퍼 크기 조회
 #[tauri::command]
-pub fn list_audio_devices() -> Result<Vec<AudioDevice>, String> {
-    let host = cpal::default_host();
+pub fn get_device_capabilities(
+    device_id: String,
+    host: Option<String>,
+) -> Result<DeviceCapabilities, TeuImError> {
+    let host = resolve_host(host.as_deref(), Some(&device_id))?;
+    let device = find_input_device(&host, &device_id)?;
+    caps::capabilities(device_id, &device)
+}
+
+/// 호스트 ID로 호스트 열기
+///
+/// 지정하지 않으면 장치 ID 앞부분의 호스트 이름(`stable_device_id` 참고)을 따르고,
+/// 그것도 없으면 기본 호스트를 쓴다.
+fn resolve_host(host: Option<&str>, device_id: Option<&str>) -> Result<cpal::Host, TeuImError> {
+    let available = cpal::available_hosts();
+    let host_id = match host {
+        Some(name) => Some(
+            available
+                .into_iter()
+                .find(|id| id.name().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    TeuImError::invalid_argument(format!("사용할 수 없는 오디오 호스트: {}", name))
+                })?,
+        ),
+        None => device_id.and_then(|device_id| {
+            available.into_iter().find(|id| {
+                device_id
+                    .strip_prefix(&id.name().to_lowercase())
+                    .is_some_and(|rest| rest.starts_with('-'))
+            })
+        }),
+    };
+
+    match host_id {
+        Some(id) => cpal::host_from_id(id).map_err(|e| TeuImError::Stream {
+            message: e.to_string(),
+        }),
+        None => Ok(cpal::default_host()),
+    }
+}
+
+/// 기본 장치 항목을 포함한 입력 장치 목록 생성
+fn collect_devices(host: &cpal::Host) -> Vec<AudioDevice> {
     let mut devices = Vec::new();
 
     // 기본 입력 장치
@@ -32,206 +419,1757 @@ pub fn list_audio_devices() -> Result<Vec<AudioDevice>, String> {
         if let Ok(name) = device.name() {
             devices.push(AudioDevice {
                 id: "default".to_string(),
-                name: format!("{} (기본)", name),
+                name: crate::i18n::tf("device.default", &[("device", &name)]),
             });
         }
     }
 
     // 모든 입력 장치
-    if let Ok(input_devices) = host.input_devices() {
-        for (idx, device) in input_devices.enumerate() {
-            if let Ok(name) = device.name() {
-                devices.push(AudioDevice {
-                    id: format!("device_{}", idx),
-                    name,
-                });
+    for (id, device) in enumerate_input_devices(host) {
+        if let Ok(name) = device.name() {
+            devices.push(AudioDevice { id, name });
+        }
+    }
+
+    devices
+}
+
+/// 장치 추가/제거 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioDeviceChange {
+    pub device: AudioDevice,
+    pub devices: Vec<AudioDevice>,
+}
+
+/// 장치 목록 감시 주기
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 장치 연결/해제 감시 스레드 시작
+///
+/// cpal은 핫플러그 알림을 제공하지 않으므로 주기적으로 목록을 비교해
+/// `audio-device-added` / `audio-device-removed` 이벤트를 보낸다.
+pub fn spawn_device_watcher(app: AppHandle) {
+    thread::spawn(move || {
+        let host = cpal::default_host();
+        let mut known = collect_devices(&host);
+
+        loop {
+            thread::sleep(DEVICE_POLL_INTERVAL);
+
+            let current = collect_devices(&host);
+            let is_tracked = |d: &AudioDevice| d.id != "default";
+
+            for device in current.iter().filter(|d| is_tracked(d)) {
+                if !known.iter().any(|k| k.id == device.id) {
+                    log::info!("오디오 장치 연결됨: {}", device.name);
+                    let _ = app.emit("audio-device-added", AudioDeviceChange {
+                        device: device.clone(),
+                        devices: current.clone(),
+                    });
+                }
+            }
+
+            for device in known.iter().filter(|d| is_tracked(d)) {
+                if !current.iter().any(|c| c.id == device.id) {
+                    log::info!("오디오 장치 연결 해제됨: {}", device.name);
+                    let _ = app.emit("audio-device-removed", AudioDeviceChange {
+                        device: device.clone(),
+                        devices: current.clone(),
+                    });
+                }
             }
+
+            known = current;
         }
+    });
+}
+
+/// 호스트와 장치 이름으로 안정적인 장치 ID 생성
+///
+/// 열거 순서와 무관하게 같은 장치는 항상 같은 ID를 가지므로 앱을 다시 실행해도
+/// 저장된 ID로 같은 마이크를 찾을 수 있다. 이름이 같은 장치가 여러 개면
+/// 등장 순서(`occurrence`)를 접미사로 붙여 구분한다.
+fn stable_device_id(host_id: cpal::HostId, name: &str, occurrence: usize) -> String {
+    // FNV-1a 64비트: 표준 라이브러리 해시와 달리 빌드/버전이 바뀌어도 값이 고정됨
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in name.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
     }
 
-    Ok(devices)
+    let host = host_id.name().to_lowercase();
+    if occurrence == 0 {
+        format!("{}-{:016x}", host, hash)
+    } else {
+        format!("{}-{:016x}-{}", host, hash, occurrence)
+    }
 }
 
-/// 오디오 캡처 시작
-#[tauri::command]
-pub fn start_audio_capture(app: AppHandle, device_id: String) -> Result<(), String> {
-    // 이미 실행 중이면 에러
-    if IS_RUNNING.load(Ordering::SeqCst) {
-        return Err("오디오 캡처가 이미 실행 중입니다".to_string());
+/// 입력 장치를 안정적인 ID와 함께 열거
+fn enumerate_input_devices(host: &cpal::Host) -> Vec<(String, Device)> {
+    match host.input_devices() {
+        Ok(devices) => with_stable_ids(host, devices),
+        Err(_) => Vec::new(),
     }
+}
 
-    let host = cpal::default_host();
+/// 출력 장치를 안정적인 ID와 함께 열거
+fn enumerate_output_devices(host: &cpal::Host) -> Vec<(String, Device)> {
+    match host.output_devices() {
+        Ok(devices) => with_stable_ids(host, devices),
+        Err(_) => Vec::new(),
+    }
+}
 
-    // 장치 선택
-    let device: Device = if device_id == "default" {
-        host.default_input_device()
-            .ok_or("기본 입력 장치를 찾을 수 없습니다")?
-    } else {
-        let idx: usize = device_id
-            .strip_prefix("device_")
-            .and_then(|s| s.parse().ok())
-            .ok_or("잘못된 장치 ID")?;
+/// 장치마다 안정적인 ID 부여
+fn with_stable_ids(
+    host: &cpal::Host,
+    devices: impl Iterator<Item = Device>,
+) -> Vec<(String, Device)> {
+    let mut result = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
 
-        host.input_devices()
-            .map_err(|e| e.to_string())?
-            .nth(idx)
-            .ok_or("장치를 찾을 수 없습니다")?
+    for device in devices {
+        let Ok(name) = device.name() else {
+            continue;
+        };
+        let occurrence = seen.entry(name.clone()).or_insert(0);
+        let id = stable_device_id(host.id(), &name, *occurrence);
+        *occurrence += 1;
+        result.push((id, device));
+    }
+
+    result
+}
+
+/// 장치 ID로 출력 장치 검색 (`default`는 기본 출력 장치)
+fn find_output_device(host: &cpal::Host, device_id: &str) -> Result<Device, TeuImError> {
+    let not_found = || TeuImError::DeviceNotFound {
+        device_id: device_id.to_string(),
+    };
+
+    if device_id == "default" {
+        return host.default_output_device().ok_or_else(not_found);
+    }
+
+    enumerate_output_devices(host)
+        .into_iter()
+        .find(|(id, _)| id == device_id)
+        .map(|(_, device)| device)
+        .ok_or_else(not_found)
+}
+
+/// 장치 ID로 입력 장치 검색
+fn find_input_device(host: &cpal::Host, device_id: &str) -> Result<Device, TeuImError> {
+    let not_found = || TeuImError::DeviceNotFound {
+        device_id: device_id.to_string(),
     };
 
+    if device_id == "default" {
+        return host.default_input_device().ok_or_else(not_found);
+    }
+
+    // 이전 버전의 인덱스 기반 ID(device_N) 호환
+    if let Some(idx) = device_id
+        .strip_prefix("device_")
+        .and_then(|s| s.parse::<usize>().ok())
+    {
+        log::warn!("인덱스 기반 장치 ID 사용됨 (더 이상 권장되지 않음): {}", device_id);
+        return host
+            .input_devices()
+            .map_err(|e| TeuImError::Stream {
+                message: e.to_string(),
+            })?
+            .nth(idx)
+            .ok_or_else(not_found);
+    }
+
+    enumerate_input_devices(host)
+        .into_iter()
+        .find(|(id, _)| id == device_id)
+        .map(|(_, device)| device)
+        .ok_or_else(not_found)
+}
+
+/// 오디오 캡처 시작
+///
+/// `target_sample_rate`를 지정하면 장치 샘플레이트와 무관하게 해당 레이트의
+/// 모노 i16으로 변환해 보낸다 (음성 인식용은 16kHz). `chunk_ms` 단위로 모아서
+/// 내보내며 기본값은 100ms. `vad_sensitivity`(0~1)를 주면 음성 구간 검출을 켜고
+/// 발화 중인 오디오만 전사 엔진으로 보낸다. `options`로 입력 채널, 스테레오
+/// 출력, f32 샘플, 장치 샘플레이트와 버퍼 크기를 고를 수 있다. 장치가 지원하지 않는
+/// 샘플레이트나 버퍼 크기는 기본값으로 물러서고 `capture-config-fallback`을 보낸다.
+/// 캡처 세션 ID를 반환한다.
+#[tauri::command]
+pub fn start_audio_capture(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    device_id: String,
+    target_sample_rate: Option<u32>,
+    chunk_ms: Option<u32>,
+    vad_sensitivity: Option<f32>,
+    options: Option<CaptureOptions>,
+) -> Result<String, TeuImError> {
+    let options = options.unwrap_or_default();
+    let host = resolve_host(options.host.as_deref(), Some(&device_id))?;
+
+    // 장치 선택
+    let mut device = find_input_device(&host, &device_id)?;
+    let mut device_id = device_id;
+
+    // 블루투스 헤드셋은 마이크를 열면 통화 품질로 바뀌므로 요청하면 다른 장치로 캡처
+    let headset_name = device.name().unwrap_or_default();
+    let hands_free = bluetooth::is_hands_free(&device);
+    let mut substitute_device_name = None;
+    if hands_free && options.avoid_bluetooth_input {
+        match bluetooth::substitute_input(&host) {
+            Some((id, other)) => {
+                log::info!("블루투스 헤드셋 대신 다른 입력 장치로 캡처: {}", id);
+                substitute_device_name = other.name().ok();
+                device_id = id;
+                device = other;
+            }
+            None => log::warn!("블루투스 헤드셋 대신 쓸 입력 장치가 없음"),
+        }
+    }
+
     let device_name = device.name().unwrap_or_default();
     log::info!("오디오 캡처 시작: {}", device_name);
 
-    // 장치의 기본 설정 사용
-    let config = device
+    // 요청한 설정이 없거나 지원하지 않으면 장치의 기본 설정 사용
+    let default = device
         .default_input_config()
-        .map_err(|e| format!("기본 설정 조회 실패: {}", e))?;
+        .map_err(|e| TeuImError::from_default_config(e, &device_name))?;
+    let (requested_rate, requested_buffer) = (options.device_sample_rate, options.buffer_size);
+    let SelectedConfig {
+        config,
+        buffer_size,
+        fallback,
+    } = caps::select_config(&device, default, requested_rate, requested_buffer);
 
     log::info!(
-        "오디오 설정: {} 채널, {}Hz, {:?}",
+        "오디오 설정: {} 채널, {}Hz, {:?}, 버퍼 {:?}",
         config.channels(),
         config.sample_rate().0,
-        config.sample_format()
+        config.sample_format(),
+        buffer_size
     );
+    let sample_rate = config.sample_rate().0;
 
-    // 플래그 초기화
-    STOP_FLAG.store(false, Ordering::SeqCst);
-    IS_RUNNING.store(true, Ordering::SeqCst);
+    let session_id = spawn_capture(
+        app.clone(),
+        &manager,
+        CaptureSource::Microphone,
+        device_id,
+        device,
+        config,
+        StreamFormat {
+            sample_rate: target_sample_rate,
+            chunk_ms,
+            vad_sensitivity,
+            buffer_size,
+            options,
+        },
+    )?;
 
-    // 별도 스레드에서 오디오 캡처 실행
-    thread::spawn(move || {
-        run_audio_capture(device, config, app);
-    });
+    if let Some(message) = fallback {
+        log::warn!("요청한 오디오 설정을 쓸 수 없음: {}", message);
+        let _ = app.emit("capture-config-fallback", CaptureConfigFallback {
+            session_id: session_id.clone(),
+            device_name,
+            requested_sample_rate: requested_rate,
+            requested_buffer_size: requested_buffer,
+            sample_rate,
+            buffer_size: match buffer_size {
+                BufferSize::Fixed(frames) => Some(frames),
+                BufferSize::Default => None,
+            },
+            message,
+        });
+    }
+    if hands_free {
+        let message = match &substitute_device_name {
+            Some(name) => format!("블루투스 헤드셋 마이크 대신 {}에서 캡처합니다", name),
+            None => "블루투스 헤드셋 마이크는 통화 품질(HFP)로 캡처되어 전사 정확도가 떨어질 수 있습니다".to_string(),
+        };
+        log::warn!("{}: {}", headset_name, message);
+        let _ = app.emit("capture-quality-warning", CaptureQualityWarning {
+            session_id: session_id.clone(),
+            device_name: headset_name,
+            sample_rate,
+            substitute_device_name,
+            message,
+        });
+    }
+    Ok(session_id)
+}
+
+/// 시스템 오디오(루프백) 캡처 시작
+///
+/// 마이크가 아니라 기기에서 재생 중인 소리(Zoom/Teams 등 회의 음성)를 캡처한다.
+#[tauri::command]
+pub fn start_system_audio_capture(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    target_sample_rate: Option<u32>,
+    chunk_ms: Option<u32>,
+    options: Option<CaptureOptions>,
+) -> Result<String, TeuImError> {
+    let options = options.unwrap_or_default();
+    let host = resolve_host(options.host.as_deref(), None)?;
+    let found = find_loopback_device(&host);
+    let format = StreamFormat {
+        sample_rate: target_sample_rate,
+        chunk_ms,
+        vad_sensitivity: None,
+        buffer_size: BufferSize::Default,
+        options,
+    };
+    let (device, config) = match found {
+        Ok(found) => found,
+        // 가상 장치가 없으면 PulseAudio 기본 모니터 소스를 이름으로 직접 엶
+        #[cfg(target_os = "linux")]
+        Err(_) => return start_pulse_monitor_capture(app, &manager, format),
+        #[cfg(not(target_os = "linux"))]
+        Err(e) => return Err(e),
+    };
+
+    log::info!(
+        "시스템 오디오 캡처 시작: {} ({} 채널, {}Hz)",
+        device.name().unwrap_or_default(),
+        config.channels(),
+        config.sample_rate().0
+    );
+
+    spawn_capture(
+        app,
+        &manager,
+        CaptureSource::System,
+        "system".to_string(),
+        device,
+        config,
+        format,
+    )
+}
+
+/// 마이크와 시스템 오디오 동시 캡처 시작
+///
+/// 두 스트림은 같은 샘플레이트로 맞춰지며 `audio-data` 이벤트의 `source`로 구분된다.
+/// 마이크, 시스템 순서로 세션 ID를 반환한다.
+#[tauri::command]
+pub fn start_dual_capture(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    device_id: String,
+    target_sample_rate: Option<u32>,
+    chunk_ms: Option<u32>,
+) -> Result<Vec<String>, TeuImError> {
+    let mic_id = start_audio_capture(
+        app.clone(),
+        manager.clone(),
+        device_id,
+        target_sample_rate,
+        chunk_ms,
+        None,
+        None,
+    )?;
+
+    // 시스템 오디오 시작에 실패하면 마이크도 함께 정리
+    match start_system_audio_capture(app, manager.clone(), target_sample_rate, chunk_ms, None) {
+        Ok(system_id) => Ok(vec![mic_id, system_id]),
+        Err(e) => {
+            let _ = manager.stop(&mic_id);
+            Err(e)
+        }
+    }
+}
+
+/// 실행 중인 캡처 세션 목록 조회
+#[tauri::command]
+pub fn list_capture_sessions(manager: State<'_, CaptureManager>) -> Vec<CaptureSessionInfo> {
+    manager.list()
+}
+
+/// 캡처 일시정지 (스트림은 열어 둔 채 이벤트 전송·녹음·전사를 멈춤)
+#[tauri::command]
+pub fn pause_audio_capture(
+    manager: State<'_, CaptureManager>,
+    session_id: String,
+) -> Result<CaptureSessionInfo, TeuImError> {
+    let info = manager.set_paused(&session_id, true)?;
+    log::info!("오디오 캡처 일시정지: {}", session_id);
+    Ok(info)
+}
+
+/// 일시정지한 캡처 재개
+#[tauri::command]
+pub fn resume_audio_capture(
+    manager: State<'_, CaptureManager>,
+    session_id: String,
+) -> Result<CaptureSessionInfo, TeuImError> {
+    let info = manager.set_paused(&session_id, false)?;
+    log::info!("오디오 캡처 재개: {} (누적 일시정지 {}ms)", session_id, info.paused_ms);
+    Ok(info)
+}
+
+/// 마이크 음소거 켜기/끄기
+///
+/// 스트림은 열어 둔 채 무음을 내보내므로 전사·녹음 타임스탬프가 끊기지 않는다.
+/// 전사 중이면 음소거한 구간을 저장소 세션 타임라인에 표시한다.
+#[tauri::command]
+pub fn set_capture_muted(app: AppHandle, muted: bool) {
+    set_muted(&app, muted);
+}
 
+/// 음소거 상태를 바꾸고 바뀌었으면 `capture-muted`로 알림
+pub fn set_muted(app: &AppHandle, muted: bool) {
+    let manager = app.state::<CaptureManager>();
+    if manager.muted().swap(muted, Ordering::Relaxed) != muted {
+        log::info!("마이크 음소거: {}", if muted { "켜짐" } else { "꺼짐" });
+        let _ = app.emit("capture-muted", muted);
+    }
+}
+
+/// 음소거 켜고 끄기 (단축키·페달용)
+pub fn toggle_muted(app: &AppHandle) {
+    let muted = app.state::<CaptureManager>().muted().load(Ordering::Relaxed);
+    set_muted(app, !muted);
+}
+
+/// 실행 중인 캡처 세션별 지연, 콜백 지터, 유실 버퍼 수, 총 프레임 수 조회
+#[tauri::command]
+pub fn get_capture_stats(manager: State<'_, CaptureManager>) -> Vec<CaptureStats> {
+    manager.stats()
+}
+
+/// 캡처 잡음 제거 켜기/끄기 (실행 중인 세션에도 바로 적용)
+#[tauri::command]
+pub fn set_noise_suppression(manager: State<'_, CaptureManager>, enabled: bool) {
+    manager.noise_suppression().store(enabled, Ordering::Relaxed);
+    log::info!("잡음 제거: {}", if enabled { "켜짐" } else { "꺼짐" });
+}
+
+/// 수동 입력 게인 설정 (dB, 내보내기와 전사 전에 적용)
+#[tauri::command]
+pub fn set_input_gain(manager: State<'_, CaptureManager>, db: f32) -> Result<(), TeuImError> {
+    if !INPUT_GAIN_RANGE.contains(&db) {
+        return Err(TeuImError::invalid_argument(format!(
+            "입력 게인은 {}~{}dB 사이여야 합니다: {}",
+            INPUT_GAIN_RANGE.start(),
+            INPUT_GAIN_RANGE.end(),
+            db
+        )));
+    }
+
+    manager.gain().set_manual_db(db);
+    log::info!("입력 게인: {}dB", db);
     Ok(())
 }
 
-fn run_audio_capture(device: Device, config: SupportedStreamConfig, app: AppHandle) {
-    let sample_rate = config.sample_rate().0;
-    let channels = config.channels() as usize;
-    let sample_format = config.sample_format();
+/// 캡처 중인 입력을 출력 장치로 들려주기 시작 (모니터링)
+///
+/// 통역사가 다른 앱 없이 올바른 마이크가 켜져 있는지 확인할 수 있다.
+/// `output_device_id`는 `list_output_devices`의 ID(`default`는 기본 출력 장치),
+/// `volume`은 0~2(1이 원래 크기), `latency_ms`는 출력 버퍼 지연(기본 60ms)이다.
+/// `session_id`를 생략하면 실행 중인 마이크 캡처 세션을 들려준다.
+#[tauri::command]
+pub fn start_monitoring(
+    manager: State<'_, CaptureManager>,
+    output_device_id: String,
+    volume: f32,
+    latency_ms: Option<u32>,
+    session_id: Option<String>,
+) -> Result<MonitorStatus, TeuImError> {
+    check_monitor_volume(volume)?;
+    let latency_ms = latency_ms.unwrap_or(DEFAULT_MONITOR_LATENCY_MS);
+    if !MONITOR_LATENCY_RANGE.contains(&latency_ms) {
+        return Err(TeuImError::invalid_argument(format!(
+            "모니터링 지연은 {}~{}ms 사이여야 합니다: {}",
+            MONITOR_LATENCY_RANGE.start(),
+            MONITOR_LATENCY_RANGE.end(),
+            latency_ms
+        )));
+    }
 
-    let err_fn = |err| log::error!("오디오 스트림 오류: {}", err);
+    let sessions = manager.list();
+    let session = match session_id {
+        Some(id) => sessions.into_iter().find(|s| s.id == id),
+        None => sessions.into_iter().find(|s| s.source == CaptureSource::Microphone),
+    }
+    .ok_or("모니터링할 캡처 세션이 없습니다")?;
 
-    let stream = match sample_format {
-        SampleFormat::F32 => build_stream_f32(&device, &config.into(), channels, sample_rate, app.clone(), err_fn),
-        SampleFormat::I16 => build_stream_i16(&device, &config.into(), channels, sample_rate, app.clone(), err_fn),
-        SampleFormat::U16 => build_stream_u16(&device, &config.into(), channels, sample_rate, app.clone(), err_fn),
-        _ => {
-            log::error!("지원하지 않는 샘플 포맷: {:?}", sample_format);
-            IS_RUNNING.store(false, Ordering::SeqCst);
-            return;
+    let host = resolve_host(None, Some(&output_device_id))?;
+    let device = find_output_device(&host, &output_device_id)?;
+    manager.monitor().start(session.id, session.sample_rate, device, volume, latency_ms)
+}
+
+/// 모니터링 음량 변경 (0~2, 1이 원래 크기)
+#[tauri::command]
+pub fn set_monitoring_volume(
+    manager: State<'_, CaptureManager>,
+    volume: f32,
+) -> Result<MonitorStatus, TeuImError> {
+    check_monitor_volume(volume)?;
+    manager
+        .monitor()
+        .set_volume(volume)
+        .ok_or_else(|| TeuImError::from("모니터링 중이 아닙니다"))
+}
+
+/// 모니터링 중지
+#[tauri::command]
+pub fn stop_monitoring(manager: State<'_, CaptureManager>) {
+    manager.monitor().stop();
+}
+
+/// 모니터링 상태 조회 (모니터링 중이 아니면 없음)
+#[tauri::command]
+pub fn get_monitoring_status(manager: State<'_, CaptureManager>) -> Option<MonitorStatus> {
+    manager.monitor().status()
+}
+
+fn check_monitor_volume(volume: f32) -> Result<(), TeuImError> {
+    if !MONITOR_VOLUME_RANGE.contains(&volume) {
+        return Err(TeuImError::invalid_argument(format!(
+            "모니터링 음량은 {}~{} 사이여야 합니다: {}",
+            MONITOR_VOLUME_RANGE.start(),
+            MONITOR_VOLUME_RANGE.end(),
+            volume
+        )));
+    }
+    Ok(())
+}
+
+/// 자동 게인 조절(AGC) 켜기/끄기
+///
+/// `target_db`는 목표 RMS 레벨(dBFS)이며 생략하면 -20dBFS.
+#[tauri::command]
+pub fn set_auto_gain(
+    manager: State<'_, CaptureManager>,
+    enabled: bool,
+    target_db: Option<f32>,
+) -> Result<(), TeuImError> {
+    let target_db = target_db.unwrap_or(DEFAULT_AGC_TARGET_DB);
+    if !(-60.0..=0.0).contains(&target_db) {
+        return Err(TeuImError::invalid_argument(format!(
+            "AGC 목표 레벨은 -60~0dBFS 사이여야 합니다: {}",
+            target_db
+        )));
+    }
+
+    manager.gain().set_agc_target_db(enabled.then_some(target_db));
+    log::info!("자동 게인 조절: {}", if enabled { "켜짐" } else { "꺼짐" });
+    Ok(())
+}
+
+/// 바이너리 오디오 채널 구독
+///
+/// 구독 중에는 `audio-data` JSON 이벤트 대신 채널로 원시 PCM 프레임을 보낸다.
+/// 프레임 형식은 `encode_audio_frame` 참고. 구독 ID를 반환한다.
+#[tauri::command]
+pub fn subscribe_audio(
+    manager: State<'_, CaptureManager>,
+    channel: Channel<InvokeResponseBody>,
+) -> u32 {
+    let id = channel.id();
+    manager.subscribers().lock().unwrap().insert(id, channel);
+    log::info!("오디오 채널 구독: {}", id);
+    id
+}
+
+/// 바이너리 오디오 채널 구독 해제
+#[tauri::command]
+pub fn unsubscribe_audio(manager: State<'_, CaptureManager>, subscription_id: u32) {
+    manager.subscribers().lock().unwrap().remove(&subscription_id);
+}
+
+/// 프론트엔드로 내보낼 오디오 형식과 처리 단계 요청
+struct StreamFormat {
+    sample_rate: Option<u32>,
+    chunk_ms: Option<u32>,
+    vad_sensitivity: Option<f32>,
+    /// 장치 스트림 버퍼 크기
+    buffer_size: BufferSize,
+    options: CaptureOptions,
+}
+
+/// 캡처 입력 (장치 또는 원격 송신기)
+struct CaptureInput {
+    source: CaptureSource,
+    device_id: String,
+    device_name: String,
+    channels: usize,
+    sample_rate: u32,
+}
+
+/// 캡처 세션 하나의 출력단과 버스 소비자
+struct CapturePipeline {
+    info: CaptureSessionInfo,
+    paused: Arc<AtomicBool>,
+    stats: Arc<Mutex<CaptureStats>>,
+    consumers: Vec<thread::JoinHandle<()>>,
+    sink: CaptureSink,
+}
+
+/// 입력 형식에 맞춰 출력단과 버스 소비자 구성
+fn build_pipeline(
+    app: &AppHandle,
+    manager: &CaptureManager,
+    input: CaptureInput,
+    format: &StreamFormat,
+) -> Result<CapturePipeline, TeuImError> {
+    let CaptureInput {
+        source,
+        device_id,
+        device_name,
+        channels: input_channels,
+        sample_rate: input_rate,
+    } = input;
+    let output_rate = format.sample_rate.unwrap_or(input_rate);
+    if output_rate == 0 {
+        return Err(TeuImError::invalid_argument("잘못된 샘플레이트: 0"));
+    }
+
+    let chunk_ms = format
+        .chunk_ms
+        .or_else(|| app.state::<SettingsStore>().get().chunk_ms)
+        .unwrap_or(DEFAULT_CHUNK_MS);
+    if !CHUNK_MS_RANGE.contains(&chunk_ms) {
+        return Err(TeuImError::invalid_argument(format!(
+            "청크 길이는 {}~{}ms 사이여야 합니다: {}",
+            CHUNK_MS_RANGE.start(),
+            CHUNK_MS_RANGE.end(),
+            chunk_ms
+        )));
+    }
+
+    // 선택한 채널 (스테레오면 다음 채널까지, 지정하지 않으면 다운믹스)
+    let options = &format.options;
+    let sources: Vec<ChannelSource> = match (options.channel, options.stereo) {
+        (Some(first), true) => vec![ChannelSource::Single(first), ChannelSource::Single(first + 1)],
+        (None, true) => vec![ChannelSource::Single(0), ChannelSource::Single(1)],
+        (Some(channel), false) => vec![ChannelSource::Single(channel)],
+        (None, false) => match options.downmix {
+            DownmixMode::Average => vec![ChannelSource::Average],
+            DownmixMode::FirstChannel => vec![ChannelSource::Single(0)],
+        },
+    };
+    if let Some(ChannelSource::Single(missing)) = sources
+        .iter()
+        .find(|source| matches!(source, ChannelSource::Single(index) if *index >= input_channels))
+    {
+        return Err(TeuImError::invalid_argument(format!(
+            "장치에 없는 입력 채널입니다: {} (채널 {}개)",
+            missing, input_channels
+        )));
+    }
+    let output_channels = sources.len();
+    let chains = sources
+        .into_iter()
+        .map(|source| {
+            ChannelChain::new(
+                source,
+                input_rate,
+                output_rate,
+                manager.noise_suppression(),
+                manager.gain(),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let info = CaptureSessionInfo {
+        id: Uuid::new_v4().to_string(),
+        source,
+        device_id,
+        device_name,
+        sample_rate: output_rate,
+        started_at: Utc::now(),
+        paused: false,
+        paused_ms: 0,
+        stream_index: manager.next_stream_index(),
+    };
+    let paused = Arc::new(AtomicBool::new(false));
+    let vad = format
+        .vad_sensitivity
+        .map(|sensitivity| VoiceDetector::new(output_rate, sensitivity))
+        .transpose()
+        .map_err(TeuImError::invalid_argument)?;
+    let (bus, bus_writer) = AudioBus::new(output_rate);
+    let clock = StreamClock::new(info.id.clone(), source);
+    let stats = clock.shared();
+    // 음소거와 눌러서 말하기는 마이크에만 적용
+    let muted = match source {
+        CaptureSource::Microphone => manager.muted(),
+        CaptureSource::System | CaptureSource::Remote => Arc::new(AtomicBool::new(false)),
+    };
+    let push_to_talk = match source {
+        CaptureSource::Microphone => manager.push_to_talk(),
+        CaptureSource::System | CaptureSource::Remote => Arc::new(AtomicBool::new(false)),
+    };
+
+    let emitter = FrameEmitter {
+        source,
+        session_id: info.id.clone(),
+        stream_index: info.stream_index,
+        sample_rate: output_rate,
+        channels: output_channels as u8,
+        sample_format: format.options.sample_format,
+        subscribers: manager.subscribers(),
+        app: app.clone(),
+    };
+    let outbox = Outbox::spawn(stats.clone(), move |samples| emitter.emit(samples));
+
+    // 시스템 오디오는 재생 중인 소리가 없으면 원래 조용하므로 감시하지 않음
+    let silence = match source {
+        CaptureSource::System => None,
+        CaptureSource::Microphone | CaptureSource::Remote => {
+            let seconds = app
+                .state::<SettingsStore>()
+                .get()
+                .silence_warning_secs
+                .unwrap_or(DEFAULT_SILENCE_WARNING_SECS);
+            Some(SilenceWatchdog::new(info.id.clone(), source, seconds))
+        }
+    };
+    let clipping = Arc::new(AtomicBool::new(false));
+
+    let sink = CaptureSink {
+        source,
+        session_id: info.id.clone(),
+        channels: input_channels,
+        meter: LevelMeter::new(input_channels, input_rate),
+        silence,
+        clip_detector: ClipDetector::new(info.id.clone(), source, input_channels, input_rate),
+        clipping: clipping.clone(),
+        input_rate,
+        sample_rate: output_rate,
+        paused: paused.clone(),
+        was_paused: false,
+        push_to_talk: push_to_talk.clone(),
+        muted: muted.clone(),
+        clock,
+        chains,
+        chunker: ChunkAccumulator::new(output_rate, chunk_ms, output_channels),
+        bus: bus_writer,
+        outbox,
+        app: app.clone(),
+    };
+
+    // 쓰기 끝(싱크)이 사라지면 남은 샘플을 처리하고 스스로 종료
+    let consumers = vec![
+        bus.spawn(DURABLE_BUS_CAPACITY_MS, RecordingConsumer {
+            session_id: info.id.clone(),
+            recorder: manager.recorder(),
+        }),
+        bus.spawn(DURABLE_BUS_CAPACITY_MS, TranscriptionConsumer {
+            source,
+            session_id: info.id.clone(),
+            sample_rate: output_rate,
+            paused: paused.clone(),
+            was_paused: false,
+            push_to_talk,
+            muted,
+            mute_start: None,
+            clipping,
+            clipping_start: None,
+            capture_stats: stats.clone(),
+            audio_stats: None,
+            vad,
+            speech_preroll: VecDeque::new(),
+            transcription_feed: manager.transcription_feed(),
+            app: app.clone(),
+        }),
+        bus.spawn(DEFAULT_BUS_CAPACITY_MS, RemoteConsumer {
+            session_id: info.id.clone(),
+            tap: manager.remote_tap(),
+        }),
+        bus.spawn(DEFAULT_BUS_CAPACITY_MS, MonitorConsumer {
+            session_id: info.id.clone(),
+            monitor: manager.monitor(),
+        }),
+        bus.spawn(DEFAULT_BUS_CAPACITY_MS, ReplayConsumer {
+            session_id: info.id.clone(),
+            sample_rate: output_rate,
+            replay: manager.replay(),
+        }),
+    ];
+
+    Ok(CapturePipeline {
+        info,
+        paused,
+        stats,
+        consumers,
+        sink,
+    })
+}
+
+/// 캡처 스레드 시작 후 세션 등록
+fn spawn_capture(
+    app: AppHandle,
+    manager: &CaptureManager,
+    source: CaptureSource,
+    device_id: String,
+    device: Device,
+    config: SupportedStreamConfig,
+    format: StreamFormat,
+) -> Result<String, TeuImError> {
+    let input = CaptureInput {
+        source,
+        device_id,
+        device_name: device.name().unwrap_or_default(),
+        channels: config.channels() as usize,
+        sample_rate: config.sample_rate().0,
+    };
+    let CapturePipeline {
+        info,
+        paused,
+        stats,
+        consumers,
+        sink,
+    } = build_pipeline(&app, manager, input, &format)?;
+
+    let reopen = ReopenTarget {
+        host: format.options.host.clone(),
+        device_id: info.device_id.clone(),
+        sample_rate: format.options.device_sample_rate,
+        buffer_size: format.options.buffer_size,
+        policy: format.options.recovery.clone(),
+    };
+    let buffer_size = format.buffer_size;
+    let session_id = manager.start(info, paused, stats, consumers, move |stop_rx| {
+        // 스트림이 열렸는지 확인한 뒤에 세션으로 등록
+        let (ready_tx, ready_rx) = mpsc::sync_channel(1);
+        let handle = thread::spawn(move || {
+            run_audio_capture(device, config, buffer_size, sink, reopen, ready_tx, stop_rx);
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => Ok(handle),
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                Err(e)
+            }
+            Err(_) => Err(TeuImError::Stream {
+                message: "캡처 스레드가 시작 전에 종료됨".to_string(),
+            }),
+        }
+    })?;
+
+    crate::tray::refresh(&app);
+    Ok(session_id)
+}
+
+/// 원격 송신기에서 받은 오디오로 캡처 세션 시작
+///
+/// 네트워크 쪽이 `samples`로 모노 f32 블록을 넘기며, 송신기 샘플레이트 그대로
+/// 내보낸다. 연결이 끊기면 `capture-error`(복구 없음)를 보낸다.
+/// 캡처 세션 ID를 반환한다.
+pub(crate) fn start_remote_capture(
+    app: AppHandle,
+    manager: &CaptureManager,
+    device_id: String,
+    device_name: String,
+    sample_rate: u32,
+    samples: Receiver<Vec<f32>>,
+) -> Result<String, TeuImError> {
+    let input = CaptureInput {
+        source: CaptureSource::Remote,
+        device_id,
+        device_name,
+        channels: 1,
+        sample_rate,
+    };
+    let format = StreamFormat {
+        sample_rate: None,
+        chunk_ms: None,
+        vad_sensitivity: None,
+        buffer_size: BufferSize::Default,
+        options: CaptureOptions::default(),
+    };
+    let CapturePipeline {
+        info,
+        paused,
+        stats,
+        consumers,
+        sink,
+    } = build_pipeline(&app, manager, input, &format)?;
+
+    let session_id = manager.start(info, paused, stats, consumers, move |stop_rx| {
+        Ok(thread::spawn(move || run_remote_capture(sink, samples, stop_rx)))
+    })?;
+
+    crate::tray::refresh(&app);
+    Ok(session_id)
+}
+
+/// 원격 오디오를 출력단으로 넘기는 캡처 스레드 (중지 신호나 연결 끊김까지)
+fn run_remote_capture(mut sink: CaptureSink, samples: Receiver<Vec<f32>>, stop_rx: Receiver<()>) {
+    log::info!("원격 마이크 캡처 시작됨 ({}Hz)", sink.sample_rate);
+    while let Err(mpsc::TryRecvError::Empty) = stop_rx.try_recv() {
+        match samples.recv_timeout(Duration::from_millis(100)) {
+            Ok(block) => sink.push(&block),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                log::warn!("원격 마이크 연결 끊김: {}", sink.session_id);
+                let _ = sink.app.emit("capture-error", CaptureError {
+                    session_id: sink.session_id.clone(),
+                    source: sink.source,
+                    message: "원격 마이크 연결이 끊겼습니다".to_string(),
+                    attempt: 0,
+                    recovering: false,
+                });
+                return;
+            }
         }
+    }
+    log::info!("원격 마이크 캡처 중지됨");
+}
+
+/// PulseAudio 기본 모니터 소스로 시스템 오디오 캡처 시작
+///
+/// cpal(ALSA)로는 모니터 소스를 이름으로 열 수 없어 libpulse로 직접 연다.
+/// 소리 서버 연결이 끊기면 `capture-error`(복구 없음)를 보낸다.
+#[cfg(target_os = "linux")]
+fn start_pulse_monitor_capture(
+    app: AppHandle,
+    manager: &CaptureManager,
+    format: StreamFormat,
+) -> Result<String, TeuImError> {
+    let input = CaptureInput {
+        source: CaptureSource::System,
+        device_id: "system".to_string(),
+        device_name: pulse::MONITOR_SOURCE.to_string(),
+        channels: pulse::CHANNELS as usize,
+        sample_rate: pulse::SAMPLE_RATE,
     };
+    let CapturePipeline {
+        info,
+        paused,
+        stats,
+        consumers,
+        sink,
+    } = build_pipeline(&app, manager, input, &format)?;
+
+    log::info!(
+        "시스템 오디오 캡처 시작: {} ({} 채널, {}Hz)",
+        pulse::MONITOR_SOURCE,
+        pulse::CHANNELS,
+        pulse::SAMPLE_RATE
+    );
+    let session_id = manager.start(info, paused, stats, consumers, move |stop_rx| {
+        // 소스가 열렸는지 확인한 뒤에 세션으로 등록
+        let (ready_tx, ready_rx) = mpsc::sync_channel(1);
+        let handle = thread::spawn(move || match pulse::MonitorSource::open() {
+            Ok(source) => {
+                let _ = ready_tx.send(Ok(()));
+                run_pulse_capture(sink, source, stop_rx);
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(TeuImError::Stream { message: e }));
+            }
+        });
+
+        match ready_rx.recv() {
+            Ok(Ok(())) => Ok(handle),
+            Ok(Err(e)) => {
+                let _ = handle.join();
+                Err(e)
+            }
+            Err(_) => Err(TeuImError::Stream {
+                message: "캡처 스레드가 시작 전에 종료됨".to_string(),
+            }),
+        }
+    })?;
+
+    crate::tray::refresh(&app);
+    Ok(session_id)
+}
+
+/// 모니터 소스를 읽어 출력단으로 넘기는 캡처 스레드 (중지 신호나 읽기 실패까지)
+#[cfg(target_os = "linux")]
+fn run_pulse_capture(
+    mut sink: CaptureSink,
+    mut source: pulse::MonitorSource,
+    stop_rx: Receiver<()>,
+) {
+    while let Err(mpsc::TryRecvError::Empty) = stop_rx.try_recv() {
+        match source.read() {
+            Ok(block) => sink.push(&block),
+            Err(message) => {
+                log::warn!("{}: {}", message, sink.session_id);
+                let _ = sink.app.emit("capture-error", CaptureError {
+                    session_id: sink.session_id.clone(),
+                    source: sink.source,
+                    message,
+                    attempt: 0,
+                    recovering: false,
+                });
+                return;
+            }
+        }
+    }
+    log::info!("시스템 오디오 캡처 중지됨");
+}
+
+/// 루프백 장치 이름에 흔히 쓰이는 키워드 (가상 오디오 드라이버)
+#[cfg(not(target_os = "windows"))]
+const LOOPBACK_NAME_HINTS: &[&str] = &["blackhole", "soundflower", "loopback", "monitor"];
+
+/// 시스템 오디오를 캡처할 장치와 설정 검색
+///
+/// - Windows: 기본 출력 장치에 입력 스트림을 열면 WASAPI 루프백으로 동작
+/// - macOS: BlackHole/Soundflower 같은 가상 장치를 입력으로 사용
+/// - Linux: 가상 장치가 없으면 `start_pulse_monitor_capture`로 PulseAudio 모니터 소스를 엶
+#[cfg(target_os = "windows")]
+fn find_loopback_device(
+    host: &cpal::Host,
+) -> Result<(Device, SupportedStreamConfig), TeuImError> {
+    let device = host
+        .default_output_device()
+        .ok_or("기본 출력 장치를 찾을 수 없습니다")?;
+    let config = device
+        .default_output_config()
+        .map_err(|e| TeuImError::from_default_config(e, &device.name().unwrap_or_default()))?;
+    Ok((device, config))
+}
 
-    let stream = match stream {
-        Ok(s) => s,
+#[cfg(not(target_os = "windows"))]
+fn find_loopback_device(
+    host: &cpal::Host,
+) -> Result<(Device, SupportedStreamConfig), TeuImError> {
+    let virtual_device = enumerate_input_devices(host).into_iter().find(|(_, device)| {
+        device
+            .name()
+            .map(|name| {
+                let name = name.to_lowercase();
+                LOOPBACK_NAME_HINTS.iter().any(|hint| name.contains(hint))
+            })
+            .unwrap_or(false)
+    });
+
+    if let Some((_, device)) = virtual_device {
+        let config = device
+            .default_input_config()
+            .map_err(|e| TeuImError::from_default_config(e, &device.name().unwrap_or_default()))?;
+        return Ok((device, config));
+    }
+
+    Err("시스템 오디오 장치를 찾을 수 없습니다. BlackHole 같은 가상 오디오 장치를 설치하세요".into())
+}
+
+/// 스트림 오류 후 다시 열 장치와 복구 정책
+struct ReopenTarget {
+    host: Option<String>,
+    device_id: String,
+    /// 처음 캡처할 때 요청한 샘플레이트와 버퍼 크기
+    sample_rate: Option<u32>,
+    buffer_size: Option<u32>,
+    policy: RecoveryPolicy,
+}
+
+/// 캡처 스레드
+///
+/// 스트림 오류가 나면 `capture-error`를 보내고, 복구 정책에 따라 지수 백오프로
+/// 장치를 다시 열어 같은 세션(같은 출력단)으로 계속 내보낸다.
+fn run_audio_capture(
+    device: Device,
+    config: SupportedStreamConfig,
+    buffer_size: BufferSize,
+    sink: CaptureSink,
+    reopen: ReopenTarget,
+    ready_tx: SyncSender<Result<(), TeuImError>>,
+    stop_rx: Receiver<()>,
+) {
+    let sample_rate = config.sample_rate().0;
+    let source = sink.source;
+    let output_rate = sink.sample_rate;
+    let session_id = sink.session_id.clone();
+    let app = sink.app.clone();
+
+    let sink = Arc::new(Mutex::new(sink));
+    let (error_tx, error_rx) = mpsc::channel();
+
+    let mut stream = match open_stream(&device, config, buffer_size, &sink, &error_tx) {
+        Ok(stream) => stream,
         Err(e) => {
-            log::error!("스트림 생성 실패: {}", e);
-            IS_RUNNING.store(false, Ordering::SeqCst);
+            let _ = ready_tx.send(Err(e));
             return;
         }
     };
 
-    if let Err(e) = stream.play() {
-        log::error!("스트림 시작 실패: {}", e);
-        IS_RUNNING.store(false, Ordering::SeqCst);
-        return;
-    }
+    log::info!("오디오 캡처 스트림 시작됨 ({:?}, {}Hz -> {}Hz)", source, sample_rate, output_rate);
+    let _ = ready_tx.send(Ok(()));
 
-    log::info!("오디오 캡처 스트림 시작됨 ({}Hz)", sample_rate);
+    let emit_error = |message: String, attempt: u32, recovering: bool| {
+        let _ = app.emit("capture-error", CaptureError {
+            session_id: session_id.clone(),
+            source,
+            message,
+            attempt,
+            recovering,
+        });
+    };
+
+    // 중지 신호가 오거나 관리자가 사라질 때까지 스트림 오류를 감시
+    while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(Duration::from_millis(100)) {
+        let Ok(err) = error_rx.try_recv() else {
+            continue;
+        };
+        // 한 번에 여러 오류가 몰려와도 복구는 한 번만
+        while error_rx.try_recv().is_ok() {}
+
+        log::error!("오디오 스트림 오류 ({:?}): {}", source, err);
+        if !reopen.policy.enabled {
+            emit_error(err.to_string(), 0, false);
+            continue;
+        }
+        emit_error(err.to_string(), 0, true);
+        drop(stream);
 
-    // 중지 플래그가 설정될 때까지 대기
-    while !STOP_FLAG.load(Ordering::SeqCst) {
-        thread::sleep(std::time::Duration::from_millis(100));
+        let mut delay = RESTART_INITIAL_DELAY;
+        let mut attempt = 0;
+        stream = loop {
+            attempt += 1;
+            if reopen.policy.max_attempts.is_some_and(|max| attempt > max) {
+                log::error!("오디오 장치 복구 포기 ({:?})", source);
+                emit_error("장치를 다시 열지 못했습니다".to_string(), attempt - 1, false);
+                return;
+            }
+            match stop_rx.recv_timeout(delay) {
+                Err(RecvTimeoutError::Timeout) => {}
+                _ => {
+                    log::info!("오디오 캡처 중지됨 ({:?})", source);
+                    return;
+                }
+            }
+
+            let reopened = reopen_device(source, &reopen).and_then(|(device, selected, fallback)| {
+                let device_name = device.name().unwrap_or_default();
+                let config = selected.config;
+                let mut guard = sink.lock().unwrap();
+                guard.reconfigure(config.channels() as usize, config.sample_rate().0)?;
+                guard.clock.reset();
+                drop(guard);
+                let stream = open_stream(&device, config, selected.buffer_size, &sink, &error_tx)?;
+                Ok((stream, device_name, fallback))
+            });
+
+            match reopened {
+                Ok((stream, device_name, fallback)) => {
+                    log::info!("오디오 장치 복구됨: {} (시도 {})", device_name, attempt);
+                    let _ = app.emit("capture-recovered", CaptureRecovered {
+                        session_id: session_id.clone(),
+                        source,
+                        device_name,
+                        fallback,
+                    });
+                    break stream;
+                }
+                Err(e) => {
+                    log::warn!("오디오 장치 다시 열기 실패 (시도 {}): {}", attempt, e);
+                    emit_error(e.to_string(), attempt, true);
+                    delay = (delay * 2).min(RESTART_MAX_DELAY);
+                }
+            }
+        };
     }
 
     drop(stream);
-    IS_RUNNING.store(false, Ordering::SeqCst);
-    log::info!("오디오 캡처 중지됨");
+    log::info!("오디오 캡처 중지됨 ({:?})", source);
+}
+
+/// 복구할 장치 다시 찾기 (기본 장치로 전환했으면 `true`)
+fn reopen_device(
+    source: CaptureSource,
+    target: &ReopenTarget,
+) -> Result<(Device, SelectedConfig, bool), TeuImError> {
+    let host = resolve_host(target.host.as_deref(), Some(&target.device_id))?;
+    if source == CaptureSource::System {
+        let (device, config) = find_loopback_device(&host)?;
+        let selected = SelectedConfig {
+            config,
+            buffer_size: BufferSize::Default,
+            fallback: None,
+        };
+        return Ok((device, selected, false));
+    }
+
+    let (device, fallback) = match find_input_device(&host, &target.device_id) {
+        Ok(device) => (device, false),
+        Err(_) if target.policy.fallback_to_default && target.device_id != "default" => {
+            (find_input_device(&host, "default")?, true)
+        }
+        Err(e) => return Err(e),
+    };
+    let device_name = device.name().unwrap_or_default();
+    let default = device
+        .default_input_config()
+        .map_err(|e| TeuImError::from_default_config(e, &device_name))?;
+    let selected = caps::select_config(&device, default, target.sample_rate, target.buffer_size);
+    if let Some(message) = &selected.fallback {
+        log::warn!("복구한 장치에서 요청한 오디오 설정을 쓸 수 없음: {}", message);
+    }
+    Ok((device, selected, fallback))
 }
 
-fn build_stream_f32(
+/// 입력 스트림 생성 후 시작 (오류는 `error_tx`로 캡처 스레드에 전달)
+fn open_stream(
     device: &Device,
-    config: &cpal::StreamConfig,
+    config: SupportedStreamConfig,
+    buffer_size: BufferSize,
+    sink: &Arc<Mutex<CaptureSink>>,
+    error_tx: &Sender<cpal::StreamError>,
+) -> Result<cpal::Stream, TeuImError> {
+    let sample_format = config.sample_format();
+    let device_name = device.name().unwrap_or_default();
+
+    let error_tx = error_tx.clone();
+    let err_fn = move |err| {
+        let _ = error_tx.send(err);
+    };
+    let mut stream_config: cpal::StreamConfig = config.into();
+    stream_config.buffer_size = buffer_size;
+    let sink = sink.clone();
+
+    let stream = match sample_format {
+        SampleFormat::F32 => build_stream::<f32>(device, &stream_config, sink, err_fn),
+        SampleFormat::I16 => build_stream::<i16>(device, &stream_config, sink, err_fn),
+        SampleFormat::U16 => build_stream::<u16>(device, &stream_config, sink, err_fn),
+        _ => {
+            return Err(TeuImError::UnsupportedFormat {
+                message: format!("{:?}", sample_format),
+            });
+        }
+    };
+
+    let stream = stream.map_err(|e| TeuImError::from_build_stream(e, &device_name))?;
+    stream.play().map_err(|e| match e {
+        cpal::PlayStreamError::DeviceNotAvailable => TeuImError::DeviceBusy { device_name },
+        e => TeuImError::Stream {
+            message: format!("스트림 시작 실패: {}", e),
+        },
+    })?;
+    Ok(stream)
+}
+
+/// 바이너리 오디오 프레임 헤더 크기
+///
+/// 헤더 뒤의 샘플이 i16·f32 모두 정렬되도록 12바이트로 맞춘다.
+const AUDIO_FRAME_HEADER_LEN: usize = 12;
+
+/// 바이너리 오디오 프레임 생성
+///
+/// `[sample_rate: u32 LE][source: u8][channels: u8][format: u8][reserved: 1 byte]`
+/// `[stream_index: u32 LE][samples...]`
+///
+/// `stream_index`는 캡처 세션 정보의 `stream_index`로, 여러 세션을 동시에
+/// 캡처할 때 프레임이 어느 세션 것인지 가린다. 샘플은 `format`에 따라
+/// i16 LE(0) 또는 f32 LE(1)이며 다채널이면 인터리브된다.
+fn encode_audio_frame(
+    samples: &[f32],
+    sample_rate: u32,
+    channels: u8,
+    format: OutputSampleFormat,
+    source: CaptureSource,
+    stream_index: u32,
+) -> Vec<u8> {
+    let sample_len = match format {
+        OutputSampleFormat::I16 => 2,
+        OutputSampleFormat::F32 => 4,
+    };
+    let mut frame = Vec::with_capacity(AUDIO_FRAME_HEADER_LEN + samples.len() * sample_len);
+    frame.extend_from_slice(&sample_rate.to_le_bytes());
+    frame.push(source.code());
+    frame.push(channels);
+    frame.push(format.code());
+    frame.push(0);
+    frame.extend_from_slice(&stream_index.to_le_bytes());
+    for sample in samples {
+        match format {
+            OutputSampleFormat::I16 => frame.extend_from_slice(&quantize(*sample).to_le_bytes()),
+            OutputSampleFormat::F32 => frame.extend_from_slice(&sample.to_le_bytes()),
+        }
+    }
+    frame
+}
+
+/// -1.0~1.0 샘플을 i16으로 양자화
+pub(crate) fn quantize(sample: f32) -> i16 {
+    i16::from_sample(sample.clamp(-1.0, 1.0))
+}
+
+/// 채널별 샘플을 프레임 단위로 인터리브
+fn interleave(mut channels: Vec<Vec<f32>>) -> Vec<f32> {
+    if channels.len() == 1 {
+        return channels.pop().unwrap_or_default();
+    }
+    let frames = channels.iter().map(Vec::len).min().unwrap_or(0);
+    (0..frames)
+        .flat_map(|i| channels.iter().map(move |channel| channel[i]))
+        .collect()
+}
+
+/// 캡처 콜백에서 샘플을 받아 변환 후 프론트엔드로 보내는 출력단
+struct CaptureSink {
+    source: CaptureSource,
+    session_id: String,
+    /// 장치 입력 채널 수
     channels: usize,
+    meter: LevelMeter,
+    /// 스트림이 무음만 내면 경고 (시스템 오디오는 `None`)
+    silence: Option<SilenceWatchdog>,
+    clip_detector: ClipDetector,
+    /// 마지막 클리핑 판단 구간에 클리핑이 있었는지 (전사 소비자가 구간 표시로 기록)
+    clipping: Arc<AtomicBool>,
+    input_rate: u32,
     sample_rate: u32,
+    paused: Arc<AtomicBool>,
+    was_paused: bool,
+    /// 눌러서 말하기 키를 뗀 동안 켜짐 (일시정지처럼 처리)
+    push_to_talk: Arc<AtomicBool>,
+    /// 켜져 있으면 입력 대신 무음을 내보냄
+    muted: Arc<AtomicBool>,
+    clock: StreamClock,
+    /// 내보낼 채널별 처리 단계 (첫 번째가 녹음·전사용)
+    chains: Vec<ChannelChain>,
+    chunker: ChunkAccumulator,
+    /// 녹음·전사 등 버스 소비자에게 첫 번째 채널 전달
+    bus: BusWriter,
+    /// 프런트엔드로 보낼 청크 (전송 스레드가 `FrameEmitter`로 보냄)
+    outbox: Outbox,
     app: AppHandle,
-    err_fn: impl Fn(cpal::StreamError) + Send + 'static,
-) -> Result<cpal::Stream, cpal::BuildStreamError> {
-    device.build_input_stream(
-        config,
-        move |data: &[f32], _: &cpal::InputCallbackInfo| {
-            // 모노로 변환 (첫 번째 채널만 사용)
-            let mono: Vec<i16> = data
-                .chunks(channels)
-                .map(|frame| {
-                    let sample = frame[0].clamp(-1.0, 1.0);
-                    if sample < 0.0 {
-                        (sample * 32768.0) as i16
-                    } else {
-                        (sample * 32767.0) as i16
+}
+
+impl CaptureSink {
+    /// 복구하며 연 장치의 채널 수나 샘플레이트가 다르면 입력 단계를 다시 구성
+    fn reconfigure(&mut self, channels: usize, input_rate: u32) -> Result<(), String> {
+        if channels == self.channels && input_rate == self.input_rate {
+            return Ok(());
+        }
+        for chain in &mut self.chains {
+            chain.set_input_rate(input_rate)?;
+        }
+        self.meter = LevelMeter::new(channels, input_rate);
+        self.clip_detector.reconfigure(channels, input_rate);
+        self.channels = channels;
+        self.input_rate = input_rate;
+        Ok(())
+    }
+
+    /// 콜백 타임스탬프로 지연·지터·오버런 통계 갱신 (일시정지 중에도 측정)
+    fn track_timing(&mut self, timestamp: &cpal::InputStreamTimestamp, samples: usize) {
+        let frames = samples / self.channels.max(1);
+        if let Some(xrun) = self.clock.track(timestamp, frames, self.input_rate) {
+            let _ = self.app.emit("capture-xrun", xrun);
+        }
+    }
+
+    /// 콜백에서 받은 인터리브 샘플 처리
+    fn push(&mut self, interleaved: &[f32]) {
+        if self.paused.load(Ordering::Relaxed) || self.push_to_talk.load(Ordering::Relaxed) {
+            // 일시정지 직전까지 모인 오디오는 내보냄 (전사 구간은 전사 소비자가 확정)
+            if !self.was_paused {
+                self.was_paused = true;
+                if let Some(rest) = self.chunker.flush() {
+                    self.outbox.push(rest);
+                }
+                if let Some(watchdog) = self.silence.as_mut() {
+                    watchdog.reset();
+                }
+                self.clip_detector.reset();
+                self.clipping.store(false, Ordering::Relaxed);
+            }
+            return;
+        }
+        self.was_paused = false;
+
+        let muted = self.muted.load(Ordering::Relaxed);
+        let silence;
+        let interleaved = if muted {
+            silence = vec![0.0; interleaved.len()];
+            &silence[..]
+        } else {
+            interleaved
+        };
+
+        if let Some(levels) = self.meter.process(interleaved) {
+            // 음소거 중의 무음은 사용자가 의도한 것이므로 경고하지 않음
+            match self.silence.as_mut() {
+                Some(watchdog) if muted => watchdog.reset(),
+                Some(watchdog) => match watchdog.update(&levels) {
+                    Some(SilenceEvent::Detected(event)) => {
+                        log::warn!(
+                            "캡처 입력 무음 {:.0}초: {}",
+                            event.silent_secs,
+                            event.session_id
+                        );
+                        let _ = self.app.emit("silence-detected", event);
+                    }
+                    Some(SilenceEvent::Ended(event)) => {
+                        let _ = self.app.emit("silence-ended", event);
                     }
-                })
-                .collect();
+                    None => {}
+                },
+                None => {}
+            }
+            let _ = self.app.emit("audio-level", AudioLevel {
+                session_id: self.session_id.clone(),
+                source: self.source,
+                rms_db: levels.rms_db,
+                peak_db: levels.peak_db,
+            });
+        }
+
+        let (clipped, clipping) = self.clip_detector.process(interleaved);
+        if let Some(clipping) = clipping {
+            self.clipping.store(clipping, Ordering::Relaxed);
+        }
+        if let Some(event) = clipped {
+            self.clock.record_clipping();
+            log::warn!("캡처 입력 클리핑 ({:?}): {}", event.severity, event.session_id);
+            let _ = self.app.emit("audio-clipping", event);
+        }
+
+        // 선택한 채널별로 처리하고 목표 샘플레이트로 변환
+        let channels = self.channels;
+        let outputs: Vec<Vec<f32>> = self
+            .chains
+            .iter_mut()
+            .map(|chain| chain.process(interleaved, channels))
+            .collect();
+        if outputs[0].is_empty() {
+            return;
+        }
+
+        // 녹음과 전사는 버스를 거쳐 각 소비자 스레드에서 처리
+        let dropped = self.bus.write(&outputs[0]);
+        if let Some(overflow) = self.clock.track_bus(dropped) {
+            let _ = self.app.emit("audio-bus-overflow", overflow);
+        }
+
+        for chunk in self.chunker.push(&interleave(outputs)) {
+            self.outbox.push(chunk);
+        }
+    }
+}
+
+/// 청크를 `audio-data` 이벤트나 바이너리 채널로 프런트엔드에 보내는 전송단
+struct FrameEmitter {
+    source: CaptureSource,
+    session_id: String,
+    stream_index: u32,
+    sample_rate: u32,
+    channels: u8,
+    sample_format: OutputSampleFormat,
+    subscribers: AudioSubscribers,
+    app: AppHandle,
+}
 
-            let _ = app.emit("audio-data", AudioData {
-                samples: mono,
-                sample_rate,
+impl FrameEmitter {
+    fn emit(&self, samples: Vec<f32>) {
+        let channels = self.channels;
+
+        // 바이너리 구독자가 있으면 채널로만 전송, 없으면 기존 JSON 이벤트 사용
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if subscribers.is_empty() {
+            drop(subscribers);
+            let samples = match self.sample_format {
+                OutputSampleFormat::I16 => {
+                    AudioSamples::I16(samples.iter().map(|sample| quantize(*sample)).collect())
+                }
+                OutputSampleFormat::F32 => AudioSamples::F32(samples),
+            };
+            let _ = self.app.emit("audio-data", AudioData {
+                samples,
+                sample_rate: self.sample_rate,
+                channels: channels as u16,
+                sample_format: self.sample_format,
+                source: self.source,
+                session_id: self.session_id.clone(),
             });
-        },
-        err_fn,
-        None,
-    )
+            return;
+        }
+
+        let frame = encode_audio_frame(
+            &samples,
+            self.sample_rate,
+            channels,
+            self.sample_format,
+            self.source,
+            self.stream_index,
+        );
+        subscribers.retain(|id, channel| {
+            match channel.send(InvokeResponseBody::Raw(frame.clone())) {
+                Ok(()) => true,
+                Err(e) => {
+                    log::warn!("오디오 채널 전송 실패, 구독 해제: {} ({})", id, e);
+                    false
+                }
+            }
+        });
+    }
 }
 
-fn build_stream_i16(
-    device: &Device,
-    config: &cpal::StreamConfig,
-    channels: usize,
+impl Drop for CaptureSink {
+    // 스트림이 닫힐 때 청크를 다 채우지 못한 마지막 샘플도 내보냄
+    fn drop(&mut self) {
+        if let Some(rest) = self.chunker.flush() {
+            self.outbox.push(rest);
+        }
+    }
+}
+
+/// 녹음기로 오디오를 넘기는 버스 소비자
+struct RecordingConsumer {
+    session_id: String,
+    recorder: Recorder,
+}
+
+impl AudioConsumer for RecordingConsumer {
+    // 녹음 중이면 청크 대기 없이 바로 파일에 기록
+    fn process(&mut self, samples: &[f32]) {
+        let samples: Vec<i16> = samples.iter().map(|sample| quantize(*sample)).collect();
+        self.recorder.write(&self.session_id, &samples);
+    }
+
+    fn finish(&mut self) {
+        self.recorder.disarm_preroll(&self.session_id);
+    }
+}
+
+/// 원격 마이크 송신기로 오디오를 넘기는 버스 소비자
+struct RemoteConsumer {
+    session_id: String,
+    tap: RemoteTap,
+}
+
+impl AudioConsumer for RemoteConsumer {
+    fn process(&mut self, samples: &[f32]) {
+        self.tap.write(&self.session_id, samples);
+    }
+}
+
+/// 모니터링 출력으로 오디오를 넘기는 버스 소비자
+struct MonitorConsumer {
+    session_id: String,
+    monitor: Monitor,
+}
+
+impl AudioConsumer for MonitorConsumer {
+    fn process(&mut self, samples: &[f32]) {
+        self.monitor.write(&self.session_id, samples);
+    }
+
+    // 들려주던 캡처가 끝나면 출력 스트림도 닫음
+    fn finish(&mut self) {
+        if self.monitor.status().is_some_and(|status| status.session_id == self.session_id) {
+            self.monitor.stop();
+        }
+    }
+}
+
+/// 다시 듣기 버퍼에 최근 오디오를 남기는 버스 소비자
+struct ReplayConsumer {
+    session_id: String,
+    sample_rate: u32,
+    replay: ReplayBuffer,
+}
+
+impl AudioConsumer for ReplayConsumer {
+    fn process(&mut self, samples: &[f32]) {
+        self.replay.write(&self.session_id, self.sample_rate, samples);
+    }
+
+    fn finish(&mut self) {
+        self.replay.remove(&self.session_id);
+    }
+}
+
+/// VAD를 거쳐 전사 엔진으로 오디오를 넘기는 버스 소비자
+struct TranscriptionConsumer {
+    source: CaptureSource,
+    session_id: String,
     sample_rate: u32,
+    paused: Arc<AtomicBool>,
+    was_paused: bool,
+    push_to_talk: Arc<AtomicBool>,
+    muted: Arc<AtomicBool>,
+    /// 진행 중인 음소거 구간 (저장소 세션 ID, 시작 위치 ms)
+    mute_start: Option<(String, i64)>,
+    clipping: Arc<AtomicBool>,
+    /// 진행 중인 클리핑 구간 (저장소 세션 ID, 시작 위치 ms)
+    clipping_start: Option<(String, i64)>,
+    capture_stats: Arc<Mutex<CaptureStats>>,
+    /// 지금 전사 중인 저장소 세션의 오디오 통계
+    audio_stats: Option<StatsCollector>,
+    vad: Option<VoiceDetector>,
+    /// 발화 시작 판정 전까지의 오디오 (전사 엔진에 앞부분이 잘리지 않게 전달)
+    speech_preroll: VecDeque<i16>,
+    transcription_feed: TranscriptionFeed,
     app: AppHandle,
-    err_fn: impl Fn(cpal::StreamError) + Send + 'static,
-) -> Result<cpal::Stream, cpal::BuildStreamError> {
-    device.build_input_stream(
-        config,
-        move |data: &[i16], _: &cpal::InputCallbackInfo| {
-            // 모노로 변환
-            let mono: Vec<i16> = data
-                .chunks(channels)
-                .map(|frame| frame[0])
-                .collect();
-
-            let _ = app.emit("audio-data", AudioData {
-                samples: mono,
-                sample_rate,
+}
+
+impl AudioConsumer for TranscriptionConsumer {
+    fn process(&mut self, resampled: &[f32]) {
+        self.was_paused = false;
+        let muted = self.muted.load(Ordering::Relaxed);
+        self.track_mute(muted);
+        self.track_clipping(self.clipping.load(Ordering::Relaxed));
+        self.track_stats(resampled, muted);
+        let samples: Vec<i16> = resampled.iter().map(|sample| quantize(*sample)).collect();
+        self.feed(resampled, &samples);
+    }
+
+    // 일시정지되거나 눌러서 말하기 키를 떼면 밀린 샘플을 다 넘긴 뒤 전사 구간 확정
+    fn idle(&mut self) {
+        let paused =
+            self.paused.load(Ordering::Relaxed) || self.push_to_talk.load(Ordering::Relaxed);
+        if paused && !self.was_paused {
+            self.transcription_feed.flush(&self.session_id);
+        }
+        self.was_paused = paused;
+    }
+
+    // 음소거·클리핑 중에 캡처가 끝나면 그 지점까지를 구간으로 기록
+    fn finish(&mut self) {
+        self.track_mute(false);
+        self.track_clipping(false);
+        self.save_stats();
+        // 넘기지 못한 마지막 무음도 세션 길이에 넣음
+        let rest = self.speech_preroll.len();
+        self.speech_preroll.clear();
+        self.transcription_feed.skip(&self.session_id, self.sample_rate, rest);
+    }
+}
+
+impl TranscriptionConsumer {
+    /// 음소거가 풀리면 음소거 구간을 저장소 세션 타임라인에 기록
+    fn track_mute(&mut self, muted: bool) {
+        let start = self.mute_start.take();
+        self.mute_start = self.track_mark(MarkKind::Mute, muted, start);
+    }
+
+    /// 클리핑이 멎으면 클리핑 구간을 저장소 세션 타임라인에 기록
+    fn track_clipping(&mut self, clipping: bool) {
+        let start = self.clipping_start.take();
+        self.clipping_start = self.track_mark(MarkKind::Clipping, clipping, start);
+    }
+
+    /// 전사 중인 저장소 세션이 바뀌면 모은 오디오 통계를 저장하고 새로 모음
+    fn track_stats(&mut self, resampled: &[f32], muted: bool) {
+        let storage_session_id = self
+            .transcription_feed
+            .position(&self.session_id)
+            .map(|(id, _)| id);
+        let current = self.audio_stats.as_ref().map(|stats| stats.storage_session_id());
+        if current != storage_session_id.as_deref() {
+            self.save_stats();
+            self.audio_stats = storage_session_id.map(|id| {
+                let capture = self.capture_stats.lock().unwrap();
+                StatsCollector::new(id, self.source, self.sample_rate, &capture)
             });
-        },
-        err_fn,
-        None,
-    )
+        }
+        // 음소거 중의 무음은 통계에서 뺌
+        if let Some(stats) = self.audio_stats.as_mut().filter(|_| !muted) {
+            stats.push(resampled);
+        }
+    }
+
+    fn save_stats(&mut self) {
+        let Some(collector) = self.audio_stats.take() else {
+            return;
+        };
+        let stats = collector.finish(&self.capture_stats.lock().unwrap());
+        if let Err(e) = self.app.state::<Storage>().set_session_audio_stats(&stats) {
+            log::error!("세션 오디오 통계 저장 실패: {}", e);
+        }
+    }
+
+    /// 구간이 이어지는 동안 시작 위치를 들고 있다가 끝나면 구간 표시로 저장
+    fn track_mark(
+        &self,
+        kind: MarkKind,
+        active: bool,
+        start: Option<(String, i64)>,
+    ) -> Option<(String, i64)> {
+        if active {
+            return start.or_else(|| self.transcription_feed.position(&self.session_id));
+        }
+        let (storage_session_id, start_ms) = start?;
+        let end_ms = match self.transcription_feed.position(&self.session_id) {
+            Some((id, position)) if id == storage_session_id => position,
+            _ => start_ms,
+        };
+        let storage = self.app.state::<Storage>();
+        let marked = storage.add_session_mark(&storage_session_id, kind, start_ms, end_ms);
+        if let Err(e) = marked {
+            log::error!("타임라인 구간 저장 실패 ({:?}): {}", kind, e);
+        }
+        None
+    }
+
+    /// 전사 엔진으로 오디오 전달 (VAD가 켜져 있으면 발화 구간만, 나머지는 길이만)
+    fn feed(&mut self, resampled: &[f32], samples: &[i16]) {
+        let Some(vad) = self.vad.as_mut() else {
+            self.transcription_feed.push(&self.session_id, self.sample_rate, samples);
+            return;
+        };
+
+        for event in vad.process(resampled) {
+            let payload = match event {
+                VadEvent::SpeechStart { start_ms } => {
+                    let preroll: Vec<i16> = self.speech_preroll.drain(..).collect();
+                    self.transcription_feed.push(&self.session_id, self.sample_rate, &preroll);
+                    ("speech-start", SpeechEvent {
+                        session_id: self.session_id.clone(),
+                        source: self.source,
+                        start_ms,
+                        end_ms: None,
+                    })
+                }
+                VadEvent::SpeechEnd { start_ms, end_ms } => {
+                    self.transcription_feed.flush(&self.session_id);
+                    ("speech-end", SpeechEvent {
+                        session_id: self.session_id.clone(),
+                        source: self.source,
+                        start_ms,
+                        end_ms: Some(end_ms),
+                    })
+                }
+            };
+            let _ = self.app.emit(payload.0, payload.1);
+        }
+
+        if vad.is_speaking() {
+            self.transcription_feed.push(&self.session_id, self.sample_rate, samples);
+        } else {
+            // 최근 300ms만 보관하고 밀려난 무음은 건너뛴 길이로 알림
+            let limit = self.sample_rate as usize * 3 / 10;
+            self.speech_preroll.extend(samples);
+            let excess = self.speech_preroll.len().saturating_sub(limit);
+            self.speech_preroll.drain(..excess);
+            self.transcription_feed.skip(&self.session_id, self.sample_rate, excess);
+        }
+    }
 }
 
-fn build_stream_u16(
+fn build_stream<T>(
     device: &Device,
     config: &cpal::StreamConfig,
-    channels: usize,
-    sample_rate: u32,
-    app: AppHandle,
+    sink: Arc<Mutex<CaptureSink>>,
     err_fn: impl Fn(cpal::StreamError) + Send + 'static,
-) -> Result<cpal::Stream, cpal::BuildStreamError> {
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
     device.build_input_stream(
         config,
-        move |data: &[u16], _: &cpal::InputCallbackInfo| {
-            // u16 -> i16 변환 및 모노로 변환
-            let mono: Vec<i16> = data
-                .chunks(channels)
-                .map(|frame| (frame[0] as i32 - 32768) as i16)
-                .collect();
-
-            let _ = app.emit("audio-data", AudioData {
-                samples: mono,
-                sample_rate,
-            });
+        move |data: &[T], info: &cpal::InputCallbackInfo| {
+            let interleaved: Vec<f32> = data.iter().map(|s| f32::from_sample(*s)).collect();
+            let mut sink = sink.lock().unwrap();
+            sink.track_timing(&info.timestamp(), data.len());
+            sink.push(&interleaved);
         },
         err_fn,
         None,
@@ -239,16 +2177,19 @@ fn build_stream_u16(
 }
 
 /// 오디오 캡처 중지
+///
+/// `session_id`를 생략하면 실행 중인 모든 캡처를 중지한다.
 #[tauri::command]
-pub fn stop_audio_capture() -> Result<(), String> {
-    STOP_FLAG.store(true, Ordering::SeqCst);
-
-    // 스레드가 종료될 때까지 잠시 대기
-    let mut wait_count = 0;
-    while IS_RUNNING.load(Ordering::SeqCst) && wait_count < 20 {
-        thread::sleep(std::time::Duration::from_millis(50));
-        wait_count += 1;
+pub fn stop_audio_capture(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    session_id: Option<String>,
+) -> Result<(), TeuImError> {
+    match session_id {
+        Some(id) => manager.stop(&id)?,
+        None => manager.stop_all(),
     }
 
+    crate::tray::refresh(&app);
     Ok(())
 }
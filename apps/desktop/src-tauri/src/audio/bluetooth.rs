@@ -0,0 +1,62 @@
+use cpal::traits::{DeviceTrait, HostTrait};
+use cpal::Device;
+
+use super::caps;
+
+/// 통화 프로필(HFP/HSP) 입력 장치 이름에 흔히 쓰이는 키워드
+const HANDS_FREE_HINTS: &[&str] = &["hands-free", "handsfree", "hfp", "hsp", "headset"];
+
+/// 블루투스 장치 이름에 흔히 쓰이는 키워드
+const BLUETOOTH_HINTS: &[&str] = &[
+    "bluetooth",
+    "bluez",
+    "ag audio",
+    "airpods",
+    "beats",
+    "buds",
+    "wh-1000",
+    "wf-1000",
+];
+
+/// 통화 프로필이 쓰는 최고 샘플레이트 (mSBC 16kHz, CVSD 8kHz)
+const HANDS_FREE_MAX_RATE: u32 = 16_000;
+
+/// 블루투스 헤드셋의 통화 프로필 마이크인지 추정
+///
+/// 블루투스 헤드셋은 마이크를 여는 순간 고음질(A2DP)에서 통화 품질(HFP)로
+/// 바뀌므로, 이름이 블루투스 장치처럼 보이고 16kHz보다 높은 샘플레이트를
+/// 지원하지 않으면 통화 프로필로 본다.
+pub fn is_hands_free(device: &Device) -> bool {
+    let name = device.name().unwrap_or_default().to_lowercase();
+    if !BLUETOOTH_HINTS.iter().any(|hint| name.contains(hint)) {
+        return false;
+    }
+    if HANDS_FREE_HINTS.iter().any(|hint| name.contains(hint)) {
+        return true;
+    }
+    caps::supported_configs(device)
+        .map(|configs| {
+            configs
+                .iter()
+                .all(|config| config.max_sample_rate().0 <= HANDS_FREE_MAX_RATE)
+        })
+        .unwrap_or(false)
+}
+
+/// 블루투스 헤드셋 대신 캡처할 입력 장치 (기본 장치가 블루투스가 아니면 우선)
+pub fn substitute_input(host: &cpal::Host) -> Option<(String, Device)> {
+    let default_name = host
+        .default_input_device()
+        .and_then(|device| device.name().ok());
+    let mut candidates: Vec<_> = super::enumerate_input_devices(host)
+        .into_iter()
+        .filter(|(_, device)| !is_hands_free(device))
+        .collect();
+    let default = candidates
+        .iter()
+        .position(|(_, device)| device.name().ok() == default_name);
+    match default {
+        Some(index) => Some(candidates.swap_remove(index)),
+        None => candidates.into_iter().next(),
+    }
+}
@@ -0,0 +1,241 @@
+use rtrb::{Consumer, Producer, RingBuffer};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// 소비자 스레드가 새 샘플을 확인하는 주기
+const BUS_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// 소비자별 기본 버퍼 길이 (ms)
+pub const DEFAULT_BUS_CAPACITY_MS: u32 = 2_000;
+
+/// 녹음·전사 소비자 버퍼 길이 (ms, 디스크나 전사가 잠시 멈춰도 샘플을 잃지 않을 만큼)
+pub const DURABLE_BUS_CAPACITY_MS: u32 = 30_000;
+
+/// 캡처 세션 하나의 처리된 오디오를 여러 소비자에게 나눠 주는 버스
+///
+/// 캡처 콜백은 [`BusWriter`]로 소비자마다 하나씩 있는 고정 크기 링 버퍼에 샘플을
+/// 복사만 하고, 녹음·VAD·전사 같은 무거운 처리는 각 소비자 스레드에서 한다.
+/// 버퍼는 등록할 때 미리 잡아 두어 콜백에서는 메모리를 할당하지 않는다. 녹음·전사는
+/// [`DURABLE_BUS_CAPACITY_MS`]처럼 긴 버퍼를 써서 디스크가 잠시 멈춰도 버티고,
+/// 그래도 넘치면 버린 샘플 수를 통계(`bus_dropped_samples`)에 남긴다.
+/// 버스에 흐르는 오디오는 출력 샘플레이트의 모노 f32 (첫 번째 채널)다.
+#[derive(Clone)]
+pub struct AudioBus {
+    sample_rate: u32,
+    register_tx: Sender<Subscriber>,
+}
+
+/// 쓰기 끝이 가진 소비자 하나의 링 버퍼 (넘치면 새 샘플을 버림)
+type Subscriber = Producer<f32>;
+
+/// 캡처 콜백이 가진 버스 쓰기 끝
+pub struct BusWriter {
+    register_rx: Receiver<Subscriber>,
+    subscribers: Vec<Subscriber>,
+}
+
+/// 소비자 하나가 가진 버스 읽기 끝
+pub struct BusReader(Consumer<f32>);
+
+/// 버스에서 오디오를 받아 처리하는 소비자 (전용 스레드에서 실행)
+pub trait AudioConsumer: Send + 'static {
+    /// 새로 도착한 샘플 처리
+    fn process(&mut self, samples: &[f32]);
+
+    /// 읽을 샘플이 없을 때 주기적으로 호출
+    fn idle(&mut self) {}
+
+    /// 캡처가 끝나 버퍼를 모두 비운 뒤 한 번 호출
+    fn finish(&mut self) {}
+}
+
+impl AudioBus {
+    pub fn new(sample_rate: u32) -> (Self, BusWriter) {
+        let (register_tx, register_rx) = mpsc::channel();
+        let bus = Self {
+            sample_rate,
+            register_tx,
+        };
+        let writer = BusWriter {
+            register_rx,
+            subscribers: Vec::new(),
+        };
+        (bus, writer)
+    }
+
+    /// 새 소비자 등록 (`capacity_ms`만큼 밀려도 샘플을 잃지 않음)
+    ///
+    /// 등록은 다음 콜백부터 반영되고, 그 이전 오디오는 받지 않는다.
+    pub fn subscribe(&self, capacity_ms: u32) -> BusReader {
+        let capacity = (self.sample_rate as usize * capacity_ms as usize / 1000).max(1);
+        let (producer, consumer) = RingBuffer::new(capacity);
+        // 쓰기 끝이 이미 사라졌으면 읽기 끝은 곧바로 닫힌 상태가 됨
+        let _ = self.register_tx.send(producer);
+        BusReader(consumer)
+    }
+
+    /// 소비자를 등록하고 전용 스레드에서 실행
+    ///
+    /// 스레드는 캡처가 끝나 남은 샘플을 모두 처리하면 종료된다.
+    pub fn spawn<C: AudioConsumer>(&self, capacity_ms: u32, consumer: C) -> JoinHandle<()> {
+        run(self.subscribe(capacity_ms), consumer)
+    }
+}
+
+fn run<C: AudioConsumer>(mut reader: BusReader, mut consumer: C) -> JoinHandle<()> {
+    thread::spawn(move || loop {
+        let samples = reader.read();
+        if !samples.is_empty() {
+            consumer.process(&samples);
+            continue;
+        }
+        if reader.is_closed() {
+            consumer.finish();
+            break;
+        }
+        consumer.idle();
+        thread::sleep(BUS_POLL_INTERVAL);
+    })
+}
+
+impl BusWriter {
+    /// 모든 소비자에게 샘플 복사 (캡처 콜백에서 호출)
+    ///
+    /// 링 버퍼가 넘쳐 가장 많이 버린 소비자의 버린 샘플 수를 반환한다.
+    pub fn write(&mut self, samples: &[f32]) -> usize {
+        while let Ok(subscriber) = self.register_rx.try_recv() {
+            self.subscribers.push(subscriber);
+        }
+
+        let mut dropped = 0;
+        self.subscribers.retain_mut(|producer| {
+            if producer.is_abandoned() {
+                return false;
+            }
+            let count = samples.len().min(producer.slots());
+            if let Ok(chunk) = producer.write_chunk_uninit(count) {
+                chunk.fill_from_iter(samples.iter().copied());
+            }
+            dropped = dropped.max(samples.len() - count);
+            true
+        });
+        dropped
+    }
+}
+
+impl BusReader {
+    /// 쌓인 샘플을 모두 꺼냄
+    pub fn read(&mut self) -> Vec<f32> {
+        let available = self.0.slots();
+        match self.0.read_chunk(available) {
+            Ok(chunk) => chunk.into_iter().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// 캡처가 끝나 더 읽을 샘플이 없는지
+    pub fn is_closed(&self) -> bool {
+        self.0.is_abandoned() && self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn ring_keeps_oldest_samples_and_reports_overflow() {
+        // 1kHz에서 5ms = 5샘플
+        let (bus, mut writer) = AudioBus::new(1_000);
+        let mut reader = bus.subscribe(5);
+        assert_eq!(writer.write(&[1.0, 2.0, 3.0]), 0);
+        assert_eq!(writer.write(&[4.0, 5.0, 6.0, 7.0]), 2);
+        assert_eq!(reader.read(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!(reader.read().is_empty());
+    }
+
+    #[test]
+    fn durable_reader_absorbs_a_stalled_consumer() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        let mut reader = bus.subscribe(DURABLE_BUS_CAPACITY_MS);
+        let block: Vec<f32> = (0..10_000).map(|i| i as f32).collect();
+        for _ in 0..3 {
+            assert_eq!(writer.write(&block), 0);
+        }
+        assert_eq!(writer.write(&block), 10_000);
+        assert_eq!(reader.read().len(), 30_000);
+    }
+
+    #[test]
+    fn overflow_reports_the_most_behind_reader() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        let mut short = bus.subscribe(2);
+        let _long = bus.subscribe(10);
+        assert_eq!(writer.write(&[1.0, 2.0, 3.0, 4.0]), 2);
+        assert_eq!(short.read(), vec![1.0, 2.0]);
+        assert_eq!(writer.write(&[5.0, 6.0, 7.0]), 1);
+    }
+
+    #[test]
+    fn subscriber_only_sees_audio_after_registration() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        writer.write(&[1.0]);
+        let mut reader = bus.subscribe(10);
+        writer.write(&[2.0]);
+        assert_eq!(reader.read(), vec![2.0]);
+    }
+
+    #[test]
+    fn reader_closes_after_writer_is_dropped_and_drained() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        let mut ring = bus.subscribe(10);
+        let mut durable = bus.subscribe(DURABLE_BUS_CAPACITY_MS);
+        writer.write(&[1.0, 2.0]);
+        drop(writer);
+
+        assert!(!ring.is_closed());
+        assert_eq!(ring.read(), vec![1.0, 2.0]);
+        assert!(ring.is_closed());
+
+        assert_eq!(durable.read(), vec![1.0, 2.0]);
+        assert!(durable.is_closed());
+    }
+
+    #[test]
+    fn dropped_reader_is_unregistered() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        drop(bus.subscribe(1));
+        drop(bus.subscribe(DURABLE_BUS_CAPACITY_MS));
+        writer.write(&[1.0, 2.0, 3.0]);
+        assert!(writer.subscribers.is_empty());
+    }
+
+    struct Collect(Arc<Mutex<(Vec<f32>, bool)>>);
+
+    impl AudioConsumer for Collect {
+        fn process(&mut self, samples: &[f32]) {
+            self.0.lock().unwrap().0.extend_from_slice(samples);
+        }
+
+        fn finish(&mut self) {
+            self.0.lock().unwrap().1 = true;
+        }
+    }
+
+    #[test]
+    fn consumer_thread_drains_then_finishes() {
+        let (bus, mut writer) = AudioBus::new(1_000);
+        let collected = Arc::new(Mutex::new((Vec::new(), false)));
+        let thread = bus.spawn(DURABLE_BUS_CAPACITY_MS, Collect(collected.clone()));
+        writer.write(&[1.0, 2.0]);
+        writer.write(&[3.0]);
+        drop(writer);
+        thread.join().unwrap();
+
+        let (samples, finished) = &*collected.lock().unwrap();
+        assert_eq!(samples, &vec![1.0, 2.0, 3.0]);
+        assert!(finished);
+    }
+}
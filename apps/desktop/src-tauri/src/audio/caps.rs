@@ -0,0 +1,200 @@
+use cpal::traits::DeviceTrait;
+use cpal::{
+    BufferSize, Device, SampleFormat, SampleRate, SupportedBufferSize, SupportedStreamConfig,
+    SupportedStreamConfigRange,
+};
+use serde::Serialize;
+
+use crate::error::TeuImError;
+
+/// 장치가 범위로 알려줄 때 목록에 넣어 보는 일반적인 샘플레이트
+const COMMON_SAMPLE_RATES: [u32; 11] = [
+    8_000, 11_025, 16_000, 22_050, 32_000, 44_100, 48_000, 88_200, 96_000, 176_400, 192_000,
+];
+
+/// 입력 장치의 기본 스트림 설정
+#[derive(Debug, Clone, Serialize)]
+pub struct DefaultStreamConfig {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: String,
+}
+
+/// 입력 장치가 지원하는 스트림 설정
+#[derive(Debug, Clone, Serialize)]
+pub struct DeviceCapabilities {
+    pub device_id: String,
+    pub name: String,
+    /// 지원하는 샘플레이트 (오름차순)
+    pub sample_rates: Vec<u32>,
+    /// 지원하는 채널 수 (오름차순)
+    pub channel_counts: Vec<u16>,
+    /// 지원하는 샘플 형식 (`i16`, `u16`, `f32` 등)
+    pub sample_formats: Vec<String>,
+    /// 버퍼 크기 범위 (프레임 수, 호스트가 알려주지 않으면 없음)
+    pub min_buffer_size: Option<u32>,
+    pub max_buffer_size: Option<u32>,
+    pub default_config: Option<DefaultStreamConfig>,
+}
+
+/// 장치의 지원 설정 목록 조회
+pub fn supported_configs(device: &Device) -> Result<Vec<SupportedStreamConfigRange>, TeuImError> {
+    let device_name = device.name().unwrap_or_default();
+    device
+        .supported_input_configs()
+        .map(|configs| configs.collect())
+        .map_err(|e| match e {
+            cpal::SupportedStreamConfigsError::DeviceNotAvailable => {
+                TeuImError::DeviceBusy { device_name }
+            }
+            e => TeuImError::Stream {
+                message: format!("지원 설정 조회 실패: {}", e),
+            },
+        })
+}
+
+/// cpal 지원 설정 범위를 프런트엔드용 목록으로 정리
+pub fn capabilities(device_id: String, device: &Device) -> Result<DeviceCapabilities, TeuImError> {
+    let configs = supported_configs(device)?;
+
+    let mut sample_rates = Vec::new();
+    let mut channel_counts = Vec::new();
+    let mut sample_formats = Vec::new();
+    let mut buffer_range: Option<(u32, u32)> = None;
+    for config in &configs {
+        let (min, max) = (config.min_sample_rate().0, config.max_sample_rate().0);
+        sample_rates.extend([min, max]);
+        sample_rates.extend(
+            COMMON_SAMPLE_RATES
+                .iter()
+                .filter(|rate| (min..=max).contains(*rate)),
+        );
+        channel_counts.push(config.channels());
+        sample_formats.push(config.sample_format().to_string());
+        if let SupportedBufferSize::Range { min, max } = *config.buffer_size() {
+            buffer_range = Some(match buffer_range {
+                Some((lo, hi)) => (lo.min(min), hi.max(max)),
+                None => (min, max),
+            });
+        }
+    }
+    sample_rates.sort_unstable();
+    sample_rates.dedup();
+    channel_counts.sort_unstable();
+    channel_counts.dedup();
+    sample_formats.sort();
+    sample_formats.dedup();
+
+    let default_config = device
+        .default_input_config()
+        .ok()
+        .map(|config| DefaultStreamConfig {
+            sample_rate: config.sample_rate().0,
+            channels: config.channels(),
+            sample_format: config.sample_format().to_string(),
+        });
+
+    Ok(DeviceCapabilities {
+        device_id,
+        name: device.name().unwrap_or_default(),
+        sample_rates,
+        channel_counts,
+        sample_formats,
+        min_buffer_size: buffer_range.map(|(min, _)| min),
+        max_buffer_size: buffer_range.map(|(_, max)| max),
+        default_config,
+    })
+}
+
+/// 요청한 스트림 설정을 검증해 고른 결과
+pub struct SelectedConfig {
+    pub config: SupportedStreamConfig,
+    pub buffer_size: BufferSize,
+    /// 요청을 따르지 못하고 기본값으로 물러선 이유 (모두 따랐으면 없음)
+    pub fallback: Option<String>,
+}
+
+/// 요청한 샘플레이트와 버퍼 크기를 장치의 지원 설정에 맞춰 고름
+///
+/// 샘플레이트를 지원하는 설정 중 기본 설정과 채널 수·샘플 형식이 같은 것을 우선한다.
+/// 지원하지 않는 값이면 기본 설정(버퍼는 호스트 기본값)으로 물러서고 이유를 남긴다.
+pub fn select_config(
+    device: &Device,
+    default: SupportedStreamConfig,
+    sample_rate: Option<u32>,
+    buffer_size: Option<u32>,
+) -> SelectedConfig {
+    let mut reasons = Vec::new();
+    let supported = if sample_rate.is_some() || buffer_size.is_some() {
+        supported_configs(device).unwrap_or_else(|e| {
+            reasons.push(e.to_string());
+            Vec::new()
+        })
+    } else {
+        Vec::new()
+    };
+
+    let config = match sample_rate {
+        Some(rate) if rate == default.sample_rate().0 => default,
+        Some(rate) => {
+            let rank = |range: &SupportedStreamConfigRange| {
+                (
+                    range.channels() == default.channels(),
+                    range.sample_format() == default.sample_format(),
+                )
+            };
+            let matched = supported
+                .iter()
+                .filter(|range| is_capturable(range.sample_format()))
+                .filter(|range| {
+                    (range.min_sample_rate().0..=range.max_sample_rate().0).contains(&rate)
+                })
+                .max_by_key(|range| rank(range));
+            match matched {
+                Some(range) => range.with_sample_rate(SampleRate(rate)),
+                None => {
+                    reasons.push(format!(
+                        "장치가 {}Hz를 지원하지 않아 기본값 {}Hz로 캡처합니다",
+                        rate,
+                        default.sample_rate().0
+                    ));
+                    default
+                }
+            }
+        }
+        None => default,
+    };
+
+    let buffer_size = match (buffer_size, config.buffer_size()) {
+        (None, _) => BufferSize::Default,
+        (Some(0), _) => {
+            reasons.push("버퍼 크기 0은 쓸 수 없어 호스트 기본값을 사용합니다".to_string());
+            BufferSize::Default
+        }
+        (Some(frames), SupportedBufferSize::Range { min, max })
+            if !(*min..=*max).contains(&frames) =>
+        {
+            reasons.push(format!(
+                "버퍼 크기 {}은(는) 지원 범위({}~{})를 벗어나 호스트 기본값을 사용합니다",
+                frames, min, max
+            ));
+            BufferSize::Default
+        }
+        // 범위를 알려주지 않는 호스트는 요청대로 열어 봄
+        (Some(frames), _) => BufferSize::Fixed(frames),
+    };
+
+    SelectedConfig {
+        config,
+        buffer_size,
+        fallback: (!reasons.is_empty()).then(|| reasons.join(". ")),
+    }
+}
+
+/// 캡처 스트림을 열 수 있는 샘플 형식인지
+fn is_capturable(format: SampleFormat) -> bool {
+    matches!(
+        format,
+        SampleFormat::F32 | SampleFormat::I16 | SampleFormat::U16
+    )
+}
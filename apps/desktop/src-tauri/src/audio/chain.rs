@@ -0,0 +1,102 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::denoise::NoiseSuppressor;
+use super::gain::{GainControl, GainStage};
+use super::resample::MonoResampler;
+
+/// 인터리브 프레임에서 모노 샘플을 꺼내는 방식
+#[derive(Debug, Clone, Copy)]
+pub enum ChannelSource {
+    Single(usize),
+    /// 모든 채널 평균 (-1.0~1.0으로 제한)
+    Average,
+}
+
+impl ChannelSource {
+    fn sample(self, frame: &[f32]) -> f32 {
+        match self {
+            ChannelSource::Single(index) => frame.get(index).copied().unwrap_or(0.0),
+            ChannelSource::Average if frame.is_empty() => 0.0,
+            // f32 장치는 1.0을 넘는 값을 줄 수 있어 평균 뒤에도 제한
+            ChannelSource::Average => {
+                (frame.iter().sum::<f32>() / frame.len() as f32).clamp(-1.0, 1.0)
+            }
+        }
+    }
+}
+
+/// 입력 채널 하나의 처리 단계 (잡음 제거 → 게인 → 리샘플링)
+pub struct ChannelChain {
+    source: ChannelSource,
+    input_rate: u32,
+    output_rate: u32,
+    noise_suppression: Arc<AtomicBool>,
+    denoiser: Option<NoiseSuppressor>,
+    gain: GainStage,
+    resampler: MonoResampler,
+}
+
+impl ChannelChain {
+    pub fn new(
+        source: ChannelSource,
+        input_rate: u32,
+        output_rate: u32,
+        noise_suppression: Arc<AtomicBool>,
+        gain: GainControl,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            source,
+            input_rate,
+            output_rate,
+            noise_suppression,
+            denoiser: None,
+            gain: GainStage::new(gain, input_rate),
+            resampler: MonoResampler::new(input_rate, output_rate)?,
+        })
+    }
+
+    /// 다시 연 장치의 샘플레이트에 맞춰 잡음 제거와 리샘플러를 새로 만듦
+    pub fn set_input_rate(&mut self, input_rate: u32) -> Result<(), String> {
+        self.resampler = MonoResampler::new(input_rate, self.output_rate)?;
+        self.gain.set_sample_rate(input_rate);
+        self.denoiser = None;
+        self.input_rate = input_rate;
+        Ok(())
+    }
+
+    /// 인터리브 샘플에서 모노 샘플을 꺼내 처리한 뒤 출력 레이트 샘플 반환
+    pub fn process(&mut self, interleaved: &[f32], channels: usize) -> Vec<f32> {
+        let mono: Vec<f32> = interleaved
+            .chunks(channels)
+            .map(|frame| self.source.sample(frame))
+            .collect();
+        let mut mono = self.suppress_noise(mono);
+        self.gain.process(&mut mono);
+        self.resampler.process(&mono)
+    }
+
+    /// 잡음 제거가 켜져 있으면 RNNoise 통과 (끄면 상태를 버려 다음에 새로 시작)
+    fn suppress_noise(&mut self, mono: Vec<f32>) -> Vec<f32> {
+        if !self.noise_suppression.load(Ordering::Relaxed) {
+            self.denoiser = None;
+            return mono;
+        }
+
+        if self.denoiser.is_none() {
+            match NoiseSuppressor::new(self.input_rate) {
+                Ok(denoiser) => self.denoiser = Some(denoiser),
+                Err(e) => {
+                    log::error!("잡음 제거 초기화 실패: {}", e);
+                    self.noise_suppression.store(false, Ordering::Relaxed);
+                    return mono;
+                }
+            }
+        }
+
+        self.denoiser
+            .as_mut()
+            .map(|d| d.process(&mono))
+            .unwrap_or(mono)
+    }
+}
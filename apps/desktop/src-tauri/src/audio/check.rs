@@ -0,0 +1,257 @@
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use serde::Serialize;
+use std::f32::consts::TAU;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use super::meter::{to_dbfs, SILENCE_DB};
+use crate::error::TeuImError;
+
+/// 테스트 톤 주파수 (Hz)
+const TONE_FREQUENCY: f32 = 1_000.0;
+
+/// 테스트 톤 길이와 레벨 (-12dBFS)
+const TONE_DURATION: Duration = Duration::from_millis(1_500);
+const TONE_AMPLITUDE: f32 = 0.25;
+
+/// 클릭 소리가 나지 않게 톤 앞뒤를 서서히 키우고 줄이는 길이 (초)
+const TONE_FADE_SECS: f32 = 0.02;
+
+/// 마이크 점검 기본 길이와 허용 범위 (초)
+pub const DEFAULT_MIC_CHECK_SECS: u32 = 5;
+pub const MIC_CHECK_SECS_RANGE: std::ops::RangeInclusive<u32> = 1..=30;
+
+/// 레벨을 재는 구간 길이 (ms)
+const BLOCK_MS: u32 = 50;
+
+/// 이 크기 이상이면 클리핑으로 봄
+const CLIP_THRESHOLD: f32 = 0.99;
+
+/// 판정 기준 (dBFS)
+const NO_SIGNAL_PEAK_DB: f32 = -70.0;
+const QUIET_SPEECH_DB: f32 = -35.0;
+const NOISY_FLOOR_DB: f32 = -45.0;
+const MIN_SPEECH_MARGIN_DB: f32 = 10.0;
+const MIN_SNR_DB: f32 = 20.0;
+
+/// 클리핑으로 문제 삼는 샘플 비율
+const MAX_CLIPPING_RATIO: f32 = 0.001;
+
+/// 마이크 점검에서 찾은 문제
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MicCheckIssue {
+    /// 입력이 거의 없음 (음소거·잘못된 장치·권한 문제)
+    NoSignal,
+    /// 말소리로 볼 만한 구간이 없음
+    NoSpeech,
+    /// 말소리가 너무 작음
+    TooQuiet,
+    /// 입력이 잘림 (게인을 낮춰야 함)
+    Clipping,
+    /// 배경 소음이 큼
+    Noisy,
+}
+
+/// 마이크 점검 결과 (온보딩 화면용)
+#[derive(Debug, Clone, Serialize)]
+pub struct MicCheckReport {
+    pub device_name: String,
+    pub sample_rate: u32,
+    pub duration_secs: f32,
+    /// 조용한 구간의 레벨 (RMS 하위 10%, dBFS)
+    pub noise_floor_db: f32,
+    /// 말하는 구간의 레벨 (RMS 상위 10%, dBFS)
+    pub speech_level_db: f32,
+    pub peak_db: f32,
+    /// 잘린 샘플 비율 (0~1)
+    pub clipping_ratio: f32,
+    pub signal_to_noise_db: f32,
+    pub issues: Vec<MicCheckIssue>,
+    /// 문제가 없으면 `true`
+    pub passed: bool,
+}
+
+/// 출력 장치로 테스트 톤 재생 (끝날 때까지 대기)
+pub fn play_test_tone(device: Device) -> Result<(), TeuImError> {
+    let device_name = device.name().unwrap_or_default();
+    let config = device
+        .default_output_config()
+        .map_err(|e| TeuImError::from_default_config(e, &device_name))?;
+    let stream_config: StreamConfig = config.clone().into();
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_tone_stream::<f32>(&device, &stream_config),
+        SampleFormat::I16 => build_tone_stream::<i16>(&device, &stream_config),
+        SampleFormat::U16 => build_tone_stream::<u16>(&device, &stream_config),
+        format => {
+            return Err(TeuImError::UnsupportedFormat {
+                message: format!("{:?}", format),
+            })
+        }
+    }
+    .map_err(|e| TeuImError::from_build_stream(e, &device_name))?;
+    stream.play().map_err(|e| TeuImError::Stream {
+        message: format!("테스트 톤 재생 실패: {}", e),
+    })?;
+
+    log::info!("테스트 톤 재생: {}", device_name);
+    thread::sleep(TONE_DURATION);
+    Ok(())
+}
+
+fn build_tone_stream<T>(
+    device: &Device,
+    config: &StreamConfig,
+) -> Result<Stream, cpal::BuildStreamError>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let rate = config.sample_rate.0 as f32;
+    let total = TONE_DURATION.as_secs_f32() * rate;
+    let fade = TONE_FADE_SECS * rate;
+    let mut frame_index = 0.0f32;
+    device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            for frame in data.chunks_mut(channels) {
+                let envelope = (frame_index / fade)
+                    .min((total - frame_index) / fade)
+                    .clamp(0.0, 1.0);
+                let sample =
+                    (TAU * TONE_FREQUENCY * frame_index / rate).sin() * TONE_AMPLITUDE * envelope;
+                frame.fill(T::from_sample(sample));
+                frame_index += 1.0;
+            }
+        },
+        |err| log::error!("테스트 톤 스트림 에러: {}", err),
+        None,
+    )
+}
+
+/// 입력 장치에서 `seconds`초 녹음 후 품질 측정
+pub fn run_mic_check(device: Device, seconds: u32) -> Result<MicCheckReport, TeuImError> {
+    let device_name = device.name().unwrap_or_default();
+    let config = device
+        .default_input_config()
+        .map_err(|e| TeuImError::from_default_config(e, &device_name))?;
+    let sample_rate = config.sample_rate().0;
+    let clip = Arc::new(Mutex::new(Vec::new()));
+    let (error_tx, error_rx) = mpsc::channel();
+
+    let stream_config: StreamConfig = config.clone().into();
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_clip_stream::<f32>(&device, &stream_config, &clip, error_tx),
+        SampleFormat::I16 => build_clip_stream::<i16>(&device, &stream_config, &clip, error_tx),
+        SampleFormat::U16 => build_clip_stream::<u16>(&device, &stream_config, &clip, error_tx),
+        format => {
+            return Err(TeuImError::UnsupportedFormat {
+                message: format!("{:?}", format),
+            })
+        }
+    }
+    .map_err(|e| TeuImError::from_build_stream(e, &device_name))?;
+    stream.play().map_err(|e| TeuImError::Stream {
+        message: format!("마이크 점검 녹음 실패: {}", e),
+    })?;
+
+    log::info!("마이크 점검 시작: {} ({}초)", device_name, seconds);
+    if let Ok(err) = error_rx.recv_timeout(Duration::from_secs(seconds.into())) {
+        return Err(TeuImError::Stream {
+            message: format!("마이크 점검 녹음 실패: {}", err),
+        });
+    }
+    drop(stream);
+
+    let samples = std::mem::take(&mut *clip.lock().unwrap());
+    Ok(analyze(device_name, sample_rate, &samples))
+}
+
+/// 첫 번째 채널만 모아 두는 입력 스트림
+fn build_clip_stream<T>(
+    device: &Device,
+    config: &StreamConfig,
+    clip: &Arc<Mutex<Vec<f32>>>,
+    error_tx: mpsc::Sender<cpal::StreamError>,
+) -> Result<Stream, cpal::BuildStreamError>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = config.channels as usize;
+    let clip = clip.clone();
+    device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            let mut clip = clip.lock().unwrap();
+            clip.extend(
+                data.chunks(channels)
+                    .map(|frame| frame[0].to_sample::<f32>()),
+            );
+        },
+        move |err| {
+            let _ = error_tx.send(err);
+        },
+        None,
+    )
+}
+
+/// 구간별 RMS로 잡음 바닥, 말소리 레벨, 클리핑을 측정해 판정
+fn analyze(device_name: String, sample_rate: u32, samples: &[f32]) -> MicCheckReport {
+    let block = (sample_rate * BLOCK_MS / 1000).max(1) as usize;
+    let mut levels: Vec<f32> = samples
+        .chunks(block)
+        .map(|chunk| {
+            let sum: f32 = chunk.iter().map(|s| s * s).sum();
+            to_dbfs((sum / chunk.len() as f32).sqrt())
+        })
+        .collect();
+    levels.sort_by(f32::total_cmp);
+    let percentile = |p: f32| {
+        levels
+            .get(((levels.len() as f32 - 1.0) * p).round() as usize)
+            .copied()
+            .unwrap_or(SILENCE_DB)
+    };
+    let noise_floor_db = percentile(0.1);
+    let speech_level_db = percentile(0.9);
+    let peak_db = to_dbfs(samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs())));
+    let clipped = samples.iter().filter(|s| s.abs() >= CLIP_THRESHOLD).count();
+    let clipping_ratio = clipped as f32 / samples.len().max(1) as f32;
+    let signal_to_noise_db = speech_level_db - noise_floor_db;
+
+    let mut issues = Vec::new();
+    if peak_db < NO_SIGNAL_PEAK_DB {
+        issues.push(MicCheckIssue::NoSignal);
+    } else {
+        if signal_to_noise_db < MIN_SPEECH_MARGIN_DB {
+            issues.push(MicCheckIssue::NoSpeech);
+        } else if speech_level_db < QUIET_SPEECH_DB {
+            issues.push(MicCheckIssue::TooQuiet);
+        }
+        if clipping_ratio > MAX_CLIPPING_RATIO {
+            issues.push(MicCheckIssue::Clipping);
+        }
+        if noise_floor_db > NOISY_FLOOR_DB
+            || (MIN_SPEECH_MARGIN_DB..MIN_SNR_DB).contains(&signal_to_noise_db)
+        {
+            issues.push(MicCheckIssue::Noisy);
+        }
+    }
+
+    MicCheckReport {
+        device_name,
+        sample_rate,
+        duration_secs: samples.len() as f32 / sample_rate as f32,
+        noise_floor_db,
+        speech_level_db,
+        peak_db,
+        clipping_ratio,
+        signal_to_noise_db,
+        passed: issues.is_empty(),
+        issues,
+    }
+}
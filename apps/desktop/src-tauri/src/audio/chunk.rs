@@ -0,0 +1,48 @@
+use std::collections::VecDeque;
+
+/// 기본 청크 길이 (ms)
+pub const DEFAULT_CHUNK_MS: u32 = 100;
+
+/// 허용하는 청크 길이 범위 (ms)
+pub const CHUNK_MS_RANGE: std::ops::RangeInclusive<u32> = 10..=2000;
+
+/// 고정 길이 청크 누적기
+///
+/// cpal 콜백 버퍼 크기(64~2048 프레임)와 무관하게 정해진 길이의 청크만
+/// 내보내서 이벤트 발생 빈도를 일정하게 유지한다. 다채널이면 인터리브된
+/// 샘플을 프레임 단위로 자른다.
+pub struct ChunkAccumulator {
+    buffer: VecDeque<f32>,
+    chunk_len: usize,
+}
+
+impl ChunkAccumulator {
+    pub fn new(sample_rate: u32, chunk_ms: u32, channels: usize) -> Self {
+        let frames = ((sample_rate as u64 * chunk_ms as u64) / 1000).max(1) as usize;
+        let chunk_len = frames * channels.max(1);
+        Self {
+            buffer: VecDeque::with_capacity(chunk_len * 2),
+            chunk_len,
+        }
+    }
+
+    /// 샘플을 쌓고 완성된 청크들을 반환
+    pub fn push(&mut self, samples: &[f32]) -> Vec<Vec<f32>> {
+        self.buffer.extend(samples);
+
+        let mut chunks = Vec::new();
+        while self.buffer.len() >= self.chunk_len {
+            chunks.push(self.buffer.drain(..self.chunk_len).collect());
+        }
+        chunks
+    }
+
+    /// 남은 샘플을 마지막 청크로 반환
+    pub fn flush(&mut self) -> Option<Vec<f32>> {
+        if self.buffer.is_empty() {
+            None
+        } else {
+            Some(self.buffer.drain(..).collect())
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::CaptureSource;
+
+/// 이 크기 이상인 샘플을 최대 레벨로 봄
+const FULL_SCALE: f32 = 0.999;
+
+/// 최대 레벨 샘플이 이만큼 연속되면 클리핑으로 봄
+const MIN_CLIP_RUN: usize = 3;
+
+/// 클리핑을 모아 알리는 구간 길이 (ms)
+const REPORT_WINDOW_MS: u64 = 500;
+
+/// 클리핑 정도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipSeverity {
+    /// 잠깐 닿은 정도 (전사에 거의 영향 없음)
+    Minor,
+    /// 들리는 왜곡
+    Moderate,
+    /// 구간 전사를 믿기 어려운 왜곡
+    Severe,
+}
+
+impl ClipSeverity {
+    /// 구간 샘플 중 클리핑 비율과 가장 긴 연속 길이로 판단
+    fn classify(clipped: usize, total: usize, longest_run: usize) -> Self {
+        let ratio = clipped as f64 / total.max(1) as f64;
+        if ratio >= 0.01 || longest_run >= 64 {
+            Self::Severe
+        } else if ratio >= 0.001 || longest_run >= 16 {
+            Self::Moderate
+        } else {
+            Self::Minor
+        }
+    }
+}
+
+/// `audio-clipping` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioClipping {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub timestamp: DateTime<Utc>,
+    /// 캡처 스트림 기준 구간 시작 위치 (ms, 일시정지 구간 제외)
+    pub offset_ms: u64,
+    pub duration_ms: u64,
+    /// 구간에서 클리핑으로 본 샘플 수 (모든 채널 합)
+    pub clipped_samples: usize,
+    /// 가장 길게 이어진 최대 레벨 샘플 수
+    pub longest_run: usize,
+    pub severity: ClipSeverity,
+}
+
+/// 콜백 입력에서 연속된 최대 레벨 샘플을 찾아 구간별로 알림
+pub struct ClipDetector {
+    session_id: String,
+    source: CaptureSource,
+    channels: usize,
+    input_rate: u32,
+    /// 채널별로 지금 이어지고 있는 최대 레벨 샘플 수
+    runs: Vec<usize>,
+    /// 지금까지 처리한 프레임 수
+    frames: u64,
+    window_start: u64,
+    window_frames: u64,
+    clipped: usize,
+    longest_run: usize,
+}
+
+impl ClipDetector {
+    pub fn new(
+        session_id: String,
+        source: CaptureSource,
+        channels: usize,
+        input_rate: u32,
+    ) -> Self {
+        Self {
+            session_id,
+            source,
+            channels: channels.max(1),
+            input_rate,
+            runs: vec![0; channels.max(1)],
+            frames: 0,
+            window_start: 0,
+            window_frames: (input_rate as u64 * REPORT_WINDOW_MS / 1000).max(1),
+            clipped: 0,
+            longest_run: 0,
+        }
+    }
+
+    /// 복구하며 연 장치의 채널 수나 샘플레이트가 바뀌면 다시 셈
+    pub fn reconfigure(&mut self, channels: usize, input_rate: u32) {
+        let frames = self.frames;
+        *self = Self::new(self.session_id.clone(), self.source, channels, input_rate);
+        self.frames = frames;
+        self.window_start = frames;
+    }
+
+    /// 일시정지처럼 입력이 끊기면 이어지던 구간을 버림
+    pub fn reset(&mut self) {
+        self.runs.fill(0);
+        self.window_start = self.frames;
+        self.clipped = 0;
+        self.longest_run = 0;
+    }
+
+    /// 인터리브 샘플을 넣고 클리핑이 있던 구간이 끝나면 이벤트 반환
+    ///
+    /// 두 번째 값은 마지막으로 끝난 구간에 클리핑이 있었는지 (없던 구간이 끝나면 `false`).
+    pub fn process(&mut self, interleaved: &[f32]) -> (Option<AudioClipping>, Option<bool>) {
+        let mut report = None;
+        let mut clipping = None;
+        for frame in interleaved.chunks(self.channels) {
+            for (run, sample) in self.runs.iter_mut().zip(frame) {
+                if sample.abs() < FULL_SCALE {
+                    if *run >= MIN_CLIP_RUN {
+                        self.clipped += *run;
+                        self.longest_run = self.longest_run.max(*run);
+                    }
+                    *run = 0;
+                } else {
+                    *run += 1;
+                }
+            }
+            self.frames += 1;
+            if self.frames - self.window_start >= self.window_frames {
+                let event = self.close_window();
+                clipping = Some(event.is_some());
+                report = event.or(report);
+            }
+        }
+        (report, clipping)
+    }
+
+    fn close_window(&mut self) -> Option<AudioClipping> {
+        // 구간 경계에 걸친 연속 구간도 이 구간에 넣음
+        for run in &mut self.runs {
+            if *run >= MIN_CLIP_RUN {
+                self.clipped += *run;
+                self.longest_run = self.longest_run.max(*run);
+                *run = 0;
+            }
+        }
+        let frames = self.frames - self.window_start;
+        let offset_ms = self.window_start * 1000 / self.input_rate as u64;
+        let clipped = std::mem::take(&mut self.clipped);
+        let longest_run = std::mem::take(&mut self.longest_run);
+        self.window_start = self.frames;
+        if clipped == 0 {
+            return None;
+        }
+
+        let total = frames as usize * self.channels;
+        Some(AudioClipping {
+            session_id: self.session_id.clone(),
+            source: self.source,
+            timestamp: Utc::now(),
+            offset_ms,
+            duration_ms: frames * 1000 / self.input_rate as u64,
+            clipped_samples: clipped,
+            longest_run,
+            severity: ClipSeverity::classify(clipped, total, longest_run),
+        })
+    }
+}
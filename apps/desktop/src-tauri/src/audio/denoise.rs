@@ -0,0 +1,60 @@
+use nnnoiseless::DenoiseState;
+
+use super::resample::MonoResampler;
+
+/// RNNoise가 처리하는 샘플레이트
+const DENOISE_RATE: u32 = 48_000;
+
+/// RNNoise(nnnoiseless) 잡음 제거 단계
+///
+/// 입력 레이트가 48kHz가 아니면 48kHz로 올려 처리한 뒤 원래 레이트로 되돌린다.
+pub struct NoiseSuppressor {
+    state: Box<DenoiseState<'static>>,
+    to_denoise_rate: MonoResampler,
+    from_denoise_rate: MonoResampler,
+    pending: Vec<f32>,
+    frame: Vec<f32>,
+    warmed_up: bool,
+}
+
+impl NoiseSuppressor {
+    pub fn new(sample_rate: u32) -> Result<Self, String> {
+        Ok(Self {
+            state: DenoiseState::new(),
+            to_denoise_rate: MonoResampler::new(sample_rate, DENOISE_RATE)?,
+            from_denoise_rate: MonoResampler::new(DENOISE_RATE, sample_rate)?,
+            pending: Vec::new(),
+            frame: vec![0.0; DenoiseState::FRAME_SIZE],
+            warmed_up: false,
+        })
+    }
+
+    /// 모노 샘플(-1.0~1.0) 처리 (프레임 단위라 출력 길이는 입력과 다를 수 있음)
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        // RNNoise는 16비트 정수 범위의 값을 기대함
+        self.pending.extend(
+            self.to_denoise_rate
+                .process(samples)
+                .iter()
+                .map(|s| s * 32768.0),
+        );
+
+        let mut denoised = Vec::new();
+        let frame_size = DenoiseState::FRAME_SIZE;
+        while self.pending.len() >= frame_size {
+            self.state
+                .process_frame(&mut self.frame, &self.pending[..frame_size]);
+            self.pending.drain(..frame_size);
+
+            if self.warmed_up {
+                denoised.extend(self.frame.iter().map(|s| s / 32768.0));
+            } else {
+                // 첫 프레임은 페이드인 잡음이 섞여 있어 무음으로 대체
+                denoised.extend(std::iter::repeat_n(0.0, frame_size));
+                self.warmed_up = true;
+            }
+        }
+
+        self.from_denoise_rate.process(&denoised)
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::{Arc, Mutex};
+
+use super::meter::to_dbfs;
+
+/// 기본 AGC 목표 레벨 (dBFS, RMS 기준)
+pub const DEFAULT_AGC_TARGET_DB: f32 = -20.0;
+
+/// 수동 게인 허용 범위 (dB)
+pub const INPUT_GAIN_RANGE: std::ops::RangeInclusive<f32> = -20.0..=30.0;
+
+/// AGC가 적용하는 게인 범위 (dB)
+const AGC_MIN_DB: f32 = -10.0;
+const AGC_MAX_DB: f32 = 30.0;
+
+/// 이보다 조용한 블록은 잡음으로 보고 게인을 올리지 않음 (dBFS)
+const NOISE_GATE_DB: f32 = -55.0;
+
+/// 게인을 내릴 때/올릴 때 시간 상수 (초)
+const ATTACK_SECS: f32 = 0.05;
+const RELEASE_SECS: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy)]
+struct GainSettings {
+    manual_db: f32,
+    agc_target_db: Option<f32>,
+}
+
+/// 모든 캡처 세션이 공유하는 게인 설정
+#[derive(Clone)]
+pub struct GainControl {
+    settings: Arc<Mutex<GainSettings>>,
+}
+
+impl Default for GainControl {
+    fn default() -> Self {
+        Self {
+            settings: Arc::new(Mutex::new(GainSettings {
+                manual_db: 0.0,
+                agc_target_db: None,
+            })),
+        }
+    }
+}
+
+impl GainControl {
+    pub fn set_manual_db(&self, db: f32) {
+        self.settings.lock().unwrap().manual_db = db;
+    }
+
+    /// `None`이면 AGC 끔
+    pub fn set_agc_target_db(&self, target_db: Option<f32>) {
+        self.settings.lock().unwrap().agc_target_db = target_db;
+    }
+
+    fn get(&self) -> GainSettings {
+        *self.settings.lock().unwrap()
+    }
+}
+
+/// 세션별 게인 단계 (수동 게인 → AGC)
+pub struct GainStage {
+    control: GainControl,
+    sample_rate: u32,
+    agc_db: f32,
+}
+
+impl GainStage {
+    pub fn new(control: GainControl, sample_rate: u32) -> Self {
+        Self {
+            control,
+            sample_rate,
+            agc_db: 0.0,
+        }
+    }
+
+    /// 장치가 바뀌어 입력 샘플레이트가 달라졌을 때 (AGC 게인은 유지)
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// 모노 샘플에 게인을 적용 (결과는 -1.0~1.0으로 제한)
+    pub fn process(&mut self, samples: &mut [f32]) {
+        if samples.is_empty() {
+            return;
+        }
+        let settings = self.control.get();
+        let manual = 10f32.powf(settings.manual_db / 20.0);
+
+        match settings.agc_target_db {
+            Some(target_db) => self.update_agc(samples, manual, target_db),
+            // 다시 켤 때 이전 게인에서 튀지 않게 초기화
+            None => self.agc_db = 0.0,
+        }
+
+        let gain = manual * 10f32.powf(self.agc_db / 20.0);
+        if gain == 1.0 {
+            return;
+        }
+        for sample in samples.iter_mut() {
+            *sample = (*sample * gain).clamp(-1.0, 1.0);
+        }
+    }
+
+    /// 블록 RMS가 목표 레벨에 가까워지도록 AGC 게인을 천천히 조정
+    fn update_agc(&mut self, samples: &[f32], manual: f32, target_db: f32) {
+        let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+        let level_db = to_dbfs(mean_square.sqrt() * manual);
+        if level_db < NOISE_GATE_DB {
+            return;
+        }
+
+        let desired = (target_db - level_db).clamp(AGC_MIN_DB, AGC_MAX_DB);
+        let block_secs = samples.len() as f32 / self.sample_rate as f32;
+        let tau = if desired < self.agc_db {
+            ATTACK_SECS
+        } else {
+            RELEASE_SECS
+        };
+        let alpha = 1.0 - (-block_secs / tau).exp();
+        self.agc_db += (desired - self.agc_db) * alpha;
+    }
+}
@@ -0,0 +1,268 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use tauri::ipc::{Channel, InvokeResponseBody};
+
+use super::gain::GainControl;
+use super::monitor::Monitor;
+use super::replay::ReplayBuffer;
+use super::stats::CaptureStats;
+use super::CaptureSource;
+use crate::error::TeuImError;
+use crate::recording::Recorder;
+use crate::remote_mic::RemoteTap;
+use crate::transcribe::TranscriptionFeed;
+
+/// 실행 중인 캡처 세션 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureSessionInfo {
+    pub id: String,
+    pub source: CaptureSource,
+    pub device_id: String,
+    pub device_name: String,
+    pub sample_rate: u32,
+    pub started_at: DateTime<Utc>,
+    pub paused: bool,
+    /// 일시정지로 건너뛴 누적 시간 (ms)
+    ///
+    /// 일시정지 중에는 오디오를 내보내지 않으므로 전사 타임스탬프는
+    /// 경과 시간에서 이 값을 뺀 "내용 시간" 기준이다.
+    pub paused_ms: i64,
+    /// 바이너리 오디오 프레임 헤더에 실리는 스트림 번호 (실행 중 세션마다 다름)
+    pub stream_index: u32,
+}
+
+/// 캡처 스레드 핸들
+struct CaptureHandle {
+    info: CaptureSessionInfo,
+    stop_tx: Sender<()>,
+    thread: JoinHandle<()>,
+    /// 캡처 콜백과 공유하는 일시정지 스위치
+    paused: Arc<AtomicBool>,
+    paused_at: Option<DateTime<Utc>>,
+    /// 캡처 콜백이 갱신하는 스트림 통계
+    stats: Arc<Mutex<CaptureStats>>,
+    /// 오디오 버스 소비자 스레드 (캡처 스레드가 끝나면 뒤따라 종료)
+    consumers: Vec<JoinHandle<()>>,
+}
+
+impl CaptureHandle {
+    /// 진행 중인 일시정지 시간까지 반영한 세션 정보
+    fn snapshot(&self) -> CaptureSessionInfo {
+        let mut info = self.info.clone();
+        if let Some(paused_at) = self.paused_at {
+            info.paused_ms += (Utc::now() - paused_at).num_milliseconds();
+        }
+        info
+    }
+}
+
+/// 캡처 세션 관리자 (Tauri 관리 상태)
+///
+/// 시작/중지가 빠르게 반복돼도 세션 목록 잠금 안에서 중복 검사와 등록이
+/// 함께 이뤄지므로 같은 장치에 스트림이 두 번 열리지 않는다.
+#[derive(Default)]
+pub struct CaptureManager {
+    sessions: Mutex<HashMap<String, CaptureHandle>>,
+    subscribers: AudioSubscribers,
+    next_stream_index: AtomicU32,
+    recorder: Recorder,
+    remote_tap: RemoteTap,
+    monitor: Monitor,
+    replay: ReplayBuffer,
+    transcription_feed: TranscriptionFeed,
+    noise_suppression: Arc<AtomicBool>,
+    muted: Arc<AtomicBool>,
+    push_to_talk: Arc<AtomicBool>,
+    gain: GainControl,
+}
+
+/// 바이너리 오디오 채널 구독자 목록 (채널 ID → 채널)
+pub type AudioSubscribers = Arc<Mutex<HashMap<u32, Channel<InvokeResponseBody>>>>;
+
+impl CaptureManager {
+    /// 새 캡처 세션 등록 후 ID 반환
+    ///
+    /// `spawn`은 중지 신호 수신단을 받아 캡처 스레드를 띄우고, 스트림이
+    /// 실제로 열린 뒤에 스레드 핸들을 돌려줘야 한다. `paused`는 캡처 콜백에
+    /// 넘긴 일시정지 스위치, `stats`는 캡처 콜백이 갱신하는 통계,
+    /// `consumers`는 세션 오디오 버스의 소비자 스레드다.
+    pub fn start<F>(
+        &self,
+        info: CaptureSessionInfo,
+        paused: Arc<AtomicBool>,
+        stats: Arc<Mutex<CaptureStats>>,
+        consumers: Vec<JoinHandle<()>>,
+        spawn: F,
+    ) -> Result<String, TeuImError>
+    where
+        F: FnOnce(Receiver<()>) -> Result<JoinHandle<()>, TeuImError>,
+    {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        if sessions
+            .values()
+            .any(|s| s.info.source == info.source && s.info.device_id == info.device_id)
+        {
+            return Err(TeuImError::DeviceBusy {
+                device_name: info.device_name,
+            });
+        }
+
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let thread = spawn(stop_rx)?;
+
+        let id = info.id.clone();
+        sessions.insert(id.clone(), CaptureHandle {
+            info,
+            stop_tx,
+            thread,
+            paused,
+            paused_at: None,
+            stats,
+            consumers,
+        });
+
+        Ok(id)
+    }
+
+    /// 캡처 콜백과 공유하는 바이너리 채널 구독자 목록
+    pub fn subscribers(&self) -> AudioSubscribers {
+        self.subscribers.clone()
+    }
+
+    /// 새 캡처 세션에 붙일 스트림 번호
+    pub fn next_stream_index(&self) -> u32 {
+        self.next_stream_index.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// 캡처 콜백과 공유하는 녹음기
+    pub fn recorder(&self) -> Recorder {
+        self.recorder.clone()
+    }
+
+    /// 캡처 세션 오디오를 출력 장치로 들려주는 모니터
+    pub fn monitor(&self) -> Monitor {
+        self.monitor.clone()
+    }
+
+    /// 캡처 세션별 최근 오디오 (다시 듣기용)
+    pub fn replay(&self) -> ReplayBuffer {
+        self.replay.clone()
+    }
+
+    /// 캡처 세션 오디오를 원격 마이크로 보내는 송신단
+    pub fn remote_tap(&self) -> RemoteTap {
+        self.remote_tap.clone()
+    }
+
+    /// 캡처 콜백과 공유하는 전사 입력 통로
+    pub fn transcription_feed(&self) -> TranscriptionFeed {
+        self.transcription_feed.clone()
+    }
+
+    /// 모든 캡처 세션이 공유하는 잡음 제거 스위치
+    pub fn noise_suppression(&self) -> Arc<AtomicBool> {
+        self.noise_suppression.clone()
+    }
+
+    /// 마이크 캡처 세션이 공유하는 음소거 스위치
+    pub fn muted(&self) -> Arc<AtomicBool> {
+        self.muted.clone()
+    }
+
+    /// 마이크 캡처 세션이 공유하는 눌러서 말하기 스위치 (켜져 있으면 키를 뗀 상태라 일시정지)
+    pub fn push_to_talk(&self) -> Arc<AtomicBool> {
+        self.push_to_talk.clone()
+    }
+
+    /// 모든 캡처 세션이 공유하는 입력 게인 설정
+    pub fn gain(&self) -> GainControl {
+        self.gain.clone()
+    }
+
+    /// 실행 중인 세션 목록
+    pub fn list(&self) -> Vec<CaptureSessionInfo> {
+        let sessions = self.sessions.lock().unwrap();
+        let mut list: Vec<_> = sessions.values().map(CaptureHandle::snapshot).collect();
+        list.sort_by_key(|info| info.started_at);
+        list
+    }
+
+    /// 실행 중인 세션별 스트림 통계 (시작 순서)
+    pub fn stats(&self) -> Vec<CaptureStats> {
+        let sessions = self.sessions.lock().unwrap();
+        let mut handles: Vec<_> = sessions.values().collect();
+        handles.sort_by_key(|handle| handle.info.started_at);
+        handles
+            .into_iter()
+            .map(|handle| handle.stats.lock().unwrap().clone())
+            .collect()
+    }
+
+    /// 일시정지/재개 (스트림은 열어 둔 채 내보내기와 녹음만 멈춤)
+    pub fn set_paused(
+        &self,
+        session_id: &str,
+        paused: bool,
+    ) -> Result<CaptureSessionInfo, TeuImError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let handle = sessions.get_mut(session_id).ok_or_else(|| {
+            TeuImError::CaptureSessionNotFound {
+                session_id: session_id.to_string(),
+            }
+        })?;
+
+        if handle.info.paused != paused {
+            let now = Utc::now();
+            match handle.paused_at.take() {
+                Some(paused_at) => handle.info.paused_ms += (now - paused_at).num_milliseconds(),
+                None => handle.paused_at = Some(now),
+            }
+            handle.info.paused = paused;
+            handle.paused.store(paused, Ordering::Relaxed);
+        }
+
+        Ok(handle.snapshot())
+    }
+
+    /// 세션 중지 후 스레드 종료 대기
+    pub fn stop(&self, session_id: &str) -> Result<(), TeuImError> {
+        let handle = self
+            .sessions
+            .lock()
+            .unwrap()
+            .remove(session_id)
+            .ok_or_else(|| TeuImError::CaptureSessionNotFound {
+                session_id: session_id.to_string(),
+            })?;
+
+        join_capture(handle);
+        Ok(())
+    }
+
+    /// 모든 세션 중지
+    pub fn stop_all(&self) {
+        let handles: Vec<_> = self.sessions.lock().unwrap().drain().map(|(_, h)| h).collect();
+        for handle in handles {
+            join_capture(handle);
+        }
+    }
+}
+
+fn join_capture(handle: CaptureHandle) {
+    let _ = handle.stop_tx.send(());
+    if handle.thread.join().is_err() {
+        log::error!("캡처 스레드가 비정상 종료됨: {}", handle.info.id);
+    }
+    // 녹음·전사 소비자가 밀린 샘플까지 처리한 뒤에 반환
+    for consumer in handle.consumers {
+        if consumer.join().is_err() {
+            log::error!("오디오 버스 소비자가 비정상 종료됨: {}", handle.info.id);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/// 레벨 이벤트 간격 (10Hz)
+const LEVEL_INTERVAL_MS: u32 = 100;
+
+/// 무음으로 간주하는 최저 레벨 (dBFS)
+pub const SILENCE_DB: f32 = -100.0;
+
+/// 진폭(0~1)을 dBFS로 변환
+pub fn to_dbfs(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        SILENCE_DB
+    } else {
+        (20.0 * amplitude.log10()).max(SILENCE_DB)
+    }
+}
+
+/// 채널별 RMS/피크 레벨 (dBFS)
+#[derive(Debug, Clone)]
+pub struct ChannelLevels {
+    pub rms_db: Vec<f32>,
+    pub peak_db: Vec<f32>,
+}
+
+/// 채널별 레벨 측정기
+///
+/// 콜백마다 제곱합과 피크를 누적하다가 100ms 분량이 모이면 한 번 결과를 낸다.
+pub struct LevelMeter {
+    channels: usize,
+    sum_squares: Vec<f64>,
+    peaks: Vec<f32>,
+    frames: usize,
+    interval_frames: usize,
+}
+
+impl LevelMeter {
+    pub fn new(channels: usize, sample_rate: u32) -> Self {
+        Self {
+            channels,
+            sum_squares: vec![0.0; channels],
+            peaks: vec![0.0; channels],
+            frames: 0,
+            interval_frames: (sample_rate * LEVEL_INTERVAL_MS / 1000).max(1) as usize,
+        }
+    }
+
+    /// 인터리브된 샘플을 누적하고 측정 구간이 끝나면 레벨 반환
+    pub fn process(&mut self, interleaved: &[f32]) -> Option<ChannelLevels> {
+        for frame in interleaved.chunks_exact(self.channels) {
+            for (ch, sample) in frame.iter().enumerate() {
+                self.sum_squares[ch] += (*sample as f64) * (*sample as f64);
+                self.peaks[ch] = self.peaks[ch].max(sample.abs());
+            }
+            self.frames += 1;
+        }
+
+        if self.frames < self.interval_frames {
+            return None;
+        }
+
+        let frames = self.frames as f64;
+        let levels = ChannelLevels {
+            rms_db: self
+                .sum_squares
+                .iter()
+                .map(|sum| to_dbfs((sum / frames).sqrt() as f32))
+                .collect(),
+            peak_db: self.peaks.iter().map(|peak| to_dbfs(*peak)).collect(),
+        };
+
+        self.sum_squares.fill(0.0);
+        self.peaks.fill(0.0);
+        self.frames = 0;
+        Some(levels)
+    }
+}
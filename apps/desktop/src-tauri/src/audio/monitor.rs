@@ -0,0 +1,270 @@
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::{Device, FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use rtrb::{Consumer, Producer, RingBuffer};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use super::resample::MonoResampler;
+use crate::error::TeuImError;
+
+/// 기본 모니터링 지연 (ms)
+pub const DEFAULT_MONITOR_LATENCY_MS: u32 = 60;
+
+/// 모니터링 지연 허용 범위 (ms)
+pub const MONITOR_LATENCY_RANGE: std::ops::RangeInclusive<u32> = 10..=1_000;
+
+/// 모니터링 음량 허용 범위 (1이 원래 크기)
+pub const MONITOR_VOLUME_RANGE: std::ops::RangeInclusive<f32> = 0.0..=2.0;
+
+/// 버퍼가 목표 지연의 이 배수를 넘게 쌓이면 밀린 샘플을 버려 지연을 되돌림
+const MAX_BACKLOG_FACTOR: usize = 3;
+
+/// 모니터링 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct MonitorStatus {
+    /// 들려주는 캡처 세션
+    pub session_id: String,
+    pub output_device_name: String,
+    pub volume: f32,
+    pub latency_ms: u32,
+}
+
+struct ActiveMonitor {
+    status: MonitorStatus,
+    resampler: MonoResampler,
+    producer: Producer<f32>,
+    volume: Arc<AtomicU32>,
+    stop_tx: mpsc::Sender<()>,
+    thread: JoinHandle<()>,
+}
+
+/// 캡처 세션 오디오를 출력 장치로 들려주는 모니터
+///
+/// 캡처 세션의 버스 소비자와 명령 핸들러가 같은 상태를 공유하도록 복제 가능한
+/// 핸들로 둔다. 소비자 스레드가 출력 레이트로 변환해 lock-free 링 버퍼에 넣고,
+/// 출력 콜백은 지연 목표만큼 쌓인 뒤부터 꺼내 재생한다.
+#[derive(Clone, Default)]
+pub struct Monitor {
+    active: Arc<Mutex<Option<ActiveMonitor>>>,
+}
+
+impl Monitor {
+    /// 모니터링 시작 (이미 모니터링 중이면 바꿈)
+    pub fn start(
+        &self,
+        session_id: String,
+        input_rate: u32,
+        device: Device,
+        volume: f32,
+        latency_ms: u32,
+    ) -> Result<MonitorStatus, TeuImError> {
+        self.stop();
+
+        let device_name = device.name().unwrap_or_default();
+        let config = device
+            .default_output_config()
+            .map_err(|e| TeuImError::from_default_config(e, &device_name))?;
+        let output_rate = config.sample_rate().0;
+        let latency_frames = (output_rate as usize * latency_ms as usize / 1000).max(1);
+        let (producer, consumer) = RingBuffer::new(latency_frames * MAX_BACKLOG_FACTOR * 2);
+        let resampler =
+            MonoResampler::new(input_rate, output_rate).map_err(TeuImError::invalid_argument)?;
+        let volume = Arc::new(AtomicU32::new(volume.to_bits()));
+
+        // 출력 스트림은 스레드를 벗어날 수 없어(`!Send`) 전용 스레드에서 소유
+        let playback = Playback {
+            consumer,
+            volume: volume.clone(),
+            latency_frames,
+            primed: false,
+        };
+        let (ready_tx, ready_rx) = mpsc::sync_channel(1);
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let thread = thread::spawn(move || run_output(device, config, playback, ready_tx, stop_rx));
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                return Err(e);
+            }
+            Err(_) => {
+                return Err(TeuImError::Stream {
+                    message: "모니터링 스레드가 시작 전에 종료됨".to_string(),
+                })
+            }
+        }
+
+        let status = MonitorStatus {
+            session_id,
+            output_device_name: device_name,
+            volume: f32::from_bits(volume.load(Ordering::Relaxed)),
+            latency_ms,
+        };
+        log::info!(
+            "모니터링 시작: {} -> {} ({}ms)",
+            status.session_id,
+            status.output_device_name,
+            latency_ms
+        );
+        *self.active.lock().unwrap() = Some(ActiveMonitor {
+            status: status.clone(),
+            resampler,
+            producer,
+            volume,
+            stop_tx,
+            thread,
+        });
+        Ok(status)
+    }
+
+    /// 모니터링 중지 (모니터링 중이었으면 `true`)
+    pub fn stop(&self) -> bool {
+        let Some(active) = self.active.lock().unwrap().take() else {
+            return false;
+        };
+        let _ = active.stop_tx.send(());
+        if active.thread.join().is_err() {
+            log::error!("모니터링 스레드가 비정상 종료됨");
+        }
+        log::info!("모니터링 중지: {}", active.status.session_id);
+        true
+    }
+
+    pub fn set_volume(&self, volume: f32) -> Option<MonitorStatus> {
+        let mut active = self.active.lock().unwrap();
+        let active = active.as_mut()?;
+        active.volume.store(volume.to_bits(), Ordering::Relaxed);
+        active.status.volume = volume;
+        Some(active.status.clone())
+    }
+
+    pub fn status(&self) -> Option<MonitorStatus> {
+        self.active
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|active| active.status.clone())
+    }
+
+    /// 모니터링 중인 세션의 샘플을 출력 버퍼로 전달 (밀리면 넘친 샘플은 버림)
+    pub fn write(&self, session_id: &str, samples: &[f32]) {
+        let mut active = self.active.lock().unwrap();
+        let Some(active) = active.as_mut() else {
+            return;
+        };
+        if active.status.session_id != session_id {
+            return;
+        }
+
+        let converted = active.resampler.process(samples);
+        let count = converted.len().min(active.producer.slots());
+        if let Ok(chunk) = active.producer.write_chunk_uninit(count) {
+            chunk.fill_from_iter(converted);
+        }
+    }
+}
+
+/// 출력 콜백이 가진 링 버퍼 읽기 끝
+struct Playback {
+    consumer: Consumer<f32>,
+    volume: Arc<AtomicU32>,
+    /// 재생을 시작하기 전에 쌓아 둘 프레임 수
+    latency_frames: usize,
+    primed: bool,
+}
+
+impl Playback {
+    /// 다음 샘플 (버퍼가 비면 무음을 내고 다시 지연만큼 쌓일 때까지 대기)
+    fn next(&mut self) -> f32 {
+        if !self.primed {
+            if self.consumer.slots() < self.latency_frames {
+                return 0.0;
+            }
+            self.primed = true;
+        }
+        match self.consumer.pop() {
+            Ok(sample) => sample * f32::from_bits(self.volume.load(Ordering::Relaxed)),
+            Err(_) => {
+                self.primed = false;
+                0.0
+            }
+        }
+    }
+
+    /// 밀린 샘플을 버려 목표 지연으로 되돌림
+    fn trim_backlog(&mut self) {
+        let available = self.consumer.slots();
+        if available > self.latency_frames * MAX_BACKLOG_FACTOR {
+            let excess = available - self.latency_frames;
+            if let Ok(chunk) = self.consumer.read_chunk(excess) {
+                chunk.commit_all();
+            }
+        }
+    }
+}
+
+/// 출력 스트림을 열고 중지 신호까지 유지
+fn run_output(
+    device: Device,
+    config: cpal::SupportedStreamConfig,
+    playback: Playback,
+    ready_tx: SyncSender<Result<(), TeuImError>>,
+    stop_rx: Receiver<()>,
+) {
+    let device_name = device.name().unwrap_or_default();
+    let stream_config: StreamConfig = config.clone().into();
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_stream::<f32>(&device, &stream_config, playback),
+        SampleFormat::I16 => build_stream::<i16>(&device, &stream_config, playback),
+        SampleFormat::U16 => build_stream::<u16>(&device, &stream_config, playback),
+        format => Err(cpal::BuildStreamError::BackendSpecific {
+            err: cpal::BackendSpecificError {
+                description: format!("지원하지 않는 출력 형식: {:?}", format),
+            },
+        }),
+    }
+    .map_err(|e| TeuImError::from_build_stream(e, &device_name));
+    let stream = match stream {
+        Ok(stream) => stream,
+        Err(e) => {
+            let _ = ready_tx.send(Err(e));
+            return;
+        }
+    };
+    if let Err(e) = stream.play() {
+        let _ = ready_tx.send(Err(TeuImError::Stream {
+            message: format!("모니터링 출력 시작 실패: {}", e),
+        }));
+        return;
+    }
+    let _ = ready_tx.send(Ok(()));
+
+    // 중지 신호가 오거나 모니터가 사라질 때까지 유지
+    let _ = stop_rx.recv();
+    drop(stream);
+}
+
+fn build_stream<T>(
+    device: &Device,
+    config: &StreamConfig,
+    mut playback: Playback,
+) -> Result<Stream, cpal::BuildStreamError>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            playback.trim_backlog();
+            for frame in data.chunks_mut(channels) {
+                frame.fill(T::from_sample(playback.next()));
+            }
+        },
+        |err| log::error!("모니터링 스트림 에러: {}", err),
+        None,
+    )
+}
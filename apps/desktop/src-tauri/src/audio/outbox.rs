@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+use super::stats::CaptureStats;
+
+/// 프런트엔드로 보내려고 쌓아 두는 최대 청크 수 (넘치면 가장 오래된 것부터 버림)
+pub const MAX_QUEUED_CHUNKS: usize = 32;
+
+#[derive(Default)]
+struct Queue {
+    chunks: VecDeque<Vec<f32>>,
+    closed: bool,
+    /// 직전 청크를 넣을 때 큐가 넘쳤는지 (넘치기 시작할 때만 기록)
+    overflowing: bool,
+}
+
+/// 캡처 콜백과 `audio-data` 전송 사이의 제한 큐
+///
+/// 웹뷰가 멈춰 전송이 밀려도 큐가 끝없이 자라지 않도록 가장 오래된 청크를
+/// 버리고, 버린 개수는 캡처 통계에 남긴다. 전송은 전용 스레드에서 하므로
+/// 캡처 콜백은 직렬화나 IPC를 기다리지 않는다.
+pub struct Outbox {
+    shared: Arc<(Mutex<Queue>, Condvar)>,
+    stats: Arc<Mutex<CaptureStats>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Outbox {
+    /// 전송 스레드 시작 (`deliver`는 청크 하나를 프런트엔드로 보냄)
+    pub fn spawn<F>(stats: Arc<Mutex<CaptureStats>>, mut deliver: F) -> Self
+    where
+        F: FnMut(Vec<f32>) + Send + 'static,
+    {
+        let shared = Arc::new((Mutex::new(Queue::default()), Condvar::new()));
+        let queue = shared.clone();
+        let thread = thread::spawn(move || {
+            let (lock, ready) = &*queue;
+            loop {
+                let chunk = {
+                    let mut queue = lock.lock().unwrap();
+                    loop {
+                        if let Some(chunk) = queue.chunks.pop_front() {
+                            break chunk;
+                        }
+                        if queue.closed {
+                            return;
+                        }
+                        queue = ready.wait(queue).unwrap();
+                    }
+                };
+                deliver(chunk);
+            }
+        });
+
+        Self {
+            shared,
+            stats,
+            thread: Some(thread),
+        }
+    }
+
+    /// 보낼 청크 추가 (큐가 가득 차면 가장 오래된 청크를 버림)
+    pub fn push(&self, chunk: Vec<f32>) {
+        let (lock, ready) = &*self.shared;
+        let mut queue = lock.lock().unwrap();
+        let overflowing = queue.chunks.len() >= MAX_QUEUED_CHUNKS;
+        if overflowing {
+            queue.chunks.pop_front();
+            self.stats.lock().unwrap().dropped_chunks += 1;
+            if !queue.overflowing {
+                log::warn!("프런트엔드 전송이 밀려 오래된 오디오 청크를 버림");
+            }
+        }
+        queue.overflowing = overflowing;
+        queue.chunks.push_back(chunk);
+        ready.notify_one();
+    }
+}
+
+impl Drop for Outbox {
+    // 남은 청크를 모두 보낸 뒤 전송 스레드 종료
+    fn drop(&mut self) {
+        let (lock, ready) = &*self.shared;
+        lock.lock().unwrap().closed = true;
+        ready.notify_one();
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                log::error!("오디오 전송 스레드가 비정상 종료됨");
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use libpulse_binding::sample::{Format, Spec};
+use libpulse_binding::stream::Direction;
+use libpulse_simple_binding::Simple;
+
+/// PulseAudio 기본 모니터 소스 이름 (기본 출력 장치에서 재생 중인 소리)
+pub const MONITOR_SOURCE: &str = "@DEFAULT_MONITOR@";
+
+/// 모니터 소스를 여는 형식 (PulseAudio가 출력 장치 형식에서 변환해 줌)
+pub const CHANNELS: u8 = 2;
+pub const SAMPLE_RATE: u32 = 48_000;
+
+/// 한 번에 읽는 길이 (ms)
+const READ_MS: u32 = 20;
+
+/// PulseAudio 모니터 소스 녹음
+///
+/// 소스 이름을 연결할 때 직접 넘기므로 `PULSE_SOURCE` 같은 프로세스 전역 환경 변수를
+/// 바꾸지 않는다. 같은 프로세스의 마이크 캡처는 영향을 받지 않는다.
+pub struct MonitorSource {
+    simple: Simple,
+    buffer: Vec<u8>,
+}
+
+impl MonitorSource {
+    pub fn open() -> Result<Self, String> {
+        let spec = Spec {
+            format: Format::FLOAT32NE,
+            channels: CHANNELS,
+            rate: SAMPLE_RATE,
+        };
+        let simple = Simple::new(
+            None,
+            "Teu-Im",
+            Direction::Record,
+            Some(MONITOR_SOURCE),
+            "시스템 오디오",
+            &spec,
+            None,
+            None,
+        )
+        .map_err(|e| format!("PulseAudio 모니터 소스 열기 실패: {}", e))?;
+        let samples = (SAMPLE_RATE * READ_MS / 1000) as usize * CHANNELS as usize;
+        Ok(Self {
+            simple,
+            buffer: vec![0; samples * std::mem::size_of::<f32>()],
+        })
+    }
+
+    /// 인터리브된 샘플 한 블록을 읽음 (찰 때까지 막힘)
+    pub fn read(&mut self) -> Result<Vec<f32>, String> {
+        self.simple
+            .read(&mut self.buffer)
+            .map_err(|e| format!("PulseAudio 모니터 소스 읽기 실패: {}", e))?;
+        Ok(self
+            .buffer
+            .chunks_exact(4)
+            .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect())
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// 캡처 세션마다 보관하는 최근 오디오 길이 (초)
+pub const REPLAY_BUFFER_SECS: u32 = 120;
+
+/// 다시 듣기 길이 허용 범위 (초)
+pub const REPLAY_SECS_RANGE: std::ops::RangeInclusive<u32> = 1..=REPLAY_BUFFER_SECS;
+
+/// 세션 하나의 최근 오디오 (버스와 같은 첫 채널, 버스 레이트)
+struct History {
+    sample_rate: u32,
+    samples: VecDeque<f32>,
+    /// 캡처 시작 이후 버스로 들어온 전체 프레임 수
+    total_frames: u64,
+}
+
+impl History {
+    fn capacity(&self) -> usize {
+        self.sample_rate as usize * REPLAY_BUFFER_SECS as usize
+    }
+}
+
+/// 버퍼에서 잘라 낸 최근 오디오
+#[derive(Debug, Clone)]
+pub struct ReplayClip {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+    /// 캡처 세션 기준 잘라 낸 구간 시작 (ms, 일시정지 구간 제외)
+    pub start_ms: i64,
+}
+
+impl ReplayClip {
+    pub fn duration_ms(&self) -> i64 {
+        (self.samples.len() as u64 * 1000 / self.sample_rate.max(1) as u64) as i64
+    }
+}
+
+/// 캡처 세션별 최근 오디오 링 버퍼
+///
+/// 버스 소비자가 쓰고 `replay_last`가 읽도록 복제 가능한 핸들로 둔다.
+/// 통역사가 놓친 숫자나 이름을 다시 듣는 용도라 첫 채널만 보관한다.
+#[derive(Clone, Default)]
+pub struct ReplayBuffer {
+    sessions: Arc<Mutex<HashMap<String, History>>>,
+}
+
+impl ReplayBuffer {
+    /// 세션 샘플 추가 (`samples`는 버스의 모노 샘플)
+    pub fn write(&self, session_id: &str, sample_rate: u32, samples: &[f32]) {
+        let mut sessions = self.sessions.lock().unwrap();
+        let history = sessions
+            .entry(session_id.to_string())
+            .or_insert_with(|| History {
+                sample_rate,
+                samples: VecDeque::new(),
+                total_frames: 0,
+            });
+
+        history.samples.extend(samples);
+        history.total_frames += samples.len() as u64;
+
+        let excess = history.samples.len().saturating_sub(history.capacity());
+        history.samples.drain(..excess);
+    }
+
+    /// 세션의 최근 `seconds`초 (보관한 오디오가 더 짧으면 있는 만큼)
+    pub fn last(&self, session_id: &str, seconds: u32) -> Option<ReplayClip> {
+        let sessions = self.sessions.lock().unwrap();
+        let history = sessions.get(session_id)?;
+        let count = (history.sample_rate as usize * seconds as usize).min(history.samples.len());
+        if count == 0 {
+            return None;
+        }
+
+        let start_frame = history.total_frames - count as u64;
+        Some(ReplayClip {
+            samples: history
+                .samples
+                .range(history.samples.len() - count..)
+                .copied()
+                .collect(),
+            sample_rate: history.sample_rate,
+            start_ms: (start_frame * 1000 / history.sample_rate as u64) as i64,
+        })
+    }
+
+    /// 캡처가 끝난 세션의 버퍼 해제
+    pub fn remove(&self, session_id: &str) {
+        self.sessions.lock().unwrap().remove(session_id);
+    }
+}
@@ -0,0 +1,60 @@
+use rubato::{FftFixedInOut, Resampler};
+
+/// 모노 f32 스트림용 리샘플러
+///
+/// cpal 콜백은 매번 다른 길이의 버퍼를 넘겨주지만 rubato는 고정 길이 청크를
+/// 요구하므로, 남은 입력은 다음 호출까지 보관했다가 이어서 처리한다.
+pub struct MonoResampler {
+    inner: Option<FftFixedInOut<f32>>,
+    pending: Vec<f32>,
+}
+
+impl MonoResampler {
+    /// 입력/출력 샘플레이트로 리샘플러 생성 (같으면 그대로 통과)
+    pub fn new(input_rate: u32, output_rate: u32) -> Result<Self, String> {
+        let inner = if input_rate == output_rate {
+            None
+        } else {
+            // 10ms 단위 청크
+            let chunk_size = (input_rate / 100).max(1) as usize;
+            let resampler = FftFixedInOut::<f32>::new(
+                input_rate as usize,
+                output_rate as usize,
+                chunk_size,
+                1,
+            )
+            .map_err(|e| format!("리샘플러 생성 실패: {}", e))?;
+            Some(resampler)
+        };
+
+        Ok(Self {
+            inner,
+            pending: Vec::new(),
+        })
+    }
+
+    /// 입력 샘플을 변환해 지금까지 만들어진 출력 샘플 반환
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        let Some(resampler) = self.inner.as_mut() else {
+            return input.to_vec();
+        };
+
+        self.pending.extend_from_slice(input);
+
+        let mut output = Vec::new();
+        loop {
+            let needed = resampler.input_frames_next();
+            if self.pending.len() < needed {
+                break;
+            }
+
+            match resampler.process(&[&self.pending[..needed]], None) {
+                Ok(mut frames) => output.append(&mut frames[0]),
+                Err(e) => log::error!("리샘플링 실패: {}", e),
+            }
+            self.pending.drain(..needed);
+        }
+
+        output
+    }
+}
@@ -0,0 +1,236 @@
+use cpal::{InputStreamTimestamp, StreamInstant};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::CaptureSource;
+
+/// 지연·지터 이동 평균에서 새 값의 가중치
+const SMOOTHING: f64 = 0.1;
+
+/// 예상보다 이만큼 늦게 도착한 버퍼 앞은 유실로 판정 (버퍼 길이 대비)
+const XRUN_GAP_RATIO: f64 = 0.5;
+
+/// 유실 판정에 필요한 최소 공백 (ms, 타임스탬프 오차 무시)
+const XRUN_MIN_GAP_MS: f64 = 2.0;
+
+/// `capture-xrun` 이벤트를 보내는 최소 간격
+const XRUN_EVENT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// `audio-bus-overflow` 이벤트를 보내는 최소 간격
+const OVERFLOW_EVENT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 캡처 스트림 통계 (`get_capture_stats` 응답 항목)
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureStats {
+    pub session_id: String,
+    pub source: CaptureSource,
+    /// 장치에서 받은 총 프레임 수
+    pub frames_captured: u64,
+    pub callbacks: u64,
+    /// 장치가 샘플을 잡은 시점부터 콜백까지의 지연 (ms, 이동 평균)
+    pub latency_ms: Option<f64>,
+    /// 콜백 간격이 버퍼 길이에서 벗어난 정도 (ms, 이동 평균)
+    pub jitter_ms: f64,
+    /// 입력 공백으로 추정한 유실 버퍼 수 (오버런)
+    pub dropped_buffers: u64,
+    /// 모니터링 등 실시간 소비자가 밀려 버스에서 샘플을 버리기 시작한 횟수
+    pub bus_overflows: u64,
+    /// 버스에서 버린 샘플 수 (가장 많이 버린 소비자 기준)
+    pub bus_dropped_samples: u64,
+    /// 프런트엔드 전송이 밀려 보내지 못하고 버린 오디오 청크 수
+    pub dropped_chunks: u64,
+    /// `audio-clipping` 이벤트로 알린 클리핑 구간 수
+    pub clipping_events: u64,
+}
+
+/// `capture-xrun` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureXrun {
+    pub session_id: String,
+    pub source: CaptureSource,
+    /// 이번에 감지한 입력 공백 (ms)
+    pub gap_ms: f64,
+    /// 세션 누적 유실 버퍼 수
+    pub dropped_buffers: u64,
+}
+
+/// `audio-bus-overflow` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct BusOverflow {
+    pub session_id: String,
+    pub source: CaptureSource,
+    /// 지난 이벤트 이후 버린 샘플 수
+    pub dropped_samples: u64,
+    /// 세션 누적 버린 샘플 수
+    pub total_dropped_samples: u64,
+}
+
+/// 직전 콜백 정보
+struct LastBuffer {
+    capture: StreamInstant,
+    callback: StreamInstant,
+    duration_ms: f64,
+}
+
+/// 캡처 콜백 타임스탬프로 통계를 갱신하는 측정기
+///
+/// 캡처 콜백에서 호출하며, 통계는 관리자와 공유하는 값에 바로 반영한다.
+pub struct StreamClock {
+    stats: Arc<Mutex<CaptureStats>>,
+    last: Option<LastBuffer>,
+    last_xrun_event: Option<Instant>,
+    /// 버스가 샘플을 버리는 중인지
+    bus_overflowing: bool,
+    last_overflow_event: Option<Instant>,
+    /// 지난 `audio-bus-overflow` 이벤트 이후 버린 샘플 수
+    unreported_drops: u64,
+}
+
+impl StreamClock {
+    pub fn new(session_id: String, source: CaptureSource) -> Self {
+        Self {
+            stats: Arc::new(Mutex::new(CaptureStats {
+                session_id,
+                source,
+                frames_captured: 0,
+                callbacks: 0,
+                latency_ms: None,
+                jitter_ms: 0.0,
+                dropped_buffers: 0,
+                bus_overflows: 0,
+                bus_dropped_samples: 0,
+                dropped_chunks: 0,
+                clipping_events: 0,
+            })),
+            last: None,
+            last_xrun_event: None,
+            bus_overflowing: false,
+            last_overflow_event: None,
+            unreported_drops: 0,
+        }
+    }
+
+    /// 관리자와 공유하는 통계
+    pub fn shared(&self) -> Arc<Mutex<CaptureStats>> {
+        self.stats.clone()
+    }
+
+    /// 장치를 다시 열었을 때 이전 스트림과의 공백을 유실로 세지 않도록 초기화
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// 콜백 하나 반영 (이벤트로 알릴 오버런이 있으면 반환)
+    pub fn track(
+        &mut self,
+        timestamp: &InputStreamTimestamp,
+        frames: usize,
+        sample_rate: u32,
+    ) -> Option<CaptureXrun> {
+        let duration_ms = frames as f64 * 1000.0 / sample_rate as f64;
+        let latency_ms = timestamp
+            .callback
+            .duration_since(&timestamp.capture)
+            .map(|latency| latency.as_secs_f64() * 1000.0);
+
+        let mut stats = self.stats.lock().unwrap();
+        stats.frames_captured += frames as u64;
+        stats.callbacks += 1;
+        if let Some(latency_ms) = latency_ms {
+            stats.latency_ms = Some(match stats.latency_ms {
+                Some(average) => average + (latency_ms - average) * SMOOTHING,
+                None => latency_ms,
+            });
+        }
+
+        let mut xrun = None;
+        if let Some(last) = &self.last {
+            if let Some(interval) = timestamp.callback.duration_since(&last.callback) {
+                let deviation = (interval.as_secs_f64() * 1000.0 - last.duration_ms).abs();
+                stats.jitter_ms += (deviation - stats.jitter_ms) * SMOOTHING;
+            }
+
+            // 직전 버퍼가 끝난 시점보다 늦게 잡힌 샘플이면 그 사이를 잃은 것
+            let gap_ms = timestamp
+                .capture
+                .duration_since(&last.capture)
+                .map(|elapsed| elapsed.as_secs_f64() * 1000.0 - last.duration_ms)
+                .unwrap_or(0.0);
+            if gap_ms > XRUN_MIN_GAP_MS && gap_ms > last.duration_ms * XRUN_GAP_RATIO {
+                let lost = (gap_ms / last.duration_ms.max(1.0)).round().max(1.0) as u64;
+                stats.dropped_buffers += lost;
+
+                // 오버런이 몰려도 로그와 이벤트는 간격을 두고 한 번씩
+                let now = Instant::now();
+                if self
+                    .last_xrun_event
+                    .is_none_or(|at| now.duration_since(at) >= XRUN_EVENT_INTERVAL)
+                {
+                    log::warn!(
+                        "오디오 입력 오버런: {:.1}ms 유실 ({})",
+                        gap_ms,
+                        stats.session_id
+                    );
+                    self.last_xrun_event = Some(now);
+                    xrun = Some(CaptureXrun {
+                        session_id: stats.session_id.clone(),
+                        source: stats.source,
+                        gap_ms,
+                        dropped_buffers: stats.dropped_buffers,
+                    });
+                }
+            }
+        }
+
+        self.last = Some(LastBuffer {
+            capture: timestamp.capture,
+            callback: timestamp.callback,
+            duration_ms,
+        });
+        xrun
+    }
+
+    /// 버스에 쓴 결과 반영 (`dropped`는 이번에 버린 샘플 수, 이벤트로 알릴 게 있으면 반환)
+    pub fn track_bus(&mut self, dropped: usize) -> Option<BusOverflow> {
+        let overflowing = dropped > 0;
+        let started = overflowing && !self.bus_overflowing;
+        self.bus_overflowing = overflowing;
+        if !overflowing && self.unreported_drops == 0 {
+            return None;
+        }
+
+        let mut stats = self.stats.lock().unwrap();
+        if started {
+            stats.bus_overflows += 1;
+            log::warn!(
+                "오디오 버스 소비자가 밀려 샘플을 버림 ({})",
+                stats.session_id
+            );
+        }
+        stats.bus_dropped_samples += dropped as u64;
+        self.unreported_drops += dropped as u64;
+
+        // 계속 버리는 동안은 간격을 두고, 멈추면 남은 수를 바로 알림
+        let now = Instant::now();
+        let due = !overflowing
+            || self
+                .last_overflow_event
+                .is_none_or(|at| now.duration_since(at) >= OVERFLOW_EVENT_INTERVAL);
+        if !due {
+            return None;
+        }
+        self.last_overflow_event = Some(now);
+        Some(BusOverflow {
+            session_id: stats.session_id.clone(),
+            source: stats.source,
+            dropped_samples: std::mem::take(&mut self.unreported_drops),
+            total_dropped_samples: stats.bus_dropped_samples,
+        })
+    }
+
+    /// 입력 클리핑을 알림
+    pub fn record_clipping(&self) {
+        self.stats.lock().unwrap().clipping_events += 1;
+    }
+}
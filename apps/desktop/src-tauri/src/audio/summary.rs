@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::meter::{to_dbfs, SILENCE_DB};
+use super::stats::CaptureStats;
+use super::CaptureSource;
+
+/// 레벨을 재는 블록 길이 (ms)
+const BLOCK_MS: u32 = 100;
+
+/// 블록 RMS가 이보다 작으면 무음 블록으로 봄 (dBFS)
+const SILENT_BLOCK_DB: f32 = -50.0;
+
+/// 잡음 바닥으로 보는 블록 레벨 백분위
+const NOISE_PERCENTILE: f64 = 0.1;
+
+/// 말소리 레벨로 보는 블록 레벨 백분위
+const SPEECH_PERCENTILE: f64 = 0.9;
+
+/// 세션 오디오 통계 (`get_session_stats` 응답)
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionAudioStats {
+    pub session_id: String,
+    pub source: CaptureSource,
+    /// 통계에 들어간 오디오 길이 (ms, 음소거 구간 제외)
+    pub duration_ms: i64,
+    /// 전체 평균 레벨 (dBFS)
+    pub average_db: f32,
+    pub peak_db: f32,
+    /// 조용한 블록 기준 잡음 바닥 (dBFS)
+    pub noise_floor_db: f32,
+    /// 말소리 레벨과 잡음 바닥의 차이로 추정한 SNR (dB, 블록이 모자라면 `None`)
+    pub snr_db: Option<f32>,
+    /// `audio-clipping` 이벤트로 알린 클리핑 구간 수
+    pub clipping_count: u64,
+    /// 무음 블록 비율 (%)
+    pub silence_percent: f32,
+    /// 입력 공백으로 추정한 유실 버퍼 수
+    pub dropped_buffers: u64,
+    /// 프런트엔드로 보내지 못하고 버린 오디오 청크 수
+    pub dropped_chunks: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 저장소 세션 하나에 들어간 오디오로 통계를 모음
+///
+/// 전사 소비자 스레드에서 변환된 첫 번째 채널을 받으며, 클리핑·유실 수는
+/// 캡처 통계에서 모으기 시작한 시점과의 차이로 계산한다.
+pub struct StatsCollector {
+    storage_session_id: String,
+    source: CaptureSource,
+    block_len: usize,
+    /// 채우는 중인 블록의 제곱합과 샘플 수
+    block_power: f64,
+    block_samples: usize,
+    /// 끝난 블록별 RMS (dBFS)
+    blocks: Vec<f32>,
+    total_power: f64,
+    total_samples: u64,
+    peak: f32,
+    sample_rate: u32,
+    base_clipping: u64,
+    base_dropped_buffers: u64,
+    base_dropped_chunks: u64,
+}
+
+impl StatsCollector {
+    pub fn new(
+        storage_session_id: String,
+        source: CaptureSource,
+        sample_rate: u32,
+        capture: &CaptureStats,
+    ) -> Self {
+        Self {
+            storage_session_id,
+            source,
+            block_len: (sample_rate * BLOCK_MS / 1000).max(1) as usize,
+            block_power: 0.0,
+            block_samples: 0,
+            blocks: Vec::new(),
+            total_power: 0.0,
+            total_samples: 0,
+            peak: 0.0,
+            sample_rate,
+            base_clipping: capture.clipping_events,
+            base_dropped_buffers: capture.dropped_buffers,
+            base_dropped_chunks: capture.dropped_chunks,
+        }
+    }
+
+    pub fn storage_session_id(&self) -> &str {
+        &self.storage_session_id
+    }
+
+    pub fn push(&mut self, samples: &[f32]) {
+        for sample in samples {
+            let power = f64::from(sample * sample);
+            self.block_power += power;
+            self.total_power += power;
+            self.peak = self.peak.max(sample.abs());
+            self.block_samples += 1;
+            if self.block_samples == self.block_len {
+                let rms = (self.block_power / self.block_len as f64).sqrt();
+                self.blocks.push(to_dbfs(rms as f32));
+                self.block_power = 0.0;
+                self.block_samples = 0;
+            }
+        }
+        self.total_samples += samples.len() as u64;
+    }
+
+    /// 모은 통계 (클리핑·유실 수는 지금의 캡처 통계 기준)
+    pub fn finish(&self, capture: &CaptureStats) -> SessionAudioStats {
+        let mut levels = self.blocks.clone();
+        levels.sort_by(f32::total_cmp);
+        let percentile = |p: f64| {
+            let index = ((levels.len() - 1) as f64 * p).round() as usize;
+            levels[index]
+        };
+        let (noise_floor_db, snr_db) = match levels.len() {
+            0 => (SILENCE_DB, None),
+            // 1초가 안 되면 말소리와 잡음을 가를 수 없음
+            len if len < (1000 / BLOCK_MS) as usize => (percentile(NOISE_PERCENTILE), None),
+            _ => {
+                let noise = percentile(NOISE_PERCENTILE);
+                (noise, Some(percentile(SPEECH_PERCENTILE) - noise))
+            }
+        };
+        let silent = levels.iter().filter(|db| **db < SILENT_BLOCK_DB).count();
+        let average = (self.total_power / self.total_samples.max(1) as f64).sqrt();
+
+        SessionAudioStats {
+            session_id: self.storage_session_id.clone(),
+            source: self.source,
+            duration_ms: (self.total_samples * 1000 / u64::from(self.sample_rate.max(1))) as i64,
+            average_db: to_dbfs(average as f32),
+            peak_db: to_dbfs(self.peak),
+            noise_floor_db,
+            snr_db,
+            clipping_count: capture.clipping_events.saturating_sub(self.base_clipping),
+            silence_percent: match levels.len() {
+                0 => 0.0,
+                len => silent as f32 * 100.0 / len as f32,
+            },
+            dropped_buffers: capture
+                .dropped_buffers
+                .saturating_sub(self.base_dropped_buffers),
+            dropped_chunks: capture
+                .dropped_chunks
+                .saturating_sub(self.base_dropped_chunks),
+            updated_at: Utc::now(),
+        }
+    }
+}
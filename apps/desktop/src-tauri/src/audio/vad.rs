@@ -0,0 +1,104 @@
+use earshot::Detector;
+
+use super::resample::MonoResampler;
+
+/// VAD 입력 샘플레이트
+const VAD_SAMPLE_RATE: u32 = 16_000;
+
+/// earshot 프레임 길이 (16ms)
+const FRAME_LEN: usize = 256;
+const FRAME_MS: i64 = 16;
+
+/// 발화 시작으로 판단하는 연속 음성 프레임 수 (~48ms)
+const ONSET_FRAMES: u32 = 3;
+
+/// 발화 종료로 판단하는 연속 무음 프레임 수 (~320ms)
+const HANGOVER_FRAMES: u32 = 20;
+
+/// 발화 구간 변화
+#[derive(Debug, Clone, Copy)]
+pub enum VadEvent {
+    SpeechStart { start_ms: i64 },
+    SpeechEnd { start_ms: i64, end_ms: i64 },
+}
+
+/// 음성 구간 검출기
+///
+/// 입력을 16kHz로 맞춘 뒤 16ms 프레임마다 음성 확률을 구하고, 짧은 잡음이나
+/// 말 사이 쉼에 흔들리지 않도록 시작/종료에 각각 여유 프레임을 둔다.
+pub struct VoiceDetector {
+    detector: Box<Detector>,
+    resampler: MonoResampler,
+    frame: Vec<f32>,
+    threshold: f32,
+    speaking: bool,
+    voiced_run: u32,
+    silent_run: u32,
+    speech_start_ms: i64,
+    position_ms: i64,
+}
+
+impl VoiceDetector {
+    /// `sensitivity`는 0.0(둔감)~1.0(민감)
+    pub fn new(input_rate: u32, sensitivity: f32) -> Result<Self, String> {
+        if !(0.0..=1.0).contains(&sensitivity) {
+            return Err(format!("VAD 민감도는 0~1 사이여야 합니다: {}", sensitivity));
+        }
+
+        Ok(Self {
+            detector: Detector::default_boxed(),
+            resampler: MonoResampler::new(input_rate, VAD_SAMPLE_RATE)?,
+            frame: Vec::with_capacity(FRAME_LEN),
+            threshold: 0.8 - 0.6 * sensitivity,
+            speaking: false,
+            voiced_run: 0,
+            silent_run: 0,
+            speech_start_ms: 0,
+            position_ms: 0,
+        })
+    }
+
+    pub fn is_speaking(&self) -> bool {
+        self.speaking
+    }
+
+    /// 샘플을 처리하고 발생한 발화 시작/종료 목록 반환
+    pub fn process(&mut self, samples: &[f32]) -> Vec<VadEvent> {
+        let mut events = Vec::new();
+
+        for sample in self.resampler.process(samples) {
+            self.frame.push(sample.clamp(-1.0, 1.0));
+            if self.frame.len() < FRAME_LEN {
+                continue;
+            }
+
+            let voiced = self.detector.predict_f32(&self.frame) >= self.threshold;
+            self.frame.clear();
+            self.position_ms += FRAME_MS;
+
+            if voiced {
+                self.voiced_run += 1;
+                self.silent_run = 0;
+            } else {
+                self.silent_run += 1;
+                self.voiced_run = 0;
+            }
+
+            if !self.speaking && self.voiced_run >= ONSET_FRAMES {
+                self.speaking = true;
+                self.speech_start_ms = self.position_ms - ONSET_FRAMES as i64 * FRAME_MS;
+                events.push(VadEvent::SpeechStart {
+                    start_ms: self.speech_start_ms,
+                });
+            } else if self.speaking && self.silent_run >= HANGOVER_FRAMES {
+                self.speaking = false;
+                events.push(VadEvent::SpeechEnd {
+                    start_ms: self.speech_start_ms,
+                    end_ms: self.position_ms - HANGOVER_FRAMES as i64 * FRAME_MS,
+                });
+            }
+        }
+
+        events
+    }
+}
@@ -0,0 +1,109 @@
+use serde::Serialize;
+use std::time::Instant;
+
+use super::meter::{ChannelLevels, SILENCE_DB};
+use super::CaptureSource;
+
+/// 기본 무음 경고 시간 (초)
+pub const DEFAULT_SILENCE_WARNING_SECS: u32 = 30;
+
+/// 무음 경고 시간 허용 범위 (초)
+pub const SILENCE_WARNING_SECS_RANGE: std::ops::RangeInclusive<u32> = 5..=600;
+
+/// 모든 채널의 피크가 이보다 작으면 무음으로 봄 (dBFS)
+const SILENT_PEAK_DB: f32 = -70.0;
+
+/// `silence-detected` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct SilenceDetected {
+    pub session_id: String,
+    pub source: CaptureSource,
+    /// 무음이 이어진 시간 (초)
+    pub silent_secs: f64,
+    /// 입력이 완전히 0 (하드웨어 음소거·잘못된 장치일 가능성이 큼)
+    pub digital_zero: bool,
+}
+
+/// `silence-ended` 이벤트 페이로드 (경고 뒤 소리가 다시 들어옴)
+#[derive(Debug, Clone, Serialize)]
+pub struct SilenceEnded {
+    pub session_id: String,
+    pub source: CaptureSource,
+    pub silent_secs: f64,
+}
+
+pub enum SilenceEvent {
+    Detected(SilenceDetected),
+    Ended(SilenceEnded),
+}
+
+/// 캡처 스트림이 무음만 내는지 감시
+///
+/// 레벨 측정 구간마다 피크를 보고, 무음이 경고 시간만큼 이어지면 경고한 뒤
+/// 무음이 계속되는 동안 같은 간격으로 다시 경고한다.
+pub struct SilenceWatchdog {
+    session_id: String,
+    source: CaptureSource,
+    threshold_secs: f64,
+    silent_since: Option<Instant>,
+    /// 무음 구간 내내 입력이 0이었는지
+    all_zero: bool,
+    /// 다음 경고를 낼 무음 길이 (초)
+    next_warning_secs: f64,
+}
+
+impl SilenceWatchdog {
+    pub fn new(session_id: String, source: CaptureSource, threshold_secs: u32) -> Self {
+        Self {
+            session_id,
+            source,
+            threshold_secs: threshold_secs.into(),
+            silent_since: None,
+            all_zero: true,
+            next_warning_secs: threshold_secs.into(),
+        }
+    }
+
+    /// 일시정지·음소거처럼 일부러 조용한 동안에는 무음 길이를 다시 셈
+    pub fn reset(&mut self) {
+        self.silent_since = None;
+        self.all_zero = true;
+        self.next_warning_secs = self.threshold_secs;
+    }
+
+    /// 측정 구간 레벨을 넣고 경고할 때가 되면 이벤트 반환
+    pub fn update(&mut self, levels: &ChannelLevels) -> Option<SilenceEvent> {
+        let peak = levels.peak_db.iter().copied().fold(SILENCE_DB, f32::max);
+        if peak >= SILENT_PEAK_DB {
+            let warned = self.next_warning_secs > self.threshold_secs;
+            let silent_secs = self.silent_secs();
+            self.reset();
+            return warned.then(|| {
+                SilenceEvent::Ended(SilenceEnded {
+                    session_id: self.session_id.clone(),
+                    source: self.source,
+                    silent_secs,
+                })
+            });
+        }
+
+        let since = *self.silent_since.get_or_insert_with(Instant::now);
+        self.all_zero &= peak <= SILENCE_DB;
+        let silent_secs = since.elapsed().as_secs_f64();
+        if silent_secs < self.next_warning_secs {
+            return None;
+        }
+        self.next_warning_secs += self.threshold_secs;
+        Some(SilenceEvent::Detected(SilenceDetected {
+            session_id: self.session_id.clone(),
+            source: self.source,
+            silent_secs,
+            digital_zero: self.all_zero,
+        }))
+    }
+
+    fn silent_secs(&self) -> f64 {
+        self.silent_since
+            .map_or(0.0, |since| since.elapsed().as_secs_f64())
+    }
+}
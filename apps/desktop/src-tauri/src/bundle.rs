@@ -0,0 +1,380 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use uuid::Uuid;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::data_dir;
+use crate::encryption;
+use crate::error::TeuImError;
+use crate::glossary::{GlossaryTerm, GlossaryTermInput};
+use crate::storage::{MarkKind, SessionSummary, Storage, Translation};
+use crate::transcribe::TranscriptWord;
+
+/// 묶음 파일 형식 이름 (`manifest.json`의 `format`)
+const BUNDLE_FORMAT: &str = "teuim-session";
+
+/// 묶음 파일 형식 버전 (읽을 수 있는 최대 버전)
+const BUNDLE_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const TRANSCRIPT_FILE: &str = "transcript.json";
+const GLOSSARY_FILE: &str = "glossary.json";
+const AUDIO_FILE: &str = "audio.wav";
+
+/// 묶음 파일 머리 정보와 세션 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub format: String,
+    pub version: u32,
+    /// 내보낸 앱 버전
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub title: Option<String>,
+    pub device_name: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_ms: i64,
+    /// 속한 프로젝트 이름 (가져올 때 같은 이름의 프로젝트로 분류)
+    pub project: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 녹음 포함 여부
+    pub has_audio: bool,
+}
+
+/// 묶음 파일의 전사 구간 (번역 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleSegment {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub confidence: Option<f32>,
+    #[serde(default)]
+    pub words: Vec<TranscriptWord>,
+    #[serde(default)]
+    pub translations: Vec<Translation>,
+}
+
+/// 묶음 파일의 타임라인 구간 표시
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleMark {
+    pub kind: MarkKind,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// 묶음 파일의 전사 결과
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BundleTranscript {
+    pub segments: Vec<BundleSegment>,
+    pub marks: Vec<BundleMark>,
+}
+
+/// 가져온 세션 (`import_session_bundle` 응답)
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedBundle {
+    pub session: SessionSummary,
+    /// 용어집에 새로 추가한 용어 수 (이미 있는 용어는 그대로 둠)
+    pub glossary_added: usize,
+}
+
+fn add_json(
+    zip: &mut ZipWriter<BufWriter<File>>,
+    name: &str,
+    value: &impl Serialize,
+) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(name, options).map_err(|e| e.to_string())?;
+    serde_json::to_writer_pretty(zip, value).map_err(|e| e.to_string())
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(
+    archive: &mut ZipArchive<BufReader<File>>,
+    name: &str,
+) -> Result<Option<T>, String> {
+    let file = match archive.by_name(name) {
+        Ok(file) => file,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(format!("묶음 파일 읽기 실패: {}", e)),
+    };
+    serde_json::from_reader(file)
+        .map(Some)
+        .map_err(|e| format!("묶음 파일의 {}이 올바르지 않습니다: {}", name, e))
+}
+
+/// 묶음에 넣을 세션 정보·전사와 녹음 파일 경로 모으기
+fn collect(
+    storage: &Storage,
+    session_id: &str,
+) -> Result<(BundleManifest, BundleTranscript, Option<String>), TeuImError> {
+    let detail = storage.get_session(session_id)?;
+    let project = match &detail.session.project_id {
+        Some(id) => storage
+            .list_projects()?
+            .into_iter()
+            .find(|project| &project.id == id)
+            .map(|project| project.name),
+        None => None,
+    };
+    let audio_path = detail.session.audio_path.clone().filter(|path| {
+        let exists = Path::new(path).is_file();
+        if !exists {
+            log::warn!("녹음 파일이 없어 묶음에서 제외: {}", path);
+        }
+        exists
+    });
+
+    let manifest = BundleManifest {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        title: detail.session.title,
+        device_name: detail.session.device_name,
+        started_at: detail.session.started_at,
+        ended_at: detail.session.ended_at,
+        duration_ms: detail.session.duration_ms,
+        project,
+        tags: detail.session.tags,
+        has_audio: audio_path.is_some(),
+    };
+    let transcript = BundleTranscript {
+        segments: detail
+            .segments
+            .into_iter()
+            .map(|segment| BundleSegment {
+                text: segment.text,
+                start_ms: segment.start_ms,
+                end_ms: segment.end_ms,
+                speaker: segment.speaker,
+                confidence: segment.confidence,
+                words: segment.words,
+                translations: segment.translations,
+            })
+            .collect(),
+        marks: detail
+            .marks
+            .into_iter()
+            .map(|mark| BundleMark {
+                kind: mark.kind,
+                start_ms: mark.start_ms,
+                end_ms: mark.end_ms,
+            })
+            .collect(),
+    };
+    Ok((manifest, transcript, audio_path))
+}
+
+/// 세션 내용 지문 (동기화에서 마지막 동기화 뒤 바뀌었는지 확인용)
+///
+/// 내보낸 시각·앱 버전처럼 내용과 상관없는 값과 녹음 파일 자체는 넣지 않는다.
+pub fn fingerprint(storage: &Storage, session_id: &str) -> Result<String, TeuImError> {
+    let (manifest, transcript, _) = collect(storage, session_id)?;
+    let contents = serde_json::to_vec(&(
+        &manifest.title,
+        &manifest.device_name,
+        manifest.started_at,
+        manifest.ended_at,
+        manifest.duration_ms,
+        &manifest.project,
+        &manifest.tags,
+        manifest.has_audio,
+        &transcript,
+    ))
+    .map_err(|e| e.to_string())?;
+    Ok(Sha256::digest(&contents)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// 세션을 묶음 파일로 저장
+pub fn write_bundle(app: &AppHandle, session_id: &str, path: &Path) -> Result<(), TeuImError> {
+    let storage = app.state::<Storage>();
+    let (manifest, transcript, audio_path) = collect(&storage, session_id)?;
+    let glossary: Vec<GlossaryTerm> = storage.list_glossary()?;
+
+    let file = File::create(path).map_err(|e| format!("파일 저장 실패: {}", e))?;
+    let mut zip = ZipWriter::new(BufWriter::new(file));
+    add_json(&mut zip, MANIFEST_FILE, &manifest)?;
+    add_json(&mut zip, TRANSCRIPT_FILE, &transcript)?;
+    add_json(&mut zip, GLOSSARY_FILE, &glossary)?;
+    if let Some(audio_path) = &audio_path {
+        // 암호화한 녹음은 풀어서 넣음 (다른 기기에서 열 수 있게)
+        let mut source = encryption::open_recording(app, audio_path)?;
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .large_file(true);
+        zip.start_file(AUDIO_FILE, options)
+            .map_err(|e| e.to_string())?;
+        std::io::copy(&mut source, &mut zip).map_err(|e| format!("녹음 파일 읽기 실패: {}", e))?;
+    }
+    zip.finish()
+        .and_then(|mut writer| writer.flush().map_err(Into::into))
+        .map_err(|e| format!("파일 저장 실패: {}", e))?;
+    Ok(())
+}
+
+/// 묶음의 용어 중 아직 없는 것만 용어집에 추가
+fn merge_glossary(storage: &Storage, terms: Vec<GlossaryTermInput>) -> Result<usize, TeuImError> {
+    let existing: HashSet<String> = storage
+        .list_glossary()?
+        .into_iter()
+        .map(|term| term.term.to_lowercase())
+        .collect();
+    let added: Vec<GlossaryTermInput> = terms
+        .into_iter()
+        .filter(|term| !term.term.trim().is_empty())
+        .filter(|term| !existing.contains(&term.term.to_lowercase()))
+        .collect();
+    if added.is_empty() {
+        return Ok(0);
+    }
+    storage.import_glossary(added)
+}
+
+type OpenedBundle = (
+    ZipArchive<BufReader<File>>,
+    BundleManifest,
+    BundleTranscript,
+    Vec<GlossaryTermInput>,
+);
+
+/// 묶음 파일을 열어 머리 정보·전사·용어집 읽기
+fn open(path: &Path) -> Result<OpenedBundle, TeuImError> {
+    let file = File::open(path).map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let mut archive = ZipArchive::new(BufReader::new(file))
+        .map_err(|_| TeuImError::invalid_argument("Teu-Im 세션 묶음 파일이 아닙니다"))?;
+
+    let manifest: BundleManifest = read_json(&mut archive, MANIFEST_FILE)?
+        .filter(|manifest: &BundleManifest| manifest.format == BUNDLE_FORMAT)
+        .ok_or_else(|| TeuImError::invalid_argument("Teu-Im 세션 묶음 파일이 아닙니다"))?;
+    if manifest.version > BUNDLE_VERSION {
+        return Err(TeuImError::invalid_argument(format!(
+            "더 새 버전의 앱에서 만든 묶음 파일입니다 (형식 버전 {})",
+            manifest.version
+        )));
+    }
+    let transcript: BundleTranscript =
+        read_json(&mut archive, TRANSCRIPT_FILE)?.unwrap_or_default();
+    let glossary: Vec<GlossaryTermInput> =
+        read_json(&mut archive, GLOSSARY_FILE)?.unwrap_or_default();
+    Ok((archive, manifest, transcript, glossary))
+}
+
+/// 묶음 파일을 가져오지 않고 끝까지 읽어 검사 (녹음은 CRC까지 확인)
+pub fn check_bundle(path: &Path) -> Result<(), TeuImError> {
+    let (mut archive, manifest, _, _) = open(path)?;
+    if manifest.has_audio {
+        let mut entry = archive
+            .by_name(AUDIO_FILE)
+            .map_err(|e| format!("묶음 파일에 녹음이 없습니다: {}", e))?;
+        std::io::copy(&mut entry, &mut std::io::sink())
+            .map_err(|e| format!("묶음 파일의 녹음이 손상되었습니다: {}", e))?;
+    }
+    Ok(())
+}
+
+/// 묶음 파일에서 세션을 `session_id`로 가져오기 (생략하면 새 ID)
+pub fn read_bundle(
+    app: &AppHandle,
+    path: &Path,
+    session_id: Option<&str>,
+) -> Result<ImportedBundle, TeuImError> {
+    let (mut archive, manifest, transcript, glossary) = open(path)?;
+    let storage = app.state::<Storage>();
+    let session_id = session_id
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    storage.import_session(&session_id, &manifest, &transcript)?;
+
+    // 녹음은 녹음 폴더에 새 세션 ID로 풀어 둠 (실패하면 가져온 세션도 지움)
+    if manifest.has_audio {
+        let mut extract = || -> Result<PathBuf, TeuImError> {
+            let audio_path = data_dir::recording_path(app, &format!("{}.wav", session_id))?;
+            if let Some(parent) = audio_path.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            let mut entry = archive
+                .by_name(AUDIO_FILE)
+                .map_err(|e| format!("묶음 파일에 녹음이 없습니다: {}", e))?;
+            let mut output = BufWriter::new(
+                File::create(&audio_path).map_err(|e| format!("녹음 저장 실패: {}", e))?,
+            );
+            std::io::copy(&mut entry, &mut output)
+                .and_then(|_| output.flush())
+                .map_err(|e| {
+                    let _ = std::fs::remove_file(&audio_path);
+                    format!("녹음 저장 실패: {}", e)
+                })?;
+            Ok(audio_path)
+        };
+        match extract() {
+            Ok(audio_path) => {
+                storage.set_audio_path(&session_id, &audio_path.to_string_lossy())?;
+            }
+            Err(e) => {
+                let _ = storage.delete_session(&session_id);
+                return Err(e);
+            }
+        }
+    }
+
+    let glossary_added = merge_glossary(&storage, glossary)?;
+    Ok(ImportedBundle {
+        session: storage.get_session(&session_id)?.session,
+        glossary_added,
+    })
+}
+
+/// 세션을 묶음 파일(`.teuim`)로 내보내기
+///
+/// 녹음, 전사·번역, 용어집 사본, 세션 정보를 zip 하나에 담아 다른 기기로 옮길 수 있게 한다.
+/// 암호화한 녹음은 풀어서 넣는다. 저장한 파일 경로를 반환한다.
+#[tauri::command]
+pub async fn export_session_bundle(
+    app: AppHandle,
+    session_id: String,
+    path: String,
+) -> Result<String, TeuImError> {
+    // 녹음 압축은 오래 걸릴 수 있어 블로킹 스레드에서 처리
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = PathBuf::from(path);
+        if let Err(e) = write_bundle(&app, &session_id, &path) {
+            let _ = std::fs::remove_file(&path);
+            return Err(e);
+        }
+        log::info!("세션 묶음 내보냄: {}", path.display());
+        Ok(path.to_string_lossy().into_owned())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 묶음 파일(`.teuim`)에서 세션 가져오기
+///
+/// 항상 새 세션으로 추가하고, 용어집은 아직 없는 용어만 합친다.
+#[tauri::command]
+pub async fn import_session_bundle(
+    app: AppHandle,
+    path: String,
+) -> Result<ImportedBundle, TeuImError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let imported = read_bundle(&app, Path::new(&path), None)?;
+        log::info!("세션 묶음 가져옴: {} (세션 {})", path, imported.session.id);
+        // 암호화를 켰으면 가져온 녹음도 암호화
+        encryption::spawn_encrypt_recordings(app.clone());
+        Ok(imported)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
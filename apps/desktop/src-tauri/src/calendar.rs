@@ -0,0 +1,564 @@
+use chrono::{DateTime, Duration as DateDuration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+use crate::credentials;
+use crate::error::TeuImError;
+use crate::notifications;
+use crate::scheduler::{self, Schedule, ScheduleOptions, DURATION_SECS_RANGE};
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+mod caldav;
+mod google;
+mod ics;
+
+/// 다가오는 회의를 확인하는 주기
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 캘린더를 다시 읽는 간격
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 지금부터 읽어 오는 일정 범위 (시간)
+const LOOKAHEAD_HOURS: i64 = 24;
+
+/// 회의 시작 몇 분 전에 알리거나 캡처를 예약할지
+const LEAD_MINUTES: i64 = 2;
+
+/// 캘린더 요청 제한 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 일정을 읽어 올 곳
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CalendarKind {
+    /// iCalendar 구독 주소 (Google·Outlook의 비밀 ICS 주소, `webcal://` 포함)
+    Ics { url: String },
+    /// CalDAV 캘린더 모음 주소 (Nextcloud, iCloud, Fastmail 등)
+    Caldav {
+        url: String,
+        #[serde(default)]
+        username: Option<String>,
+    },
+    /// Google Calendar API (`connect_google_calendar`로 로그인 필요)
+    Google {
+        /// Google Cloud 콘솔에서 만든 데스크톱 앱 OAuth 클라이언트 ID
+        client_id: String,
+        /// 생략하면 기본 캘린더
+        #[serde(default = "primary_calendar")]
+        calendar_id: String,
+    },
+}
+
+fn primary_calendar() -> String {
+    "primary".to_string()
+}
+
+/// 회의가 다가올 때 할 일
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingAction {
+    /// 읽기만 함 (`get_upcoming_meetings`로 조회)
+    Off,
+    /// 알림을 띄우고 `record_meeting`을 기다림
+    #[default]
+    Prompt,
+    /// 회의 시간에 맞춰 캡처 자동 시작
+    AutoStart,
+}
+
+/// 연결한 캘린더 (`set_calendar`로만 변경)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarSource {
+    /// 비워 두고 추가하면 새로 만듦
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub kind: CalendarKind,
+    #[serde(default)]
+    pub action: MeetingAction,
+    /// 회의 캡처에 쓸 입력 장치 (생략하면 기본 장치)
+    #[serde(default)]
+    pub device_id: Option<String>,
+    /// 전사·녹음 여부와 전사 언어 (세션 제목은 회의 제목을 씀)
+    #[serde(default)]
+    pub options: ScheduleOptions,
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    let parsed =
+        reqwest::Url::parse(url).map_err(|e| format!("잘못된 캘린더 주소: {} ({})", url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https" | "webcal") {
+        return Err(format!("캘린더 주소는 http(s)여야 합니다: {}", url));
+    }
+    Ok(())
+}
+
+impl CalendarSource {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("캘린더 이름을 입력하세요".to_string());
+        }
+        match &self.kind {
+            CalendarKind::Ics { url } | CalendarKind::Caldav { url, .. } => validate_url(url),
+            CalendarKind::Google {
+                client_id,
+                calendar_id,
+            } => {
+                if client_id.trim().is_empty() || calendar_id.trim().is_empty() {
+                    return Err("Google 클라이언트 ID와 캘린더 ID를 입력하세요".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 캘린더에서 읽은 일정 하나 (반복 일정은 회차마다 하나)
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub summary: Option<String>,
+    pub location: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// 다가오는 회의
+#[derive(Debug, Clone, Serialize)]
+pub struct Meeting {
+    /// `{캘린더 ID}:{일정 UID}:{시작 시각}` (반복 일정은 회차마다 다름)
+    pub id: String,
+    pub calendar_id: String,
+    pub calendar_name: String,
+    pub title: String,
+    pub location: Option<String>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+}
+
+/// `meeting-upcoming` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingNotice {
+    pub meeting: Meeting,
+    /// 자동 시작으로 만든 예약 (알리기만 했으면 `None`)
+    pub schedule_id: Option<String>,
+}
+
+/// `calendar-connected` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarConnected {
+    pub calendar_id: String,
+    pub error: Option<String>,
+}
+
+/// 캘린더 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct CalendarService {
+    /// 캘린더별 (읽은 시각, 회의 목록)
+    cache: Mutex<HashMap<String, (Instant, Vec<Meeting>)>>,
+    /// 이미 알리거나 예약한 회의
+    handled: Mutex<HashSet<String>>,
+}
+
+/// CalDAV 암호 또는 Google 클라이언트 비밀번호를 두는 키체인 항목
+fn secret_entry(calendar_id: &str) -> String {
+    format!("calendar-{}", calendar_id)
+}
+
+/// Google 갱신 토큰을 두는 키체인 항목
+fn token_entry(calendar_id: &str) -> String {
+    format!("calendar-{}-token", calendar_id)
+}
+
+fn client() -> Result<reqwest::Client, TeuImError> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| TeuImError::from(e.to_string()))
+}
+
+/// 캘린더에서 `from`~`to`와 겹치는 일정 읽기
+async fn fetch(
+    source: &CalendarSource,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<CalendarEvent>, TeuImError> {
+    let client = client()?;
+    match &source.kind {
+        CalendarKind::Ics { url } => {
+            let url = match url.strip_prefix("webcal://") {
+                Some(rest) => format!("https://{}", rest),
+                None => url.clone(),
+            };
+            let response = client
+                .get(url)
+                .send()
+                .await
+                .map_err(|e| format!("캘린더 연결 실패: {}", e))?;
+            let status = response.status();
+            if !status.is_success() {
+                return Err(format!("캘린더 읽기 실패: HTTP {}", status).into());
+            }
+            let body = response
+                .text()
+                .await
+                .map_err(|e| format!("캘린더 읽기 실패: {}", e))?;
+            Ok(ics::parse_events(&body, from, to))
+        }
+        CalendarKind::Caldav { url, username } => {
+            let password = credentials::secret(&secret_entry(&source.id))?;
+            let documents = caldav::fetch(
+                &client,
+                url,
+                username.as_deref(),
+                password.as_deref(),
+                from,
+                to,
+            )
+            .await?;
+            Ok(documents
+                .iter()
+                .flat_map(|document| ics::parse_events(document, from, to))
+                .collect())
+        }
+        CalendarKind::Google {
+            client_id,
+            calendar_id,
+        } => {
+            let refresh_token = credentials::secret(&token_entry(&source.id))?
+                .ok_or("Google 캘린더에 로그인하지 않았습니다")?;
+            let client_secret = credentials::secret(&secret_entry(&source.id))?;
+            Ok(google::events(
+                &client,
+                client_id,
+                client_secret.as_deref(),
+                &refresh_token,
+                calendar_id,
+                from,
+                to,
+            )
+            .await?)
+        }
+    }
+}
+
+/// 캘린더의 다가오는 회의 (`force`가 아니면 최근에 읽은 목록 재사용)
+async fn meetings(
+    app: &AppHandle,
+    source: &CalendarSource,
+    force: bool,
+) -> Result<Vec<Meeting>, TeuImError> {
+    if !force {
+        let service = app.state::<CalendarService>();
+        let cache = service.cache.lock().unwrap();
+        if let Some((fetched_at, meetings)) = cache.get(&source.id) {
+            if fetched_at.elapsed() < REFRESH_INTERVAL {
+                return Ok(meetings.clone());
+            }
+        }
+    }
+
+    let now = Utc::now();
+    let events = fetch(source, now, now + DateDuration::hours(LOOKAHEAD_HOURS)).await?;
+    let meetings: Vec<_> = events
+        .into_iter()
+        .map(|event| Meeting {
+            id: format!("{}:{}:{}", source.id, event.uid, event.start.timestamp()),
+            calendar_id: source.id.clone(),
+            calendar_name: source.name.clone(),
+            title: event
+                .summary
+                .unwrap_or_else(|| "제목 없는 회의".to_string()),
+            location: event.location,
+            start_time: event.start,
+            end_time: event.end,
+        })
+        .collect();
+    app.state::<CalendarService>()
+        .cache
+        .lock()
+        .unwrap()
+        .insert(source.id.clone(), (Instant::now(), meetings.clone()));
+    Ok(meetings)
+}
+
+/// 회의로 만든 예약 (없으면 `None`)
+fn scheduled(storage: &Storage, meeting_id: &str) -> Result<Option<Schedule>, TeuImError> {
+    Ok(storage
+        .schedules()?
+        .into_iter()
+        .find(|schedule| schedule.calendar_event.as_deref() == Some(meeting_id)))
+}
+
+/// 회의 시간 동안 캡처하도록 예약 (이미 시작한 회의면 바로 시작)
+fn schedule_meeting(
+    storage: &Storage,
+    source: &CalendarSource,
+    meeting: &Meeting,
+) -> Result<Schedule, TeuImError> {
+    let seconds = (meeting.end_time - meeting.start_time).num_seconds();
+    let duration_secs = u32::try_from(seconds)
+        .unwrap_or(u32::MAX)
+        .clamp(*DURATION_SECS_RANGE.start(), *DURATION_SECS_RANGE.end());
+    let options = ScheduleOptions {
+        title: Some(meeting.title.clone()),
+        ..source.options.clone()
+    };
+    let schedule = scheduler::add(
+        storage,
+        meeting.start_time,
+        duration_secs,
+        source.device_id.clone(),
+        options,
+        Some(meeting.id.clone()),
+    )?;
+    log::info!("회의 캡처 예약: {} ({})", meeting.title, schedule.id);
+    Ok(schedule)
+}
+
+/// 곧 시작하는 회의를 알리거나 자동 시작 예약
+fn handle(app: &AppHandle, source: &CalendarSource, meeting: Meeting) -> Result<(), TeuImError> {
+    let storage = app.state::<Storage>();
+    // 앱을 다시 켠 뒤에는 이미 예약한 회의를 다시 알리지 않음
+    if scheduled(&storage, &meeting.id)?.is_some() {
+        return Ok(());
+    }
+    let schedule_id = match source.action {
+        MeetingAction::AutoStart => Some(schedule_meeting(&storage, source, &meeting)?.id),
+        _ => None,
+    };
+    notifications::meeting_upcoming(app, &meeting.title, schedule_id.is_some());
+    let _ = app.emit(
+        "meeting-upcoming",
+        MeetingNotice {
+            meeting,
+            schedule_id,
+        },
+    );
+    Ok(())
+}
+
+/// 캘린더마다 곧 시작하는 회의 확인
+async fn check(app: &AppHandle) {
+    let calendars = app.state::<SettingsStore>().get().calendars;
+    for source in calendars
+        .iter()
+        .filter(|source| source.action != MeetingAction::Off)
+    {
+        let meetings = match meetings(app, source, false).await {
+            Ok(meetings) => meetings,
+            Err(e) => {
+                log::warn!("캘린더 읽기 실패: {} ({})", source.name, e);
+                continue;
+            }
+        };
+        let now = Utc::now();
+        for meeting in meetings {
+            if meeting.start_time > now + DateDuration::minutes(LEAD_MINUTES)
+                || meeting.end_time <= now
+            {
+                continue;
+            }
+            let first = app
+                .state::<CalendarService>()
+                .handled
+                .lock()
+                .unwrap()
+                .insert(meeting.id.clone());
+            if !first {
+                continue;
+            }
+            let title = meeting.title.clone();
+            match handle(app, source, meeting) {
+                Ok(()) | Err(TeuImError::DatabaseLocked) => {}
+                Err(e) => log::warn!("회의 처리 실패: {} ({})", title, e),
+            }
+        }
+    }
+}
+
+/// 주기적으로 캘린더를 읽어 곧 시작하는 회의를 알리거나 캡처 예약
+pub fn spawn_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            check(&app).await;
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// 연결한 캘린더 목록
+#[tauri::command]
+pub fn list_calendars(store: State<'_, SettingsStore>) -> Vec<CalendarSource> {
+    store.get().calendars
+}
+
+/// 캘린더 추가 또는 변경 (`id`가 비어 있으면 추가)
+///
+/// `secret`은 CalDAV 암호 또는 Google 클라이언트 비밀번호로, OS 키체인에 저장한다
+/// (생략하면 기존 값 유지).
+#[tauri::command]
+pub fn set_calendar(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    source: CalendarSource,
+    secret: Option<String>,
+) -> Result<CalendarSource, TeuImError> {
+    let mut source = source;
+    if source.id.is_empty() {
+        source.id = Uuid::new_v4().to_string();
+    }
+    source.name = source.name.trim().to_string();
+    source.validate()?;
+
+    if let Some(secret) = secret.filter(|secret| !secret.is_empty()) {
+        credentials::set_secret(&secret_entry(&source.id), &secret)?;
+    }
+    let updated = store.modify(|settings| {
+        match settings.calendars.iter_mut().find(|c| c.id == source.id) {
+            Some(existing) => *existing = source.clone(),
+            None => settings.calendars.push(source.clone()),
+        }
+    })?;
+    app.state::<CalendarService>()
+        .cache
+        .lock()
+        .unwrap()
+        .remove(&source.id);
+    let _ = app.emit("settings-changed", &updated);
+    log::info!("캘린더 설정 변경: {}", source.name);
+    Ok(source)
+}
+
+/// 캘린더 연결 해제 (키체인의 암호와 토큰도 삭제)
+#[tauri::command]
+pub fn remove_calendar(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    calendar_id: String,
+) -> Result<(), TeuImError> {
+    let updated = store.modify(|settings| settings.calendars.retain(|c| c.id != calendar_id))?;
+    credentials::delete_secret(&secret_entry(&calendar_id))?;
+    credentials::delete_secret(&token_entry(&calendar_id))?;
+    app.state::<CalendarService>()
+        .cache
+        .lock()
+        .unwrap()
+        .remove(&calendar_id);
+    let _ = app.emit("settings-changed", &updated);
+    log::info!("캘린더 연결 해제: {}", calendar_id);
+    Ok(())
+}
+
+/// 모든 캘린더의 24시간 안 회의 (시작 시각 순, 읽지 못한 캘린더는 건너뜀)
+#[tauri::command]
+pub async fn get_upcoming_meetings(app: AppHandle) -> Result<Vec<Meeting>, TeuImError> {
+    let calendars = app.state::<SettingsStore>().get().calendars;
+    let mut upcoming = Vec::new();
+    for source in &calendars {
+        match meetings(&app, source, true).await {
+            Ok(meetings) => upcoming.extend(meetings),
+            Err(e) => log::warn!("캘린더 읽기 실패: {} ({})", source.name, e),
+        }
+    }
+    upcoming.sort_by_key(|meeting| meeting.start_time);
+    Ok(upcoming)
+}
+
+/// 알림을 받은 회의 캡처 (회의 제목을 세션 이름으로, 이미 시작했으면 바로 시작)
+#[tauri::command]
+pub fn record_meeting(app: AppHandle, meeting_id: String) -> Result<Schedule, TeuImError> {
+    let meeting = app
+        .state::<CalendarService>()
+        .cache
+        .lock()
+        .unwrap()
+        .values()
+        .flat_map(|(_, meetings)| meetings)
+        .find(|meeting| meeting.id == meeting_id)
+        .cloned()
+        .ok_or_else(|| {
+            TeuImError::invalid_argument(format!("회의를 찾을 수 없습니다: {}", meeting_id))
+        })?;
+    let source = app
+        .state::<SettingsStore>()
+        .get()
+        .calendars
+        .into_iter()
+        .find(|source| source.id == meeting.calendar_id)
+        .ok_or_else(|| {
+            TeuImError::invalid_argument(format!(
+                "캘린더를 찾을 수 없습니다: {}",
+                meeting.calendar_id
+            ))
+        })?;
+
+    let storage = app.state::<Storage>();
+    if let Some(schedule) = scheduled(&storage, &meeting.id)? {
+        return Ok(schedule);
+    }
+    schedule_meeting(&storage, &source, &meeting)
+}
+
+/// Google 캘린더 로그인 시작 (돌려준 주소를 브라우저로 열면 됨)
+///
+/// 로그인을 마치거나 실패하면 `calendar-connected` 이벤트로 알린다.
+#[tauri::command]
+pub async fn connect_google_calendar(
+    app: AppHandle,
+    calendar_id: String,
+) -> Result<String, TeuImError> {
+    let client_id = app
+        .state::<SettingsStore>()
+        .get()
+        .calendars
+        .into_iter()
+        .find_map(|source| match source.kind {
+            CalendarKind::Google { client_id, .. } if source.id == calendar_id => Some(client_id),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            TeuImError::invalid_argument(format!(
+                "Google 캘린더를 찾을 수 없습니다: {}",
+                calendar_id
+            ))
+        })?;
+    let client_secret = credentials::secret(&secret_entry(&calendar_id))?;
+    let login = google::begin_login(&client_id).await?;
+    let url = login.url.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let result = match client() {
+            Ok(client) => {
+                google::finish_login(&client, login, &client_id, client_secret.as_deref())
+                    .await
+                    .and_then(|token| credentials::set_secret(&token_entry(&calendar_id), &token))
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        match &result {
+            Ok(()) => {
+                log::info!("Google 캘린더 연결됨: {}", calendar_id);
+                app.state::<CalendarService>()
+                    .cache
+                    .lock()
+                    .unwrap()
+                    .remove(&calendar_id);
+            }
+            Err(e) => log::warn!("Google 캘린더 연결 실패: {}", e),
+        }
+        let _ = app.emit(
+            "calendar-connected",
+            CalendarConnected {
+                calendar_id,
+                error: result.err(),
+            },
+        );
+    });
+    Ok(url)
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+
+/// CalDAV 시간 범위 형식
+const TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// 캘린더 모음에서 `from`~`to`와 겹치는 일정의 iCalendar 문서 목록 가져오기
+///
+/// 서버가 반복 일정을 회차별로 펼쳐 주도록 `expand`를 함께 요청한다.
+pub async fn fetch(
+    client: &reqwest::Client,
+    url: &str,
+    username: Option<&str>,
+    password: Option<&str>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<String>, String> {
+    let range = format!(
+        "start=\"{}\" end=\"{}\"",
+        from.format(TIME_FORMAT),
+        to.format(TIME_FORMAT)
+    );
+    let body = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop>
+    <c:calendar-data>
+      <c:expand {range}/>
+    </c:calendar-data>
+  </d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VEVENT">
+        <c:time-range {range}/>
+      </c:comp-filter>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#
+    );
+
+    let method = Method::from_bytes(b"REPORT").expect("올바른 메서드 이름");
+    let mut request = client
+        .request(method, url)
+        .header("Depth", "1")
+        .header(CONTENT_TYPE, "application/xml; charset=utf-8")
+        .body(body);
+    if let Some(username) = username {
+        request = request.basic_auth(username, password);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("CalDAV 서버 연결 실패: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("CalDAV 일정 조회 실패: HTTP {}", status));
+    }
+    let xml = response
+        .text()
+        .await
+        .map_err(|e| format!("CalDAV 응답 읽기 실패: {}", e))?;
+    calendar_data(&xml)
+}
+
+/// multistatus 응답에서 `calendar-data` 내용만 모음
+fn calendar_data(xml: &str) -> Result<Vec<String>, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut documents = Vec::new();
+    let mut text: Option<String> = None;
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            format!(
+                "CalDAV 응답 읽기 실패 (위치 {}): {}",
+                reader.buffer_position(),
+                e
+            )
+        })?;
+        match event {
+            Event::Start(e) if e.local_name().as_ref() == b"calendar-data" => {
+                text = Some(String::new());
+            }
+            Event::Text(e) => {
+                if let Some(text) = text.as_mut() {
+                    let value = e
+                        .unescape()
+                        .map_err(|e| format!("CalDAV 응답 읽기 실패: {}", e))?;
+                    text.push_str(&value);
+                }
+            }
+            Event::CData(e) => {
+                if let Some(text) = text.as_mut() {
+                    text.push_str(&String::from_utf8_lossy(&e.into_inner()));
+                }
+            }
+            Event::End(e) if e.local_name().as_ref() == b"calendar-data" => {
+                documents.extend(text.take());
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(documents)
+}
@@ -0,0 +1,254 @@
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+use super::CalendarEvent;
+
+const AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const CALENDARS_URL: &str = "https://www.googleapis.com/calendar/v3/calendars";
+
+/// 일정 읽기 전용 권한
+const SCOPE: &str = "https://www.googleapis.com/auth/calendar.readonly";
+
+/// 브라우저에서 로그인을 마칠 때까지 기다리는 시간
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// 로그인 뒤 브라우저에 보여 줄 페이지
+const LOGIN_DONE_PAGE: &str = "<!doctype html><meta charset=\"utf-8\">\
+    <p>Teu-Im 캘린더 연결이 끝났습니다. 이 창을 닫아도 됩니다.</p>";
+
+/// 진행 중인 로그인 (PKCE와 루프백 리디렉션 사용)
+pub struct Login {
+    /// 브라우저로 열 로그인 주소
+    pub url: String,
+    listener: TcpListener,
+    redirect_uri: String,
+    verifier: String,
+    state: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct EventList {
+    #[serde(default)]
+    items: Vec<GoogleEvent>,
+}
+
+#[derive(Deserialize)]
+struct GoogleEvent {
+    id: String,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    location: Option<String>,
+    start: Option<EventTime>,
+    end: Option<EventTime>,
+}
+
+/// 종일 일정은 `date`만 있고 `dateTime`이 없음
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventTime {
+    #[serde(default)]
+    date_time: Option<DateTime<Utc>>,
+}
+
+/// 로그인 주소를 만들고 리디렉션을 받을 루프백 포트 열기
+pub async fn begin_login(client_id: &str) -> Result<Login, String> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| format!("로그인 응답 포트 열기 실패: {}", e))?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    let redirect_uri = format!("http://127.0.0.1:{}", port);
+    let verifier = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .encode(Sha256::digest(verifier.as_bytes()));
+    let state = Uuid::new_v4().simple().to_string();
+
+    let url = Url::parse_with_params(
+        AUTH_URL,
+        [
+            ("client_id", client_id),
+            ("redirect_uri", &redirect_uri),
+            ("response_type", "code"),
+            ("scope", SCOPE),
+            ("code_challenge", &challenge),
+            ("code_challenge_method", "S256"),
+            ("state", &state),
+            ("access_type", "offline"),
+            ("prompt", "consent"),
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(Login {
+        url: url.to_string(),
+        listener,
+        redirect_uri,
+        verifier,
+        state,
+    })
+}
+
+/// 브라우저의 리디렉션을 받아 갱신 토큰으로 교환
+pub async fn finish_login(
+    client: &reqwest::Client,
+    login: Login,
+    client_id: &str,
+    client_secret: Option<&str>,
+) -> Result<String, String> {
+    let code = tokio::time::timeout(LOGIN_TIMEOUT, receive_code(&login))
+        .await
+        .map_err(|_| "Google 로그인 시간이 지났습니다".to_string())??;
+
+    let mut form = vec![
+        ("grant_type", "authorization_code"),
+        ("code", code.as_str()),
+        ("client_id", client_id),
+        ("redirect_uri", login.redirect_uri.as_str()),
+        ("code_verifier", login.verifier.as_str()),
+    ];
+    form.extend(client_secret.map(|secret| ("client_secret", secret)));
+    let tokens = request_token(client, &form).await?;
+    tokens
+        .refresh_token
+        .ok_or_else(|| "Google이 갱신 토큰을 돌려주지 않았습니다".to_string())
+}
+
+/// 루프백 포트로 들어온 요청에서 인증 코드 꺼내기
+async fn receive_code(login: &Login) -> Result<String, String> {
+    loop {
+        let (mut stream, _) = login
+            .listener
+            .accept()
+            .await
+            .map_err(|e| format!("로그인 응답 받기 실패: {}", e))?;
+        let mut buffer = vec![0u8; 8 * 1024];
+        let read = stream.read(&mut buffer).await.unwrap_or(0);
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        // 요청 줄: GET /?code=...&state=... HTTP/1.1
+        let Some(target) = request.split_whitespace().nth(1) else {
+            continue;
+        };
+        let Ok(url) = Url::parse(&format!("http://127.0.0.1{}", target)) else {
+            continue;
+        };
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        // 파비콘 요청처럼 로그인과 무관한 요청은 무시
+        if param("state").as_deref() != Some(login.state.as_str()) {
+            continue;
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            LOGIN_DONE_PAGE.len(),
+            LOGIN_DONE_PAGE
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        if let Some(error) = param("error") {
+            return Err(format!("Google 로그인 실패: {}", error));
+        }
+        return param("code").ok_or_else(|| "인증 코드가 없습니다".to_string());
+    }
+}
+
+async fn request_token(
+    client: &reqwest::Client,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse, String> {
+    let response = client
+        .post(TOKEN_URL)
+        .form(form)
+        .send()
+        .await
+        .map_err(|e| format!("Google 연결 실패: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Google 토큰 발급 실패: HTTP {} {}", status, body));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Google 토큰 응답 해석 실패: {}", e))
+}
+
+/// `from`~`to`와 겹치는 일정 (반복 일정은 회차별로)
+pub async fn events(
+    client: &reqwest::Client,
+    client_id: &str,
+    client_secret: Option<&str>,
+    refresh_token: &str,
+    calendar_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<CalendarEvent>, String> {
+    let mut form = vec![
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+        ("client_id", client_id),
+    ];
+    form.extend(client_secret.map(|secret| ("client_secret", secret)));
+    let access_token = request_token(client, &form).await?.access_token;
+
+    let mut url = Url::parse(CALENDARS_URL).map_err(|e| e.to_string())?;
+    url.path_segments_mut()
+        .map_err(|_| "잘못된 Google 캘린더 주소".to_string())?
+        .push(calendar_id)
+        .push("events");
+    url.query_pairs_mut()
+        .append_pair("timeMin", &from.to_rfc3339())
+        .append_pair("timeMax", &to.to_rfc3339())
+        .append_pair("singleEvents", "true")
+        .append_pair("orderBy", "startTime")
+        .append_pair("maxResults", "250");
+
+    let response = client
+        .get(url)
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(|e| format!("Google 캘린더 연결 실패: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Google 캘린더 일정 조회 실패: HTTP {}", status));
+    }
+    let list: EventList = response
+        .json()
+        .await
+        .map_err(|e| format!("Google 캘린더 응답 해석 실패: {}", e))?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .filter(|event| event.status.as_deref() != Some("cancelled"))
+        .filter_map(|event| {
+            Some(CalendarEvent {
+                start: event.start?.date_time?,
+                end: event.end?.date_time?,
+                uid: event.id,
+                summary: event.summary,
+                location: event.location,
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,357 @@
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use std::collections::HashSet;
+
+use super::CalendarEvent;
+
+/// 반복 일정을 펼칠 때 확인하는 최대 회차 (잘못된 규칙으로 끝없이 돌지 않게)
+const MAX_OCCURRENCES: usize = 20_000;
+
+/// 날짜·시각 값 (반복 규칙은 적힌 시간대 그대로 펼친 뒤 UTC로 바꿈)
+#[derive(Debug, Clone, Copy)]
+struct DateValue {
+    naive: NaiveDateTime,
+    /// `Z`로 끝나는 UTC 값 (아니면 로컬 시각으로 봄)
+    utc: bool,
+}
+
+impl DateValue {
+    fn to_utc(self) -> Option<DateTime<Utc>> {
+        if self.utc {
+            return Some(self.naive.and_utc());
+        }
+        Local
+            .from_local_datetime(&self.naive)
+            .earliest()
+            .map(|local| local.with_timezone(&Utc))
+    }
+
+    fn with_naive(self, naive: NaiveDateTime) -> Self {
+        Self { naive, ..self }
+    }
+}
+
+/// 읽는 중인 VEVENT
+#[derive(Default)]
+struct RawEvent {
+    uid: Option<String>,
+    summary: Option<String>,
+    location: Option<String>,
+    start: Option<DateValue>,
+    end: Option<DateValue>,
+    duration: Option<Duration>,
+    rrule: Option<String>,
+    exdates: Vec<DateValue>,
+    recurrence_id: Option<DateValue>,
+    cancelled: bool,
+    /// 종일 일정 (회의가 아니므로 건너뜀)
+    all_day: bool,
+}
+
+/// iCalendar 문서에서 `from`~`to`와 겹치는 일정 추출
+///
+/// 종일 일정과 취소된 일정은 뺀다. `TZID`가 붙은 시각은 로컬 시각으로 보며,
+/// 반복 규칙은 DAILY·WEEKLY·MONTHLY·YEARLY와 INTERVAL, COUNT, UNTIL,
+/// WEEKLY의 BYDAY만 지원한다.
+pub fn parse_events(ics: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<CalendarEvent> {
+    let mut raw_events = Vec::new();
+    let mut current: Option<RawEvent> = None;
+    // VEVENT 안의 VALARM 같은 하위 구성 요소 깊이
+    let mut nested = 0usize;
+
+    for line in unfold(ics) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") if current.is_none() => current = Some(RawEvent::default()),
+            ("BEGIN", _) if current.is_some() => nested += 1,
+            ("END", "VEVENT") if nested == 0 => raw_events.extend(current.take()),
+            ("END", _) if current.is_some() => nested = nested.saturating_sub(1),
+            _ => {
+                let Some(event) = current.as_mut().filter(|_| nested == 0) else {
+                    continue;
+                };
+                apply_property(event, &name, params, value);
+            }
+        }
+    }
+
+    // 따로 적힌 회차(RECURRENCE-ID)는 반복 규칙으로 만든 같은 회차를 대신함
+    let overridden: HashSet<(String, DateTime<Utc>)> = raw_events
+        .iter()
+        .filter_map(|event| {
+            Some((
+                event.uid.clone()?,
+                event.recurrence_id.and_then(DateValue::to_utc)?,
+            ))
+        })
+        .collect();
+
+    let mut events = Vec::new();
+    for raw in raw_events {
+        if raw.cancelled || raw.all_day {
+            continue;
+        }
+        let (Some(uid), Some(start)) = (raw.uid.clone(), raw.start) else {
+            continue;
+        };
+        let length = match (raw.end.and_then(DateValue::to_utc), start.to_utc()) {
+            (Some(end), Some(begin)) => end - begin,
+            _ => raw.duration.unwrap_or_else(Duration::zero),
+        };
+        if length <= Duration::zero() {
+            continue;
+        }
+
+        let excluded: HashSet<DateTime<Utc>> = raw
+            .exdates
+            .iter()
+            .filter_map(|date| date.to_utc())
+            .collect();
+        let starts = match (&raw.rrule, raw.recurrence_id) {
+            (Some(rule), None) => expand(start, rule, to),
+            _ => start.to_utc().into_iter().collect(),
+        };
+        for begin in starts {
+            if excluded.contains(&begin)
+                || (raw.recurrence_id.is_none() && overridden.contains(&(uid.clone(), begin)))
+            {
+                continue;
+            }
+            let end = begin + length;
+            if end > from && begin < to {
+                events.push(CalendarEvent {
+                    uid: uid.clone(),
+                    summary: raw.summary.clone(),
+                    location: raw.location.clone(),
+                    start: begin,
+                    end,
+                });
+            }
+        }
+    }
+    events.sort_by_key(|event| event.start);
+    events
+}
+
+/// 접힌 줄(공백·탭으로 시작하는 줄)을 앞 줄에 이어 붙임
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// `NAME;PARAM=VALUE:값`을 (대문자 이름, 매개변수, 값)으로 나눔
+fn split_property(line: &str) -> Option<(String, &str, &str)> {
+    // 따옴표 안의 콜론(예: ALTREP="http://...")은 구분자가 아님
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(index, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(index),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    Some((name.to_ascii_uppercase(), params, value))
+}
+
+fn apply_property(event: &mut RawEvent, name: &str, params: &str, value: &str) {
+    let is_date = params.to_ascii_uppercase().contains("VALUE=DATE")
+        && !params.to_ascii_uppercase().contains("VALUE=DATE-TIME");
+    match name {
+        "UID" => event.uid = Some(value.to_string()),
+        "SUMMARY" => event.summary = Some(unescape(value)).filter(|s| !s.is_empty()),
+        "LOCATION" => event.location = Some(unescape(value)).filter(|s| !s.is_empty()),
+        "DTSTART" => {
+            event.all_day = is_date || value.len() == 8;
+            event.start = parse_date(value);
+        }
+        "DTEND" => event.end = parse_date(value),
+        "DURATION" => event.duration = parse_duration(value),
+        "RRULE" => event.rrule = Some(value.to_ascii_uppercase()),
+        "EXDATE" => event
+            .exdates
+            .extend(value.split(',').filter_map(parse_date)),
+        "RECURRENCE-ID" => event.recurrence_id = parse_date(value),
+        "STATUS" => event.cancelled = value.eq_ignore_ascii_case("CANCELLED"),
+        _ => {}
+    }
+}
+
+/// 텍스트 값의 `\n`, `\,`, `\;`, `\\` 풀기
+fn unescape(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(other) => text.push(other),
+            None => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+/// `20240301T140000Z`, `20240301T140000`, `20240301` 형식
+fn parse_date(value: &str) -> Option<DateValue> {
+    let value = value.trim();
+    let (value, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(rest) => (rest, true),
+        None => (value, false),
+    };
+    let naive = if value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?
+    };
+    Some(DateValue { naive, utc })
+}
+
+/// `PT1H30M`, `P1D`, `P1W` 형식의 길이
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (negative, value) = match value.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.trim().trim_start_matches('+')),
+    };
+    let mut rest = value.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if let Some(time) = rest.strip_prefix('T') {
+            in_time = true;
+            rest = time;
+            continue;
+        }
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount: i64 = rest[..digits].parse().ok()?;
+        total += match (&rest[digits..digits + 1], in_time) {
+            ("W", false) => Duration::weeks(amount),
+            ("D", false) => Duration::days(amount),
+            ("H", true) => Duration::hours(amount),
+            ("M", true) => Duration::minutes(amount),
+            ("S", true) => Duration::seconds(amount),
+            _ => return None,
+        };
+        rest = &rest[digits + 1..];
+    }
+    Some(if negative { -total } else { total })
+}
+
+/// 반복 규칙으로 `to` 전까지의 회차 시작 시각 생성
+fn expand(start: DateValue, rule: &str, to: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut freq = None;
+    let mut interval = 1u32;
+    let mut count = None;
+    let mut until = None;
+    let mut by_day = Vec::new();
+    for part in rule.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        match key {
+            "FREQ" => freq = Some(value),
+            "INTERVAL" => interval = value.parse().unwrap_or(1).max(1),
+            "COUNT" => count = value.parse::<usize>().ok(),
+            "UNTIL" => until = parse_date(value).and_then(DateValue::to_utc),
+            "BYDAY" => {
+                by_day = value
+                    .split(',')
+                    .filter_map(|day| {
+                        weekday(day.trim_start_matches(|c: char| {
+                            c == '+' || c == '-' || c.is_ascii_digit()
+                        }))
+                    })
+                    .collect()
+            }
+            _ => {}
+        }
+    }
+
+    let first = start.naive;
+    let candidates: Box<dyn Iterator<Item = NaiveDateTime>> = match freq {
+        Some("DAILY") => {
+            Box::new((0..).map(move |n: i64| first + Duration::days(n * i64::from(interval))))
+        }
+        Some("WEEKLY") if !by_day.is_empty() => {
+            let week_start = first - Duration::days(first.weekday().num_days_from_monday().into());
+            let mut days: Vec<i64> = by_day
+                .iter()
+                .map(|day| day.num_days_from_monday().into())
+                .collect();
+            days.sort_unstable();
+            days.dedup();
+            Box::new((0..).flat_map(move |n: i64| {
+                let week = week_start + Duration::weeks(n * i64::from(interval));
+                days.clone()
+                    .into_iter()
+                    .map(move |day| week + Duration::days(day))
+            }))
+        }
+        Some("WEEKLY") => {
+            Box::new((0..).map(move |n: i64| first + Duration::weeks(n * i64::from(interval))))
+        }
+        Some("MONTHLY") => {
+            Box::new((0..).filter_map(move |n: u32| add_months(first, n * interval)))
+        }
+        Some("YEARLY") => {
+            Box::new((0..).filter_map(move |n: u32| add_months(first, n * interval * 12)))
+        }
+        _ => Box::new(std::iter::once(first)),
+    };
+
+    let mut starts = Vec::new();
+    let mut produced = 0usize;
+    for naive in candidates.take(MAX_OCCURRENCES) {
+        if naive < first {
+            continue;
+        }
+        let Some(begin) = start.with_naive(naive).to_utc() else {
+            continue;
+        };
+        if begin >= to || until.is_some_and(|until| begin > until) {
+            break;
+        }
+        produced += 1;
+        if count.is_some_and(|count| produced > count) {
+            break;
+        }
+        starts.push(begin);
+    }
+    starts
+}
+
+/// `months`개월 뒤 같은 날 (그 달에 없는 날이면 `None`)
+fn add_months(date: NaiveDateTime, months: u32) -> Option<NaiveDateTime> {
+    let total = date.year() * 12 + date.month0() as i32 + months as i32;
+    NaiveDate::from_ymd_opt(total / 12, total as u32 % 12 + 1, date.day())
+        .map(|day| day.and_time(date.time()))
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
@@ -0,0 +1,396 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+
+use crate::error::TeuImError;
+use crate::transcribe::TranscriptSegment;
+
+/// 보관하는 최근 확정 줄 수
+const HISTORY_LINES: usize = 100;
+
+/// `lines` 파라미터를 생략했을 때 돌려주는 줄 수
+const DEFAULT_LINES: usize = 3;
+
+/// 요청 헤더 최대 크기
+const MAX_REQUEST_LEN: usize = 8 * 1024;
+
+/// 프록시나 브라우저가 유휴 연결을 끊지 않게 보내는 SSE 주석 간격
+const SSE_KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// 자막 한 줄
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptionLine {
+    pub id: String,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    /// 스트리밍 모드 부분 결과에서 더는 바뀌지 않을 앞부분
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stable_text: Option<String>,
+}
+
+/// `GET /captions?format=json` 응답 (자막 오버레이 창에도 같은 형태로 보냄)
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptionsSnapshot {
+    pub lines: Vec<CaptionLine>,
+    /// 아직 확정되지 않은 현재 문장
+    pub partial: Option<CaptionLine>,
+}
+
+/// SSE로 보내는 갱신 (`event:` 이름과 JSON 데이터)
+#[derive(Debug, Clone)]
+struct CaptionUpdate {
+    event: &'static str,
+    data: String,
+}
+
+#[derive(Default)]
+struct History {
+    lines: VecDeque<CaptionLine>,
+    partial: Option<CaptionLine>,
+}
+
+struct RunningServer {
+    port: u16,
+    shutdown: watch::Sender<bool>,
+}
+
+/// OBS 브라우저 소스용 자막 HTTP 서버 (Tauri 관리 상태)
+///
+/// - `GET /captions` — 최근 N줄을 일반 텍스트로 (`?lines=N`, `?format=json`)
+/// - `GET /captions/stream` — `partial` / `final` 이벤트를 보내는 SSE 스트림
+///
+/// 서버가 꺼져 있어도 최근 줄은 계속 모아 두므로 켜자마자 내용이 보인다.
+pub struct CaptionServer {
+    history: Mutex<History>,
+    tx: broadcast::Sender<CaptionUpdate>,
+    running: Mutex<Option<RunningServer>>,
+}
+
+impl Default for CaptionServer {
+    fn default() -> Self {
+        Self {
+            history: Mutex::new(History::default()),
+            tx: broadcast::channel(HISTORY_LINES).0,
+            running: Mutex::new(None),
+        }
+    }
+}
+
+impl CaptionServer {
+    /// 최근 확정 `count`줄과 현재 부분 결과
+    pub fn snapshot(&self, count: usize) -> CaptionsSnapshot {
+        let history = self.history.lock().unwrap();
+        let skip = history.lines.len().saturating_sub(count);
+        CaptionsSnapshot {
+            lines: history.lines.iter().skip(skip).cloned().collect(),
+            partial: history.partial.clone(),
+        }
+    }
+}
+
+/// 전사 결과를 자막 기록에 반영하고 SSE 구독자에게 알림
+pub fn push(app: &AppHandle, segment: &TranscriptSegment) {
+    let server = app.state::<CaptionServer>();
+    let line = CaptionLine {
+        id: segment.id.clone(),
+        text: segment.text.trim().to_string(),
+        start_ms: segment.start_ms,
+        end_ms: segment.end_ms,
+        stable_text: segment.stable_text.clone(),
+    };
+
+    {
+        let mut history = server.history.lock().unwrap();
+        if segment.is_final {
+            history.partial = None;
+            history.lines.push_back(line.clone());
+            let excess = history.lines.len().saturating_sub(HISTORY_LINES);
+            history.lines.drain(..excess);
+        } else {
+            history.partial = Some(line.clone());
+        }
+    }
+    crate::overlay::refresh(app);
+
+    if server.tx.receiver_count() == 0 {
+        return;
+    }
+    match serde_json::to_string(&line) {
+        Ok(data) => {
+            let event = if segment.is_final { "final" } else { "partial" };
+            let _ = server.tx.send(CaptionUpdate { event, data });
+        }
+        Err(e) => log::error!("자막 직렬화 실패: {}", e),
+    }
+}
+
+/// 다른 출처 페이지에 응답을 열어 줄 CORS 헤더 (`allowed_origin`을 준 경우만)
+fn cors_header(allowed_origin: Option<&str>) -> Result<String, TeuImError> {
+    let Some(origin) = allowed_origin.map(|origin| origin.trim().trim_end_matches('/')) else {
+        return Ok(String::new());
+    };
+    let valid = (origin.starts_with("http://") || origin.starts_with("https://"))
+        && !origin.chars().any(|c| c.is_whitespace() || c.is_control());
+    if !valid {
+        return Err(TeuImError::invalid_argument(format!(
+            "허용할 출처는 http(s)://호스트[:포트] 형식이어야 합니다: {}",
+            origin
+        )));
+    }
+    Ok(format!(
+        "Access-Control-Allow-Origin: {}\r\nVary: Origin\r\n",
+        origin
+    ))
+}
+
+/// 자막 서버 시작 후 실제 포트 반환 (`port`가 0이면 빈 포트 자동 선택)
+///
+/// 기본으로는 CORS 헤더를 보내지 않아 OBS 브라우저 소스처럼 주소를 직접 여는 곳에서만 읽힌다.
+/// 다른 웹 페이지에서 읽어야 하면 그 출처를 `allowed_origin`(예: `http://localhost:8080`)으로 준다.
+#[tauri::command]
+pub async fn start_caption_server(
+    app: AppHandle,
+    port: u16,
+    allowed_origin: Option<String>,
+) -> Result<u16, TeuImError> {
+    let cors: Arc<str> = cors_header(allowed_origin.as_deref())?.into();
+    let server = app.state::<CaptionServer>();
+    if let Some(running) = server.running.lock().unwrap().as_ref() {
+        return Err(TeuImError::from(format!(
+            "자막 서버가 이미 실행 중입니다 (포트 {})",
+            running.port
+        )));
+    }
+
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], port)))
+        .await
+        .map_err(|e| TeuImError::from(format!("자막 서버 시작 실패: {}", e)))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| TeuImError::from(e.to_string()))?
+        .port();
+
+    let (shutdown, shutdown_rx) = watch::channel(false);
+    {
+        let mut running = server.running.lock().unwrap();
+        if running.is_some() {
+            return Err("자막 서버가 이미 실행 중입니다".into());
+        }
+        *running = Some(RunningServer { port, shutdown });
+    }
+
+    tauri::async_runtime::spawn(accept_loop(app.clone(), listener, cors, shutdown_rx));
+    log::info!("자막 서버 시작: http://127.0.0.1:{}/captions", port);
+    Ok(port)
+}
+
+/// 자막 서버 중지 (열린 SSE 연결도 모두 끊음)
+#[tauri::command]
+pub fn stop_caption_server(server: State<'_, CaptionServer>) {
+    if let Some(running) = server.running.lock().unwrap().take() {
+        let _ = running.shutdown.send(true);
+        log::info!("자막 서버 중지: 포트 {}", running.port);
+    }
+}
+
+async fn accept_loop(
+    app: AppHandle,
+    listener: TcpListener,
+    cors: Arc<str>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let client = serve_client(app.clone(), stream, cors.clone(), shutdown.clone());
+                    tauri::async_runtime::spawn(client);
+                }
+                Err(e) => log::warn!("자막 클라이언트 연결 수락 실패: {}", e),
+            },
+        }
+    }
+}
+
+/// 요청 하나를 처리하고 연결을 닫음 (keep-alive 미지원)
+async fn serve_client(
+    app: AppHandle,
+    mut stream: TcpStream,
+    cors: Arc<str>,
+    shutdown: watch::Receiver<bool>,
+) {
+    let request = match read_request(&mut stream).await {
+        Ok(request) => request,
+        Err(e) => {
+            log::debug!("자막 요청 읽기 실패: {}", e);
+            return;
+        }
+    };
+
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/captions/stream") => serve_stream(&app, &mut stream, &cors, shutdown).await,
+        ("GET", "/captions") => {
+            let server = app.state::<CaptionServer>();
+            let count = request
+                .query("lines")
+                .and_then(|lines| lines.parse::<usize>().ok())
+                .unwrap_or(DEFAULT_LINES)
+                .min(HISTORY_LINES);
+            let snapshot = server.snapshot(count);
+            if request.query("format") == Some("json") {
+                let body = serde_json::to_string(&snapshot).unwrap_or_default();
+                respond(&mut stream, &cors, "200 OK", "application/json", &body).await
+            } else {
+                let body: Vec<&str> = snapshot
+                    .lines
+                    .iter()
+                    .map(|line| line.text.as_str())
+                    .collect();
+                respond(
+                    &mut stream,
+                    &cors,
+                    "200 OK",
+                    "text/plain; charset=utf-8",
+                    &body.join("\n"),
+                )
+                .await
+            }
+        }
+        ("GET", _) => {
+            respond(
+                &mut stream,
+                &cors,
+                "404 Not Found",
+                "text/plain",
+                "Not Found",
+            )
+            .await
+        }
+        _ => {
+            respond(
+                &mut stream,
+                &cors,
+                "405 Method Not Allowed",
+                "text/plain",
+                "Method Not Allowed",
+            )
+            .await
+        }
+    };
+
+    if let Err(e) = result {
+        log::debug!("자막 응답 전송 실패: {}", e);
+    }
+}
+
+/// 확정·부분 결과를 SSE 이벤트로 계속 보냄
+async fn serve_stream(
+    app: &AppHandle,
+    stream: &mut TcpStream,
+    cors: &str,
+    mut shutdown: watch::Receiver<bool>,
+) -> std::io::Result<()> {
+    let server = app.state::<CaptionServer>();
+    let mut rx = server.tx.subscribe();
+    let head = format!(
+        "HTTP/1.1 200 OK\r\n\
+         Content-Type: text/event-stream\r\n\
+         Cache-Control: no-cache\r\n\
+         Connection: keep-alive\r\n{}\r\n",
+        cors
+    );
+    stream.write_all(head.as_bytes()).await?;
+
+    let mut keepalive = tokio::time::interval(SSE_KEEPALIVE);
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => return Ok(()),
+            _ = keepalive.tick() => stream.write_all(b": keepalive\n\n").await?,
+            update = rx.recv() => match update {
+                Ok(update) => {
+                    let message = format!("event: {}\ndata: {}\n\n", update.event, update.data);
+                    stream.write_all(message.as_bytes()).await?;
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("자막 SSE 구독자가 밀려 {}개 건너뜀", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+        }
+    }
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    cors: &str,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\n\
+         Content-Type: {}\r\n\
+         Content-Length: {}\r\n\
+         Cache-Control: no-cache\r\n{}\
+         Connection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        cors,
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+impl Request {
+    fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// 요청 줄만 해석 (본문과 헤더는 쓰지 않음)
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Request> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 || buffer.len() + read > MAX_REQUEST_LEN {
+            return Err(std::io::ErrorKind::InvalidData.into());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&buffer);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    Ok(Request {
+        method,
+        path: path.trim_end_matches('/').to_string(),
+        query: query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    })
+}
@@ -0,0 +1,161 @@
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+use crate::audio::{self, CaptureManager};
+use crate::error::TeuImError;
+use crate::recording;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::stt::SttConfig;
+use crate::transcribe::{self, TranscriptionEngine, TranscriptionOptions};
+
+pub const USAGE: &str = "\
+사용법: teu-im-desktop [옵션]
+
+  --capture             마이크 캡처 시작
+  --device <ID>         캡처할 입력 장치 (생략하면 설정의 기본 장치)
+  --output <경로.wav>   캡처를 WAV 파일로 녹음
+  --transcribe          캡처를 실시간 전사 (결과는 세션으로 저장)
+  --language <코드>     전사 언어 (예: ko, en)
+  --model <경로>        전사에 쓸 Whisper 모델 (생략하면 설정의 모델)
+  --hidden              창을 띄우지 않고 트레이에서만 실행
+  -h, --help            이 도움말 출력";
+
+/// 명령줄 옵션
+///
+/// `--device`, `--output`, `--transcribe`는 캡처를 함께 시작한다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    pub capture: bool,
+    pub device_id: Option<String>,
+    pub output: Option<PathBuf>,
+    pub transcribe: bool,
+    pub language: Option<String>,
+    pub model: Option<PathBuf>,
+    pub hidden: bool,
+    pub help: bool,
+}
+
+impl CliArgs {
+    /// 실행 파일 경로를 뺀 인자 해석
+    ///
+    /// 딥 링크(`teuim://...`)는 인자로 전달되기도 하므로 건너뛴다.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .filter(|value| !value.starts_with("--"))
+                    .ok_or_else(|| format!("{} 뒤에 값이 필요합니다", name))
+            };
+            match arg.as_str() {
+                "--capture" => parsed.capture = true,
+                "--device" => parsed.device_id = Some(value("--device")?),
+                "--output" => parsed.output = Some(PathBuf::from(value("--output")?)),
+                "--transcribe" => parsed.transcribe = true,
+                "--language" => parsed.language = Some(value("--language")?),
+                "--model" => parsed.model = Some(PathBuf::from(value("--model")?)),
+                "--hidden" => parsed.hidden = true,
+                "-h" | "--help" => parsed.help = true,
+                other if other.starts_with("teuim://") => {}
+                other => return Err(format!("알 수 없는 인자: {}", other)),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// 캡처를 시작해야 하는지
+    pub fn wants_capture(&self) -> bool {
+        self.capture || self.device_id.is_some() || self.output.is_some() || self.transcribe
+    }
+
+    /// 상대 경로를 `cwd` 기준으로 바꿈 (두 번째 실행에서 넘어온 인자용)
+    pub fn resolve_paths(&mut self, cwd: &Path) {
+        for path in [&mut self.output, &mut self.model].into_iter().flatten() {
+            if path.is_relative() {
+                *path = cwd.join(&*path);
+            }
+        }
+    }
+}
+
+/// 명령줄로 요청한 캡처·녹음·전사를 백그라운드에서 시작
+pub fn execute(app: &AppHandle, args: CliArgs) {
+    if !args.wants_capture() {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = start(&app, &args).await {
+            log::error!("명령줄 캡처 시작 실패: {}", e);
+        }
+        crate::tray::refresh(&app);
+    });
+}
+
+async fn start(app: &AppHandle, args: &CliArgs) -> Result<(), TeuImError> {
+    let device_id = args
+        .device_id
+        .clone()
+        .or_else(|| app.state::<SettingsStore>().get().default_device_id)
+        .unwrap_or_else(|| "default".to_string());
+    let capture_id = audio::start_audio_capture(
+        app.clone(),
+        app.state::<CaptureManager>(),
+        device_id,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    log::info!("명령줄 캡처 시작: {}", capture_id);
+
+    let mut storage_session_id = None;
+    if args.transcribe {
+        ensure_model(app, args.model.as_deref()).await?;
+        let options = TranscriptionOptions {
+            session_id: Some(capture_id.clone()),
+            language: args.language.clone(),
+            ..Default::default()
+        };
+        storage_session_id = Some(transcribe::start_transcription(
+            app.clone(),
+            app.state::<TranscriptionEngine>(),
+            app.state::<CaptureManager>(),
+            app.state::<Storage>(),
+            app.state::<SttConfig>(),
+            Some(options),
+        )?);
+    }
+
+    if let Some(output) = &args.output {
+        recording::start_recording(
+            app.clone(),
+            app.state::<CaptureManager>(),
+            app.state::<Storage>(),
+            output.to_string_lossy().into_owned(),
+            None,
+            Some(capture_id),
+            storage_session_id,
+        )?;
+    }
+    Ok(())
+}
+
+/// 클라우드 제공자를 쓰지 않는데 모델이 없으면 지정한(또는 설정의) 모델 로드
+pub(crate) async fn ensure_model(app: &AppHandle, model: Option<&Path>) -> Result<(), TeuImError> {
+    let engine = app.state::<TranscriptionEngine>();
+    if app.state::<SttConfig>().current().is_some()
+        || (model.is_none() && engine.context().is_some())
+    {
+        return Ok(());
+    }
+
+    let path = model
+        .map(|path| path.to_string_lossy().into_owned())
+        .or_else(|| app.state::<SettingsStore>().get().model_path)
+        .ok_or(TeuImError::ModelNotLoaded)?;
+    transcribe::load_model(engine, app.state::<SettingsStore>(), path).await?;
+    Ok(())
+}
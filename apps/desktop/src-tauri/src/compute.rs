@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::thread;
+use whisper_rs::WhisperContextParameters;
+
+/// 로컬 추론 연산 장치
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComputeBackend {
+    #[default]
+    Cpu,
+    /// NVIDIA GPU (`cuda` 기능으로 빌드)
+    Cuda,
+    /// Apple GPU (`metal` 기능으로 빌드)
+    Metal,
+    /// Vulkan 지원 GPU (`vulkan` 기능으로 빌드)
+    Vulkan,
+}
+
+impl ComputeBackend {
+    const ALL: [Self; 4] = [Self::Cpu, Self::Cuda, Self::Metal, Self::Vulkan];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Cpu => "CPU",
+            Self::Cuda => "CUDA",
+            Self::Metal => "Metal",
+            Self::Vulkan => "Vulkan",
+        }
+    }
+
+    /// 이 빌드에 포함됐는지
+    fn compiled(self) -> bool {
+        match self {
+            Self::Cpu => true,
+            Self::Cuda => cfg!(feature = "cuda"),
+            Self::Metal => cfg!(feature = "metal"),
+            Self::Vulkan => cfg!(feature = "vulkan"),
+        }
+    }
+}
+
+/// 로컬 추론 장치 설정 (모델을 다시 로드하면 적용)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComputeSettings {
+    pub backend: ComputeBackend,
+    /// CPU 추론 스레드 수 (생략하면 코어 수, 최대 4)
+    pub cpu_threads: Option<u32>,
+    /// GPU 사용 시 CPU 쪽 연산에 쓸 스레드 수 (생략하면 CPU와 같음)
+    pub gpu_threads: Option<u32>,
+    /// 사용할 GPU 번호 (여러 개일 때, 기본 0)
+    pub gpu_device: u32,
+}
+
+impl ComputeSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.backend.compiled() {
+            return Err(format!(
+                "이 빌드는 {} 가속을 지원하지 않습니다",
+                self.backend.name()
+            ));
+        }
+        if self.cpu_threads == Some(0) || self.gpu_threads == Some(0) {
+            return Err("추론 스레드 수는 1 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    /// 실제로 쓸 장치 (고른 GPU를 찾지 못하면 CPU)
+    pub fn resolve(&self) -> ComputeBackend {
+        if self.backend == ComputeBackend::Cpu {
+            return ComputeBackend::Cpu;
+        }
+        match detect(self.backend) {
+            info if info.available => self.backend,
+            info => {
+                log::warn!(
+                    "{} 가속을 쓸 수 없어 CPU로 추론: {}",
+                    self.backend.name(),
+                    info.detail.unwrap_or_default()
+                );
+                ComputeBackend::Cpu
+            }
+        }
+    }
+
+    /// 모델 로드 매개변수
+    pub fn context_parameters(&self, backend: ComputeBackend) -> WhisperContextParameters<'static> {
+        let mut params = WhisperContextParameters::default();
+        params.use_gpu(backend != ComputeBackend::Cpu);
+        params.gpu_device(self.gpu_device as i32);
+        params
+    }
+
+    /// 전사 옵션에 스레드 수가 없을 때 쓸 값
+    pub fn threads(&self, backend: ComputeBackend) -> u32 {
+        let cpu = self.cpu_threads.unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map(|n| n.get().min(4) as u32)
+                .unwrap_or(1)
+        });
+        match backend {
+            ComputeBackend::Cpu => cpu,
+            _ => self.gpu_threads.unwrap_or(cpu),
+        }
+    }
+}
+
+/// 연산 장치 정보 (`get_compute_backends` 결과)
+#[derive(Debug, Clone, Serialize)]
+pub struct ComputeBackendInfo {
+    pub backend: ComputeBackend,
+    /// 이 빌드에 포함됐는지
+    pub compiled: bool,
+    /// 이 컴퓨터에서 쓸 수 있는지
+    pub available: bool,
+    /// 찾은 장치 이름
+    pub devices: Vec<String>,
+    /// 쓸 수 없는 이유나 CPU 명령어 확장 등 부가 정보
+    pub detail: Option<String>,
+}
+
+/// 드라이버 라이브러리가 흔한 위치에 있는지
+fn library_present(names: &[&str]) -> bool {
+    let mut dirs: Vec<String> = Vec::new();
+    if cfg!(target_os = "windows") {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| "C:\\Windows".to_string());
+        dirs.push(format!("{}\\System32", root));
+    } else {
+        dirs.extend(
+            [
+                "/usr/lib",
+                "/usr/lib64",
+                "/usr/lib/x86_64-linux-gnu",
+                "/usr/lib/aarch64-linux-gnu",
+                "/usr/local/cuda/lib64",
+                "/usr/lib/wsl/lib",
+            ]
+            .map(str::to_string),
+        );
+    }
+    dirs.iter()
+        .any(|dir| names.iter().any(|name| Path::new(dir).join(name).exists()))
+}
+
+fn cpu_detail() -> String {
+    let info = whisper_rs::SystemInfo::default();
+    let extensions: Vec<&str> = [
+        ("AVX", info.avx),
+        ("AVX2", info.avx2),
+        ("FMA", info.fma),
+        ("F16C", info.f16c),
+    ]
+    .into_iter()
+    .filter_map(|(name, on)| on.then_some(name))
+    .collect();
+    format!(
+        "코어 {}개, 명령어 확장: {}",
+        thread::available_parallelism().map_or(1, |n| n.get()),
+        if extensions.is_empty() {
+            "없음".to_string()
+        } else {
+            extensions.join(", ")
+        }
+    )
+}
+
+#[cfg(feature = "vulkan")]
+fn vulkan_devices() -> Vec<String> {
+    whisper_rs::vulkan::list_devices()
+        .into_iter()
+        .map(|device| format!("{} ({}MB)", device.name, device.vram.total / (1024 * 1024)))
+        .collect()
+}
+
+#[cfg(not(feature = "vulkan"))]
+fn vulkan_devices() -> Vec<String> {
+    Vec::new()
+}
+
+/// 장치를 실제로 쓸 수 있는지 확인
+fn detect(backend: ComputeBackend) -> ComputeBackendInfo {
+    let compiled = backend.compiled();
+    let mut info = ComputeBackendInfo {
+        backend,
+        compiled,
+        available: false,
+        devices: Vec::new(),
+        detail: None,
+    };
+    if !compiled {
+        info.detail = Some(format!(
+            "{} 기능 없이 빌드됨",
+            backend.name().to_lowercase()
+        ));
+        return info;
+    }
+
+    match backend {
+        ComputeBackend::Cpu => {
+            info.available = true;
+            info.detail = Some(cpu_detail());
+        }
+        ComputeBackend::Cuda => {
+            info.available = library_present(&["libcuda.so.1", "libcuda.so", "nvcuda.dll"]);
+            if !info.available {
+                info.detail = Some("NVIDIA 드라이버를 찾을 수 없습니다".to_string());
+            }
+        }
+        ComputeBackend::Metal => {
+            info.available = cfg!(target_os = "macos");
+            if !info.available {
+                info.detail = Some("macOS에서만 쓸 수 있습니다".to_string());
+            }
+        }
+        ComputeBackend::Vulkan => {
+            info.devices = vulkan_devices();
+            info.available = !info.devices.is_empty();
+            if !info.available {
+                info.detail = Some("Vulkan 장치를 찾을 수 없습니다".to_string());
+            }
+        }
+    }
+    info
+}
+
+/// 연산 장치별 빌드 포함·사용 가능 여부
+#[tauri::command]
+pub fn get_compute_backends() -> Vec<ComputeBackendInfo> {
+    ComputeBackend::ALL.into_iter().map(detect).collect()
+}
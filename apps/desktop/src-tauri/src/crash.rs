@@ -0,0 +1,279 @@
+use chrono::{DateTime, Utc};
+use minidumper_child::{ClientHandle, MinidumperChild};
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::State;
+use uuid::Uuid;
+
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+
+/// 앱 식별자 (`tauri.conf.json`의 `identifier`와 같아야 함)
+///
+/// 충돌 처리기는 Tauri가 뜨기 전에 설치되므로 앱 데이터 폴더를 직접 계산한다.
+const APP_IDENTIFIER: &str = "com.teu-im.desktop";
+
+/// 미니덤프를 받는 보조 프로세스로 실행할 때 붙이는 인자
+const REPORTER_ARG: &str = "--crash-reporter-server";
+
+/// 보고서 전송 요청 제한 시간
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 충돌 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashKind {
+    /// Rust 패닉 (해당 스레드만 끝났을 수 있음)
+    Panic,
+    /// 세그폴트 등 네이티브 충돌 (미니덤프 포함)
+    Native,
+}
+
+/// 로컬에 저장된 충돌 보고서
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub id: String,
+    pub kind: CrashKind,
+    pub time: DateTime<Utc>,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// 패닉 메시지 (네이티브 충돌이면 생략)
+    pub message: Option<String>,
+    /// 패닉 위치 (`파일:줄:열`)
+    pub location: Option<String>,
+    /// 패닉이 난 스레드 이름
+    pub thread: Option<String>,
+    pub backtrace: Option<String>,
+    /// 미니덤프 파일 크기 (바이트, 없으면 생략)
+    pub minidump_size: Option<u64>,
+}
+
+impl CrashReport {
+    fn new(kind: CrashKind) -> Self {
+        Self {
+            id: format!(
+                "{}-{}",
+                Utc::now().format("%Y%m%d-%H%M%S"),
+                &Uuid::new_v4().simple().to_string()[..8]
+            ),
+            kind,
+            time: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            message: None,
+            location: None,
+            thread: None,
+            backtrace: None,
+            minidump_size: None,
+        }
+    }
+}
+
+/// 충돌 보고서 폴더 (앱 데이터 폴더의 `crashes`)
+fn crash_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_IDENTIFIER).join("crashes"))
+}
+
+fn report_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{}.json", id))
+}
+
+fn minidump_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{}.dmp", id))
+}
+
+fn save_report(dir: &Path, report: &CrashReport) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let contents = serde_json::to_vec_pretty(report).map_err(|e| e.to_string())?;
+    std::fs::write(report_path(dir, &report.id), contents).map_err(|e| e.to_string())
+}
+
+/// 충돌 보고 프로세스가 받은 미니덤프 저장
+fn save_minidump(dir: &Path, minidump: &[u8]) -> Result<(), String> {
+    let mut report = CrashReport::new(CrashKind::Native);
+    report.minidump_size = Some(minidump.len() as u64);
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    std::fs::write(minidump_path(dir, &report.id), minidump).map_err(|e| e.to_string())?;
+    save_report(dir, &report)
+}
+
+/// 패닉을 로그에 남기고 보고서로 저장하는 훅 설치
+///
+/// 캡처 스레드 같은 작업 스레드의 패닉은 앱을 끝내지 않으므로, 훅이 없으면 아무 흔적 없이
+/// 해당 기능만 멈춘다.
+fn install_panic_hook(dir: PathBuf) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "알 수 없는 패닉".to_string());
+        let location = info.location().map(|location| {
+            format!(
+                "{}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            )
+        });
+        let thread = std::thread::current().name().map(str::to_string);
+        log::error!(
+            "패닉 발생 ({} 스레드): {} ({})",
+            thread.as_deref().unwrap_or("이름 없는"),
+            message,
+            location.as_deref().unwrap_or("위치 모름")
+        );
+
+        let mut report = CrashReport::new(CrashKind::Panic);
+        report.message = Some(message);
+        report.location = location;
+        report.thread = thread;
+        report.backtrace = Some(Backtrace::force_capture().to_string());
+        if let Err(e) = save_report(&dir, &report) {
+            log::error!("충돌 보고서 저장 실패: {}", e);
+        }
+        previous(info);
+    }));
+}
+
+/// 패닉 훅과 네이티브 충돌 처리기 설치 (`run` 맨 처음에 호출)
+///
+/// 네이티브 충돌은 같은 실행 파일을 보조 프로세스로 한 번 더 띄워 미니덤프를 받는다.
+/// 보조 프로세스로 실행된 경우에는 이 함수 안에서 프로세스가 끝난다.
+/// 반환값은 앱이 끝날 때까지 유지해야 한다.
+pub fn install() -> Option<ClientHandle> {
+    let dir = crash_dir()?;
+    install_panic_hook(dir.clone());
+
+    let reporter = MinidumperChild::new()
+        .with_server_arg(REPORTER_ARG.to_string())
+        .with_crashes_dir(dir.join("incoming"))
+        .on_minidump(move |minidump, _| {
+            if let Err(e) = save_minidump(&dir, &minidump) {
+                eprintln!("미니덤프 저장 실패: {}", e);
+            }
+        });
+    match reporter.spawn() {
+        Ok(handle) => Some(handle),
+        Err(e) => {
+            eprintln!("충돌 처리기를 설치할 수 없습니다: {}", e);
+            None
+        }
+    }
+}
+
+/// 보고서 ID 확인 (폴더 밖 파일을 가리키지 못하게)
+fn checked_id(id: &str) -> Result<&str, TeuImError> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(TeuImError::invalid_argument(format!(
+            "잘못된 충돌 보고서 ID: {}",
+            id
+        )));
+    }
+    Ok(id)
+}
+
+fn read_report(dir: &Path, id: &str) -> Result<CrashReport, TeuImError> {
+    let contents = std::fs::read(report_path(dir, checked_id(id)?)).map_err(|_| {
+        TeuImError::invalid_argument(format!("충돌 보고서를 찾을 수 없습니다: {}", id))
+    })?;
+    serde_json::from_slice(&contents).map_err(|e| TeuImError::from(e.to_string()))
+}
+
+fn remove_report(dir: &Path, id: &str) {
+    let _ = std::fs::remove_file(report_path(dir, id));
+    let _ = std::fs::remove_file(minidump_path(dir, id));
+}
+
+fn existing_crash_dir() -> Result<PathBuf, TeuImError> {
+    crash_dir().ok_or_else(|| TeuImError::from("충돌 보고서 폴더를 찾을 수 없습니다".to_string()))
+}
+
+/// 아직 보내거나 지우지 않은 충돌 보고서 (최근 것부터)
+///
+/// 앱 시작 시 화면에서 호출해, 보고서가 있으면 보낼지 사용자에게 묻는다.
+#[tauri::command]
+pub fn list_crash_reports() -> Result<Vec<CrashReport>, TeuImError> {
+    let dir = existing_crash_dir()?;
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+    let mut reports: Vec<CrashReport> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "json" {
+                return None;
+            }
+            serde_json::from_slice(&std::fs::read(&path).ok()?).ok()
+        })
+        .collect();
+    reports.sort_by_key(|report| std::cmp::Reverse(report.time));
+    Ok(reports)
+}
+
+/// 사용자가 동의한 충돌 보고서 전송 (보낸 보고서는 로컬에서 지움)
+///
+/// 보고서 JSON과 미니덤프를 `multipart/form-data`로 설정의 `crash_report_endpoint`에 보낸다.
+/// 보낸 보고서 수를 반환한다.
+#[tauri::command]
+pub async fn send_crash_reports(
+    store: State<'_, SettingsStore>,
+    ids: Vec<String>,
+) -> Result<usize, TeuImError> {
+    let endpoint = store.get().crash_report_endpoint.ok_or_else(|| {
+        TeuImError::invalid_argument("충돌 보고서를 받을 주소가 설정되지 않았습니다")
+    })?;
+    let dir = existing_crash_dir()?;
+    let client = reqwest::Client::new();
+
+    let mut sent = 0;
+    for id in &ids {
+        let report = read_report(&dir, id)?;
+        let mut form = reqwest::multipart::Form::new().part(
+            "report",
+            reqwest::multipart::Part::text(
+                serde_json::to_string(&report).map_err(|e| e.to_string())?,
+            )
+            .mime_str("application/json")
+            .map_err(|e| e.to_string())?,
+        );
+        if report.minidump_size.is_some() {
+            let minidump = std::fs::read(minidump_path(&dir, &report.id))
+                .map_err(|e| format!("미니덤프를 읽을 수 없습니다: {}", e))?;
+            form = form.part(
+                "minidump",
+                reqwest::multipart::Part::bytes(minidump).file_name(format!("{}.dmp", report.id)),
+            );
+        }
+
+        client
+            .post(&endpoint)
+            .timeout(UPLOAD_TIMEOUT)
+            .multipart(form)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("충돌 보고서 전송 실패: {}", e))?;
+        remove_report(&dir, &report.id);
+        sent += 1;
+    }
+    log::info!("충돌 보고서 {}개 전송", sent);
+    Ok(sent)
+}
+
+/// 충돌 보고서 보내지 않고 지우기
+#[tauri::command]
+pub fn discard_crash_reports(ids: Vec<String>) -> Result<(), TeuImError> {
+    let dir = existing_crash_dir()?;
+    for id in &ids {
+        remove_report(&dir, checked_id(id)?);
+    }
+    Ok(())
+}
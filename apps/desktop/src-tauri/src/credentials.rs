@@ -0,0 +1,81 @@
+use keyring::Entry;
+
+/// OS 키체인에 저장할 때 쓰는 서비스 이름
+const SERVICE: &str = "Teu-Im";
+
+fn entry(provider: &str) -> Result<Entry, String> {
+    let valid = !provider.is_empty()
+        && provider
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(format!("잘못된 제공자 이름: {}", provider));
+    }
+
+    Entry::new(SERVICE, provider).map_err(|e| format!("키체인 접근 실패: {}", e))
+}
+
+/// 저장된 API 키 조회 (없으면 `None`)
+pub fn api_key(provider: &str) -> Result<Option<String>, String> {
+    match entry(provider)?.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("API 키 조회 실패: {}", e)),
+    }
+}
+
+/// 앱 내부 비밀 값 조회 (API 키와 같은 키체인 서비스에 보관)
+pub(crate) fn secret(name: &str) -> Result<Option<String>, String> {
+    match entry(name)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("키체인 조회 실패: {}", e)),
+    }
+}
+
+/// 앱 내부 비밀 값을 OS 키체인에 저장
+pub(crate) fn set_secret(name: &str, value: &str) -> Result<(), String> {
+    entry(name)?
+        .set_password(value)
+        .map_err(|e| format!("키체인 저장 실패: {}", e))
+}
+
+/// 앱 내부 비밀 값 삭제 (없으면 무시)
+pub(crate) fn delete_secret(name: &str) -> Result<(), String> {
+    match entry(name)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!("키체인 삭제 실패: {}", e)),
+    }
+}
+
+/// 제공자 API 키를 OS 키체인에 저장
+#[tauri::command]
+pub fn set_api_key(provider: String, key: String) -> Result<(), String> {
+    if key.trim().is_empty() {
+        return Err("API 키가 비어 있습니다".to_string());
+    }
+
+    entry(&provider)?
+        .set_password(key.trim())
+        .map_err(|e| format!("API 키 저장 실패: {}", e))?;
+    log::info!("API 키 저장됨: {}", provider);
+    Ok(())
+}
+
+/// 제공자 API 키 저장 여부 (키 값은 프론트엔드로 보내지 않음)
+#[tauri::command]
+pub fn has_api_key(provider: String) -> Result<bool, String> {
+    Ok(api_key(&provider)?.is_some())
+}
+
+/// 제공자 API 키 삭제
+#[tauri::command]
+pub fn delete_api_key(provider: String) -> Result<(), String> {
+    match entry(&provider)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => {
+            log::info!("API 키 삭제됨: {}", provider);
+            Ok(())
+        }
+        Err(e) => Err(format!("API 키 삭제 실패: {}", e)),
+    }
+}
@@ -0,0 +1,280 @@
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::audio::CaptureManager;
+use crate::error::TeuImError;
+use crate::i18n::{t, tf};
+use crate::jobs::JobQueue;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::transcribe::TranscriptionEngine;
+
+/// 데이터 폴더 안의 세션 데이터베이스 파일 이름
+const DATABASE_FILE: &str = "teu-im.db";
+
+/// 데이터 폴더 안의 녹음 폴더 이름
+const RECORDINGS_DIR: &str = "recordings";
+
+/// 데이터 폴더 위치 (`data-directory-changed` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct DataDirectory {
+    pub path: String,
+    pub database_path: String,
+    /// 상대 경로로 시작한 녹음이 저장되는 폴더
+    pub recordings_path: String,
+    /// 앱 데이터 폴더를 그대로 쓰는지
+    pub is_default: bool,
+}
+
+/// 데이터 폴더 이동 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct DataDirectoryMove {
+    #[serde(flatten)]
+    pub directory: DataDirectory,
+    /// 녹음 폴더 밖에 있어 옮기지 않은 세션 녹음 파일
+    pub left_behind: Vec<String>,
+}
+
+fn default_dir(app: &AppHandle) -> Result<PathBuf, TeuImError> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| TeuImError::from(format!("앱 데이터 폴더를 찾을 수 없습니다: {}", e)))
+}
+
+/// 현재 데이터 폴더 (설정에 없으면 앱 데이터 폴더)
+pub fn data_dir(app: &AppHandle) -> Result<PathBuf, TeuImError> {
+    match app.state::<SettingsStore>().get().data_dir {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => default_dir(app),
+    }
+}
+
+/// 세션 데이터베이스 경로
+pub fn database_path(app: &AppHandle) -> Result<PathBuf, TeuImError> {
+    Ok(data_dir(app)?.join(DATABASE_FILE))
+}
+
+/// 녹음 파일 경로 (상대 경로면 녹음 폴더 기준)
+pub fn recording_path(app: &AppHandle, path: &str) -> Result<PathBuf, TeuImError> {
+    let path = PathBuf::from(path);
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    Ok(data_dir(app)?.join(RECORDINGS_DIR).join(path))
+}
+
+fn describe(app: &AppHandle) -> Result<DataDirectory, TeuImError> {
+    let dir = data_dir(app)?;
+    Ok(DataDirectory {
+        path: dir.to_string_lossy().to_string(),
+        database_path: dir.join(DATABASE_FILE).to_string_lossy().to_string(),
+        recordings_path: dir.join(RECORDINGS_DIR).to_string_lossy().to_string(),
+        is_default: app.state::<SettingsStore>().get().data_dir.is_none(),
+    })
+}
+
+/// `from` 폴더의 파일을 `to`로 복사하고 (원본, 사본) 목록을 `copied`에 추가
+///
+/// 사본 자리에 이미 파일이 있으면 덮어쓰지 않고 실패한다.
+fn copy_tree(from: &Path, to: &Path, copied: &mut Vec<(PathBuf, PathBuf)>) -> Result<(), String> {
+    fs::create_dir_all(to).map_err(|e| format!("폴더 생성 실패 ({}): {}", to.display(), e))?;
+    let entries =
+        fs::read_dir(from).map_err(|e| format!("폴더 읽기 실패 ({}): {}", from.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let source = entry.path();
+        let target = to.join(entry.file_name());
+        if source.is_dir() {
+            copy_tree(&source, &target, copied)?;
+        } else if target.exists() {
+            return Err(tf(
+                "error.file_exists",
+                &[("path", &target.to_string_lossy())],
+            ));
+        } else {
+            fs::copy(&source, &target)
+                .map_err(|e| format!("파일 복사 실패 ({}): {}", source.display(), e))?;
+            copied.push((source, target));
+        }
+    }
+    Ok(())
+}
+
+/// 심볼릭 링크와 `..`를 푼 경로 (아직 없는 경로는 있는 데까지만 실제 경로로 풀고
+/// 나머지를 이어 붙임)
+fn resolve(path: &Path) -> PathBuf {
+    let components: Vec<Component> = path.components().collect();
+    for existing in (1..=components.len()).rev() {
+        let Ok(mut resolved) = fs::canonicalize(components[..existing].iter().collect::<PathBuf>())
+        else {
+            continue;
+        };
+        for component in &components[existing..] {
+            match component {
+                Component::ParentDir => {
+                    resolved.pop();
+                }
+                Component::CurDir => {}
+                component => resolved.push(component),
+            }
+        }
+        return resolved;
+    }
+    path.to_path_buf()
+}
+
+/// 데이터베이스 파일과 WAL 부속 파일
+fn database_files(path: &Path) -> Vec<PathBuf> {
+    ["", "-wal", "-shm"]
+        .iter()
+        .map(|suffix| PathBuf::from(format!("{}{}", path.display(), suffix)))
+        .collect()
+}
+
+/// 현재 데이터 폴더 조회
+#[tauri::command]
+pub fn get_data_directory(app: AppHandle) -> Result<DataDirectory, TeuImError> {
+    describe(&app)
+}
+
+/// 세션 데이터베이스와 녹음 폴더를 `path`로 옮김
+///
+/// 녹음 폴더의 파일을 복사하고 데이터베이스 사본의 녹음 경로를 바꾼 뒤 한 번에
+/// 전환한다. 도중에 실패하면 복사한 파일을 지우고 기존 위치를 계속 쓰며,
+/// 전환이 끝난 뒤에야 이전 파일을 지운다. 녹음 폴더 밖에 저장한 녹음은 옮기지 않고
+/// `left_behind`로 알려준다.
+#[tauri::command]
+pub async fn set_data_directory(
+    app: AppHandle,
+    path: String,
+) -> Result<DataDirectoryMove, TeuImError> {
+    tauri::async_runtime::spawn_blocking(move || relocate(&app, &path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn relocate(app: &AppHandle, path: &str) -> Result<DataDirectoryMove, TeuImError> {
+    let store = app.state::<SettingsStore>();
+    let storage = app.state::<Storage>();
+    let busy = !app.state::<CaptureManager>().list().is_empty()
+        || app.state::<CaptureManager>().recorder().is_recording()
+        || app.state::<TranscriptionEngine>().is_running()
+        || app.state::<JobQueue>().is_busy();
+    if busy {
+        return Err(TeuImError::invalid_argument(t("error.data_dir_busy")));
+    }
+
+    let target = PathBuf::from(path);
+    if !target.is_absolute() {
+        return Err(TeuImError::invalid_argument(tf(
+            "error.data_dir_relative",
+            &[("path", path)],
+        )));
+    }
+
+    // 녹음 폴더를 그 안으로 복사하면 끝나지 않으므로 폴더를 만들기 전에 확인
+    let current = data_dir(app)?;
+    let resolved = resolve(&target);
+    if resolved == resolve(&current) {
+        return Ok(DataDirectoryMove {
+            directory: describe(app)?,
+            left_behind: Vec::new(),
+        });
+    }
+    if resolved.starts_with(resolve(&current))
+        || resolved.starts_with(resolve(&current.join(RECORDINGS_DIR)))
+    {
+        return Err(TeuImError::invalid_argument(tf(
+            "error.data_dir_nested",
+            &[("path", path)],
+        )));
+    }
+    fs::create_dir_all(&target).map_err(|e| format!("데이터 폴더 생성 실패: {}", e))?;
+    if target.join(DATABASE_FILE).exists() {
+        return Err(TeuImError::invalid_argument(tf(
+            "error.data_dir_has_database",
+            &[("path", path)],
+        )));
+    }
+
+    // 녹음 폴더 복사 (실패하면 복사한 파일만 지우고 중단)
+    let old_recordings = current.join(RECORDINGS_DIR);
+    let new_recordings = target.join(RECORDINGS_DIR);
+    let mut copied = Vec::new();
+    let rollback = |copied: &[(PathBuf, PathBuf)]| {
+        for (_, copy) in copied {
+            let _ = fs::remove_file(copy);
+        }
+    };
+    if old_recordings.is_dir() {
+        if let Err(e) = copy_tree(&old_recordings, &new_recordings, &mut copied) {
+            rollback(&copied);
+            return Err(e.into());
+        }
+    }
+
+    let copied_paths: Vec<(String, String)> = copied
+        .iter()
+        .map(|(from, to)| {
+            (
+                from.to_string_lossy().to_string(),
+                to.to_string_lossy().to_string(),
+            )
+        })
+        .collect();
+    let linked = storage.audio_paths()?;
+    let moved: Vec<_> = copied_paths
+        .into_iter()
+        .filter(|(from, _)| linked.contains(from))
+        .collect();
+    let left_behind: Vec<String> = linked
+        .into_iter()
+        .filter(|path| !Path::new(path).starts_with(&old_recordings))
+        .collect();
+
+    // 설정을 먼저 저장해 두고, 데이터베이스 전환이 실패하면 되돌림
+    let previous = store.get().data_dir;
+    let target_dir = target.to_string_lossy().to_string();
+    if let Err(e) = store.modify(|settings| settings.data_dir = Some(target_dir)) {
+        rollback(&copied);
+        return Err(e.into());
+    }
+    let old_database = current.join(DATABASE_FILE);
+    if let Err(e) = storage.relocate(&target.join(DATABASE_FILE), &moved) {
+        rollback(&copied);
+        if let Err(e) = store.modify(|settings| settings.data_dir = previous) {
+            log::error!("데이터 폴더 설정 복원 실패: {}", e);
+        }
+        return Err(e);
+    }
+
+    // 전환이 끝났으므로 이전 파일 정리 (실패해도 데이터는 이미 옮겨짐)
+    for file in database_files(&old_database)
+        .iter()
+        .chain(copied.iter().map(|(from, _)| from))
+    {
+        if let Err(e) = fs::remove_file(file) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("이전 데이터 파일 삭제 실패 ({}): {}", file.display(), e);
+            }
+        }
+    }
+    let _ = fs::remove_dir(&old_recordings);
+    log::info!(
+        "데이터 폴더 이동: {} -> {} (녹음 {}개, 옮기지 않은 녹음 {}개)",
+        current.display(),
+        target.display(),
+        copied.len(),
+        left_behind.len()
+    );
+
+    let directory = describe(app)?;
+    let _ = app.emit("settings-changed", store.get());
+    let _ = app.emit("data-directory-changed", &directory);
+    Ok(DataDirectoryMove {
+        directory,
+        left_behind,
+    })
+}
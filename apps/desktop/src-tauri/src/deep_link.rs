@@ -0,0 +1,172 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+use crate::audio::{self, CaptureManager};
+use crate::error::TeuImError;
+use crate::i18n::t;
+use crate::settings::SettingsStore;
+
+/// 앱이 처리하는 URL 스킴 (`tauri.conf.json`의 `plugins.deep-link`와 같아야 함)
+const SCHEME: &str = "teuim";
+
+/// 딥 링크로 요청된 동작 (`deep-link` 이벤트 페이로드)
+///
+/// - `teuim://session/<id>` — 세션 열기
+/// - `teuim://start-capture?device=<id>` — 마이크 캡처 시작 (장치 생략 시 설정의 기본 장치,
+///   앱에서 확인해야 시작)
+/// - `teuim://stop-capture` — 모든 캡처 중지 (앱에서 확인해야 중지)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum DeepLink {
+    OpenSession { session_id: String },
+    StartCapture { device_id: Option<String> },
+    StopCapture,
+}
+
+impl DeepLink {
+    pub fn parse(url: &Url) -> Result<Self, String> {
+        if url.scheme() != SCHEME {
+            return Err(format!("지원하지 않는 스킴: {}", url.scheme()));
+        }
+
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+        let query = |key: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.into_owned())
+                .filter(|v| !v.is_empty())
+        };
+
+        match url.host_str().unwrap_or_default() {
+            "session" => match segments.as_slice() {
+                [id] => Ok(Self::OpenSession {
+                    session_id: id.to_string(),
+                }),
+                _ => Err("세션 ID가 필요합니다 (teuim://session/<id>)".to_string()),
+            },
+            "start-capture" => Ok(Self::StartCapture {
+                device_id: query("device"),
+            }),
+            "stop-capture" => Ok(Self::StopCapture),
+            other => Err(format!("알 수 없는 딥 링크 동작: {}", other)),
+        }
+    }
+}
+
+/// 앱에서 확인을 기다리는 딥 링크 캡처 시작·중지 요청 (Tauri 관리 상태)
+///
+/// 아무 웹 페이지나 다른 앱이 링크를 열 수 있으므로 마이크를 열거나 진행 중인
+/// 녹음을 멈추는 일은 사용자가 확인한 뒤에만 한다. 새 요청이 오면 이전 요청을 대신한다.
+#[derive(Default)]
+pub struct PendingCapture(Mutex<Option<DeepLink>>);
+
+/// 딥 링크 수신 등록 (앱 시작 시)
+///
+/// 실행 중에 열린 링크는 물론, 링크로 앱이 처음 실행된 경우도 처리한다.
+/// 이미 실행 중이면 단일 인스턴스 플러그인이 두 번째 실행의 링크를 이쪽으로 넘긴다.
+pub fn init(app: &AppHandle) {
+    // 설치 없이 실행한 개발 빌드·AppImage도 링크를 받도록 스킴 등록
+    #[cfg(any(windows, target_os = "linux"))]
+    if let Err(e) = app.deep_link().register_all() {
+        log::warn!("딥 링크 스킴 등록 실패: {}", e);
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            open(&handle, &url);
+        }
+    });
+
+    match app.deep_link().get_current() {
+        Ok(Some(urls)) => {
+            for url in urls {
+                open(app, &url);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("시작 딥 링크 확인 실패: {}", e),
+    }
+}
+
+/// 링크 하나 처리
+///
+/// 캡처 시작·중지는 확인을 기다리고, 모든 링크를 `deep-link` 이벤트로 프런트엔드에
+/// 넘긴다. 모든 링크는 메인 창을 앞으로 가져온다.
+fn open(app: &AppHandle, url: &Url) {
+    let link = match DeepLink::parse(url) {
+        Ok(link) => link,
+        Err(e) => {
+            log::warn!("딥 링크 무시: {} ({})", url, e);
+            return;
+        }
+    };
+    log::info!("딥 링크 실행: {:?}", link);
+    crate::tray::show_main_window(app);
+
+    let link = match link {
+        DeepLink::OpenSession { .. } => {
+            let _ = app.emit("deep-link", link);
+            return;
+        }
+        DeepLink::StartCapture { device_id } => {
+            let device_id = device_id
+                .or_else(|| app.state::<SettingsStore>().get().default_device_id)
+                .unwrap_or_else(|| "default".to_string());
+            DeepLink::StartCapture {
+                device_id: Some(device_id),
+            }
+        }
+        DeepLink::StopCapture => link,
+    };
+
+    // 프런트엔드가 확인 창을 띄우고 `confirm_deep_link_capture`로 답함
+    *app.state::<PendingCapture>().0.lock().unwrap() = Some(link.clone());
+    let _ = app.emit("deep-link", link);
+}
+
+/// 딥 링크로 요청된 캡처 시작·중지를 사용자 확인에 따라 실행하거나 버림
+///
+/// `approve`가 참이면 요청을 실행하고, 캡처를 시작했으면 캡처 세션 ID를 반환한다.
+#[tauri::command]
+pub fn confirm_deep_link_capture(
+    app: AppHandle,
+    pending: State<'_, PendingCapture>,
+    approve: bool,
+) -> Result<Option<String>, TeuImError> {
+    let link = pending
+        .0
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or(t("error.no_pending_capture"))?;
+    if !approve {
+        log::info!("딥 링크 캡처 요청 거절: {:?}", link);
+        return Ok(None);
+    }
+
+    let manager = app.state::<CaptureManager>();
+    let session_id = match link {
+        DeepLink::StartCapture { device_id } => Some(audio::start_audio_capture(
+            app.clone(),
+            manager,
+            device_id.unwrap_or_else(|| "default".to_string()),
+            None,
+            None,
+            None,
+            None,
+        )?),
+        DeepLink::StopCapture => {
+            manager.stop_all();
+            None
+        }
+        DeepLink::OpenSession { .. } => None,
+    };
+    crate::tray::refresh(&app);
+    Ok(session_id)
+}
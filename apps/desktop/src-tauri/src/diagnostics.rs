@@ -0,0 +1,368 @@
+use chrono::Local;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use sysinfo::System;
+use tauri::{AppHandle, Manager};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::audio::{self, AudioDevice, AudioHost};
+use crate::compute::{self, ComputeBackendInfo};
+use crate::error::TeuImError;
+use crate::logging;
+use crate::models::{self, ModelEntry};
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::transcribe::TranscriptionEngine;
+
+/// 보고서에 넣을 최근 로그 줄 수
+const REPORT_LOG_LINES: usize = 2_000;
+
+/// 로그에서 지울 전사 내용을 모을 최근 세션 수
+const SCRUB_SESSIONS: usize = 20;
+
+/// 지울 전사 문구의 최소 길이 (짧은 말은 다른 로그와 겹치기 쉬움)
+const MIN_SCRUB_CHARS: usize = 4;
+
+/// 값을 가릴 설정 키에 들어가는 말
+const SECRET_KEY_PARTS: &[&str] = &["api_key", "token", "secret", "password"];
+
+#[derive(Serialize)]
+struct SystemReport {
+    app_version: &'static str,
+    os: &'static str,
+    arch: &'static str,
+    os_version: Option<String>,
+    kernel_version: Option<String>,
+    cpu: Option<String>,
+    cpu_cores: usize,
+    memory_mb: u64,
+    compute_backends: Vec<ComputeBackendInfo>,
+    /// whisper.cpp가 알려 주는 CPU 기능
+    whisper_system_info: String,
+}
+
+#[derive(Serialize)]
+struct HostDevices {
+    host: AudioHost,
+    devices: Vec<AudioDevice>,
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ModelReport {
+    /// 로드된 Whisper 모델 파일 이름
+    loaded: Option<String>,
+    catalog: Vec<ModelEntry>,
+}
+
+fn system_report() -> SystemReport {
+    let mut system = System::new();
+    system.refresh_cpu_all();
+    system.refresh_memory();
+    SystemReport {
+        app_version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        os_version: System::long_os_version(),
+        kernel_version: System::kernel_version(),
+        cpu: system
+            .cpus()
+            .first()
+            .map(|cpu| cpu.brand().trim().to_string()),
+        cpu_cores: system.cpus().len(),
+        memory_mb: system.total_memory() / (1024 * 1024),
+        compute_backends: compute::get_compute_backends(),
+        whisper_system_info: whisper_rs::print_system_info().trim().to_string(),
+    }
+}
+
+fn audio_report() -> Vec<HostDevices> {
+    audio::list_audio_hosts()
+        .into_iter()
+        .map(
+            |host| match audio::list_audio_devices(Some(host.id.clone())) {
+                Ok(devices) => HostDevices {
+                    host,
+                    devices,
+                    error: None,
+                },
+                Err(e) => HostDevices {
+                    host,
+                    devices: Vec::new(),
+                    error: Some(e.to_string()),
+                },
+            },
+        )
+        .collect()
+}
+
+fn model_report(app: &AppHandle) -> ModelReport {
+    ModelReport {
+        loaded: app
+            .state::<TranscriptionEngine>()
+            .model_path()
+            .and_then(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+            }),
+        catalog: models::list_available_models(app.clone(), app.state()).unwrap_or_default(),
+    }
+}
+
+/// 로그 줄의 `key=값`, `access_token=값` 같은 비밀 값을 가림
+fn mask_secret_params(line: &str) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let is_value_end =
+        |c: char| c.is_whitespace() || matches!(c, '&' | '"' | '\'' | ',' | ';' | ')');
+
+    let mut masked = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(index) = rest.find('=') {
+        let (before, after) = rest.split_at(index);
+        masked.push_str(before);
+        masked.push('=');
+        let name_start = before
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_name_char(*c))
+            .last()
+            .map_or(before.len(), |(i, _)| i);
+        let after = &after[1..];
+        let name = before[name_start..].to_lowercase();
+        // URL 쿼리로 API 키를 넘기는 서비스는 `key=`를 씀
+        if name == "key" || SECRET_KEY_PARTS.iter().any(|part| name.contains(part)) {
+            let value_len = after.find(is_value_end).unwrap_or(after.len());
+            if value_len > 0 {
+                masked.push_str("[가림]");
+            }
+            rest = &after[value_len..];
+        } else {
+            rest = after;
+        }
+    }
+    masked.push_str(rest);
+    masked
+}
+
+/// API 키 등 비밀 값을 가림
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                let key = key.to_lowercase();
+                if SECRET_KEY_PARTS.iter().any(|part| key.contains(part)) {
+                    *field = Value::String("[가림]".to_string());
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// 로그와 설정에서 개인 정보 지우기
+struct Scrubber {
+    home: Option<String>,
+    /// 최근 세션의 제목과 전사 문장 (긴 것부터)
+    phrases: Vec<String>,
+}
+
+impl Scrubber {
+    fn new(app: &AppHandle) -> Self {
+        let home = app
+            .path()
+            .home_dir()
+            .ok()
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .filter(|dir| dir.len() > 1);
+
+        let storage = app.state::<Storage>();
+        let mut phrases: Vec<String> = storage
+            .list_sessions(&Default::default())
+            .unwrap_or_default()
+            .into_iter()
+            .take(SCRUB_SESSIONS)
+            .filter_map(|summary| storage.get_session(&summary.id).ok())
+            .flat_map(|detail| {
+                let title = detail.session.title;
+                detail
+                    .segments
+                    .into_iter()
+                    .map(|segment| segment.text)
+                    .chain(title)
+            })
+            .map(|text| text.trim().to_string())
+            .filter(|text| text.chars().count() >= MIN_SCRUB_CHARS)
+            .collect();
+        phrases.sort_by_key(|text| std::cmp::Reverse(text.len()));
+        phrases.dedup();
+        Self { home, phrases }
+    }
+
+    fn scrub(&self, line: &str) -> String {
+        let mut line = line.to_string();
+        for phrase in &self.phrases {
+            if line.contains(phrase.as_str()) {
+                line = line.replace(phrase.as_str(), "[전사 내용]");
+            }
+        }
+        if let Some(home) = &self.home {
+            line = line.replace(home.as_str(), "~");
+        }
+        let line = mask_secret_params(&line);
+        // 이메일 주소
+        line.split_inclusive(char::is_whitespace)
+            .map(|word| {
+                let token = word.trim_end();
+                let is_email = token
+                    .split_once('@')
+                    .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+                if is_email {
+                    word.replacen(token, "[이메일]", 1)
+                } else {
+                    word.to_string()
+                }
+            })
+            .collect()
+    }
+}
+
+fn add_file(zip: &mut ZipWriter<File>, name: &str, contents: &[u8]) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(name, options).map_err(|e| e.to_string())?;
+    zip.write_all(contents).map_err(|e| e.to_string())
+}
+
+fn to_json(value: &impl Serialize) -> Result<Vec<u8>, String> {
+    serde_json::to_vec_pretty(value).map_err(|e| e.to_string())
+}
+
+/// 지원 요청용 진단 보고서(zip) 생성
+///
+/// 시스템·오디오 장치·모델 정보, 비밀 값을 가린 설정, 최근 로그를 담는다.
+/// 로그에서는 최근 세션의 전사 내용, 홈 폴더 경로, 이메일 주소, `key=` 같은
+/// URL 쿼리의 비밀 값을 지운다.
+/// `path`를 생략하면 다운로드 폴더(없으면 로그 폴더)에 저장하고, 저장한 경로를 반환한다.
+#[tauri::command]
+pub async fn generate_diagnostics_report(
+    app: AppHandle,
+    path: Option<String>,
+) -> Result<String, TeuImError> {
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let dir = app
+                .path()
+                .download_dir()
+                .or_else(|_| logging::log_dir(&app).map_err(|e| e.to_string()))
+                .map_err(|e| format!("보고서를 저장할 폴더를 찾을 수 없습니다: {}", e))?;
+            dir.join(format!(
+                "teu-im-diagnostics-{}.zip",
+                Local::now().format("%Y%m%d-%H%M%S")
+            ))
+        }
+    };
+
+    // 장치 조회와 로그 정리는 시간이 걸릴 수 있어 블로킹 스레드에서 처리
+    tauri::async_runtime::spawn_blocking(move || {
+        let scrubber = Scrubber::new(&app);
+
+        let mut settings =
+            serde_json::to_value(app.state::<SettingsStore>().get()).map_err(|e| e.to_string())?;
+        redact(&mut settings);
+        let settings = scrubber.scrub(&serde_json::to_string_pretty(&settings).unwrap_or_default());
+
+        let logs = logging::recent_lines(&logging::log_dir(&app)?, REPORT_LOG_LINES)?
+            .iter()
+            .map(|line| scrubber.scrub(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let models = scrubber.scrub(&String::from_utf8_lossy(&to_json(&model_report(&app))?));
+
+        let file = File::create(&path).map_err(|e| format!("파일 저장 실패: {}", e))?;
+        let mut zip = ZipWriter::new(file);
+        add_file(&mut zip, "system.json", &to_json(&system_report())?)?;
+        add_file(&mut zip, "audio.json", &to_json(&audio_report())?)?;
+        add_file(&mut zip, "settings.json", settings.as_bytes())?;
+        add_file(&mut zip, "models.json", models.as_bytes())?;
+        add_file(&mut zip, "recent.log", logs.as_bytes())?;
+        zip.finish().map_err(|e| format!("파일 저장 실패: {}", e))?;
+
+        log::info!("진단 보고서 생성: {}", path.display());
+        Ok::<_, TeuImError>(path.to_string_lossy().into_owned())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn scrubber() -> Scrubber {
+        Scrubber {
+            home: Some("/home/minji".to_string()),
+            phrases: vec!["분기 매출 보고".to_string()],
+        }
+    }
+
+    #[test]
+    fn redacts_secret_settings_recursively() {
+        let mut settings = json!({
+            "stt": { "provider": "google", "api_key": "AIza123" },
+            "sync": [{ "access_token": "abc", "password": "pw" }],
+            "ptt": { "key": "F13" },
+        });
+        redact(&mut settings);
+        assert_eq!(
+            settings,
+            json!({
+                "stt": { "provider": "google", "api_key": "[가림]" },
+                "sync": [{ "access_token": "[가림]", "password": "[가림]" }],
+                "ptt": { "key": "F13" },
+            })
+        );
+    }
+
+    #[test]
+    fn masks_secret_query_params() {
+        assert_eq!(
+            mask_secret_params("GET https://example.com/v1?key=AIza123&lang=ko failed"),
+            "GET https://example.com/v1?key=[가림]&lang=ko failed"
+        );
+        assert_eq!(
+            mask_secret_params("refresh access_token=abc, Client_Secret=xyz"),
+            "refresh access_token=[가림], Client_Secret=[가림]"
+        );
+        // 이름에 key가 들어가도 비밀 값이 아니면 그대로
+        assert_eq!(
+            mask_secret_params("hotkey=F13 monkey=1"),
+            "hotkey=F13 monkey=1"
+        );
+        assert_eq!(mask_secret_params("key= a==b"), "key= a==b");
+    }
+
+    #[test]
+    fn scrubs_transcript_home_and_email() {
+        let line = "/home/minji/rec.wav 저장, 분기 매출 보고 전사, 문의 minji@example.com";
+        assert_eq!(
+            scrubber().scrub(line),
+            "~/rec.wav 저장, [전사 내용] 전사, 문의 [이메일]"
+        );
+    }
+
+    #[test]
+    fn scrub_masks_query_keys() {
+        assert_eq!(
+            scrubber().scrub("요청 실패: https://translation.googleapis.com/?key=secret"),
+            "요청 실패: https://translation.googleapis.com/?key=[가림]"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::audio::CaptureManager;
+use crate::i18n::tf;
+use crate::settings::SettingsStore;
+
+/// 녹음 중 남은 공간 확인 주기 (이 사이에 쓰는 양보다 중지 한도가 넉넉해야 함)
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+const MB: u64 = 1024 * 1024;
+
+/// 녹음 저장 공간 한도
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiskGuardSettings {
+    /// 남은 공간이 이 크기(MB)보다 작아지면 `disk-space-low` 경고
+    pub warn_mb: u64,
+    /// 남은 공간이 이 크기(MB)보다 작아지면 녹음을 마무리하고 중지
+    pub stop_mb: u64,
+}
+
+impl Default for DiskGuardSettings {
+    fn default() -> Self {
+        Self {
+            warn_mb: 1024,
+            stop_mb: 200,
+        }
+    }
+}
+
+impl DiskGuardSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.stop_mb > self.warn_mb {
+            return Err(tf(
+                "error.disk_limits",
+                &[
+                    ("stop_mb", &self.stop_mb.to_string()),
+                    ("warn_mb", &self.warn_mb.to_string()),
+                ],
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `disk-space-low` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskSpaceLow {
+    /// 녹음 파일 경로
+    pub path: String,
+    pub available_mb: u64,
+    pub warn_mb: u64,
+    pub stop_mb: u64,
+    /// 중지 한도에 닿아 녹음을 멈췄는지
+    pub stopped: bool,
+}
+
+/// 저장 공간 경고 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct DiskGuard {
+    /// 공간이 다시 늘어나기 전까지 경고를 반복하지 않도록 기록
+    warned: AtomicBool,
+}
+
+fn limits(app: &AppHandle) -> DiskGuardSettings {
+    app.try_state::<SettingsStore>()
+        .map(|store| store.get().disk_guard)
+        .unwrap_or_default()
+}
+
+/// 녹음 파일이 놓일 볼륨의 남은 공간 (바이트)
+fn available_space(path: &Path) -> Result<u64, String> {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    fs2::available_space(dir).map_err(|e| format!("남은 공간 확인 실패: {}", e))
+}
+
+fn warn(app: &AppHandle, path: &Path, available: u64, stopped: bool) {
+    let limits = limits(app);
+    let payload = DiskSpaceLow {
+        path: path.to_string_lossy().to_string(),
+        available_mb: available / MB,
+        warn_mb: limits.warn_mb,
+        stop_mb: limits.stop_mb,
+        stopped,
+    };
+    log::warn!(
+        "녹음 폴더 남은 공간 부족: {}MB ({})",
+        payload.available_mb,
+        payload.path
+    );
+    crate::notifications::low_disk(app, payload.available_mb);
+    let _ = app.emit("disk-space-low", payload);
+}
+
+/// 녹음을 시작하기 전 확인 (중지 한도 아래면 시작하지 않음)
+///
+/// 녹음 폴더가 아직 없으면 만든 뒤에 확인한다.
+pub fn check_before_recording(app: &AppHandle, path: &Path) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| format!("녹음 폴더 생성 실패: {}", e))?;
+    }
+    let available = available_space(path)?;
+    let limits = limits(app);
+    if available < limits.stop_mb * MB {
+        return Err(tf(
+            "error.disk_full",
+            &[("free_mb", &(available / MB).to_string())],
+        ));
+    }
+
+    let guard = app.state::<DiskGuard>();
+    let low = available < limits.warn_mb * MB;
+    if low {
+        warn(app, path, available, false);
+    }
+    guard.warned.store(low, Ordering::Relaxed);
+    Ok(())
+}
+
+/// 녹음 중 남은 공간을 주기적으로 확인하는 스레드 시작
+///
+/// 중지 한도에 닿으면 쓰기 도중 실패하기 전에 녹음 파일을 마무리하고 닫는다.
+/// 캡처 세션은 계속 실행된다.
+pub fn spawn_watcher(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(POLL_INTERVAL);
+        let recorder = app.state::<CaptureManager>().recorder();
+        let guard = app.state::<DiskGuard>();
+        let Some(path) = recorder.path() else {
+            guard.warned.store(false, Ordering::Relaxed);
+            continue;
+        };
+        let available = match available_space(&path) {
+            Ok(available) => available,
+            Err(e) => {
+                log::warn!("녹음 폴더 {}", e);
+                continue;
+            }
+        };
+
+        let limits = limits(&app);
+        if available < limits.stop_mb * MB {
+            match recorder.stop() {
+                Ok(result) => {
+                    log::warn!("저장 공간 부족으로 녹음 중지: {}", result.path);
+                    warn(&app, &path, available, true);
+                    let _ = app.emit("recording-stopped", result);
+                }
+                Err(e) => log::error!("저장 공간 부족으로 녹음 중지 실패: {}", e),
+            }
+            guard.warned.store(false, Ordering::Relaxed);
+        } else if available < limits.warn_mb * MB {
+            if !guard.warned.swap(true, Ordering::Relaxed) {
+                warn(&app, &path, available, false);
+            }
+        } else {
+            guard.warned.store(false, Ordering::Relaxed);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(warn_mb: u64, stop_mb: u64) -> DiskGuardSettings {
+        DiskGuardSettings { warn_mb, stop_mb }
+    }
+
+    #[test]
+    fn default_limits_are_valid() {
+        assert!(DiskGuardSettings::default().validate().is_ok());
+    }
+
+    #[test]
+    fn stop_limit_may_equal_warn_limit() {
+        assert!(limits(500, 500).validate().is_ok());
+        assert!(limits(0, 0).validate().is_ok());
+    }
+
+    #[test]
+    fn stop_limit_above_warn_limit_is_rejected() {
+        assert!(limits(100, 200).validate().is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, State};
+
+use crate::error::TeuImError;
+use crate::storage::{SegmentSnapshot, Storage, StoredSegment};
+
+/// 세션별로 보관하는 실행 취소 단계 수
+const MAX_UNDO_DEPTH: usize = 100;
+
+/// 구간 ID와 그 시점의 행 상태 (`None`이면 행이 없던 상태)
+type SegmentStates = Vec<(String, Option<SegmentSnapshot>)>;
+
+/// 편집 한 번으로 바뀐 구간들의 전후 상태
+struct EditStep {
+    before: SegmentStates,
+    after: SegmentStates,
+}
+
+#[derive(Default)]
+struct SessionStacks {
+    undo: Vec<EditStep>,
+    redo: Vec<EditStep>,
+}
+
+/// `transcript-updated` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptUpdated {
+    pub session_id: String,
+    /// 바뀐 구간 ID (삭제·병합된 구간 포함)
+    pub segment_ids: Vec<String>,
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
+/// 전사 편집 실행 취소·다시 실행 기록 (Tauri 관리 상태)
+///
+/// 메모리에만 두므로 앱을 다시 시작하면 사라진다. 편집 내용 자체는
+/// 저장소의 구간 리비전으로 남는다.
+#[derive(Default)]
+pub struct EditHistory {
+    sessions: Mutex<HashMap<String, SessionStacks>>,
+}
+
+impl EditHistory {
+    /// 새 편집 기록 (다시 실행 기록은 버림)
+    fn push(&self, session_id: &str, step: EditStep) {
+        let mut sessions = self.sessions.lock().unwrap();
+        let stacks = sessions.entry(session_id.to_string()).or_default();
+        stacks.redo.clear();
+        stacks.undo.push(step);
+        let excess = stacks.undo.len().saturating_sub(MAX_UNDO_DEPTH);
+        stacks.undo.drain(..excess);
+    }
+
+    /// 한 단계 되돌리거나 다시 적용 (`undo`가 참이면 실행 취소)
+    fn step(
+        &self,
+        storage: &Storage,
+        session_id: &str,
+        undo: bool,
+    ) -> Result<TranscriptUpdated, TeuImError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let stacks = sessions.entry(session_id.to_string()).or_default();
+        let step = if undo {
+            stacks.undo.pop()
+        } else {
+            stacks.redo.pop()
+        };
+        let Some(step) = step else {
+            return Err(TeuImError::invalid_argument(if undo {
+                "실행 취소할 편집이 없습니다"
+            } else {
+                "다시 실행할 편집이 없습니다"
+            }));
+        };
+
+        let states = if undo { &step.before } else { &step.after };
+        if let Err(e) = storage.restore_segments(states) {
+            // 적용에 실패한 단계는 원래 스택으로 돌려 둠
+            if undo {
+                stacks.undo.push(step);
+            } else {
+                stacks.redo.push(step);
+            }
+            return Err(e);
+        }
+
+        let segment_ids = step.before.iter().map(|(id, _)| id.clone()).collect();
+        if undo {
+            stacks.redo.push(step);
+        } else {
+            stacks.undo.push(step);
+        }
+        Ok(TranscriptUpdated {
+            session_id: session_id.to_string(),
+            segment_ids,
+            can_undo: !stacks.undo.is_empty(),
+            can_redo: !stacks.redo.is_empty(),
+        })
+    }
+}
+
+/// 구간들의 현재 상태 조회
+fn snapshot(storage: &Storage, ids: &[String]) -> Result<SegmentStates, TeuImError> {
+    ids.iter()
+        .map(|id| Ok((id.clone(), storage.segment_snapshot(id)?)))
+        .collect()
+}
+
+/// 편집 전 상태를 남기고 편집을 실행한 뒤 실행 취소 기록과 알림 처리
+fn apply_edit<T>(
+    app: &AppHandle,
+    storage: &Storage,
+    history: &EditHistory,
+    ids: &[String],
+    edit: impl FnOnce() -> Result<(T, Vec<String>), TeuImError>,
+) -> Result<T, TeuImError> {
+    let before = snapshot(storage, ids)?;
+    let session_id = match before.iter().find_map(|(_, state)| state.as_ref()) {
+        Some(state) => state.session_id.clone(),
+        None => {
+            return Err(TeuImError::SegmentNotFound {
+                segment_id: ids.first().cloned().unwrap_or_default(),
+            })
+        }
+    };
+
+    let (result, touched) = edit()?;
+
+    // 편집으로 새로 생긴 구간은 편집 전에는 없던 것으로 기록
+    let mut before = before;
+    for id in &touched {
+        if !before.iter().any(|(known, _)| known == id) {
+            before.push((id.clone(), None));
+        }
+    }
+    let ids: Vec<String> = before.iter().map(|(id, _)| id.clone()).collect();
+    let after = snapshot(storage, &ids)?;
+
+    history.push(&session_id, EditStep { before, after });
+    let _ = app.emit(
+        "transcript-updated",
+        TranscriptUpdated {
+            session_id,
+            segment_ids: ids,
+            can_undo: true,
+            can_redo: false,
+        },
+    );
+    Ok(result)
+}
+
+/// 전사 구간 텍스트 수정 (원본 ASR 결과는 리비전으로 보관)
+#[tauri::command]
+pub fn update_segment(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    history: State<'_, EditHistory>,
+    id: String,
+    text: String,
+) -> Result<StoredSegment, TeuImError> {
+    apply_edit(&app, &storage, &history, std::slice::from_ref(&id), || {
+        Ok((storage.update_segment(&id, &text)?, Vec::new()))
+    })
+}
+
+/// 전사 구간을 둘로 나누기 (`position`은 글자 위치)
+#[tauri::command]
+pub fn split_segment(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    history: State<'_, EditHistory>,
+    id: String,
+    position: usize,
+    split_ms: Option<i64>,
+) -> Result<Vec<StoredSegment>, TeuImError> {
+    apply_edit(&app, &storage, &history, std::slice::from_ref(&id), || {
+        let (first, second) = storage.split_segment(&id, position, split_ms)?;
+        let created = vec![second.id.clone()];
+        Ok((vec![first, second], created))
+    })
+}
+
+/// 같은 세션의 전사 구간 합치기
+#[tauri::command]
+pub fn merge_segments(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    history: State<'_, EditHistory>,
+    ids: Vec<String>,
+) -> Result<StoredSegment, TeuImError> {
+    apply_edit(&app, &storage, &history, &ids, || {
+        Ok((storage.merge_segments(&ids)?, Vec::new()))
+    })
+}
+
+/// 세션의 마지막 전사 편집 실행 취소
+#[tauri::command]
+pub fn undo_edit(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    history: State<'_, EditHistory>,
+    session_id: String,
+) -> Result<TranscriptUpdated, TeuImError> {
+    let updated = history.step(&storage, &session_id, true)?;
+    let _ = app.emit("transcript-updated", &updated);
+    Ok(updated)
+}
+
+/// 실행 취소한 전사 편집 다시 실행
+#[tauri::command]
+pub fn redo_edit(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    history: State<'_, EditHistory>,
+    session_id: String,
+) -> Result<TranscriptUpdated, TeuImError> {
+    let updated = history.step(&storage, &session_id, false)?;
+    let _ = app.emit("transcript-updated", &updated);
+    Ok(updated)
+}
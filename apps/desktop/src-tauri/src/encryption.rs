@@ -0,0 +1,637 @@
+use age::secrecy::{ExposeSecret, SecretString};
+use age::x25519;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::CaptureManager;
+use crate::credentials;
+use crate::error::TeuImError;
+use crate::jobs::JobQueue;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+/// 키체인 모드에서 저장소 키를 두는 항목 이름
+const KEYCHAIN_ENTRY: &str = "storage-key";
+
+/// 암호 모드에서 암호로 감싼 저장소 키 파일 (앱 데이터 폴더)
+const KEY_FILE: &str = "storage-key.age";
+
+/// 암호화한 녹음 파일 확장자 (원래 이름 뒤에 붙임)
+const ENCRYPTED_SUFFIX: &str = ".age";
+
+/// age 암호화 파일 머리 (동기화로 내려받은 묶음이 암호화됐는지 확인)
+const AGE_MAGIC: &[u8] = b"age-encryption.org/";
+
+/// 저장소 암호 최소 길이
+const MIN_PASSPHRASE_CHARS: usize = 8;
+
+/// 저장소 키 보관 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySource {
+    /// 실행할 때마다 암호를 입력해 잠금 해제
+    Passphrase,
+    /// OS 키체인에 둔 키로 시작할 때 자동 잠금 해제
+    Keychain,
+}
+
+/// 저장 데이터 암호화 설정 (`enable_encryption`/`disable_encryption`으로만 변경)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EncryptionSettings {
+    /// 키 보관 방식 (생략하면 암호화하지 않음)
+    pub key_source: Option<KeySource>,
+    /// 녹음 파일을 암호화할 age 공개 키 (잠긴 동안에도 새 녹음을 암호화할 수 있음)
+    pub recipient: Option<String>,
+    /// 동기화 묶음을 함께 암호화할 다른 기기의 age 공개 키 (`set_sync_recipients`로 변경)
+    pub sync_recipients: Vec<String>,
+}
+
+/// 암호화 상태 (`database-lock-changed` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct EncryptionStatus {
+    /// 키 보관 방식 (암호화하지 않았으면 생략)
+    pub key_source: Option<KeySource>,
+    pub locked: bool,
+}
+
+/// 잠금 해제된 저장소 키 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Vault {
+    identity: Mutex<Option<x25519::Identity>>,
+    /// 녹음 파일 암호화·복호화를 한 번에 하나만
+    converting: Mutex<()>,
+}
+
+/// 녹음 파일 읽기용 (암호화한 파일이면 읽으면서 복호화)
+pub trait RecordingReader: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> RecordingReader for T {}
+
+pub fn is_encrypted(path: &str) -> bool {
+    path.ends_with(ENCRYPTED_SUFFIX)
+}
+
+fn key_file(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(KEY_FILE))
+        .map_err(|e| e.to_string())
+}
+
+/// 저장소 키에서 SQLCipher 원시 키 유도
+fn database_key(identity: &x25519::Identity) -> String {
+    let digest = Sha256::digest(identity.to_string().expose_secret().as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("x'{}'", hex)
+}
+
+/// 보관한 저장소 키 읽기 (암호 모드면 `passphrase`로 키 파일을 풂)
+fn load_identity(
+    app: &AppHandle,
+    source: KeySource,
+    passphrase: Option<&str>,
+) -> Result<x25519::Identity, TeuImError> {
+    let secret = match source {
+        KeySource::Keychain => {
+            credentials::secret(KEYCHAIN_ENTRY)?.ok_or("키체인에 저장소 키가 없습니다")?
+        }
+        KeySource::Passphrase => {
+            let passphrase =
+                passphrase.ok_or_else(|| TeuImError::invalid_argument("암호를 입력하세요"))?;
+            let contents = std::fs::read(key_file(app)?)
+                .map_err(|e| format!("저장소 키 파일을 읽을 수 없습니다: {}", e))?;
+            let decryptor = age::Decryptor::new_buffered(&contents[..])
+                .map_err(|e| format!("저장소 키 파일이 손상되었습니다: {}", e))?;
+            let identity = age::scrypt::Identity::new(SecretString::from(passphrase.to_string()));
+            let mut reader = decryptor
+                .decrypt(std::iter::once(&identity as &dyn age::Identity))
+                .map_err(|_| TeuImError::invalid_argument("암호가 맞지 않습니다"))?;
+            let mut secret = String::new();
+            reader
+                .read_to_string(&mut secret)
+                .map_err(|e| format!("저장소 키 파일이 손상되었습니다: {}", e))?;
+            secret
+        }
+    };
+    secret
+        .trim()
+        .parse()
+        .map_err(|e| TeuImError::from(format!("저장소 키가 손상되었습니다: {}", e)))
+}
+
+/// 새 저장소 키 보관 (`passphrase`가 있으면 암호로 감싼 파일, 없으면 키체인)
+fn save_identity(
+    app: &AppHandle,
+    identity: &x25519::Identity,
+    passphrase: Option<&str>,
+) -> Result<KeySource, TeuImError> {
+    let secret = identity.to_string();
+    let Some(passphrase) = passphrase else {
+        credentials::set_secret(KEYCHAIN_ENTRY, secret.expose_secret())?;
+        return Ok(KeySource::Keychain);
+    };
+    if passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+        return Err(TeuImError::invalid_argument(format!(
+            "암호는 {}자 이상이어야 합니다",
+            MIN_PASSPHRASE_CHARS
+        )));
+    }
+
+    let encryptor =
+        age::Encryptor::with_user_passphrase(SecretString::from(passphrase.to_string()));
+    let mut contents = Vec::new();
+    let mut writer = encryptor
+        .wrap_output(&mut contents)
+        .map_err(|e| e.to_string())?;
+    writer
+        .write_all(secret.expose_secret().as_bytes())
+        .and_then(|_| writer.finish().map(|_| ()))
+        .map_err(|e| e.to_string())?;
+
+    let path = key_file(app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(&path, contents).map_err(|e| format!("저장소 키 저장 실패: {}", e))?;
+    Ok(KeySource::Passphrase)
+}
+
+fn forget_identity(app: &AppHandle) {
+    if let Ok(path) = key_file(app) {
+        let _ = std::fs::remove_file(path);
+    }
+    if let Err(e) = credentials::delete_secret(KEYCHAIN_ENTRY) {
+        log::warn!("키체인의 저장소 키 삭제 실패: {}", e);
+    }
+}
+
+/// 세션 저장소 열기 (설정 로드 뒤 setup에서 호출)
+///
+/// 키체인 모드는 키체인의 키로 바로 열고, 암호 모드나 키를 읽지 못하면 잠긴 상태로 연다.
+pub fn open_storage(app: &AppHandle, path: &Path) -> Result<Storage, TeuImError> {
+    let Some(source) = app.state::<SettingsStore>().get().encryption.key_source else {
+        return Storage::open(path, None);
+    };
+
+    if source == KeySource::Keychain {
+        match load_identity(app, source, None) {
+            Ok(identity) => {
+                let storage = Storage::open(path, Some(&database_key(&identity)))?;
+                *app.state::<Vault>().identity.lock().unwrap() = Some(identity);
+                return Ok(storage);
+            }
+            Err(e) => log::warn!("저장소 키를 읽지 못해 잠긴 상태로 시작: {}", e),
+        }
+    }
+    Storage::locked(path)
+}
+
+/// 녹음 파일 열기 (암호화한 파일은 잠금 해제된 키로 읽으면서 복호화)
+pub fn open_recording(app: &AppHandle, path: &str) -> Result<Box<dyn RecordingReader>, String> {
+    let file = File::open(path).map_err(|e| format!("녹음 파일 열기 실패: {}", e))?;
+    if !is_encrypted(path) {
+        return Ok(Box::new(BufReader::new(file)));
+    }
+
+    let identity = app
+        .state::<Vault>()
+        .identity
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or_else(|| TeuImError::DatabaseLocked.to_string())?;
+    let reader = age::Decryptor::new_buffered(BufReader::new(file))
+        .and_then(|decryptor| decryptor.decrypt(std::iter::once(&identity as &dyn age::Identity)))
+        .map_err(|e| format!("녹음 파일 복호화 실패: {}", e))?;
+    Ok(Box::new(reader))
+}
+
+/// `path`를 `recipients` 모두가 열 수 있게 암호화한 `path.age`를 만들고 그 경로 반환
+fn encrypt_file(path: &Path, recipients: &[x25519::Recipient]) -> Result<PathBuf, String> {
+    let target = PathBuf::from(format!("{}{}", path.display(), ENCRYPTED_SUFFIX));
+    let tmp_path = target.with_extension("age.tmp");
+
+    let write = || -> std::io::Result<()> {
+        let encryptor =
+            age::Encryptor::with_recipients(recipients.iter().map(|r| r as &dyn age::Recipient))
+                .map_err(std::io::Error::other)?;
+        let mut input = BufReader::new(File::open(path)?);
+        let mut writer = encryptor.wrap_output(BufWriter::new(File::create(&tmp_path)?))?;
+        std::io::copy(&mut input, &mut writer)?;
+        writer.finish()?.into_inner()?.sync_all()?;
+        std::fs::rename(&tmp_path, &target)
+    };
+    write().map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        format!("녹음 파일 암호화 실패: {}", e)
+    })?;
+    Ok(target)
+}
+
+/// 암호화한 `path.age`를 풀어 원래 경로에 쓰고 그 경로 반환
+fn decrypt_file(path: &str, identity: &x25519::Identity) -> Result<PathBuf, String> {
+    let target = PathBuf::from(path.strip_suffix(ENCRYPTED_SUFFIX).unwrap_or(path));
+    let tmp_path = PathBuf::from(format!("{}.tmp", target.display()));
+
+    let write = || -> Result<(), String> {
+        let mut reader = age::Decryptor::new_buffered(BufReader::new(
+            File::open(path).map_err(|e| e.to_string())?,
+        ))
+        .and_then(|decryptor| decryptor.decrypt(std::iter::once(identity as &dyn age::Identity)))
+        .map_err(|e| e.to_string())?;
+        let mut output = BufWriter::new(File::create(&tmp_path).map_err(|e| e.to_string())?);
+        std::io::copy(&mut reader, &mut output).map_err(|e| e.to_string())?;
+        output
+            .into_inner()
+            .map_err(|e| e.to_string())?
+            .sync_all()
+            .map_err(|e| e.to_string())?;
+        std::fs::rename(&tmp_path, &target).map_err(|e| e.to_string())
+    };
+    write().map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        format!("녹음 파일 복호화 실패: {}", e)
+    })?;
+    Ok(target)
+}
+
+/// age 공개 키 읽기
+fn parse_recipient(recipient: &str) -> Result<x25519::Recipient, TeuImError> {
+    recipient.trim().parse().map_err(|_| {
+        TeuImError::invalid_argument(format!("올바른 age 공개 키가 아닙니다: {}", recipient))
+    })
+}
+
+/// 암호화를 켰으면 동기화로 올릴 묶음 파일을 암호화 (경로는 그대로)
+///
+/// 묶음에는 풀어 둔 녹음과 전사가 들어 있어 그대로 올리면 저장 데이터 암호화가
+/// 무의미해진다. 이 기기와 `sync_recipients`의 다른 기기 공개 키로 함께 암호화해 저장소 키가
+/// 다른 기기끼리도 열 수 있게 한다. 공개 키를 읽을 수 없으면 평문을 올리지 않도록 오류를 낸다.
+pub fn seal_bundle(app: &AppHandle, path: &Path) -> Result<(), TeuImError> {
+    let settings = app.state::<SettingsStore>().get().encryption;
+    if settings.key_source.is_none() {
+        return Ok(());
+    }
+    let own = settings
+        .recipient
+        .and_then(|r| r.parse::<x25519::Recipient>().ok())
+        .ok_or("암호화용 공개 키가 없어 동기화할 수 없습니다")?;
+    let mut recipients = vec![own];
+    for recipient in &settings.sync_recipients {
+        recipients.push(parse_recipient(recipient)?);
+    }
+
+    let sealed =
+        encrypt_file(path, &recipients).map_err(|e| format!("동기화 묶음 암호화 실패: {}", e))?;
+    std::fs::rename(&sealed, path).map_err(|e| {
+        let _ = std::fs::remove_file(&sealed);
+        format!("동기화 묶음 암호화 실패: {}", e)
+    })?;
+    Ok(())
+}
+
+/// 내려받은 묶음 파일이 암호화돼 있으면 잠금 해제된 키로 풂 (경로는 그대로)
+pub fn open_bundle(app: &AppHandle, path: &Path) -> Result<(), TeuImError> {
+    let mut magic = [0u8; AGE_MAGIC.len()];
+    let sealed = File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok_and(|_| magic == AGE_MAGIC);
+    if !sealed {
+        return Ok(());
+    }
+
+    let Some(identity) = app.state::<Vault>().identity.lock().unwrap().clone() else {
+        let encrypting = app.state::<SettingsStore>().get().encryption.key_source;
+        return Err(match encrypting {
+            Some(_) => TeuImError::DatabaseLocked,
+            None => {
+                "암호화한 동기화 묶음입니다. 암호화를 켜고 이 기기의 공개 키를 올린 기기에 등록하세요"
+                    .into()
+            }
+        });
+    };
+    let encrypted = PathBuf::from(format!("{}{}", path.display(), ENCRYPTED_SUFFIX));
+    std::fs::rename(path, &encrypted).map_err(|e| e.to_string())?;
+    let result = decrypt_file(&encrypted.to_string_lossy(), &identity);
+    let _ = std::fs::remove_file(&encrypted);
+    result.map_err(|e| {
+        TeuImError::from(format!(
+            "동기화 묶음을 풀 수 없습니다 (올린 기기에 이 기기의 공개 키를 등록하세요): {}",
+            e
+        ))
+    })?;
+    Ok(())
+}
+
+/// 세션에 연결된 평문 녹음을 모두 암호화 (녹음 중인 파일 제외)
+fn encrypt_recordings(app: &AppHandle) {
+    let settings = app.state::<SettingsStore>().get().encryption;
+    if settings.key_source.is_none() {
+        return;
+    }
+    let Some(recipient) = settings
+        .recipient
+        .and_then(|r| r.parse::<x25519::Recipient>().ok())
+    else {
+        log::warn!("녹음 암호화용 공개 키가 없습니다");
+        return;
+    };
+
+    let vault = app.state::<Vault>();
+    let _converting = vault.converting.lock().unwrap();
+    let storage = app.state::<Storage>();
+    let Ok(paths) = storage.audio_paths() else {
+        return;
+    };
+    let recording = app.state::<CaptureManager>().recorder().path();
+
+    let mut encrypted = 0;
+    for path in paths.iter().filter(|path| !is_encrypted(path)) {
+        let source = Path::new(path);
+        if recording.as_deref() == Some(source) || !source.is_file() {
+            continue;
+        }
+        let result = encrypt_file(source, std::slice::from_ref(&recipient)).and_then(|target| {
+            storage
+                .replace_audio_path(path, &target.to_string_lossy())
+                .map_err(String::from)
+        });
+        match result {
+            Ok(()) => {
+                encrypted += 1;
+                if let Err(e) = std::fs::remove_file(source) {
+                    log::warn!("암호화한 녹음의 원본 삭제 실패: {} ({})", path, e);
+                }
+            }
+            Err(e) => log::warn!("{}: {}", e, path),
+        }
+    }
+    if encrypted > 0 {
+        log::info!("녹음 파일 {}개 암호화", encrypted);
+    }
+}
+
+/// 백그라운드에서 평문 녹음 암호화 (녹음을 마쳤거나 잠금을 풀었을 때)
+pub fn spawn_encrypt_recordings(app: AppHandle) {
+    std::thread::spawn(move || encrypt_recordings(&app));
+}
+
+/// 암호화한 녹음을 모두 원래 파일로 되돌림 (하나라도 실패하면 중단)
+fn decrypt_recordings(app: &AppHandle, identity: &x25519::Identity) -> Result<(), TeuImError> {
+    let vault = app.state::<Vault>();
+    let _converting = vault.converting.lock().unwrap();
+    let storage = app.state::<Storage>();
+    for path in storage
+        .audio_paths()?
+        .iter()
+        .filter(|path| is_encrypted(path))
+    {
+        if !Path::new(path).is_file() {
+            continue;
+        }
+        let target = decrypt_file(path, identity)?;
+        storage.replace_audio_path(path, &target.to_string_lossy())?;
+        let _ = std::fs::remove_file(path);
+    }
+    Ok(())
+}
+
+fn status(app: &AppHandle) -> EncryptionStatus {
+    EncryptionStatus {
+        key_source: app.state::<SettingsStore>().get().encryption.key_source,
+        locked: app.state::<Storage>().is_locked(),
+    }
+}
+
+fn notify(app: &AppHandle) -> EncryptionStatus {
+    let status = status(app);
+    let _ = app.emit("database-lock-changed", &status);
+    status
+}
+
+/// 캡처·녹음·전사 중에는 키를 바꾸거나 잠글 수 없음
+fn ensure_idle(app: &AppHandle) -> Result<(), TeuImError> {
+    if crate::shutdown::is_busy(app) || app.state::<JobQueue>().is_busy() {
+        return Err(TeuImError::invalid_argument(
+            "캡처·녹음·전사를 마친 뒤 다시 시도하세요",
+        ));
+    }
+    Ok(())
+}
+
+/// 저장 데이터 암호화 상태 조회
+#[tauri::command]
+pub fn get_encryption_status(app: AppHandle) -> EncryptionStatus {
+    status(&app)
+}
+
+/// 데이터베이스와 녹음 파일 암호화 켜기
+///
+/// `passphrase`를 주면 실행할 때마다 그 암호로 잠금을 풀고, 생략하면 OS 키체인에 키를 둔다.
+/// 데이터베이스는 SQLCipher로, 녹음은 age로 암호화한다 (녹음은 백그라운드에서 처리).
+/// 진행 중인 세션 저널과 녹음 중인 파일은 끝날 때까지 암호화되지 않는다.
+#[tauri::command]
+pub async fn enable_encryption(
+    app: AppHandle,
+    passphrase: Option<String>,
+) -> Result<EncryptionStatus, TeuImError> {
+    if app
+        .state::<SettingsStore>()
+        .get()
+        .encryption
+        .key_source
+        .is_some()
+    {
+        return Err(TeuImError::invalid_argument("이미 암호화되어 있습니다"));
+    }
+    ensure_idle(&app)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let identity = x25519::Identity::generate();
+        let source = save_identity(&app, &identity, passphrase.as_deref())?;
+        if let Err(e) = app.state::<Storage>().rekey(Some(&database_key(&identity))) {
+            forget_identity(&app);
+            return Err(e);
+        }
+
+        let saved = app.state::<SettingsStore>().modify(|settings| {
+            settings.encryption.key_source = Some(source);
+            settings.encryption.recipient = Some(identity.to_public().to_string());
+        });
+        // 설정을 저장하지 못하면 다음 실행 때 키를 찾지 않으므로 평문으로 되돌림
+        let settings = match saved {
+            Ok(settings) => settings,
+            Err(e) => {
+                if let Err(rollback) = app.state::<Storage>().rekey(None) {
+                    log::error!("암호화 설정 저장 실패 후 되돌리기 실패: {}", rollback);
+                    return Err(e.into());
+                }
+                forget_identity(&app);
+                return Err(e.into());
+            }
+        };
+        *app.state::<Vault>().identity.lock().unwrap() = Some(identity);
+        let _ = app.emit("settings-changed", &settings);
+        log::info!("저장 데이터 암호화 켜짐 ({:?})", source);
+
+        spawn_encrypt_recordings(app.clone());
+        Ok(notify(&app))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 암호화 끄기 (녹음과 데이터베이스를 평문으로 되돌리고 키 삭제, 잠금 해제 상태에서만)
+#[tauri::command]
+pub async fn disable_encryption(app: AppHandle) -> Result<EncryptionStatus, TeuImError> {
+    if app
+        .state::<SettingsStore>()
+        .get()
+        .encryption
+        .key_source
+        .is_none()
+    {
+        return Err(TeuImError::invalid_argument("암호화되어 있지 않습니다"));
+    }
+    ensure_idle(&app)?;
+    let identity = app
+        .state::<Vault>()
+        .identity
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or(TeuImError::DatabaseLocked)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        decrypt_recordings(&app, &identity)?;
+        app.state::<Storage>().rekey(None)?;
+
+        let settings = app.state::<SettingsStore>().modify(|settings| {
+            settings.encryption.key_source = None;
+            settings.encryption.recipient = None;
+        })?;
+        forget_identity(&app);
+        *app.state::<Vault>().identity.lock().unwrap() = None;
+        let _ = app.emit("settings-changed", &settings);
+        log::info!("저장 데이터 암호화 꺼짐");
+        Ok(notify(&app))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 데이터베이스를 닫고 키를 메모리에서 지움
+#[tauri::command]
+pub fn lock_database(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    vault: State<'_, Vault>,
+) -> Result<EncryptionStatus, TeuImError> {
+    if app
+        .state::<SettingsStore>()
+        .get()
+        .encryption
+        .key_source
+        .is_none()
+    {
+        return Err(TeuImError::invalid_argument(
+            "암호화하지 않은 데이터베이스는 잠글 수 없습니다",
+        ));
+    }
+    ensure_idle(&app)?;
+    let _converting = vault.converting.lock().unwrap();
+    storage.lock()?;
+    *vault.identity.lock().unwrap() = None;
+    Ok(notify(&app))
+}
+
+/// 데이터베이스 잠금 해제 (암호 모드면 `passphrase` 필요)
+#[tauri::command]
+pub async fn unlock_database(
+    app: AppHandle,
+    passphrase: Option<String>,
+) -> Result<EncryptionStatus, TeuImError> {
+    let source = app
+        .state::<SettingsStore>()
+        .get()
+        .encryption
+        .key_source
+        .ok_or_else(|| TeuImError::invalid_argument("암호화되어 있지 않습니다"))?;
+    if !app.state::<Storage>().is_locked() {
+        return Ok(status(&app));
+    }
+
+    // 암호 모드의 키 파일 풀기(scrypt)는 시간이 걸려 블로킹 스레드에서 처리
+    tauri::async_runtime::spawn_blocking(move || {
+        let identity = load_identity(&app, source, passphrase.as_deref())?;
+        app.state::<Storage>().unlock(&database_key(&identity))?;
+        *app.state::<Vault>().identity.lock().unwrap() = Some(identity);
+
+        spawn_encrypt_recordings(app.clone());
+        Ok(notify(&app))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// 동기화 묶음을 함께 암호화할 다른 기기의 공개 키 설정
+///
+/// 각 기기의 공개 키(`encryption.recipient`)를 서로 등록하면 저장소 키가 달라도
+/// 암호화한 동기화 묶음을 주고받을 수 있다. 이 기기의 공개 키와 중복은 빼고 저장한다.
+#[tauri::command]
+pub fn set_sync_recipients(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    recipients: Vec<String>,
+) -> Result<EncryptionSettings, TeuImError> {
+    let own = store.get().encryption.recipient;
+    let mut keys: Vec<String> = Vec::new();
+    for recipient in &recipients {
+        let key = parse_recipient(recipient)?.to_string();
+        if own.as_deref() != Some(key.as_str()) && !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    let settings = store.modify(|settings| settings.encryption.sync_recipients = keys)?;
+    let _ = app.emit("settings-changed", &settings);
+    log::info!(
+        "동기화 공개 키 설정: {}개",
+        settings.encryption.sync_recipients.len()
+    );
+    Ok(settings.encryption)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_sealed_for_two_devices_opens_with_either_identity() {
+        let dir = std::env::temp_dir().join(format!("teuim-encryption-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bundle.teuim");
+        std::fs::write(&path, b"session bundle").unwrap();
+
+        let laptop = x25519::Identity::generate();
+        let desktop = x25519::Identity::generate();
+        let sealed = encrypt_file(&path, &[laptop.to_public(), desktop.to_public()]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        for identity in [&laptop, &desktop] {
+            let opened = decrypt_file(&sealed.to_string_lossy(), identity).unwrap();
+            assert_eq!(std::fs::read(&opened).unwrap(), b"session bundle");
+            std::fs::remove_file(opened).unwrap();
+        }
+
+        let stranger = x25519::Identity::generate();
+        assert!(decrypt_file(&sealed.to_string_lossy(), &stranger).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
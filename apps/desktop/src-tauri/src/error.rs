@@ -0,0 +1,243 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+use crate::i18n::{t, tf};
+
+/// 명령이 프론트엔드로 돌려주는 구조화된 에러
+///
+/// `{ "code": "device_busy", "message": "...", "device_name": "..." }`처럼
+/// 에러 코드, 사람이 읽을 메시지, 변형별 문맥 필드로 직렬화된다.
+/// 프론트엔드는 메시지 문자열 대신 `code`로 분기하면 된다.
+#[derive(Debug, Clone)]
+pub enum TeuImError {
+    /// 장치 ID에 해당하는 장치가 없음
+    DeviceNotFound {
+        device_id: String,
+    },
+    /// 장치가 이미 캡처 중이거나 다른 앱이 점유 중
+    DeviceBusy {
+        device_name: String,
+    },
+    /// OS가 장치 접근을 거부함 (마이크 권한 등)
+    PermissionDenied {
+        message: String,
+    },
+    /// 장치가 요청한 스트림 설정/샘플 포맷을 지원하지 않음
+    UnsupportedFormat {
+        message: String,
+    },
+    /// 스트림 생성·시작 등 오디오 백엔드 오류
+    Stream {
+        message: String,
+    },
+    CaptureSessionNotFound {
+        session_id: String,
+    },
+    /// 전사 모델이 로드되지 않음
+    ModelNotLoaded,
+    ModelLoad {
+        path: String,
+        message: String,
+    },
+    TranscriptionRunning,
+    SessionNotFound {
+        session_id: String,
+    },
+    SegmentNotFound {
+        segment_id: String,
+    },
+    GlossaryTermNotFound {
+        term_id: String,
+    },
+    Database {
+        message: String,
+    },
+    /// 암호화한 데이터베이스가 잠겨 있음 (`unlock_database` 필요)
+    DatabaseLocked,
+    /// 명령 인자가 허용 범위를 벗어남
+    InvalidArgument {
+        message: String,
+    },
+    /// 아직 분류되지 않은 그 밖의 오류
+    Other {
+        message: String,
+    },
+}
+
+impl TeuImError {
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::InvalidArgument {
+            message: message.into(),
+        }
+    }
+
+    /// 프론트엔드에서 분기에 쓰는 에러 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DeviceNotFound { .. } => "device_not_found",
+            Self::DeviceBusy { .. } => "device_busy",
+            Self::PermissionDenied { .. } => "permission_denied",
+            Self::UnsupportedFormat { .. } => "unsupported_format",
+            Self::Stream { .. } => "stream",
+            Self::CaptureSessionNotFound { .. } => "capture_session_not_found",
+            Self::ModelNotLoaded => "model_not_loaded",
+            Self::ModelLoad { .. } => "model_load",
+            Self::TranscriptionRunning => "transcription_running",
+            Self::SessionNotFound { .. } => "session_not_found",
+            Self::SegmentNotFound { .. } => "segment_not_found",
+            Self::GlossaryTermNotFound { .. } => "glossary_term_not_found",
+            Self::Database { .. } => "database",
+            Self::DatabaseLocked => "database_locked",
+            Self::InvalidArgument { .. } => "invalid_argument",
+            Self::Other { .. } => "other",
+        }
+    }
+
+    /// 메시지 외에 함께 보내는 문맥 필드
+    fn context(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            Self::DeviceNotFound { device_id } => vec![("device_id", device_id)],
+            Self::DeviceBusy { device_name } => vec![("device_name", device_name)],
+            Self::CaptureSessionNotFound { session_id } | Self::SessionNotFound { session_id } => {
+                vec![("session_id", session_id)]
+            }
+            Self::SegmentNotFound { segment_id } => vec![("segment_id", segment_id)],
+            Self::GlossaryTermNotFound { term_id } => vec![("term_id", term_id)],
+            Self::ModelLoad { path, .. } => vec![("path", path)],
+            _ => Vec::new(),
+        }
+    }
+
+    /// cpal 스트림 생성 에러 분류
+    ///
+    /// cpal은 권한 거부를 따로 구분하지 않아 백엔드 메시지로 추정한다.
+    pub fn from_build_stream(err: cpal::BuildStreamError, device_name: &str) -> Self {
+        match err {
+            cpal::BuildStreamError::DeviceNotAvailable => Self::DeviceBusy {
+                device_name: device_name.to_string(),
+            },
+            cpal::BuildStreamError::StreamConfigNotSupported
+            | cpal::BuildStreamError::InvalidArgument => Self::UnsupportedFormat {
+                message: err.to_string(),
+            },
+            cpal::BuildStreamError::BackendSpecific { err } => {
+                let message = err.description;
+                let lower = message.to_lowercase();
+                if ["permission", "denied", "not authorized", "access"]
+                    .iter()
+                    .any(|hint| lower.contains(hint))
+                {
+                    Self::PermissionDenied { message }
+                } else {
+                    Self::Stream { message }
+                }
+            }
+            err => Self::Stream {
+                message: err.to_string(),
+            },
+        }
+    }
+
+    /// cpal 기본 스트림 설정 조회 에러 분류
+    pub fn from_default_config(err: cpal::DefaultStreamConfigError, device_name: &str) -> Self {
+        match err {
+            cpal::DefaultStreamConfigError::DeviceNotAvailable => Self::DeviceBusy {
+                device_name: device_name.to_string(),
+            },
+            cpal::DefaultStreamConfigError::StreamTypeNotSupported => Self::UnsupportedFormat {
+                message: err.to_string(),
+            },
+            cpal::DefaultStreamConfigError::BackendSpecific { err } => Self::Stream {
+                message: err.description,
+            },
+        }
+    }
+}
+
+impl fmt::Display for TeuImError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Self::DeviceNotFound { device_id } if device_id == "default" => {
+                t("error.device_not_found_default").to_string()
+            }
+            Self::DeviceNotFound { device_id } => {
+                tf("error.device_not_found", &[("device", device_id)])
+            }
+            Self::DeviceBusy { device_name } => tf("error.device_busy", &[("device", device_name)]),
+            Self::PermissionDenied { message } => {
+                tf("error.permission_denied", &[("message", message)])
+            }
+            Self::UnsupportedFormat { message } => {
+                tf("error.unsupported_format", &[("message", message)])
+            }
+            Self::Stream { message } => tf("error.stream", &[("message", message)]),
+            Self::CaptureSessionNotFound { session_id } => {
+                tf("error.capture_session_not_found", &[("id", session_id)])
+            }
+            Self::ModelNotLoaded => t("error.model_not_loaded").to_string(),
+            Self::ModelLoad { message, .. } => tf("error.model_load", &[("message", message)]),
+            Self::TranscriptionRunning => t("error.transcription_running").to_string(),
+            Self::SessionNotFound { session_id } => {
+                tf("error.session_not_found", &[("id", session_id)])
+            }
+            Self::SegmentNotFound { segment_id } => {
+                tf("error.segment_not_found", &[("id", segment_id)])
+            }
+            Self::GlossaryTermNotFound { term_id } => {
+                tf("error.glossary_term_not_found", &[("id", term_id)])
+            }
+            Self::Database { message } => tf("error.database", &[("message", message)]),
+            Self::DatabaseLocked => t("error.database_locked").to_string(),
+            // 만드는 곳에서 넣은 문구를 그대로 씀 (번역 여부는 만드는 곳에 달림)
+            Self::InvalidArgument { message } | Self::Other { message } => {
+                return f.write_str(message)
+            }
+        };
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for TeuImError {}
+
+impl Serialize for TeuImError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let context = self.context();
+        let mut map = serializer.serialize_map(Some(2 + context.len()))?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("message", &self.to_string())?;
+        for (key, value) in context {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// 아직 `String` 에러를 쓰는 내부 함수와 섞어 쓸 수 있게 변환
+impl From<String> for TeuImError {
+    fn from(message: String) -> Self {
+        Self::Other { message }
+    }
+}
+
+impl From<&str> for TeuImError {
+    fn from(message: &str) -> Self {
+        Self::Other {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<TeuImError> for String {
+    fn from(err: TeuImError) -> Self {
+        err.to_string()
+    }
+}
+
+impl From<rusqlite::Error> for TeuImError {
+    fn from(err: rusqlite::Error) -> Self {
+        Self::Database {
+            message: err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::encryption;
+use crate::error::TeuImError;
+use crate::retention;
+use crate::storage::{MediaAlignment, Storage};
+
+mod audio;
+mod clipboard;
+mod docx;
+mod subtitle;
+mod text;
+
+pub use clipboard::ClipboardFormat;
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Srt,
+    Vtt,
+    Txt,
+    Markdown,
+    Docx,
+}
+
+/// 녹음 내보내기 형식
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioExportFormat {
+    Wav,
+    Flac,
+    Mp3,
+    /// `opus` 기능으로 빌드했을 때만 지원
+    Opus,
+}
+
+/// 녹음 내보내기 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AudioExportOptions {
+    /// 손실 압축 비트레이트 (kbps, 생략하면 MP3 64 / Opus 32)
+    pub bitrate_kbps: Option<u32>,
+}
+
+/// 내보내기 옵션
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ExportOptions {
+    /// 자막 한 줄 최대 글자 수
+    pub max_line_length: usize,
+    /// 자막 하나가 화면에 머무는 최대 시간 (ms)
+    pub max_cue_ms: i64,
+    /// 화자 라벨 포함 (TXT/Markdown)
+    pub include_speakers: bool,
+    /// 구간 시작 시각 포함 (TXT/Markdown)
+    pub include_timestamps: bool,
+    /// 제목, 시작 시각, 장치 등 세션 정보 머리말 포함 (TXT/Markdown)
+    pub include_metadata: bool,
+    /// 번역이 있으면 원문과 번역을 2열 표로 배치 (DOCX)
+    pub bilingual: bool,
+    /// 2열 표에 넣을 번역 언어 (생략하면 처음 찾은 번역 언어)
+    pub translation_language: Option<String>,
+    /// 저장된 영상 정렬 (SRT/VTT 시각에 적용, 내보낼 때 채움)
+    #[serde(skip)]
+    pub alignment: Option<MediaAlignment>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            max_line_length: 42,
+            max_cue_ms: 7_000,
+            include_speakers: true,
+            include_timestamps: true,
+            include_metadata: true,
+            bilingual: false,
+            translation_language: None,
+            alignment: None,
+        }
+    }
+}
+
+/// 저장된 세션의 전사 결과를 파일로 내보내기
+///
+/// 저장한 파일 경로를 반환한다.
+#[tauri::command]
+pub fn export_transcript(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    session_id: String,
+    format: ExportFormat,
+    path: String,
+    options: Option<ExportOptions>,
+) -> Result<String, TeuImError> {
+    let mut options = options.unwrap_or_default();
+    if options.max_line_length == 0 || options.max_cue_ms <= 0 {
+        return Err(TeuImError::invalid_argument(
+            "줄 길이와 자막 시간은 0보다 커야 합니다",
+        ));
+    }
+
+    let session = storage.get_session(&session_id)?;
+    options.alignment = storage.media_alignment(&session_id)?;
+    let contents = match format {
+        ExportFormat::Srt => subtitle::to_srt(&session.segments, &options).into_bytes(),
+        ExportFormat::Vtt => subtitle::to_vtt(&session.segments, &options).into_bytes(),
+        ExportFormat::Txt => text::to_txt(&session, &options).into_bytes(),
+        ExportFormat::Markdown => text::to_markdown(&session, &options).into_bytes(),
+        ExportFormat::Docx => docx::to_docx(&session, &options)?,
+    };
+
+    let path = PathBuf::from(path);
+    std::fs::write(&path, contents).map_err(|e| format!("파일 저장 실패: {}", e))?;
+
+    log::info!("전사 결과 내보냄: {}", path.display());
+    retention::on_exported(&app, &session_id);
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// 선택한 구간을 형식에 맞춰 시스템 클립보드에 복사
+///
+/// 구간은 시간순으로 정렬한다. `language`는 2개 언어 형식에서 넣을 번역 언어
+/// (생략하면 처음 찾은 번역). 복사한 글자 수를 반환한다.
+#[tauri::command]
+pub fn copy_segments_to_clipboard(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    segment_ids: Vec<String>,
+    format: Option<ClipboardFormat>,
+    language: Option<String>,
+) -> Result<usize, TeuImError> {
+    if segment_ids.is_empty() {
+        return Err(TeuImError::invalid_argument("복사할 구간을 선택하세요"));
+    }
+    let segments = storage.get_segments(&segment_ids)?;
+    let text =
+        clipboard::to_clipboard_text(&segments, format.unwrap_or_default(), language.as_deref());
+    app.clipboard()
+        .write_text(text.clone())
+        .map_err(|e| format!("클립보드 복사 실패: {}", e))?;
+
+    log::info!("구간 {}개 클립보드에 복사", segments.len());
+    Ok(text.chars().count())
+}
+
+/// 세션 녹음을 압축 형식으로 내보내기
+///
+/// 저장한 파일 경로를 반환한다.
+#[tauri::command]
+pub async fn export_session_audio(
+    app: AppHandle,
+    session_id: String,
+    format: AudioExportFormat,
+    path: String,
+    options: Option<AudioExportOptions>,
+) -> Result<String, TeuImError> {
+    let options = options.unwrap_or_default();
+    if options.bitrate_kbps == Some(0) {
+        return Err(TeuImError::invalid_argument(
+            "비트레이트는 0보다 커야 합니다",
+        ));
+    }
+
+    let source = app
+        .state::<Storage>()
+        .audio_path(&session_id)?
+        .ok_or("세션에 연결된 녹음 파일이 없습니다")?;
+
+    // 인코딩은 오래 걸릴 수 있어 블로킹 스레드에서 처리
+    tauri::async_runtime::spawn_blocking(move || -> Result<String, TeuImError> {
+        // 암호화한 녹음은 복호화하며 읽음
+        let mut source = encryption::open_recording(&app, &source)?;
+        let path = PathBuf::from(path);
+        let contents = match format {
+            AudioExportFormat::Wav => {
+                std::fs::File::create(&path)
+                    .and_then(|mut file| std::io::copy(&mut source, &mut file))
+                    .map_err(|e| format!("파일 저장 실패: {}", e))?;
+                retention::on_exported(&app, &session_id);
+                return Ok(path.to_string_lossy().into_owned());
+            }
+            AudioExportFormat::Flac => audio::to_flac(&audio::read_wav(source)?)?,
+            AudioExportFormat::Mp3 => audio::to_mp3(
+                &audio::read_wav(source)?,
+                options.bitrate_kbps.unwrap_or(64),
+            )?,
+            #[cfg(feature = "opus")]
+            AudioExportFormat::Opus => audio::to_opus(
+                &audio::read_wav(source)?,
+                options.bitrate_kbps.unwrap_or(32),
+            )?,
+            #[cfg(not(feature = "opus"))]
+            AudioExportFormat::Opus => {
+                return Err(TeuImError::invalid_argument(
+                    "이 빌드는 Opus 내보내기를 지원하지 않습니다",
+                ))
+            }
+        };
+
+        std::fs::write(&path, contents).map_err(|e| format!("파일 저장 실패: {}", e))?;
+        log::info!("녹음 내보냄: {}", path.display());
+        retention::on_exported(&app, &session_id);
+        Ok(path.to_string_lossy().into_owned())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,203 @@
+use flacenc::component::BitRepr;
+use flacenc::error::Verify;
+use hound::{SampleFormat, WavReader};
+use mp3lame_encoder::{Bitrate, Builder, FlushNoGap, InterleavedPcm, MonoPcm};
+use std::io::Read;
+
+/// 녹음 파일에서 읽은 인터리브 PCM
+pub struct Pcm {
+    pub samples: Vec<i16>,
+    pub channels: u16,
+    pub sample_rate: u32,
+}
+
+/// 세션 녹음(WAV, 16bit 정수 또는 32bit 실수) 읽기
+pub fn read_wav(source: impl Read) -> Result<Pcm, String> {
+    let reader = WavReader::new(source).map_err(|e| format!("녹음 파일 열기 실패: {}", e))?;
+    let spec = reader.spec();
+    let samples = match spec.sample_format {
+        SampleFormat::Int => reader.into_samples::<i16>().collect::<Result<Vec<_>, _>>(),
+        SampleFormat::Float => reader
+            .into_samples::<f32>()
+            .map(|sample| sample.map(|s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16))
+            .collect::<Result<Vec<_>, _>>(),
+    }
+    .map_err(|e| format!("녹음 파일 읽기 실패: {}", e))?;
+
+    Ok(Pcm {
+        samples,
+        channels: spec.channels,
+        sample_rate: spec.sample_rate,
+    })
+}
+
+/// 무손실 FLAC 인코딩
+pub fn to_flac(pcm: &Pcm) -> Result<Vec<u8>, String> {
+    let config = flacenc::config::Encoder::default()
+        .into_verified()
+        .map_err(|(_, e)| format!("FLAC 인코더 설정 오류: {}", e))?;
+    let samples: Vec<i32> = pcm.samples.iter().map(|s| *s as i32).collect();
+    let source = flacenc::source::MemSource::from_samples(
+        &samples,
+        pcm.channels as usize,
+        16,
+        pcm.sample_rate as usize,
+    );
+
+    let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| format!("FLAC 인코딩 실패: {}", e))?;
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    stream
+        .write(&mut sink)
+        .map_err(|e| format!("FLAC 인코딩 실패: {}", e))?;
+    Ok(sink.into_inner())
+}
+
+/// MP3 인코딩 (고정 비트레이트, 모노/스테레오만)
+pub fn to_mp3(pcm: &Pcm, bitrate_kbps: u32) -> Result<Vec<u8>, String> {
+    if pcm.channels > 2 {
+        return Err(format!(
+            "MP3는 2채널까지만 지원합니다 (녹음 채널 {}개)",
+            pcm.channels
+        ));
+    }
+
+    let mut builder = Builder::new().ok_or("MP3 인코더 생성 실패")?;
+    builder
+        .set_num_channels(pcm.channels as u8)
+        .and_then(|_| builder.set_sample_rate(pcm.sample_rate))
+        .and_then(|_| builder.set_brate(mp3_bitrate(bitrate_kbps)))
+        .map_err(|e| format!("MP3 인코더 설정 오류: {}", e))?;
+    let mut encoder = builder
+        .build()
+        .map_err(|e| format!("MP3 인코더 설정 오류: {}", e))?;
+
+    let frames = pcm.samples.len() / pcm.channels.max(1) as usize;
+    let mut output = Vec::with_capacity(mp3lame_encoder::max_required_buffer_size(frames));
+    let encoded = if pcm.channels == 1 {
+        encoder.encode_to_vec(MonoPcm(&pcm.samples), &mut output)
+    } else {
+        encoder.encode_to_vec(InterleavedPcm(&pcm.samples), &mut output)
+    };
+    encoded.map_err(|e| format!("MP3 인코딩 실패: {}", e))?;
+
+    // 마지막 프레임용 여유 공간
+    output.reserve(7200);
+    encoder
+        .flush_to_vec::<FlushNoGap>(&mut output)
+        .map_err(|e| format!("MP3 인코딩 실패: {}", e))?;
+    Ok(output)
+}
+
+/// LAME이 받는 비트레이트 중 요청값 이하에서 가장 가까운 값
+fn mp3_bitrate(kbps: u32) -> Bitrate {
+    match kbps {
+        0..=15 => Bitrate::Kbps8,
+        16..=23 => Bitrate::Kbps16,
+        24..=31 => Bitrate::Kbps24,
+        32..=39 => Bitrate::Kbps32,
+        40..=47 => Bitrate::Kbps40,
+        48..=63 => Bitrate::Kbps48,
+        64..=79 => Bitrate::Kbps64,
+        80..=95 => Bitrate::Kbps80,
+        96..=111 => Bitrate::Kbps96,
+        112..=127 => Bitrate::Kbps112,
+        128..=159 => Bitrate::Kbps128,
+        160..=191 => Bitrate::Kbps160,
+        192..=223 => Bitrate::Kbps192,
+        224..=255 => Bitrate::Kbps224,
+        256..=319 => Bitrate::Kbps256,
+        _ => Bitrate::Kbps320,
+    }
+}
+
+/// Ogg Opus 인코딩 (모노로 합쳐 20ms 프레임 단위로 인코딩)
+#[cfg(feature = "opus")]
+pub fn to_opus(pcm: &Pcm, bitrate_kbps: u32) -> Result<Vec<u8>, String> {
+    use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+
+    /// Ogg Opus 그래뉼 위치는 항상 48kHz 기준
+    const GRANULE_RATE: u64 = 48_000;
+    const SERIAL: u32 = 1;
+
+    let channels = pcm.channels.max(1) as usize;
+    let mono: Vec<f32> = pcm
+        .samples
+        .chunks(channels)
+        .map(|frame| frame.iter().map(|s| *s as f32).sum::<f32>() / channels as f32 / 32768.0)
+        .collect();
+
+    // Opus가 받는 샘플레이트가 아니면 48kHz로 변환
+    let (audio, rate) = if [8_000, 12_000, 16_000, 24_000, 48_000].contains(&pcm.sample_rate) {
+        (mono, pcm.sample_rate)
+    } else {
+        let mut resampler = crate::audio::MonoResampler::new(pcm.sample_rate, 48_000)?;
+        (resampler.process(&mono), 48_000)
+    };
+
+    let mut encoder = opus::Encoder::new(rate, opus::Channels::Mono, opus::Application::Voip)
+        .map_err(|e| format!("Opus 인코더 생성 실패: {}", e))?;
+    encoder
+        .set_bitrate(opus::Bitrate::Bits(bitrate_kbps as i32 * 1000))
+        .map_err(|e| format!("Opus 인코더 설정 오류: {}", e))?;
+    let scale = GRANULE_RATE / rate as u64;
+    let lookahead = encoder
+        .get_lookahead()
+        .map_err(|e| format!("Opus 인코더 설정 오류: {}", e))? as usize;
+    let pre_skip = lookahead as u64 * scale;
+
+    let mut writer = PacketWriter::new(Vec::new());
+    let write_error = |e: std::io::Error| format!("Opus 인코딩 실패: {}", e);
+
+    let mut head = b"OpusHead".to_vec();
+    head.push(1);
+    head.push(1);
+    head.extend_from_slice(&(pre_skip as u16).to_le_bytes());
+    head.extend_from_slice(&pcm.sample_rate.to_le_bytes());
+    head.extend_from_slice(&0i16.to_le_bytes());
+    head.push(0);
+    writer
+        .write_packet(head, SERIAL, PacketWriteEndInfo::EndPage, 0)
+        .map_err(write_error)?;
+
+    let vendor = concat!("Teu-Im ", env!("CARGO_PKG_VERSION"));
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor.as_bytes());
+    tags.extend_from_slice(&0u32.to_le_bytes());
+    writer
+        .write_packet(tags, SERIAL, PacketWriteEndInfo::EndPage, 0)
+        .map_err(write_error)?;
+
+    let frame_len = rate as usize / 50;
+    // 인코더 지연만큼 더 넣어야 마지막 샘플까지 나옴
+    let frame_count = (audio.len() + lookahead).div_ceil(frame_len).max(1);
+    let mut packet = vec![0u8; 4000];
+    for index in 0..frame_count {
+        let start = index * frame_len;
+        let mut frame =
+            audio[start.min(audio.len())..(start + frame_len).min(audio.len())].to_vec();
+        frame.resize(frame_len, 0.0);
+
+        let size = encoder
+            .encode_float(&frame, &mut packet)
+            .map_err(|e| format!("Opus 인코딩 실패: {}", e))?;
+        let last = index + 1 == frame_count;
+        // 마지막 그래뉼은 실제 길이로 맞춰 패딩을 잘라냄
+        let granule = if last {
+            pre_skip + audio.len() as u64 * scale
+        } else {
+            ((index + 1) * frame_len) as u64 * scale
+        };
+        let end = if last {
+            PacketWriteEndInfo::EndStream
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        writer
+            .write_packet(packet[..size].to_vec(), SERIAL, end, granule)
+            .map_err(write_error)?;
+    }
+
+    Ok(writer.into_inner())
+}
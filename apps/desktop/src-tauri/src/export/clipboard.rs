@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use std::fmt::Write;
+
+use super::text::clock;
+use crate::storage::StoredSegment;
+
+/// 클립보드 복사 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardFormat {
+    /// 텍스트만 한 줄씩
+    #[default]
+    Plain,
+    /// `[HH:MM:SS] 화자: 내용`
+    Timestamped,
+    /// 원문 아래에 번역 (번역이 없는 구간은 원문만)
+    Bilingual,
+}
+
+/// 선택한 구간을 클립보드용 텍스트로 만듦
+pub fn to_clipboard_text(
+    segments: &[StoredSegment],
+    format: ClipboardFormat,
+    language: Option<&str>,
+) -> String {
+    let mut out = String::new();
+    for segment in segments {
+        match format {
+            ClipboardFormat::Plain => {
+                let _ = writeln!(out, "{}", segment.text);
+            }
+            ClipboardFormat::Timestamped => {
+                let _ = write!(out, "[{}] ", clock(segment.start_ms));
+                if let Some(speaker) = &segment.speaker {
+                    let _ = write!(out, "{}: ", speaker);
+                }
+                let _ = writeln!(out, "{}", segment.text);
+            }
+            ClipboardFormat::Bilingual => {
+                let _ = writeln!(out, "{}", segment.text);
+                let translation = segment
+                    .translations
+                    .iter()
+                    .find(|t| language.is_none_or(|language| t.language == language));
+                if let Some(translation) = translation {
+                    let _ = writeln!(out, "{}", translation.text);
+                }
+                out.push('\n');
+            }
+        }
+    }
+    out.trim_end().to_string()
+}
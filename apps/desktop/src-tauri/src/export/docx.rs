@@ -0,0 +1,127 @@
+use docx_rs::{Docx, Paragraph, Run, Style, StyleType, Table, TableCell, TableRow, WidthType};
+use std::io::Cursor;
+
+use super::text::{metadata, prefix, title};
+use super::ExportOptions;
+use crate::storage::{SessionDetail, StoredSegment};
+
+/// A4 본문 폭 (twip, 여백 제외)
+const BODY_WIDTH: usize = 9_000;
+
+pub fn to_docx(session: &SessionDetail, options: &ExportOptions) -> Result<Vec<u8>, String> {
+    let mut docx = Docx::new().add_style(
+        Style::new("Heading1", StyleType::Paragraph)
+            .name("Heading 1")
+            .bold()
+            .size(32),
+    );
+
+    if options.include_metadata {
+        docx = docx.add_paragraph(
+            Paragraph::new()
+                .style("Heading1")
+                .add_run(Run::new().add_text(title(session))),
+        );
+        for (label, value) in metadata(session) {
+            docx = docx.add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text(format!("{}: ", label)).bold())
+                    .add_run(Run::new().add_text(value)),
+            );
+        }
+        docx = docx.add_paragraph(Paragraph::new());
+    }
+
+    let has_translations = session.segments.iter().any(|s| !s.translations.is_empty());
+    if options.bilingual && has_translations {
+        docx = docx.add_table(bilingual_table(&session.segments, options));
+    } else {
+        for paragraph in speaker_paragraphs(&session.segments, options) {
+            docx = docx.add_paragraph(paragraph);
+        }
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    docx.build()
+        .pack(&mut buffer)
+        .map_err(|e| format!("DOCX 생성 실패: {}", e))?;
+    Ok(buffer.into_inner())
+}
+
+/// 같은 화자가 이어서 말한 구간은 한 문단으로 묶음
+fn speaker_paragraphs(segments: &[StoredSegment], options: &ExportOptions) -> Vec<Paragraph> {
+    let mut groups: Vec<(&StoredSegment, Vec<&str>)> = Vec::new();
+
+    for segment in segments {
+        match groups.last_mut() {
+            Some((first, texts))
+                if options.include_speakers
+                    && segment.speaker.is_some()
+                    && first.speaker == segment.speaker =>
+            {
+                texts.push(&segment.text);
+            }
+            _ => groups.push((segment, vec![&segment.text])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(first, texts)| {
+            let mut paragraph = Paragraph::new();
+            let prefix = prefix(first, options);
+            if !prefix.is_empty() {
+                paragraph = paragraph.add_run(Run::new().add_text(prefix).bold());
+            }
+            paragraph.add_run(Run::new().add_text(texts.join(" ")))
+        })
+        .collect()
+}
+
+/// 원문과 번역을 나란히 놓는 2열 표
+fn bilingual_table(segments: &[StoredSegment], options: &ExportOptions) -> Table {
+    let column = BODY_WIDTH / 2;
+    let language = options.translation_language.as_deref().or_else(|| {
+        segments
+            .iter()
+            .find_map(|s| s.translations.first())
+            .map(|t| t.language.as_str())
+    });
+
+    let cell = |paragraph: Paragraph| {
+        TableCell::new()
+            .width(column, WidthType::Dxa)
+            .add_paragraph(paragraph)
+    };
+    let header = |label: &str| Paragraph::new().add_run(Run::new().add_text(label).bold());
+
+    let mut rows = vec![TableRow::new(vec![
+        cell(header("원문")),
+        cell(header(language.unwrap_or("번역"))),
+    ])];
+
+    for segment in segments {
+        let mut source = Paragraph::new();
+        let prefix = prefix(segment, options);
+        if !prefix.is_empty() {
+            source = source.add_run(Run::new().add_text(prefix).bold());
+        }
+        source = source.add_run(Run::new().add_text(&segment.text));
+
+        let translation = segment
+            .translations
+            .iter()
+            .find(|t| Some(t.language.as_str()) == language)
+            .map(|t| t.text.as_str())
+            .unwrap_or_default();
+
+        rows.push(TableRow::new(vec![
+            cell(source),
+            cell(Paragraph::new().add_run(Run::new().add_text(translation))),
+        ]));
+    }
+
+    Table::new(rows)
+        .set_grid(vec![column, column])
+        .width(BODY_WIDTH, WidthType::Dxa)
+}
@@ -0,0 +1,337 @@
+use std::fmt::Write;
+
+use super::ExportOptions;
+use crate::storage::StoredSegment;
+
+/// 자막 하나에 표시할 최대 줄 수
+const MAX_LINES_PER_CUE: usize = 2;
+
+struct Cue {
+    start_ms: i64,
+    end_ms: i64,
+    lines: Vec<String>,
+}
+
+/// 길이를 확인하기 전의 자막
+struct Span {
+    start_ms: i64,
+    end_ms: i64,
+    /// 구간에서 이 자막 앞에 있는 글자 수 (공백 제외, 단어 시간 조회용)
+    letters_before: usize,
+    lines: Vec<String>,
+}
+
+pub fn to_srt(segments: &[StoredSegment], options: &ExportOptions) -> String {
+    let mut out = String::new();
+    for (index, cue) in build_cues(segments, options).iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{}\n{} --> {}\n{}\n",
+            index + 1,
+            timestamp(media_time(cue.start_ms, options), ','),
+            timestamp(media_time(cue.end_ms, options), ','),
+            cue.lines.join("\n")
+        );
+    }
+    out
+}
+
+pub fn to_vtt(segments: &[StoredSegment], options: &ExportOptions) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in build_cues(segments, options) {
+        let _ = writeln!(
+            out,
+            "{} --> {}\n{}\n",
+            timestamp(media_time(cue.start_ms, options), '.'),
+            timestamp(media_time(cue.end_ms, options), '.'),
+            cue.lines.join("\n")
+        );
+    }
+    out
+}
+
+/// 영상 정렬이 있으면 오프셋을 더하고 프레임 경계에 맞춤
+fn media_time(ms: i64, options: &ExportOptions) -> i64 {
+    match &options.alignment {
+        Some(alignment) => alignment.frame_rate.snap_ms(ms + alignment.offset_ms),
+        None => ms,
+    }
+}
+
+/// `HH:MM:SS{sep}mmm` 형식 (SRT는 쉼표, VTT는 마침표)
+fn timestamp(ms: i64, separator: char) -> String {
+    let ms = ms.max(0);
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1_000 % 60,
+        separator,
+        ms % 1_000
+    )
+}
+
+/// 구간을 줄 길이에 맞춰 나누고 자막 목록 생성
+///
+/// 단어 시간이 있으면 자막 경계를 실제 단어 시각에 맞추고, 없으면 글자 수 비율로
+/// 시간을 배분한다. `max_cue_ms`보다 긴 자막은 여러 개로 나눈다.
+fn build_cues(segments: &[StoredSegment], options: &ExportOptions) -> Vec<Cue> {
+    let mut cues = Vec::new();
+
+    for segment in segments {
+        let lines = wrap(&segment.text, options.max_line_length);
+        if lines.is_empty() {
+            continue;
+        }
+
+        let groups: Vec<&[String]> = lines.chunks(MAX_LINES_PER_CUE).collect();
+        let total_chars: usize = lines.iter().map(|line| line.chars().count()).sum();
+        let duration = (segment.end_ms - segment.start_ms).max(0);
+
+        let mut start_ms = segment.start_ms;
+        let mut consumed_chars = 0usize;
+        let mut consumed_letters = 0usize;
+        for group in groups {
+            let letters_before = consumed_letters;
+            consumed_chars += group.iter().map(|line| line.chars().count()).sum::<usize>();
+            consumed_letters += group
+                .iter()
+                .flat_map(|line| line.chars())
+                .filter(|c| !c.is_whitespace())
+                .count();
+            let end_ms = word_time(segment, consumed_letters).unwrap_or_else(|| {
+                segment.start_ms + duration * consumed_chars as i64 / total_chars.max(1) as i64
+            });
+
+            push_cue(
+                &mut cues,
+                segment,
+                options,
+                Span {
+                    start_ms,
+                    end_ms,
+                    letters_before,
+                    lines: group.to_vec(),
+                },
+            );
+            start_ms = end_ms;
+        }
+    }
+
+    cues
+}
+
+/// 자막 추가 (`max_cue_ms`보다 길면 글자 수가 반쯤 되는 곳에서 둘로 나눠 다시 확인)
+///
+/// 단어가 둘 이상이면 단어 경계에서, 한 단어뿐이면 글자 경계에서 나눈다.
+/// 나눈 시각은 단어 시간이 있으면 그 시각, 없으면 글자 수 비율로 정한다.
+fn push_cue(cues: &mut Vec<Cue>, segment: &StoredSegment, options: &ExportOptions, span: Span) {
+    let text = span.lines.join(" ");
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let letters: usize = words.iter().map(|word| word.chars().count()).sum();
+    let duration = span.end_ms - span.start_ms;
+    if duration <= options.max_cue_ms || letters < 2 {
+        // 더 나눌 수 없으면 최대 길이에서 끊음
+        cues.push(Cue {
+            start_ms: span.start_ms,
+            end_ms: span.end_ms.min(span.start_ms + options.max_cue_ms),
+            lines: span.lines,
+        });
+        return;
+    }
+
+    let (head, tail) = if words.len() > 1 {
+        let mut count = 0usize;
+        let mut split = words.len() - 1;
+        for (index, word) in words.iter().enumerate() {
+            count += word.chars().count();
+            if count * 2 >= letters {
+                split = (index + 1).min(words.len() - 1);
+                break;
+            }
+        }
+        (words[..split].join(" "), words[split..].join(" "))
+    } else {
+        let chars: Vec<char> = text.chars().collect();
+        let middle = chars.len() / 2;
+        (
+            chars[..middle].iter().collect::<String>(),
+            chars[middle..].iter().collect::<String>(),
+        )
+    };
+
+    let head_letters = head.chars().filter(|c| !c.is_whitespace()).count();
+    let split_ms = word_time(segment, span.letters_before + head_letters)
+        .unwrap_or_else(|| span.start_ms + duration * head_letters as i64 / letters as i64)
+        .clamp(span.start_ms, span.end_ms);
+
+    let tail_before = span.letters_before + head_letters;
+    for (start_ms, end_ms, letters_before, text) in [
+        (span.start_ms, split_ms, span.letters_before, head),
+        (split_ms, span.end_ms, tail_before, tail),
+    ] {
+        let lines = wrap(&text, options.max_line_length);
+        let span = Span {
+            start_ms,
+            end_ms,
+            letters_before,
+            lines,
+        };
+        push_cue(cues, segment, options, span);
+    }
+}
+
+/// 공백을 뺀 `letters`번째 글자가 끝나는 시각을 단어 시간으로 계산
+///
+/// 단어 목록이 텍스트 단어와 일대일로 맞고 모두 시간이 있을 때만 쓴다.
+fn word_time(segment: &StoredSegment, letters: usize) -> Option<i64> {
+    let text_words: Vec<&str> = segment.text.split_whitespace().collect();
+    if text_words.len() != segment.words.len() {
+        return None;
+    }
+
+    let mut consumed = 0usize;
+    for (text, word) in text_words.iter().zip(&segment.words) {
+        let (start, end) = (word.start_ms?, word.end_ms?);
+        let len = text.chars().count();
+        if letters <= consumed + len {
+            // 단어 중간에서 잘렸으면 단어 안에서 글자 수 비율로 배분
+            let inside = (letters - consumed) as i64;
+            return Some(start + (end - start) * inside / len.max(1) as i64);
+        }
+        consumed += len;
+    }
+    segment.words.last().and_then(|word| word.end_ms)
+}
+
+/// 단어 단위 줄바꿈 (한 단어가 너무 길면 글자 단위로 자름)
+fn wrap(text: &str, max_len: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        let current_len = current.chars().count();
+
+        if current_len > 0 && current_len + 1 + word_len <= max_len {
+            current.push(' ');
+            current.push_str(word);
+            continue;
+        }
+
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        let chars: Vec<char> = word.chars().collect();
+        let mut pieces = chars.chunks(max_len).peekable();
+        while let Some(piece) = pieces.next() {
+            if pieces.peek().is_some() {
+                lines.push(piece.iter().collect());
+            } else {
+                current = piece.iter().collect();
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcribe::TranscriptWord;
+
+    fn segment(text: &str, start_ms: i64, end_ms: i64) -> StoredSegment {
+        StoredSegment {
+            id: "segment".to_string(),
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+            speaker: None,
+            confidence: None,
+            words: Vec::new(),
+            translations: Vec::new(),
+        }
+    }
+
+    fn options(max_line_length: usize, max_cue_ms: i64) -> ExportOptions {
+        ExportOptions {
+            max_line_length,
+            max_cue_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn short_segment_is_one_cue() {
+        let cues = build_cues(
+            &[segment("안녕하세요 반갑습니다", 0, 2_000)],
+            &options(42, 7_000),
+        );
+        assert_eq!(cues.len(), 1);
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (0, 2_000));
+        assert_eq!(cues[0].lines, vec!["안녕하세요 반갑습니다"]);
+    }
+
+    #[test]
+    fn long_cue_is_split_at_word_boundaries() {
+        let cues = build_cues(
+            &[segment("aaaa bbbb cccc dddd", 0, 8_000)],
+            &options(42, 3_000),
+        );
+        let text: Vec<String> = cues.iter().map(|cue| cue.lines.join(" ")).collect();
+        assert_eq!(text, vec!["aaaa", "bbbb", "cccc", "dddd"]);
+        for (cue, start_ms) in cues.iter().zip([0, 2_000, 4_000, 6_000]) {
+            assert_eq!((cue.start_ms, cue.end_ms), (start_ms, start_ms + 2_000));
+        }
+    }
+
+    #[test]
+    fn single_long_word_is_split_at_characters() {
+        let cues = build_cues(&[segment("가나다라", 1_000, 9_000)], &options(42, 5_000));
+        let text: Vec<String> = cues.iter().map(|cue| cue.lines.join("")).collect();
+        assert_eq!(text, vec!["가나", "다라"]);
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (1_000, 5_000));
+        assert_eq!((cues[1].start_ms, cues[1].end_ms), (5_000, 9_000));
+    }
+
+    #[test]
+    fn split_follows_word_times() {
+        let mut long = segment("one two three", 0, 10_000);
+        long.words = [
+            ("one", 0, 1_000),
+            ("two", 1_000, 2_000),
+            ("three", 2_000, 10_000),
+        ]
+        .into_iter()
+        .map(|(text, start_ms, end_ms)| TranscriptWord {
+            text: text.to_string(),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        })
+        .collect();
+        let cues = build_cues(&[long], &options(42, 9_000));
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].lines, vec!["one two"]);
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (0, 2_000));
+        assert_eq!((cues[1].start_ms, cues[1].end_ms), (2_000, 10_000));
+    }
+
+    #[test]
+    fn unsplittable_cue_is_capped() {
+        let cues = build_cues(&[segment("a", 0, 9_000)], &options(42, 3_000));
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].end_ms, 3_000);
+    }
+
+    #[test]
+    fn timestamps_use_separator() {
+        assert_eq!(timestamp(3_723_004, ','), "01:02:03,004");
+        assert_eq!(timestamp(-5, '.'), "00:00:00.000");
+    }
+}
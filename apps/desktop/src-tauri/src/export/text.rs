@@ -0,0 +1,85 @@
+use chrono::Local;
+use std::fmt::Write;
+
+use super::ExportOptions;
+use crate::storage::{SessionDetail, StoredSegment};
+
+/// 제목이 없는 세션의 기본 제목
+const DEFAULT_TITLE: &str = "전사 기록";
+
+pub fn to_txt(session: &SessionDetail, options: &ExportOptions) -> String {
+    let mut out = String::new();
+
+    if options.include_metadata {
+        let _ = writeln!(out, "{}", title(session));
+        for (label, value) in metadata(session) {
+            let _ = writeln!(out, "{}: {}", label, value);
+        }
+        out.push('\n');
+    }
+
+    for segment in &session.segments {
+        let _ = writeln!(out, "{}{}", prefix(segment, options), segment.text);
+    }
+    out
+}
+
+pub fn to_markdown(session: &SessionDetail, options: &ExportOptions) -> String {
+    let mut out = String::new();
+
+    if options.include_metadata {
+        let _ = writeln!(out, "# {}\n", title(session));
+        for (label, value) in metadata(session) {
+            let _ = writeln!(out, "- **{}**: {}", label, value);
+        }
+        out.push('\n');
+    }
+
+    for segment in &session.segments {
+        let prefix = prefix(segment, options);
+        if prefix.is_empty() {
+            let _ = writeln!(out, "{}\n", segment.text);
+        } else {
+            let _ = writeln!(out, "**{}** {}\n", prefix.trim_end(), segment.text);
+        }
+    }
+    out
+}
+
+pub(super) fn title(session: &SessionDetail) -> &str {
+    session.session.title.as_deref().unwrap_or(DEFAULT_TITLE)
+}
+
+/// 머리말에 넣을 (항목, 값) 목록
+pub(super) fn metadata(session: &SessionDetail) -> Vec<(&'static str, String)> {
+    let info = &session.session;
+    let mut items = vec![(
+        "시작",
+        info.started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string(),
+    )];
+    if let Some(device) = &info.device_name {
+        items.push(("장치", device.clone()));
+    }
+    items.push(("길이", clock(info.duration_ms)));
+    items
+}
+
+/// 줄 앞에 붙는 `[HH:MM:SS] 화자: ` 접두어
+pub(super) fn prefix(segment: &StoredSegment, options: &ExportOptions) -> String {
+    let mut prefix = String::new();
+    if options.include_timestamps {
+        let _ = write!(prefix, "[{}] ", clock(segment.start_ms));
+    }
+    if options.include_speakers {
+        if let Some(speaker) = &segment.speaker {
+            let _ = write!(prefix, "{}: ", speaker);
+        }
+    }
+    prefix
+}
+
+/// `HH:MM:SS` 형식
+pub(super) fn clock(ms: i64) -> String {
+    let secs = ms.max(0) / 1_000;
+    format!("{:02}:{:02}:{:02}", secs / 3_600, secs / 60 % 60, secs % 60)
+}
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+mod csv_file;
+mod tmx;
+
+/// Whisper 초기 프롬프트에 넣는 최대 글자 수 (프롬프트는 모델 문맥의 절반 정도만 쓰임)
+const MAX_PROMPT_CHARS: usize = 600;
+
+/// 용어집 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct GlossaryTerm {
+    pub id: String,
+    pub term: String,
+    /// 통역용 대역어
+    pub translation: Option<String>,
+    pub note: Option<String>,
+    /// 꺼 두면 전사 힌트에서 제외
+    pub enabled: bool,
+}
+
+/// 용어집 파일 형식
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlossaryFormat {
+    Csv,
+    /// CAT 도구의 번역 메모리 교환 형식 (TMX 1.4)
+    Tmx,
+}
+
+/// 용어집 파일 옵션 (TMX 언어 지정)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GlossaryFileOptions {
+    /// 용어 언어 (가져올 때 생략하면 TMX 머리글의 `srclang`, 내보낼 때는 `ko`)
+    pub source_language: Option<String>,
+    /// 대역어 언어 (생략하면 가져올 때는 첫 번째 다른 언어, 내보낼 때는 설정의 번역 대상 언어)
+    pub target_language: Option<String>,
+}
+
+/// 용어 추가·수정 입력
+#[derive(Debug, Clone, Deserialize)]
+pub struct GlossaryTermInput {
+    pub term: String,
+    #[serde(default)]
+    pub translation: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl GlossaryTermInput {
+    /// 앞뒤 공백을 정리하고 빈 용어는 거부
+    pub fn normalized(mut self) -> Result<Self, TeuImError> {
+        // Whisper 프롬프트는 C 문자열이라 NUL을 넣을 수 없음
+        self.term = self.term.replace('\0', "").trim().to_string();
+        if self.term.is_empty() {
+            return Err(TeuImError::invalid_argument("용어를 입력하세요"));
+        }
+        self.translation = self
+            .translation
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        self.note = self
+            .note
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        Ok(self)
+    }
+}
+
+/// 전사 엔진에 넘길 용어 목록 (켜 둔 항목만)
+pub fn hints(storage: &Storage) -> Vec<String> {
+    match storage.list_glossary() {
+        Ok(terms) => terms
+            .into_iter()
+            .filter(|term| term.enabled)
+            .map(|term| term.term)
+            .collect(),
+        Err(e) => {
+            log::error!("용어집 조회 실패: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Whisper 초기 프롬프트 (용어를 앞 문맥처럼 보여 줘 같은 표기를 유도)
+pub fn whisper_prompt(hints: &[String]) -> Option<String> {
+    let mut prompt = String::new();
+    for term in hints {
+        if prompt.len() + term.len() + 2 > MAX_PROMPT_CHARS {
+            break;
+        }
+        if !prompt.is_empty() {
+            prompt.push_str(", ");
+        }
+        prompt.push_str(term);
+    }
+    (!prompt.is_empty()).then(|| format!("{}.", prompt))
+}
+
+/// 용어집 전체 조회 (용어 순)
+#[tauri::command]
+pub fn list_glossary(storage: State<'_, Storage>) -> Result<Vec<GlossaryTerm>, TeuImError> {
+    storage.list_glossary()
+}
+
+/// 용어 추가
+#[tauri::command]
+pub fn add_glossary_term(
+    storage: State<'_, Storage>,
+    term: GlossaryTermInput,
+) -> Result<GlossaryTerm, TeuImError> {
+    storage.add_glossary_term(term.normalized()?)
+}
+
+/// 용어 수정
+#[tauri::command]
+pub fn update_glossary_term(
+    storage: State<'_, Storage>,
+    id: String,
+    term: GlossaryTermInput,
+) -> Result<GlossaryTerm, TeuImError> {
+    storage.update_glossary_term(&id, term.normalized()?)
+}
+
+/// 용어 삭제
+#[tauri::command]
+pub fn delete_glossary_term(storage: State<'_, Storage>, id: String) -> Result<(), TeuImError> {
+    storage.delete_glossary_term(&id)
+}
+
+/// CSV/TMX 파일에서 용어 가져오기
+///
+/// 이미 있는 용어는 대역어와 메모를 파일 내용으로 갱신한다.
+/// 가져온 용어 수를 반환한다.
+#[tauri::command]
+pub fn import_glossary(
+    storage: State<'_, Storage>,
+    path: String,
+    format: GlossaryFormat,
+    options: Option<GlossaryFileOptions>,
+) -> Result<usize, TeuImError> {
+    let options = options.unwrap_or_default();
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| TeuImError::from(format!("파일 열기 실패: {}", e)))?;
+
+    let terms = match format {
+        GlossaryFormat::Csv => csv_file::read(&contents)?,
+        GlossaryFormat::Tmx => tmx::read(
+            &contents,
+            options.source_language.as_deref(),
+            options.target_language.as_deref(),
+        )?,
+    };
+    // 빈 용어 행은 건너뜀
+    let terms: Vec<_> = terms
+        .into_iter()
+        .filter_map(|term| term.normalized().ok())
+        .collect();
+
+    let count = storage.import_glossary(terms)?;
+    log::info!("용어집 가져옴: {} ({}개)", path, count);
+    Ok(count)
+}
+
+/// 용어집을 CSV/TMX 파일로 내보내기
+///
+/// 저장한 파일 경로를 반환한다.
+#[tauri::command]
+pub fn export_glossary(
+    storage: State<'_, Storage>,
+    settings: State<'_, SettingsStore>,
+    path: String,
+    format: GlossaryFormat,
+    options: Option<GlossaryFileOptions>,
+) -> Result<String, TeuImError> {
+    let options = options.unwrap_or_default();
+    let terms = storage.list_glossary()?;
+
+    let contents = match format {
+        GlossaryFormat::Csv => csv_file::write(&terms)?,
+        GlossaryFormat::Tmx => {
+            let source = options.source_language.unwrap_or_else(|| "ko".to_string());
+            let target = options
+                .target_language
+                .or_else(|| settings.get().target_language)
+                .ok_or_else(|| TeuImError::invalid_argument("대역어 언어를 지정하세요"))?;
+            tmx::write(&terms, &source, &target)
+        }
+    };
+
+    std::fs::write(&path, contents)
+        .map_err(|e| TeuImError::from(format!("파일 저장 실패: {}", e)))?;
+    log::info!("용어집 내보냄: {}", path);
+    Ok(path)
+}
@@ -0,0 +1,129 @@
+use super::{GlossaryTerm, GlossaryTermInput};
+
+/// 머리글로 인식하는 열 이름
+const TERM_HEADERS: &[&str] = &["term", "source", "용어", "원어"];
+const TRANSLATION_HEADERS: &[&str] = &["translation", "target", "대역어", "번역"];
+const NOTE_HEADERS: &[&str] = &["note", "notes", "comment", "메모", "비고"];
+const ENABLED_HEADERS: &[&str] = &["enabled", "사용"];
+
+/// 열 위치 (머리글이 없으면 용어, 대역어, 메모 순으로 봄)
+struct Columns {
+    term: usize,
+    translation: Option<usize>,
+    note: Option<usize>,
+    enabled: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(record: &csv::StringRecord) -> Option<Self> {
+        let find = |names: &[&str]| {
+            record
+                .iter()
+                .position(|field| names.contains(&field.trim().to_lowercase().as_str()))
+        };
+        Some(Self {
+            term: find(TERM_HEADERS)?,
+            translation: find(TRANSLATION_HEADERS),
+            note: find(NOTE_HEADERS),
+            enabled: find(ENABLED_HEADERS),
+        })
+    }
+
+    fn positional() -> Self {
+        Self {
+            term: 0,
+            translation: Some(1),
+            note: Some(2),
+            enabled: None,
+        }
+    }
+}
+
+/// CSV 용어집 읽기 (쉼표 구분, UTF-8, 머리글은 선택)
+///
+/// 머리글이 없거나 `ko-KR,en-US`처럼 언어 코드로 된 CAT 도구 형식이면
+/// 첫 열을 용어, 둘째 열을 대역어로 본다.
+pub fn read(contents: &str) -> Result<Vec<GlossaryTermInput>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(contents.trim_start_matches('\u{feff}').as_bytes());
+
+    let mut records = reader.records();
+    let mut terms = Vec::new();
+    let columns = match records.next() {
+        Some(first) => {
+            let first = first.map_err(|e| format!("CSV 읽기 실패: {}", e))?;
+            match Columns::from_header(&first) {
+                Some(columns) => columns,
+                None => {
+                    let columns = Columns::positional();
+                    if looks_like_language_header(&first) {
+                        columns
+                    } else {
+                        terms.extend(parse(&first, &columns));
+                        columns
+                    }
+                }
+            }
+        }
+        None => return Ok(terms),
+    };
+
+    for record in records {
+        let record = record.map_err(|e| format!("CSV 읽기 실패: {}", e))?;
+        terms.extend(parse(&record, &columns));
+    }
+    Ok(terms)
+}
+
+/// CSV 용어집 쓰기
+pub fn write(terms: &[GlossaryTerm]) -> Result<Vec<u8>, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let error = |e: csv::Error| format!("CSV 쓰기 실패: {}", e);
+    writer
+        .write_record(["term", "translation", "note", "enabled"])
+        .map_err(error)?;
+    for term in terms {
+        writer
+            .write_record([
+                term.term.as_str(),
+                term.translation.as_deref().unwrap_or_default(),
+                term.note.as_deref().unwrap_or_default(),
+                if term.enabled { "true" } else { "false" },
+            ])
+            .map_err(error)?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| format!("CSV 쓰기 실패: {}", e))
+}
+
+fn parse(record: &csv::StringRecord, columns: &Columns) -> Option<GlossaryTermInput> {
+    let field = |index: Option<usize>| {
+        index
+            .and_then(|index| record.get(index))
+            .map(|value| value.to_string())
+    };
+    let term = record.get(columns.term)?.to_string();
+    Some(GlossaryTermInput {
+        term,
+        translation: field(columns.translation),
+        note: field(columns.note),
+        enabled: field(columns.enabled)
+            .map(|value| !matches!(value.trim().to_lowercase().as_str(), "false" | "0" | "no"))
+            .unwrap_or(true),
+    })
+}
+
+/// `ko`, `en-US`처럼 모든 열이 언어 코드인 머리글인지
+fn looks_like_language_header(record: &csv::StringRecord) -> bool {
+    record.len() >= 2
+        && record.iter().all(|field| {
+            let mut parts = field.trim().split(['-', '_']);
+            let language = parts.next().unwrap_or_default();
+            (2..=3).contains(&language.len())
+                && language.chars().all(|c| c.is_ascii_alphabetic())
+                && parts.all(|part| (2..=4).contains(&part.len()))
+        })
+}
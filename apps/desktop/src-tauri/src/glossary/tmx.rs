@@ -0,0 +1,157 @@
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::{GlossaryTerm, GlossaryTermInput};
+
+/// 번역 단위(`<tu>`) 하나에서 모은 언어별 문장
+#[derive(Default)]
+struct TranslationUnit {
+    variants: Vec<(String, String)>,
+    note: Option<String>,
+}
+
+/// TMX 1.4 용어집 읽기
+///
+/// `source_language`(없으면 머리글의 `srclang`) 문장을 용어로,
+/// `target_language`(없으면 나머지 중 첫 번째) 문장을 대역어로 쓴다.
+pub fn read(
+    contents: &str,
+    source_language: Option<&str>,
+    target_language: Option<&str>,
+) -> Result<Vec<GlossaryTermInput>, String> {
+    let mut reader = Reader::from_str(contents);
+    reader.config_mut().trim_text(true);
+
+    let mut header_source: Option<String> = None;
+    let mut units = Vec::new();
+    let mut unit: Option<TranslationUnit> = None;
+    let mut language: Option<String> = None;
+    let mut text = String::new();
+    let mut in_text = false;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("TMX 읽기 실패 (위치 {}): {}", reader.buffer_position(), e))?;
+        match event {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"header" => header_source = attribute(&e, b"srclang"),
+                b"tu" => unit = Some(TranslationUnit::default()),
+                b"tuv" => language = attribute(&e, b"lang"),
+                b"seg" | b"note" => {
+                    in_text = true;
+                    text.clear();
+                }
+                _ => {}
+            },
+            Event::Text(e) if in_text => {
+                let value = e.unescape().map_err(|e| format!("TMX 읽기 실패: {}", e))?;
+                text.push_str(&value);
+            }
+            Event::CData(e) if in_text => {
+                text.push_str(&String::from_utf8_lossy(&e.into_inner()));
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"seg" => {
+                    in_text = false;
+                    if let (Some(unit), Some(language)) = (unit.as_mut(), language.as_ref()) {
+                        unit.variants.push((language.clone(), text.clone()));
+                    }
+                }
+                b"note" => {
+                    in_text = false;
+                    if let Some(unit) = unit.as_mut() {
+                        unit.note.get_or_insert_with(|| text.clone());
+                    }
+                }
+                b"tuv" => language = None,
+                b"tu" => units.extend(unit.take()),
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let source = source_language.map(str::to_string).or(header_source);
+    Ok(units
+        .into_iter()
+        .filter_map(|unit| {
+            let source_index = match &source {
+                Some(source) => unit
+                    .variants
+                    .iter()
+                    .position(|(lang, _)| same_language(lang, source))?,
+                None => 0,
+            };
+            let translation = unit
+                .variants
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| *index != source_index)
+                .find(|(_, (lang, _))| target_language.is_none_or(|t| same_language(lang, t)))
+                .map(|(_, (_, text))| text.clone());
+            Some(GlossaryTermInput {
+                term: unit.variants.get(source_index)?.1.clone(),
+                translation,
+                note: unit.note,
+                enabled: true,
+            })
+        })
+        .collect())
+}
+
+/// TMX 1.4 용어집 쓰기 (대역어가 없는 용어는 원어만 기록)
+pub fn write(terms: &[GlossaryTerm], source_language: &str, target_language: &str) -> Vec<u8> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<tmx version=\"1.4\">\n");
+    xml.push_str(&format!(
+        "  <header creationtool=\"Teu-Im\" creationtoolversion=\"{}\" segtype=\"phrase\" o-tmf=\"Teu-Im\" adminlang=\"en\" srclang=\"{}\" datatype=\"plaintext\"/>\n",
+        env!("CARGO_PKG_VERSION"),
+        escape(source_language)
+    ));
+    xml.push_str("  <body>\n");
+    for term in terms {
+        xml.push_str("    <tu>\n");
+        if let Some(note) = &term.note {
+            xml.push_str(&format!("      <note>{}</note>\n", escape(note.as_str())));
+        }
+        push_variant(&mut xml, source_language, &term.term);
+        if let Some(translation) = &term.translation {
+            push_variant(&mut xml, target_language, translation);
+        }
+        xml.push_str("    </tu>\n");
+    }
+    xml.push_str("  </body>\n</tmx>\n");
+    xml.into_bytes()
+}
+
+fn push_variant(xml: &mut String, language: &str, text: &str) {
+    xml.push_str(&format!(
+        "      <tuv xml:lang=\"{}\"><seg>{}</seg></tuv>\n",
+        escape(language),
+        escape(text)
+    ));
+}
+
+/// 속성 값 (`xml:lang`처럼 접두어가 붙은 이름도 지역 이름으로 찾음)
+fn attribute(element: &quick_xml::events::BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.local_name().as_ref() == name)
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+/// `ko`와 `ko-KR`처럼 주 언어가 같으면 같은 언어로 봄
+fn same_language(a: &str, b: &str) -> bool {
+    let primary = |code: &str| {
+        code.split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+    };
+    a.eq_ignore_ascii_case(b) || primary(a) == primary(b)
+}
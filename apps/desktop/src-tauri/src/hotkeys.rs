@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::settings::SettingsStore;
+
+/// 전역 단축키로 실행할 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    ToggleCapture,
+    ToggleMute,
+}
+
+/// `hotkey-triggered` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct HotkeyTriggered {
+    pub action: HotkeyAction,
+}
+
+/// 설정에 저장된 단축키 등록 (앱 시작 시)
+pub fn register_saved(app: &AppHandle) {
+    let hotkeys = app.state::<SettingsStore>().get().hotkeys;
+    for (action, accelerator) in hotkeys {
+        if let Err(e) = bind(app, action, &accelerator) {
+            log::warn!("단축키 등록 실패 ({:?}, {}): {}", action, accelerator, e);
+        }
+    }
+}
+
+/// 등록된 단축키 모두 해제 (설정 초기화 시)
+pub fn unregister_all(app: &AppHandle) {
+    if let Err(e) = app.global_shortcut().unregister_all() {
+        log::warn!("단축키 해제 실패: {}", e);
+    }
+}
+
+/// 단축키와 같은 동작 실행 (페달·MIDI 입력도 사용)
+///
+/// 음소거는 여기서 바로 바꾸고(`capture-muted`), 캡처 시작·중지는
+/// 장치 선택이 필요해 프런트엔드가 `hotkey-triggered`를 받아 처리한다.
+pub fn trigger(app: &AppHandle, action: HotkeyAction) {
+    log::debug!("단축키 실행: {:?}", action);
+    if action == HotkeyAction::ToggleMute {
+        crate::audio::toggle_muted(app);
+    }
+    let _ = app.emit("hotkey-triggered", HotkeyTriggered { action });
+}
+
+fn parse(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse()
+        .map_err(|e| format!("잘못된 단축키: {} ({})", accelerator, e))
+}
+
+fn bind(app: &AppHandle, action: HotkeyAction, accelerator: &str) -> Result<(), String> {
+    app.global_shortcut()
+        .on_shortcut(parse(accelerator)?, move |app, _, event| {
+            if event.state == ShortcutState::Pressed {
+                trigger(app, action);
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+fn unbind(app: &AppHandle, accelerator: &str) {
+    if let Ok(shortcut) = parse(accelerator) {
+        if let Err(e) = app.global_shortcut().unregister(shortcut) {
+            log::warn!("단축키 해제 실패 ({}): {}", accelerator, e);
+        }
+    }
+}
+
+/// 동작에 전역 단축키 지정 (예: `CmdOrCtrl+Shift+R`)
+#[tauri::command]
+pub async fn set_hotkey(
+    app: AppHandle,
+    settings: State<'_, SettingsStore>,
+    action: HotkeyAction,
+    accelerator: String,
+) -> Result<(), String> {
+    let shortcut = parse(&accelerator)?;
+    let hotkeys = settings.get().hotkeys;
+
+    let taken = hotkeys.iter().any(|(other, existing)| {
+        *other != action && parse(existing).map(|s| s.id()) == Ok(shortcut.id())
+    });
+    if taken {
+        return Err(format!(
+            "이미 다른 동작에 지정된 단축키입니다: {}",
+            accelerator
+        ));
+    }
+
+    let previous = hotkeys.get(&action).cloned();
+    if let Some(previous) = &previous {
+        unbind(&app, previous);
+    }
+
+    if let Err(e) = bind(&app, action, &accelerator) {
+        // 새 단축키를 쓸 수 없으면 기존 단축키 복구
+        if let Some(previous) = &previous {
+            let _ = bind(&app, action, previous);
+        }
+        return Err(format!("단축키 등록 실패: {}", e));
+    }
+
+    let updated = settings.modify(|s| {
+        s.hotkeys.insert(action, accelerator.clone());
+    })?;
+    let _ = app.emit("settings-changed", &updated);
+
+    log::info!("단축키 지정: {:?} = {}", action, accelerator);
+    Ok(())
+}
+
+/// 동작의 전역 단축키 해제
+#[tauri::command]
+pub async fn clear_hotkey(
+    app: AppHandle,
+    settings: State<'_, SettingsStore>,
+    action: HotkeyAction,
+) -> Result<(), String> {
+    let Some(accelerator) = settings.get().hotkeys.get(&action).cloned() else {
+        return Ok(());
+    };
+    unbind(&app, &accelerator);
+
+    let updated = settings.modify(|s| {
+        s.hotkeys.remove(&action);
+    })?;
+    let _ = app.emit("settings-changed", &updated);
+
+    log::info!("단축키 해제: {:?}", action);
+    Ok(())
+}
@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, Ordering};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+
+/// 백엔드가 만드는 사용자 표시 문자열의 언어
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+}
+
+impl Locale {
+    /// `ko`, `ko-KR`, `en_US.UTF-8` 같은 로캘 문자열의 언어 부분으로 판별
+    pub fn parse(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "ko" => Some(Self::Ko),
+            "en" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Self::Ko => 0,
+            Self::En => 1,
+        }
+    }
+
+    fn from_code(code: u8) -> Self {
+        match code {
+            1 => Self::En,
+            _ => Self::Ko,
+        }
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Ko => KO,
+            Self::En => EN,
+        }
+    }
+}
+
+/// 현재 로캘 (에러 `Display`처럼 앱 핸들이 없는 곳에서도 읽도록 전역으로 둠)
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+const KO: &[(&str, &str)] = &[
+    (
+        "error.device_not_found_default",
+        "기본 입력 장치를 찾을 수 없습니다",
+    ),
+    (
+        "error.device_not_found",
+        "장치를 찾을 수 없습니다: {device}",
+    ),
+    ("error.device_busy", "장치가 이미 사용 중입니다: {device}"),
+    (
+        "error.permission_denied",
+        "오디오 장치 접근이 거부되었습니다: {message}",
+    ),
+    (
+        "error.unsupported_format",
+        "지원하지 않는 오디오 형식: {message}",
+    ),
+    ("error.stream", "오디오 스트림 오류: {message}"),
+    (
+        "error.capture_session_not_found",
+        "캡처 세션을 찾을 수 없습니다: {id}",
+    ),
+    ("error.model_not_loaded", "먼저 모델을 로드하세요"),
+    ("error.model_load", "모델 로드 실패: {message}"),
+    ("error.transcription_running", "전사가 이미 실행 중입니다"),
+    ("error.session_not_found", "세션을 찾을 수 없습니다: {id}"),
+    (
+        "error.segment_not_found",
+        "전사 구간을 찾을 수 없습니다: {id}",
+    ),
+    (
+        "error.glossary_term_not_found",
+        "용어를 찾을 수 없습니다: {id}",
+    ),
+    ("error.database", "데이터베이스 오류: {message}"),
+    (
+        "error.database_locked",
+        "데이터베이스가 잠겨 있습니다. 잠금을 해제한 뒤 다시 시도하세요",
+    ),
+    (
+        "error.retention_days",
+        "보관 일수는 {min}~{max}일이어야 합니다: {days}",
+    ),
+    (
+        "error.disk_limits",
+        "녹음 중지 한도({stop_mb}MB)는 경고 한도({warn_mb}MB)보다 클 수 없습니다",
+    ),
+    (
+        "error.disk_full",
+        "저장 공간이 부족해 녹음을 시작할 수 없습니다 (남은 공간 {free_mb}MB)",
+    ),
+    ("error.already_recording", "이미 녹음 중입니다"),
+    ("error.not_recording", "녹음 중이 아닙니다"),
+    ("error.no_capture_to_record", "녹음할 캡처 세션이 없습니다"),
+    ("error.no_recording", "이 세션에는 녹음 파일이 없습니다"),
+    (
+        "error.recording_in_progress",
+        "녹음이 아직 끝나지 않았습니다",
+    ),
+    (
+        "error.already_refining",
+        "이미 다시 전사 중인 세션입니다: {id}",
+    ),
+    ("error.not_refining", "다시 전사 중인 세션이 아닙니다"),
+    ("error.session_in_progress", "진행 중인 세션입니다"),
+    ("error.session_not_interrupted", "중단된 세션이 아닙니다"),
+    ("error.session_already_ended", "이미 종료된 세션입니다"),
+    (
+        "error.data_dir_busy",
+        "캡처·녹음·전사 중에는 데이터 폴더를 옮길 수 없습니다",
+    ),
+    (
+        "error.data_dir_relative",
+        "데이터 폴더는 절대 경로여야 합니다: {path}",
+    ),
+    (
+        "error.data_dir_has_database",
+        "이미 세션 데이터베이스가 있는 폴더입니다: {path}",
+    ),
+    (
+        "error.data_dir_nested",
+        "현재 데이터 폴더 안으로는 옮길 수 없습니다: {path}",
+    ),
+    (
+        "error.file_exists",
+        "이미 같은 이름의 파일이 있습니다: {path}",
+    ),
+    (
+        "error.replay_seconds",
+        "다시 듣기 길이는 {min}~{max}초 사이여야 합니다: {seconds}",
+    ),
+    (
+        "error.no_capture_to_replay",
+        "다시 들을 캡처 세션이 없습니다",
+    ),
+    ("error.no_replay_audio", "아직 다시 들을 오디오가 없습니다"),
+    ("error.not_playing", "재생 중인 세션이 없습니다"),
+    (
+        "error.no_pending_capture",
+        "확인을 기다리는 딥 링크 캡처 요청이 없습니다",
+    ),
+    ("error.unsupported_locale", "지원하지 않는 언어: {locale}"),
+    ("device.default", "{device} (기본)"),
+    ("tray.idle", "Teu-Im - 대기 중"),
+    ("tray.recording", "Teu-Im - 캡처 중"),
+    ("tray.transcribing", "Teu-Im - 전사 중"),
+    ("tray.start_capture", "캡처 시작"),
+    ("tray.stop_capture", "캡처 중지"),
+    ("tray.show", "메인 창 열기"),
+    ("tray.quit", "종료"),
+    ("overlay.title", "Teu-Im 자막"),
+    ("notification.import_done.title", "전사 완료"),
+    (
+        "notification.import_done.body",
+        "{file} 파일 전사가 끝났습니다",
+    ),
+    ("notification.long_capture.title", "캡처 진행 중"),
+    (
+        "notification.long_capture.body",
+        "캡처가 {minutes}분째 이어지고 있습니다",
+    ),
+    ("notification.low_disk.title", "저장 공간 부족"),
+    (
+        "notification.low_disk.body",
+        "녹음 폴더의 남은 공간이 {free_mb}MB입니다",
+    ),
+    ("notification.meeting.title", "곧 회의가 시작됩니다"),
+    (
+        "notification.meeting.body",
+        "{title} 회의를 캡처하려면 Teu-Im을 여세요",
+    ),
+    (
+        "notification.meeting_auto.body",
+        "{title} 회의 캡처를 자동으로 시작합니다",
+    ),
+];
+
+const EN: &[(&str, &str)] = &[
+    (
+        "error.device_not_found_default",
+        "No default input device found",
+    ),
+    ("error.device_not_found", "Device not found: {device}"),
+    ("error.device_busy", "Device is already in use: {device}"),
+    (
+        "error.permission_denied",
+        "Access to the audio device was denied: {message}",
+    ),
+    (
+        "error.unsupported_format",
+        "Unsupported audio format: {message}",
+    ),
+    ("error.stream", "Audio stream error: {message}"),
+    (
+        "error.capture_session_not_found",
+        "Capture session not found: {id}",
+    ),
+    ("error.model_not_loaded", "Load a model first"),
+    ("error.model_load", "Failed to load model: {message}"),
+    (
+        "error.transcription_running",
+        "Transcription is already running",
+    ),
+    ("error.session_not_found", "Session not found: {id}"),
+    (
+        "error.segment_not_found",
+        "Transcript segment not found: {id}",
+    ),
+    (
+        "error.glossary_term_not_found",
+        "Glossary term not found: {id}",
+    ),
+    ("error.database", "Database error: {message}"),
+    (
+        "error.database_locked",
+        "The database is locked. Unlock it and try again",
+    ),
+    (
+        "error.retention_days",
+        "Retention must be between {min} and {max} days: {days}",
+    ),
+    (
+        "error.disk_limits",
+        "The recording stop limit ({stop_mb}MB) cannot exceed the warning limit ({warn_mb}MB)",
+    ),
+    (
+        "error.disk_full",
+        "Not enough disk space to start recording ({free_mb}MB left)",
+    ),
+    ("error.already_recording", "Already recording"),
+    ("error.not_recording", "Not recording"),
+    ("error.no_capture_to_record", "No capture session to record"),
+    ("error.no_recording", "This session has no recording"),
+    (
+        "error.recording_in_progress",
+        "The recording has not finished yet",
+    ),
+    (
+        "error.already_refining",
+        "The session is already being re-transcribed: {id}",
+    ),
+    (
+        "error.not_refining",
+        "The session is not being re-transcribed",
+    ),
+    (
+        "error.session_in_progress",
+        "The session is still in progress",
+    ),
+    (
+        "error.session_not_interrupted",
+        "The session was not interrupted",
+    ),
+    (
+        "error.session_already_ended",
+        "The session has already ended",
+    ),
+    (
+        "error.data_dir_busy",
+        "The data folder cannot be moved while capturing, recording or transcribing",
+    ),
+    (
+        "error.data_dir_relative",
+        "The data folder must be an absolute path: {path}",
+    ),
+    (
+        "error.data_dir_has_database",
+        "The folder already contains a session database: {path}",
+    ),
+    (
+        "error.data_dir_nested",
+        "The data folder cannot be moved inside itself: {path}",
+    ),
+    (
+        "error.file_exists",
+        "A file with the same name already exists: {path}",
+    ),
+    (
+        "error.replay_seconds",
+        "Replay length must be between {min} and {max} seconds: {seconds}",
+    ),
+    ("error.no_capture_to_replay", "No capture session to replay"),
+    ("error.no_replay_audio", "No audio to replay yet"),
+    ("error.not_playing", "No session is playing"),
+    (
+        "error.no_pending_capture",
+        "No deep link capture is waiting for confirmation",
+    ),
+    ("error.unsupported_locale", "Unsupported language: {locale}"),
+    ("device.default", "{device} (default)"),
+    ("tray.idle", "Teu-Im - Idle"),
+    ("tray.recording", "Teu-Im - Capturing"),
+    ("tray.transcribing", "Teu-Im - Transcribing"),
+    ("tray.start_capture", "Start capture"),
+    ("tray.stop_capture", "Stop capture"),
+    ("tray.show", "Open main window"),
+    ("tray.quit", "Quit"),
+    ("overlay.title", "Teu-Im Captions"),
+    ("notification.import_done.title", "Transcription finished"),
+    (
+        "notification.import_done.body",
+        "Finished transcribing {file}",
+    ),
+    ("notification.long_capture.title", "Capture still running"),
+    (
+        "notification.long_capture.body",
+        "Capture has been running for {minutes} minutes",
+    ),
+    ("notification.low_disk.title", "Low disk space"),
+    (
+        "notification.low_disk.body",
+        "Only {free_mb}MB left in the recordings folder",
+    ),
+    ("notification.meeting.title", "Meeting starting soon"),
+    (
+        "notification.meeting.body",
+        "Open Teu-Im to capture {title}",
+    ),
+    (
+        "notification.meeting_auto.body",
+        "Capture of {title} will start automatically",
+    ),
+];
+
+/// 현재 로캘
+pub fn locale() -> Locale {
+    Locale::from_code(CURRENT.load(Ordering::Relaxed))
+}
+
+/// 키에 해당하는 현재 로캘 문자열 (없으면 한국어, 그것도 없으면 키 자체)
+pub fn t(key: &'static str) -> &'static str {
+    let lookup = |catalog: &'static [(&'static str, &'static str)]| {
+        catalog
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, text)| *text)
+    };
+    lookup(locale().catalog())
+        .or_else(|| lookup(KO))
+        .unwrap_or(key)
+}
+
+/// `{name}` 자리표시자를 채운 현재 로캘 문자열
+pub fn tf(key: &'static str, args: &[(&str, &str)]) -> String {
+    args.iter().fold(t(key).to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+/// 시작할 때 설정(없으면 시스템 언어)으로 로캘 결정
+pub fn init(saved: Option<Locale>) {
+    let locale = saved
+        .or_else(|| sys_locale::get_locale().and_then(|tag| Locale::parse(&tag)))
+        .unwrap_or_default();
+    CURRENT.store(locale.code(), Ordering::Relaxed);
+}
+
+/// 로캘을 바꾸고 트레이 메뉴 등 이미 만든 문자열을 다시 그림
+pub fn apply(app: &AppHandle, saved: Option<Locale>) {
+    let before = locale();
+    init(saved);
+    let after = locale();
+    if before != after {
+        log::info!("표시 언어 변경: {:?}", after);
+        crate::tray::relabel(app);
+        let _ = app.emit("locale-changed", after);
+    }
+}
+
+/// 현재 표시 언어 조회
+#[tauri::command]
+pub fn get_locale() -> Locale {
+    locale()
+}
+
+/// 에러, 트레이 메뉴, 알림 등 백엔드 문자열의 언어 변경 (`ko`, `en`)
+#[tauri::command]
+pub fn set_locale(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    locale: String,
+) -> Result<Locale, TeuImError> {
+    let locale = Locale::parse(&locale).ok_or_else(|| {
+        TeuImError::invalid_argument(tf("error.unsupported_locale", &[("locale", &locale)]))
+    })?;
+    let settings = store.modify(|settings| settings.locale = Some(locale))?;
+    apply(&app, settings.locale);
+    let _ = app.emit("settings-changed", &settings);
+    Ok(locale)
+}
@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::MonoResampler;
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::transcribe::{self, TranscriptionEngine, TranscriptionOptions, WHISPER_SAMPLE_RATE};
+
+/// 가져오기 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStage {
+    Decoding,
+    Transcribing,
+}
+
+/// 이미 가져온 파일을 다시 가져올 때의 처리
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// 전사하지 않고 기존 세션 ID 반환
+    #[default]
+    Skip,
+    /// 알림만 보내고 다시 전사
+    Transcribe,
+}
+
+/// `import-duplicate` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportDuplicate {
+    pub path: String,
+    /// 같은 내용의 파일로 이미 만들어진 세션
+    pub session_id: String,
+    /// 전사를 건너뛰고 기존 세션을 돌려줬는지
+    pub skipped: bool,
+}
+
+/// `import-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportProgress {
+    pub path: String,
+    pub session_id: String,
+    pub stage: ImportStage,
+    /// 현재 단계 진행률 (0~1)
+    pub progress: f32,
+}
+
+/// 오디오 파일을 전사해 새 세션으로 저장 (WAV/MP3/M4A/OGG/FLAC)
+///
+/// 만들어진 저장소 세션 ID를 반환한다. 같은 내용의 파일을 이미 가져왔으면
+/// `import-duplicate`로 알리고, `on_duplicate`가 기본값(건너뛰기)이면 기존 세션 ID를 반환한다.
+#[tauri::command]
+pub async fn import_audio_file(
+    app: AppHandle,
+    engine: State<'_, TranscriptionEngine>,
+    path: String,
+    options: Option<TranscriptionOptions>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<String, TeuImError> {
+    let context = engine.context().ok_or(TeuImError::ModelNotLoaded)?;
+    let options = options.unwrap_or_default();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let cancel = AtomicBool::new(false);
+        let on_duplicate = on_duplicate.unwrap_or_default();
+        import_file(
+            &app,
+            &context,
+            &path,
+            &options,
+            on_duplicate,
+            &cancel,
+            |progress| {
+                let _ = app.emit("import-progress", progress);
+            },
+        )
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(TeuImError::from)
+}
+
+/// 파일 내용의 SHA-256 (16진수)
+fn fingerprint(path: &str) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|e| format!("파일 열기 실패 ({}): {}", path, e))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| format!("파일 읽기 실패 ({}): {}", path, e))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// 파일 하나를 디코딩·전사해 저장소 세션으로 기록
+pub(crate) fn import_file(
+    app: &AppHandle,
+    context: &whisper_rs::WhisperContext,
+    path: &str,
+    options: &TranscriptionOptions,
+    on_duplicate: DuplicatePolicy,
+    cancel: &AtomicBool,
+    mut on_progress: impl FnMut(ImportProgress),
+) -> Result<String, String> {
+    let storage = app.state::<Storage>();
+
+    let sha256 = fingerprint(path)?;
+    if let Some(existing) = storage.find_by_fingerprint(&sha256)? {
+        let skipped = on_duplicate == DuplicatePolicy::Skip;
+        log::warn!("이미 가져온 파일: {} (세션 {})", path, existing);
+        let _ = app.emit(
+            "import-duplicate",
+            ImportDuplicate {
+                path: path.to_string(),
+                session_id: existing.clone(),
+                skipped,
+            },
+        );
+        if skipped {
+            return Ok(existing);
+        }
+    }
+
+    let title = Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned());
+    let session_id = storage.create_session(title, None)?.id;
+    let options = &TranscriptionOptions {
+        glossary: crate::glossary::hints(&storage),
+        threads: options.threads.or_else(|| {
+            let compute = app.state::<SettingsStore>().get().compute;
+            Some(app.state::<TranscriptionEngine>().default_threads(&compute))
+        }),
+        ..options.clone()
+    };
+    log::info!("파일 가져오기 시작: {}", path);
+
+    let mut reporter = ProgressReporter::default();
+    let mut report = |stage, progress: f32| {
+        if reporter.advance(stage, progress) {
+            on_progress(ImportProgress {
+                path: path.to_string(),
+                session_id: session_id.clone(),
+                stage,
+                progress: progress.clamp(0.0, 1.0),
+            });
+        }
+    };
+
+    let result = decode(path, cancel, |progress| {
+        report(ImportStage::Decoding, progress)
+    })
+    .and_then(|audio| {
+        transcribe::transcribe_buffer(
+            context,
+            options,
+            &audio,
+            cancel,
+            |segment| {
+                if let Err(e) = storage.insert_segment(&session_id, &segment) {
+                    log::error!("전사 구간 저장 실패: {}", e);
+                }
+            },
+            |progress| report(ImportStage::Transcribing, progress),
+        )?;
+        Ok((audio.len() / (WHISPER_SAMPLE_RATE as usize / 1000)) as i64)
+    });
+
+    match result {
+        Ok(duration_ms) => {
+            storage.finish_session(&session_id, duration_ms)?;
+            storage.set_source_fingerprint(&session_id, &sha256)?;
+            log::info!("파일 가져오기 완료: {}", path);
+            Ok(session_id)
+        }
+        Err(e) => {
+            // 반쯤 만들어진 세션은 남기지 않음
+            if let Err(e) = storage.delete_session(&session_id) {
+                log::error!("가져오기 실패한 세션 삭제 실패: {}", e);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// 진행률 이벤트를 1% 단위로 줄여서 보냄
+#[derive(Default)]
+struct ProgressReporter {
+    last: Option<(ImportStage, f32)>,
+}
+
+impl ProgressReporter {
+    fn advance(&mut self, stage: ImportStage, progress: f32) -> bool {
+        let changed = match self.last {
+            Some((last_stage, last)) if last_stage == stage => {
+                progress - last >= 0.01 || (progress >= 1.0 && last < 1.0)
+            }
+            _ => true,
+        };
+        if changed {
+            self.last = Some((stage, progress));
+        }
+        changed
+    }
+}
+
+/// 오디오 파일을 16kHz 모노 f32로 디코딩
+fn decode(
+    path: &str,
+    cancel: &AtomicBool,
+    mut on_progress: impl FnMut(f32),
+) -> Result<Vec<f32>, String> {
+    let file = File::open(path).map_err(|e| format!("파일 열기 실패: {}", e))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = Path::new(path).extension().and_then(|e| e.to_str()) {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("지원하지 않는 오디오 형식: {}", e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or("오디오 트랙이 없습니다")?;
+    let track_id = track.id;
+    let total_frames = track.codec_params.n_frames;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or("샘플레이트를 알 수 없습니다")?;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("디코더 생성 실패: {}", e))?;
+    let mut resampler = MonoResampler::new(sample_rate, WHISPER_SAMPLE_RATE)?;
+
+    let mut output = Vec::new();
+    let mut decoded_frames = 0u64;
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(transcribe::CANCELLED.to_string());
+        }
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(format!("오디오 읽기 실패: {}", e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // 손상된 패킷은 건너뜀
+            Err(SymphoniaError::DecodeError(e)) => {
+                log::warn!("손상된 오디오 패킷 건너뜀: {}", e);
+                continue;
+            }
+            Err(e) => return Err(format!("오디오 디코딩 실패: {}", e)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        decoded_frames += decoded.frames() as u64;
+
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        let mono: Vec<f32> = buffer
+            .samples()
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        output.extend(resampler.process(&mono));
+
+        if let Some(total) = total_frames.filter(|total| *total > 0) {
+            on_progress(decoded_frames as f32 / total as f32);
+        }
+    }
+    on_progress(1.0);
+
+    if output.is_empty() {
+        return Err("디코딩된 오디오가 없습니다".to_string());
+    }
+    Ok(output)
+}
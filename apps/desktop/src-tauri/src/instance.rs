@@ -0,0 +1,39 @@
+use serde::Serialize;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+use crate::cli::CliArgs;
+
+/// `second-instance` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct SecondInstance {
+    /// 두 번째 실행의 명령줄 인자 (실행 파일 경로 제외)
+    pub args: Vec<String>,
+    pub cwd: String,
+}
+
+/// 앱을 다시 실행하면 새 프로세스 대신 기존 창을 앞으로 가져옴
+///
+/// 마이크를 두 프로세스가 동시에 잡지 않도록 단일 인스턴스로 유지하고,
+/// 두 번째 실행의 인자는 `second-instance` 이벤트로 프런트엔드에 넘기고,
+/// 명령줄 옵션이면 이 인스턴스에서 실행한다.
+pub fn on_second_instance(app: &AppHandle, argv: Vec<String>, cwd: String) {
+    log::info!("이미 실행 중인 인스턴스로 전환: {:?}", argv);
+    let args: Vec<String> = argv.into_iter().skip(1).collect();
+
+    match CliArgs::parse(args.clone()) {
+        Ok(mut cli) => {
+            if !cli.hidden {
+                crate::tray::show_main_window(app);
+            }
+            cli.resolve_paths(Path::new(&cwd));
+            crate::cli::execute(app, cli);
+        }
+        Err(e) => {
+            log::warn!("두 번째 실행 인자 무시: {}", e);
+            crate::tray::show_main_window(app);
+        }
+    }
+
+    let _ = app.emit("second-instance", SecondInstance { args, cwd });
+}
@@ -0,0 +1,268 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+use crate::error::TeuImError;
+use crate::import::{self, DuplicatePolicy, ImportStage};
+use crate::settings::SettingsStore;
+use crate::transcribe::{TranscriptionEngine, TranscriptionOptions};
+
+/// 설정에서 고를 수 있는 동시 작업 수
+pub const JOB_WORKERS_RANGE: RangeInclusive<u32> = 1..=8;
+
+/// 작업 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// 일괄 전사 작업 (`job-progress` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct Job {
+    pub id: String,
+    pub path: String,
+    /// 높을수록 먼저 처리
+    pub priority: i32,
+    pub status: JobStatus,
+    pub stage: Option<ImportStage>,
+    /// 현재 단계 진행률 (0~1)
+    pub progress: f32,
+    /// 완료되면 만들어진 저장소 세션
+    pub session_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+struct JobEntry {
+    job: Job,
+    options: TranscriptionOptions,
+    on_duplicate: DuplicatePolicy,
+    cancel: Arc<AtomicBool>,
+}
+
+/// 일괄 전사 대기열 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct JobQueue {
+    jobs: Mutex<Vec<JobEntry>>,
+}
+
+impl JobQueue {
+    fn update(&self, app: &AppHandle, id: &str, f: impl FnOnce(&mut Job)) {
+        let mut jobs = self.jobs.lock().unwrap();
+        if let Some(entry) = jobs.iter_mut().find(|entry| entry.job.id == id) {
+            f(&mut entry.job);
+            let _ = app.emit("job-progress", &entry.job);
+        }
+    }
+
+    /// 처리 중인 작업이 있는지
+    pub fn is_busy(&self) -> bool {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|entry| entry.job.status == JobStatus::Running)
+    }
+}
+
+/// 파일들을 일괄 전사 대기열에 추가
+#[tauri::command]
+pub fn enqueue_transcription(
+    app: AppHandle,
+    queue: State<'_, JobQueue>,
+    engine: State<'_, TranscriptionEngine>,
+    paths: Vec<String>,
+    priority: Option<i32>,
+    options: Option<TranscriptionOptions>,
+    on_duplicate: Option<DuplicatePolicy>,
+) -> Result<Vec<Job>, TeuImError> {
+    if engine.context().is_none() {
+        return Err(TeuImError::ModelNotLoaded);
+    }
+    if let Some(path) = paths
+        .iter()
+        .find(|path| !std::path::Path::new(path).is_file())
+    {
+        return Err(TeuImError::invalid_argument(format!(
+            "파일을 찾을 수 없습니다: {}",
+            path
+        )));
+    }
+
+    let options = options.unwrap_or_default();
+    let added: Vec<Job> = {
+        let mut jobs = queue.jobs.lock().unwrap();
+        paths
+            .into_iter()
+            .map(|path| {
+                let job = Job {
+                    id: Uuid::new_v4().to_string(),
+                    path,
+                    priority: priority.unwrap_or(0),
+                    status: JobStatus::Queued,
+                    stage: None,
+                    progress: 0.0,
+                    session_id: None,
+                    error: None,
+                    created_at: Utc::now(),
+                };
+                jobs.push(JobEntry {
+                    job: job.clone(),
+                    options: options.clone(),
+                    on_duplicate: on_duplicate.unwrap_or_default(),
+                    cancel: Arc::default(),
+                });
+                job
+            })
+            .collect()
+    };
+
+    for job in &added {
+        let _ = app.emit("job-progress", job);
+    }
+    log::info!("일괄 전사 작업 {}개 추가", added.len());
+    pump(&app);
+    Ok(added)
+}
+
+/// 모든 작업 조회 (추가한 순서)
+#[tauri::command]
+pub fn list_jobs(queue: State<'_, JobQueue>) -> Vec<Job> {
+    queue
+        .jobs
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|entry| entry.job.clone())
+        .collect()
+}
+
+/// 작업 취소 (실행 중이면 현재 구간을 마친 뒤 멈춤)
+#[tauri::command]
+pub fn cancel_job(app: AppHandle, queue: State<'_, JobQueue>, id: String) -> Result<(), String> {
+    let mut jobs = queue.jobs.lock().unwrap();
+    let entry = jobs
+        .iter_mut()
+        .find(|entry| entry.job.id == id)
+        .ok_or_else(|| format!("작업을 찾을 수 없습니다: {}", id))?;
+
+    match entry.job.status {
+        JobStatus::Queued => {
+            entry.job.status = JobStatus::Cancelled;
+            let _ = app.emit("job-progress", &entry.job);
+        }
+        JobStatus::Running => entry.cancel.store(true, Ordering::Relaxed),
+        _ => return Err("이미 끝난 작업입니다".to_string()),
+    }
+    log::info!("작업 취소: {}", id);
+    Ok(())
+}
+
+/// 동시 작업 수에 여유가 있으면 우선순위가 높은 대기 작업부터 시작
+pub fn pump(app: &AppHandle) {
+    let workers = app
+        .state::<SettingsStore>()
+        .get()
+        .job_workers
+        .unwrap_or(*JOB_WORKERS_RANGE.start()) as usize;
+    let queue = app.state::<JobQueue>();
+    let mut jobs = queue.jobs.lock().unwrap();
+
+    let mut running = jobs
+        .iter()
+        .filter(|entry| entry.job.status == JobStatus::Running)
+        .count();
+    while running < workers {
+        // 우선순위가 같으면 먼저 추가한 작업
+        let Some(entry) = jobs
+            .iter_mut()
+            .filter(|entry| entry.job.status == JobStatus::Queued)
+            .max_by(|a, b| {
+                a.job
+                    .priority
+                    .cmp(&b.job.priority)
+                    .then(b.job.created_at.cmp(&a.job.created_at))
+            })
+        else {
+            break;
+        };
+
+        entry.job.status = JobStatus::Running;
+        let _ = app.emit("job-progress", &entry.job);
+        running += 1;
+
+        let app = app.clone();
+        let id = entry.job.id.clone();
+        let path = entry.job.path.clone();
+        let options = entry.options.clone();
+        let on_duplicate = entry.on_duplicate;
+        let cancel = entry.cancel.clone();
+        thread::spawn(move || run_job(app, id, path, options, on_duplicate, cancel));
+    }
+}
+
+/// 작업 하나를 처리하고 다음 작업을 시작
+fn run_job(
+    app: AppHandle,
+    id: String,
+    path: String,
+    options: TranscriptionOptions,
+    on_duplicate: DuplicatePolicy,
+    cancel: Arc<AtomicBool>,
+) {
+    let queue = app.state::<JobQueue>();
+    let result = match app.state::<TranscriptionEngine>().context() {
+        Some(context) => {
+            let on_progress = |progress: import::ImportProgress| {
+                queue.update(&app, &id, |job| {
+                    job.stage = Some(progress.stage);
+                    job.progress = progress.progress;
+                    job.session_id = Some(progress.session_id);
+                });
+            };
+            import::import_file(
+                &app,
+                &context,
+                &path,
+                &options,
+                on_duplicate,
+                &cancel,
+                on_progress,
+            )
+        }
+        None => Err(TeuImError::ModelNotLoaded.to_string()),
+    };
+
+    if let Ok(session_id) = &result {
+        crate::notifications::import_completed(&app, &path);
+        crate::titling::suggest_after_session(&app, session_id);
+    }
+    queue.update(&app, &id, |job| match result {
+        Ok(session_id) => {
+            job.status = JobStatus::Completed;
+            job.session_id = Some(session_id);
+        }
+        Err(_) if cancel.load(Ordering::Relaxed) => {
+            job.status = JobStatus::Cancelled;
+            job.session_id = None;
+        }
+        Err(e) => {
+            log::error!("일괄 전사 실패 ({}): {}", path, e);
+            job.status = JobStatus::Failed;
+            job.session_id = None;
+            job.error = Some(e);
+        }
+    });
+
+    pump(&app);
+}
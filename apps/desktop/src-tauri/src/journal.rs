@@ -0,0 +1,320 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use uuid::Uuid;
+
+use crate::error::TeuImError;
+use crate::i18n::t;
+use crate::storage::{SessionSummary, Storage};
+use crate::transcribe::TranscriptSegment;
+
+/// 진행 중 세션의 녹음·부분 결과를 디스크에 남기는 주기
+pub const JOURNAL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 저널 파일에 남기는 진행 상황
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalFile {
+    session_id: String,
+    updated_at: DateTime<Utc>,
+    /// 전사 시작부터 마지막으로 처리한 시각 (ms)
+    duration_ms: i64,
+    /// 아직 확정되지 않은 마지막 부분 결과
+    partial: Option<PendingPartial>,
+}
+
+/// 확정 전에 중단된 부분 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingPartial {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// 비정상 종료로 끝나지 않은 세션
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedSession {
+    #[serde(flatten)]
+    pub session: SessionSummary,
+    /// 저널에 마지막으로 기록된 시각
+    pub journaled_at: Option<DateTime<Utc>>,
+    pub pending_partial: Option<PendingPartial>,
+    /// 녹음 파일이 남아 있는지
+    pub has_audio: bool,
+}
+
+struct ActiveJournal {
+    file: JournalFile,
+    last_write: Instant,
+}
+
+/// 진행 중 세션 저널 (Tauri 관리 상태)
+///
+/// 확정 구간은 저장소에 바로 기록되므로, 저널에는 그 사이의 부분 결과와
+/// 진행 시간만 남긴다. 앱이 비정상 종료되면 다음 실행 때 이 파일로 세션을 마무리한다.
+pub struct Journal {
+    dir: PathBuf,
+    active: Mutex<HashMap<String, ActiveJournal>>,
+}
+
+impl Journal {
+    pub fn open(dir: PathBuf) -> Result<Self, TeuImError> {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| TeuImError::from(format!("저널 폴더 생성 실패: {}", e)))?;
+        Ok(Self {
+            dir,
+            active: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn path(&self, session_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", session_id))
+    }
+
+    fn read(&self, session_id: &str) -> Option<JournalFile> {
+        let contents = std::fs::read_to_string(self.path(session_id)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// 임시 파일에 쓴 뒤 교체해 중간에 죽어도 이전 저널이 남게 함
+    fn write(&self, file: &JournalFile) {
+        let path = self.path(&file.session_id);
+        let temp = path.with_extension("json.tmp");
+        let result = serde_json::to_vec(file)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&temp, json))
+            .and_then(|_| std::fs::rename(&temp, &path));
+        if let Err(e) = result {
+            log::warn!("저널 기록 실패: {}", e);
+        }
+    }
+
+    fn remove(&self, session_id: &str) {
+        let path = self.path(session_id);
+        if path.exists() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                log::warn!("저널 삭제 실패: {}", e);
+            }
+        }
+    }
+
+    /// 진행 상황을 갱신하고 주기가 지났으면 (`force`면 바로) 파일에 기록
+    fn update(&self, session_id: &str, force: bool, apply: impl FnOnce(&mut JournalFile)) {
+        let mut active = self.active.lock().unwrap();
+        let Some(entry) = active.get_mut(session_id) else {
+            return;
+        };
+        apply(&mut entry.file);
+        if force || entry.last_write.elapsed() >= JOURNAL_INTERVAL {
+            entry.file.updated_at = Utc::now();
+            entry.last_write = Instant::now();
+            self.write(&entry.file);
+        }
+    }
+
+    fn is_active(&self, session_id: &str) -> bool {
+        self.active.lock().unwrap().contains_key(session_id)
+    }
+
+    /// 지난 실행에서 정리되지 못한 저널이 남아 있는지
+    fn is_leftover(&self, session_id: &str) -> bool {
+        !self.is_active(session_id) && self.path(session_id).exists()
+    }
+}
+
+/// 세션 저널 시작 (`offset_ms`는 이어서 기록하는 세션의 기존 길이)
+pub fn begin(app: &AppHandle, session_id: &str, offset_ms: i64) {
+    let journal = app.state::<Journal>();
+    let file = JournalFile {
+        session_id: session_id.to_string(),
+        updated_at: Utc::now(),
+        duration_ms: offset_ms,
+        partial: None,
+    };
+    journal.write(&file);
+    journal.active.lock().unwrap().insert(
+        session_id.to_string(),
+        ActiveJournal {
+            file,
+            last_write: Instant::now(),
+        },
+    );
+}
+
+/// 부분 결과 기록
+pub fn record_partial(app: &AppHandle, session_id: &str, segment: &TranscriptSegment) {
+    app.state::<Journal>().update(session_id, false, |file| {
+        file.duration_ms = file.duration_ms.max(segment.end_ms);
+        file.partial = Some(PendingPartial {
+            text: segment.text.clone(),
+            start_ms: segment.start_ms,
+            end_ms: segment.end_ms,
+        });
+    });
+}
+
+/// 확정 구간 기록 (구간은 이미 저장소에 있으므로 부분 결과만 비움)
+pub fn record_final(app: &AppHandle, session_id: &str, segment: &TranscriptSegment) {
+    app.state::<Journal>().update(session_id, false, |file| {
+        file.duration_ms = file.duration_ms.max(segment.end_ms);
+        file.partial = None;
+    });
+}
+
+/// 세션이 정상적으로 끝나 저널 삭제
+pub fn end(app: &AppHandle, session_id: &str) {
+    let journal = app.state::<Journal>();
+    journal.active.lock().unwrap().remove(session_id);
+    journal.remove(session_id);
+}
+
+/// 비정상 종료로 끝나지 않은 세션 목록 (지금 진행 중인 세션 제외)
+///
+/// 저널은 실시간 전사에서만 남기므로, 아직 가져오는 중인 파일처럼 저널 없이
+/// 종료되지 않은 세션은 중단된 것으로 보지 않는다.
+#[tauri::command]
+pub fn recover_sessions(
+    storage: State<'_, Storage>,
+    journal: State<'_, Journal>,
+) -> Result<Vec<InterruptedSession>, TeuImError> {
+    let sessions = storage
+        .list_sessions(&Default::default())?
+        .into_iter()
+        .filter(|session| session.ended_at.is_none() && journal.is_leftover(&session.id))
+        .map(|session| {
+            let file = journal.read(&session.id);
+            let has_audio = session
+                .audio_path
+                .as_deref()
+                .is_some_and(|path| Path::new(path).exists());
+            InterruptedSession {
+                journaled_at: file.as_ref().map(|file| file.updated_at),
+                pending_partial: file.and_then(|file| file.partial),
+                has_audio,
+                session,
+            }
+        })
+        .collect();
+    Ok(sessions)
+}
+
+/// 중단된 세션 마무리
+///
+/// 녹음 파일 헤더를 실제 길이에 맞게 고치고, `keep_partial`이면(기본값) 확정되지
+/// 못한 마지막 부분 결과를 구간으로 저장한 뒤 세션을 종료 상태로 만든다.
+#[tauri::command]
+pub fn finalize_recovered_session(
+    storage: State<'_, Storage>,
+    journal: State<'_, Journal>,
+    session_id: String,
+    keep_partial: Option<bool>,
+) -> Result<SessionSummary, TeuImError> {
+    if journal.is_active(&session_id) {
+        return Err(TeuImError::invalid_argument(t("error.session_in_progress")));
+    }
+    if !journal.is_leftover(&session_id) {
+        return Err(TeuImError::invalid_argument(t(
+            "error.session_not_interrupted",
+        )));
+    }
+    let detail = storage.get_session(&session_id)?;
+    if detail.session.ended_at.is_some() {
+        return Err(TeuImError::invalid_argument(t(
+            "error.session_already_ended",
+        )));
+    }
+
+    let file = journal.read(&session_id);
+    let mut duration_ms = file.as_ref().map_or(0, |file| file.duration_ms);
+    duration_ms = detail
+        .segments
+        .iter()
+        .map(|segment| segment.end_ms)
+        .fold(duration_ms, i64::max);
+
+    if keep_partial.unwrap_or(true) {
+        if let Some(partial) = file.and_then(|file| file.partial) {
+            let segment = TranscriptSegment {
+                id: Uuid::new_v4().to_string(),
+                text: partial.text,
+                start_ms: partial.start_ms,
+                end_ms: partial.end_ms,
+                is_final: true,
+                speaker: None,
+                confidence: None,
+                words: Vec::new(),
+                stable_text: None,
+            };
+            storage.insert_segment(&session_id, &segment)?;
+            duration_ms = duration_ms.max(partial.end_ms);
+        }
+    }
+
+    if let Some(path) = detail.session.audio_path.as_deref() {
+        match repair_wav(Path::new(path)) {
+            Ok(audio_ms) => duration_ms = duration_ms.max(audio_ms),
+            Err(e) => log::warn!("녹음 파일 복구 실패 ({}): {}", path, e),
+        }
+    }
+
+    storage.finish_session(&session_id, duration_ms)?;
+    journal.remove(&session_id);
+    log::info!("중단된 세션 복구: {} ({}ms)", session_id, duration_ms);
+    Ok(storage.get_session(&session_id)?.session)
+}
+
+/// 마지막 체크포인트 뒤에 쓰인 샘플까지 포함하도록 WAV 헤더 크기 수정 후 길이(ms) 반환
+fn repair_wav(path: &Path) -> std::io::Result<i64> {
+    let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+
+    let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+    let file_len = file.metadata()?.len();
+    let mut riff = [0u8; 12];
+    file.read_exact(&mut riff)?;
+    if &riff[..4] != b"RIFF" || &riff[8..] != b"WAVE" {
+        return Err(invalid("WAV 파일이 아닙니다"));
+    }
+
+    // 청크를 따라가며 fmt의 블록 크기와 data 청크 위치 찾기
+    let mut block_align = 0u64;
+    let mut byte_rate = 0u64;
+    let mut offset = 12u64;
+    let data_offset = loop {
+        let mut header = [0u8; 8];
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut header)?;
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as u64;
+        match &header[..4] {
+            b"data" => break offset,
+            b"fmt " => {
+                let mut fmt = [0u8; 16];
+                file.read_exact(&mut fmt)?;
+                byte_rate = u32::from_le_bytes([fmt[8], fmt[9], fmt[10], fmt[11]]) as u64;
+                block_align = u16::from_le_bytes([fmt[12], fmt[13]]) as u64;
+            }
+            _ => {}
+        }
+        offset += 8 + size + size % 2;
+        if offset >= file_len {
+            return Err(invalid("data 청크가 없습니다"));
+        }
+    };
+    if block_align == 0 || byte_rate == 0 {
+        return Err(invalid("fmt 청크가 없습니다"));
+    }
+
+    let data_len = (file_len - data_offset - 8) / block_align * block_align;
+    file.seek(SeekFrom::Start(4))?;
+    file.write_all(&((data_offset + data_len) as u32).to_le_bytes())?;
+    file.seek(SeekFrom::Start(data_offset + 4))?;
+    file.write_all(&(data_len as u32).to_le_bytes())?;
+    file.set_len(data_offset + 8 + data_len)?;
+    file.sync_all()?;
+
+    Ok((data_len * 1000 / byte_rate) as i64)
+}
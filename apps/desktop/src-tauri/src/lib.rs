@@ -1,6 +1,60 @@
 use tauri::Manager;
 
 mod audio;
+mod bundle;
+mod calendar;
+mod captions;
+mod cli;
+mod compute;
+mod crash;
+mod credentials;
+mod data_dir;
+mod deep_link;
+mod diagnostics;
+mod disk;
+mod edits;
+mod encryption;
+mod error;
+mod export;
+mod glossary;
+mod hotkeys;
+mod i18n;
+mod import;
+mod instance;
+mod journal;
+mod jobs;
+mod llm;
+mod logging;
+mod meeting_apps;
+mod midi;
+mod models;
+mod notifications;
+mod overlay;
+mod performance;
+mod peripherals;
+mod playback;
+mod power;
+mod ptt;
+mod recording;
+mod refine;
+mod remote_mic;
+mod retention;
+mod scheduler;
+mod settings;
+mod shutdown;
+mod storage;
+mod stream_server;
+mod stt;
+mod summary;
+mod sync;
+mod telemetry;
+mod timecode;
+mod titling;
+mod transcribe;
+mod translate;
+mod tray;
+mod updater;
+mod versions;
 
 /// 앱 버전 반환
 #[tauri::command]
@@ -22,18 +76,307 @@ async fn ping() -> String {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    pretty_env_logger::init();
+    // 패닉·네이티브 충돌 보고서 저장 (충돌 보고용 보조 프로세스는 여기서 끝남)
+    let _crash_reporter = crash::install();
+
+    let args = match cli::CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
 
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![
+        // 두 번째 실행은 기존 인스턴스로 인자를 넘기고 바로 종료 (가장 먼저 등록해야 함)
+        .plugin(tauri_plugin_single_instance::init(instance::on_second_instance))
+        .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .manage(audio::CaptureManager::default())
+        .manage(transcribe::TranscriptionEngine::default())
+        .manage(models::ModelManager::default())
+        .manage(stt::SttConfig::default())
+        .manage(translate::TranslationService::default())
+        .manage(playback::Player::default())
+        .manage(jobs::JobQueue::default())
+        .manage(refine::Refiner::default())
+        .manage(stream_server::StreamServer::default())
+        .manage(captions::CaptionServer::default())
+        .manage(overlay::CaptionOverlay::default())
+        .manage(shutdown::Shutdown::default())
+        .manage(power::PowerGuard::default())
+        .manage(edits::EditHistory::default())
+        .manage(notifications::Notifier::default())
+        .manage(disk::DiskGuard::default())
+        .manage(llm::LlmService::default())
+        .manage(performance::PerformanceMonitor::default())
+        .manage(updater::UpdateState::default())
+        .manage(telemetry::Telemetry::default())
+        .manage(encryption::Vault::default())
+        .manage(retention::Retention::default())
+        .manage(sync::SyncService::default())
+        .manage(remote_mic::RemoteMic::default())
+        .manage(scheduler::Scheduler::default())
+        .manage(calendar::CalendarService::default())
+        .manage(meeting_apps::MeetingAppWatcher::default())
+        .manage(ptt::PushToTalk::default())
+        .manage(peripherals::Peripherals::default())
+        .manage(midi::MidiListener::default())
+        .manage(deep_link::PendingCapture::default())
+        .invoke_handler(telemetry::count_commands(tauri::generate_handler![
             get_app_version,
             get_app_name,
             ping,
+            audio::list_audio_hosts,
             audio::list_audio_devices,
+            audio::get_device_capabilities,
             audio::start_audio_capture,
+            audio::start_system_audio_capture,
+            audio::start_dual_capture,
+            audio::list_capture_sessions,
+            audio::get_capture_stats,
             audio::stop_audio_capture,
-        ])
-        .setup(|app| {
+            audio::pause_audio_capture,
+            audio::resume_audio_capture,
+            audio::subscribe_audio,
+            audio::unsubscribe_audio,
+            audio::set_capture_muted,
+            audio::set_noise_suppression,
+            audio::set_input_gain,
+            audio::list_output_devices,
+            audio::start_monitoring,
+            audio::set_monitoring_volume,
+            audio::stop_monitoring,
+            audio::get_monitoring_status,
+            audio::play_test_tone,
+            audio::run_mic_check,
+            audio::set_auto_gain,
+            recording::start_recording,
+            recording::pause_recording,
+            recording::resume_recording,
+            recording::stop_recording,
+            recording::arm_capture,
+            recording::set_preroll_seconds,
+            transcribe::load_model,
+            compute::get_compute_backends,
+            timecode::align_transcript_to_media,
+            timecode::get_media_alignment,
+            timecode::clear_media_alignment,
+            performance::get_performance_status,
+            performance::set_performance_mode,
+            transcribe::start_transcription,
+            transcribe::stop_transcription,
+            stt::configure_stt_provider,
+            credentials::set_api_key,
+            credentials::has_api_key,
+            credentials::delete_api_key,
+            translate::configure_translation,
+            translate::disable_translation,
+            import::import_audio_file,
+            jobs::enqueue_transcription,
+            jobs::list_jobs,
+            jobs::cancel_job,
+            refine::refine_session,
+            refine::cancel_refinement,
+            refine::list_refined_transcripts,
+            refine::get_refined_transcript,
+            versions::list_transcript_versions,
+            versions::diff_transcript_versions,
+            stream_server::start_stream_server,
+            stream_server::stop_stream_server,
+            captions::start_caption_server,
+            captions::stop_caption_server,
+            overlay::open_caption_overlay,
+            overlay::close_caption_overlay,
+            overlay::get_caption_overlay,
+            playback::play_session,
+            playback::pause_playback,
+            playback::seek,
+            playback::stop_playback,
+            playback::replay_last,
+            models::list_available_models,
+            models::download_model,
+            models::cancel_download,
+            models::delete_model,
+            storage::create_session,
+            storage::list_sessions,
+            storage::add_tag,
+            storage::remove_tag,
+            storage::list_tags,
+            storage::list_projects,
+            storage::create_project,
+            storage::delete_project,
+            storage::move_session_to_project,
+            titling::suggest_session_title,
+            titling::set_session_title,
+            summary::summarize_session,
+            llm::configure_llm,
+            llm::get_llm_config,
+            llm::disable_llm,
+            storage::get_session,
+            storage::get_session_stats,
+            storage::delete_session,
+            storage::search_transcripts,
+            edits::update_segment,
+            edits::split_segment,
+            edits::merge_segments,
+            edits::undo_edit,
+            edits::redo_edit,
+            journal::recover_sessions,
+            journal::finalize_recovered_session,
+            storage::get_segment_history,
+            glossary::list_glossary,
+            glossary::add_glossary_term,
+            glossary::update_glossary_term,
+            glossary::delete_glossary_term,
+            glossary::import_glossary,
+            glossary::export_glossary,
+            export::export_transcript,
+            export::export_session_audio,
+            export::copy_segments_to_clipboard,
+            bundle::export_session_bundle,
+            bundle::import_session_bundle,
+            sync::set_sync_config,
+            sync::sync_now,
+            sync::resolve_sync_conflict,
+            remote_mic::start_remote_listener,
+            remote_mic::stop_remote_listener,
+            remote_mic::get_remote_listener,
+            remote_mic::connect_remote_source,
+            remote_mic::disconnect_remote_source,
+            updater::check_for_updates,
+            updater::download_update,
+            updater::install_update,
+            logging::get_recent_logs,
+            logging::export_logs,
+            diagnostics::generate_diagnostics_report,
+            telemetry::set_telemetry_enabled,
+            telemetry::get_telemetry_status,
+            crash::list_crash_reports,
+            crash::send_crash_reports,
+            crash::discard_crash_reports,
+            encryption::get_encryption_status,
+            encryption::enable_encryption,
+            encryption::disable_encryption,
+            encryption::lock_database,
+            encryption::unlock_database,
+            encryption::set_sync_recipients,
+            retention::run_retention_now,
+            retention::get_retention_log,
+            scheduler::schedule_recording,
+            scheduler::list_schedules,
+            scheduler::cancel_schedule,
+            calendar::list_calendars,
+            calendar::set_calendar,
+            calendar::remove_calendar,
+            calendar::get_upcoming_meetings,
+            calendar::record_meeting,
+            calendar::connect_google_calendar,
+            meeting_apps::get_meeting_apps,
+            deep_link::confirm_deep_link_capture,
+            settings::get_settings,
+            settings::update_settings,
+            settings::reset_settings,
+            data_dir::get_data_directory,
+            data_dir::set_data_directory,
+            i18n::get_locale,
+            i18n::set_locale,
+            hotkeys::set_hotkey,
+            hotkeys::clear_hotkey,
+            peripherals::list_foot_pedals,
+            peripherals::set_pedal_binding,
+            midi::list_midi_inputs,
+            midi::bind_midi,
+            midi::unbind_midi,
+        ]))
+        .setup(move |app| {
+            // 표준 오류와 앱 로그 폴더의 일별 로그 파일에 기록
+            logging::init(app.handle());
+
+            // 사용자 설정 (앱 설정 폴더의 JSON)
+            let settings_path = app.path().app_config_dir()?.join("settings.json");
+            let settings = settings::SettingsStore::load(&settings_path);
+            i18n::init(settings.get().locale);
+            app.manage(settings);
+
+            // 동의한 경우에만 익명 사용 통계 집계·전송
+            telemetry::init(app.handle());
+
+            // 저장된 LLM 제공자 준비
+            llm::init(app.handle(), app.state::<settings::SettingsStore>().get().llm.as_ref());
+
+            // 저장된 전역 단축키 등록
+            hotkeys::register_saved(app.handle());
+
+            // 세션 저장소 (데이터 폴더의 SQLite, 암호화했으면 키를 읽지 못하면 잠긴 채 시작)
+            let db_path = data_dir::database_path(app.handle())?;
+            app.manage(encryption::open_storage(app.handle(), &db_path)?);
+            encryption::spawn_encrypt_recordings(app.handle().clone());
+
+            // 지난 실행에서 끝나지 못한 세션 다시 전사 정리
+            refine::recover(app.handle());
+
+            // 보존 정책에 따른 오래된 녹음·세션 자동 삭제
+            retention::spawn_enforcer(app.handle().clone());
+
+            // 설정한 간격마다 다른 기기와 세션 동기화
+            sync::spawn_scheduler(app.handle().clone());
+
+            // 예약한 시각에 캡처·전사·녹음 자동 시작
+            scheduler::spawn_runner(app.handle().clone());
+
+            // 캘린더 회의가 다가오면 알리거나 캡처 자동 시작
+            calendar::spawn_watcher(app.handle().clone());
+
+            // Zoom·Teams·Meet 통화를 감지해 캡처 제안
+            meeting_apps::spawn_watcher(app.handle().clone());
+
+            // 눌러서 말하기 키 상태를 읽어 마이크 캡처 열고 닫기
+            ptt::spawn_poller(app.handle().clone());
+
+            // 전사용 페달 연결 감시와 버튼 처리
+            peripherals::spawn_watcher(app.handle().clone());
+
+            // MIDI 컨트롤러 노트로 단축키 동작 실행
+            midi::spawn_listener(app.handle().clone());
+
+            // 진행 중 세션 저널 (비정상 종료 복구용)
+            let journal_dir = app.path().app_data_dir()?.join("journal");
+            app.manage(journal::Journal::open(journal_dir)?);
+
+            // 캡처 상태 트레이 아이콘
+            tray::create(app.handle())?;
+
+            // teuim:// 링크 처리
+            deep_link::init(app.handle());
+
+            // 오디오 장치 연결/해제 감시
+            audio::spawn_device_watcher(app.handle().clone());
+
+            // 장시간 캡처 알림
+            notifications::spawn_watcher(app.handle().clone());
+
+            // 녹음 중 저장 공간 감시
+            disk::spawn_watcher(app.handle().clone());
+
+            // 배터리·과열 시 성능 절약 모드 전환
+            performance::spawn_watcher(app.handle().clone());
+
+            // 명령줄로 요청한 캡처 시작 (--hidden이면 트레이에서만 실행)
+            if args.hidden {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.hide();
+                }
+            }
+            cli::execute(app.handle(), args);
+
             // 개발 모드에서 DevTools 자동 열기
             #[cfg(debug_assertions)]
             {
@@ -42,6 +385,7 @@ pub fn run() {
             }
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("Teu-Im 앱 실행 실패");
+        .build(tauri::generate_context!())
+        .expect("Teu-Im 앱 실행 실패")
+        .run(shutdown::on_run_event);
 }
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::credentials;
+use crate::settings::SettingsStore;
+
+mod openai;
+
+pub type LlmFuture = Pin<Box<dyn Future<Output = Result<String, String>> + Send>>;
+
+/// 생성된 텍스트 조각을 받는 콜백
+pub type DeltaHandler = Box<dyn FnMut(&str) + Send>;
+
+/// 채팅 요청 (시스템 지시 + 사용자 입력 한 번)
+#[derive(Debug, Clone)]
+pub struct ChatRequest {
+    pub system: String,
+    pub user: String,
+    /// 생성할 최대 토큰 수 (생략하면 제공자 기본값)
+    pub max_tokens: Option<u32>,
+}
+
+/// 대규모 언어 모델 제공자 (요약, 제목 제안, 번역 다듬기에 사용)
+pub trait LlmProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// 생성되는 조각마다 `on_delta`를 부르고, 끝나면 전체 응답 반환
+    fn complete(&self, request: ChatRequest, on_delta: DeltaHandler) -> LlmFuture;
+}
+
+/// LLM 제공자 종류 (모두 OpenAI 호환 채팅 API 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmProviderKind {
+    Openai,
+    Ollama,
+    Vllm,
+    /// llama.cpp `llama-server`
+    LlamaCpp,
+    /// 그 밖의 OpenAI 호환 서버 (`base_url` 필수)
+    Custom,
+}
+
+impl LlmProviderKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Openai => "OpenAI",
+            Self::Ollama => "Ollama",
+            Self::Vllm => "vLLM",
+            Self::LlamaCpp => "llama.cpp",
+            Self::Custom => "LLM",
+        }
+    }
+
+    /// `/chat/completions` 앞까지의 기본 주소
+    fn default_base_url(self) -> Option<&'static str> {
+        match self {
+            Self::Openai => Some("https://api.openai.com/v1"),
+            Self::Ollama => Some("http://127.0.0.1:11434/v1"),
+            Self::Vllm => Some("http://127.0.0.1:8000/v1"),
+            Self::LlamaCpp => Some("http://127.0.0.1:8080/v1"),
+            Self::Custom => None,
+        }
+    }
+
+    /// 키체인에 API 키를 저장하는 이름
+    fn credential_name(self) -> &'static str {
+        match self {
+            Self::Openai => "openai",
+            Self::Ollama => "ollama",
+            Self::Vllm => "vllm",
+            Self::LlamaCpp => "llama_cpp",
+            Self::Custom => "llm",
+        }
+    }
+}
+
+/// LLM 제공자 설정 (API 키는 키체인에 따로 저장)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LlmConfig {
+    pub provider: LlmProviderKind,
+    /// 생략하면 제공자 기본 주소 (Ollama, vLLM 등 자체 호스팅 서버 주소)
+    #[serde(default)]
+    pub base_url: Option<String>,
+    pub model: String,
+}
+
+impl LlmConfig {
+    fn build(&self) -> Result<Arc<dyn LlmProvider>, String> {
+        let base_url = self
+            .base_url
+            .clone()
+            .filter(|url| !url.trim().is_empty())
+            .or_else(|| self.provider.default_base_url().map(str::to_string))
+            .ok_or("LLM 서버 주소를 지정하세요")?;
+        if self.model.trim().is_empty() {
+            return Err("LLM 모델 이름을 지정하세요".to_string());
+        }
+
+        // 자체 호스팅 서버는 보통 키 없이 동작
+        let api_key = credentials::api_key(self.provider.credential_name())?;
+        if self.provider == LlmProviderKind::Openai && api_key.is_none() {
+            return Err("API 키가 필요합니다".to_string());
+        }
+
+        Ok(Arc::new(openai::OpenAiCompatible::new(
+            self.provider.name(),
+            base_url,
+            self.model.trim().to_string(),
+            api_key,
+        )))
+    }
+}
+
+/// 설정된 LLM 제공자 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct LlmService {
+    active: Mutex<Option<Arc<dyn LlmProvider>>>,
+}
+
+/// 설정된 LLM 제공자 (설정하지 않았으면 `None`)
+pub fn provider(app: &AppHandle) -> Option<Arc<dyn LlmProvider>> {
+    app.try_state::<LlmService>()?
+        .active
+        .lock()
+        .unwrap()
+        .clone()
+}
+
+/// 저장된 설정으로 제공자 준비 (시작할 때, 설정을 초기화했을 때)
+pub fn init(app: &AppHandle, config: Option<&LlmConfig>) {
+    let provider = config.and_then(|config| match config.build() {
+        Ok(provider) => Some(provider),
+        Err(e) => {
+            log::warn!("저장된 LLM 설정을 쓸 수 없음: {}", e);
+            None
+        }
+    });
+    *app.state::<LlmService>().active.lock().unwrap() = provider;
+}
+
+/// LLM 제공자 설정 (`api_key`를 주면 키체인에 저장)
+#[tauri::command]
+pub fn configure_llm(
+    app: AppHandle,
+    service: State<'_, LlmService>,
+    store: State<'_, SettingsStore>,
+    config: LlmConfig,
+    api_key: Option<String>,
+) -> Result<(), String> {
+    if let Some(key) = api_key.filter(|key| !key.trim().is_empty()) {
+        credentials::set_api_key(config.provider.credential_name().to_string(), key)?;
+    }
+    let provider = config.build()?;
+
+    let settings = store.modify(|settings| settings.llm = Some(config.clone()))?;
+    log::info!("LLM 설정: {} ({})", provider.name(), config.model);
+    *service.active.lock().unwrap() = Some(provider);
+    let _ = app.emit("settings-changed", &settings);
+    Ok(())
+}
+
+/// 현재 LLM 설정 조회
+#[tauri::command]
+pub fn get_llm_config(store: State<'_, SettingsStore>) -> Option<LlmConfig> {
+    store.get().llm
+}
+
+/// LLM 사용 끄기 (제목 제안은 핵심어 방식으로 돌아감)
+#[tauri::command]
+pub fn disable_llm(
+    app: AppHandle,
+    service: State<'_, LlmService>,
+    store: State<'_, SettingsStore>,
+) -> Result<(), String> {
+    let settings = store.modify(|settings| settings.llm = None)?;
+    if service.active.lock().unwrap().take().is_some() {
+        log::info!("LLM 꺼짐");
+    }
+    let _ = app.emit("settings-changed", &settings);
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use serde_json::json;
+
+use super::{ChatRequest, DeltaHandler, LlmFuture, LlmProvider};
+
+/// OpenAI 호환 채팅 API (OpenAI, Ollama, vLLM, llama.cpp 서버 등)
+pub struct OpenAiCompatible {
+    name: &'static str,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+    client: reqwest::Client,
+}
+
+impl OpenAiCompatible {
+    pub fn new(
+        name: &'static str,
+        base_url: String,
+        model: String,
+        api_key: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            base_url,
+            model,
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+/// 스트리밍 응답의 `data:` 줄 하나에서 생성된 텍스트 추출 (`[DONE]`이면 `None`)
+fn parse_event(data: &str) -> Result<Option<String>, String> {
+    if data == "[DONE]" {
+        return Ok(None);
+    }
+    let value: serde_json::Value =
+        serde_json::from_str(data).map_err(|e| format!("응답 해석 실패: {}", e))?;
+    if let Some(message) = value["error"]["message"].as_str() {
+        return Err(format!("모델 오류: {}", message));
+    }
+    Ok(Some(
+        value["choices"][0]["delta"]["content"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    ))
+}
+
+impl LlmProvider for OpenAiCompatible {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn complete(&self, request: ChatRequest, mut on_delta: DeltaHandler) -> LlmFuture {
+        let mut body = json!({
+            "model": self.model,
+            "stream": true,
+            "messages": [
+                { "role": "system", "content": request.system },
+                { "role": "user", "content": request.user },
+            ],
+        });
+        if let Some(max_tokens) = request.max_tokens {
+            body["max_tokens"] = json!(max_tokens);
+        }
+
+        let url = format!("{}/chat/completions", self.base_url.trim_end_matches('/'));
+        let mut http = self.client.post(url).json(&body);
+        if let Some(key) = &self.api_key {
+            http = http.bearer_auth(key);
+        }
+        let name = self.name;
+
+        Box::pin(async move {
+            let mut response = http
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("{} 요청 실패: {}", name, e))?;
+
+            // 서버 전송 이벤트(SSE)를 줄 단위로 읽음 (조각이 줄 중간에서 끊길 수 있음)
+            let mut text = String::new();
+            let mut buffer = Vec::new();
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(|e| format!("{} 응답 수신 실패: {}", name, e))?
+            {
+                buffer.extend_from_slice(&chunk);
+                while let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=end).collect();
+                    let line = String::from_utf8_lossy(&line);
+                    let Some(data) = line.trim().strip_prefix("data:") else {
+                        continue;
+                    };
+                    let Some(delta) =
+                        parse_event(data.trim()).map_err(|e| format!("{} {}", name, e))?
+                    else {
+                        return Ok(text);
+                    };
+                    if !delta.is_empty() {
+                        on_delta(&delta);
+                        text.push_str(&delta);
+                    }
+                }
+            }
+            Ok(text)
+        })
+    }
+}
@@ -0,0 +1,148 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+
+use crate::error::TeuImError;
+
+/// 로그 파일 이름 (`teu-im.YYYY-MM-DD.log`)
+const LOG_FILE_PREFIX: &str = "teu-im";
+const LOG_FILE_SUFFIX: &str = "log";
+
+/// 남겨 둘 일별 로그 파일 수
+const MAX_LOG_FILES: usize = 7;
+
+/// `get_recent_logs` 기본·최대 줄 수
+const DEFAULT_RECENT_LINES: usize = 200;
+const MAX_RECENT_LINES: usize = 5_000;
+
+/// 파일 기록 스레드 (앱이 끝날 때까지 유지해야 남은 로그가 기록됨)
+static FILE_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
+
+/// 앱 로그 폴더
+pub fn log_dir(app: &AppHandle) -> Result<PathBuf, TeuImError> {
+    app.path()
+        .app_log_dir()
+        .map_err(|e| TeuImError::from(format!("로그 폴더를 찾을 수 없습니다: {}", e)))
+}
+
+/// 표준 오류와 일별 로그 파일에 함께 기록하는 로거 설정
+///
+/// `log` 매크로 기록도 함께 받는다. 수준은 `RUST_LOG`로 바꿀 수 있다 (기본 `info`).
+/// 로그 폴더를 쓸 수 없으면 표준 오류에만 기록한다.
+pub fn init(app: &AppHandle) {
+    let filter = || EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let stderr = fmt::layer().with_writer(std::io::stderr);
+
+    let appender = log_dir(app).map_err(|e| e.to_string()).and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix(LOG_FILE_SUFFIX)
+            .max_log_files(MAX_LOG_FILES)
+            .build(&dir)
+            .map_err(|e| format!("{} ({})", e, dir.display()))
+    });
+    let result = match appender {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let _ = FILE_GUARD.set(guard);
+            tracing_subscriber::registry()
+                .with(filter())
+                .with(stderr)
+                .with(fmt::layer().with_writer(writer).with_ansi(false))
+                .try_init()
+        }
+        Err(e) => {
+            eprintln!("로그 파일을 열 수 없음: {}", e);
+            tracing_subscriber::registry()
+                .with(filter())
+                .with(stderr)
+                .try_init()
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("로거 설정 실패: {}", e);
+    }
+}
+
+/// 로그 파일 목록 (오래된 것부터)
+fn log_files(dir: &Path) -> Result<Vec<PathBuf>, TeuImError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("로그 폴더 읽기 실패: {}", e).into()),
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX)
+                })
+        })
+        .collect();
+    // 파일 이름에 날짜가 들어 있어 이름순이 곧 시간순
+    files.sort();
+    Ok(files)
+}
+
+/// 최근 로그 `lines`줄 (오래된 줄부터)
+pub(crate) fn recent_lines(dir: &Path, lines: usize) -> Result<Vec<String>, TeuImError> {
+    let mut recent: Vec<String> = Vec::new();
+    for file in log_files(dir)?.iter().rev() {
+        let contents = fs::read_to_string(file)
+            .map_err(|e| format!("로그 파일 읽기 실패 ({}): {}", file.display(), e))?;
+        let mut chunk: Vec<String> = contents.lines().map(str::to_string).collect();
+        let needed = lines - recent.len();
+        if chunk.len() > needed {
+            chunk.drain(..chunk.len() - needed);
+        }
+        chunk.append(&mut recent);
+        recent = chunk;
+        if recent.len() >= lines {
+            break;
+        }
+    }
+    Ok(recent)
+}
+
+/// 최근 로그 조회 (기본 200줄)
+#[tauri::command]
+pub fn get_recent_logs(app: AppHandle, lines: Option<usize>) -> Result<Vec<String>, TeuImError> {
+    let lines = lines
+        .unwrap_or(DEFAULT_RECENT_LINES)
+        .clamp(1, MAX_RECENT_LINES);
+    recent_lines(&log_dir(&app)?, lines)
+}
+
+/// 남아 있는 로그 파일을 하나의 텍스트 파일로 내보내기 (버그 신고 첨부용)
+///
+/// 저장한 파일 경로를 반환한다.
+#[tauri::command]
+pub fn export_logs(app: AppHandle, path: String) -> Result<String, TeuImError> {
+    let files = log_files(&log_dir(&app)?)?;
+    if files.is_empty() {
+        return Err(TeuImError::invalid_argument("내보낼 로그가 없습니다"));
+    }
+
+    let mut out = fs::File::create(&path).map_err(|e| format!("파일 저장 실패: {}", e))?;
+    for file in &files {
+        let contents = fs::read(file)
+            .map_err(|e| format!("로그 파일 읽기 실패 ({}): {}", file.display(), e))?;
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        writeln!(out, "===== {} =====", name)
+            .and_then(|_| out.write_all(&contents))
+            .map_err(|e| format!("파일 저장 실패: {}", e))?;
+    }
+
+    log::info!("로그 내보냄: {} (파일 {}개)", path, files.len());
+    Ok(path)
+}
@@ -0,0 +1,207 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::CaptureManager;
+use crate::settings::SettingsStore;
+
+/// 회의 앱 확인 간격
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 통화 중으로 보는 앱 전체 CPU 사용률 (%, 음성·영상 인코딩 부하)
+const CALL_CPU_PERCENT: f32 = 8.0;
+
+/// CPU 사용률로 통화를 판단할 때 연속으로 넘어야 하는 확인 횟수
+const CALL_CPU_CHECKS: u32 = 2;
+
+/// 감지하는 회의 앱
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingApp {
+    Zoom,
+    Teams,
+    Meet,
+}
+
+impl MeetingApp {
+    const ALL: [Self; 3] = [Self::Zoom, Self::Teams, Self::Meet];
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::Zoom => "Zoom",
+            Self::Teams => "Microsoft Teams",
+            Self::Meet => "Google Meet",
+        }
+    }
+
+    /// 앱 프로세스 이름 (소문자, 확장자 제외)
+    fn process_names(self) -> &'static [&'static str] {
+        match self {
+            Self::Zoom => &["zoom", "zoom.us"],
+            Self::Teams => &["teams", "ms-teams", "msteams", "teams-for-linux"],
+            // 브라우저 탭은 구분할 수 없어 설치한 Meet 앱(PWA)만 명령줄로 찾음
+            Self::Meet => &[],
+        }
+    }
+
+    /// 회의 중에만 뜨는 보조 프로세스 이름 (소문자, 확장자 제외)
+    fn call_process_names(self) -> &'static [&'static str] {
+        match self {
+            // macOS·Windows의 Zoom 회의 창 호스트
+            Self::Zoom => &["cpthost"],
+            _ => &[],
+        }
+    }
+
+    /// 회의에 들어간 프로세스의 명령줄에 나타나는 문자열
+    fn call_command_hints(self) -> &'static [&'static str] {
+        match self {
+            Self::Zoom => &["zoommtg:", "--url="],
+            Self::Teams => &["msteams:", "/l/meetup-join/"],
+            Self::Meet => &["meet.google.com/"],
+        }
+    }
+}
+
+/// 통화 중으로 감지한 회의 앱 (`meeting-app-detected` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct DetectedMeetingApp {
+    pub app: MeetingApp,
+    pub name: &'static str,
+    /// 통화를 판단한 프로세스
+    pub pid: u32,
+    pub process_name: String,
+}
+
+/// 감지 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct MeetingAppWatcher {
+    detected: Mutex<HashMap<MeetingApp, DetectedMeetingApp>>,
+}
+
+/// 확장자를 뺀 소문자 프로세스 이름
+fn normalized_name(process: &sysinfo::Process) -> String {
+    let name = process.name().to_string_lossy().to_lowercase();
+    match name.strip_suffix(".exe") {
+        Some(stem) => stem.to_string(),
+        None => name,
+    }
+}
+
+/// 프로세스 목록에서 앱별 통화 여부 판단
+struct Detector {
+    system: System,
+    /// 앱별로 CPU 사용률이 기준을 연속으로 넘은 횟수
+    busy_checks: HashMap<MeetingApp, u32>,
+}
+
+impl Detector {
+    fn new() -> Self {
+        Self {
+            system: System::new(),
+            busy_checks: HashMap::new(),
+        }
+    }
+
+    fn scan(&mut self) -> HashMap<MeetingApp, DetectedMeetingApp> {
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_cmd(UpdateKind::OnlyIfNotSet)
+                .without_tasks(),
+        );
+
+        let mut detected = HashMap::new();
+        for app in MeetingApp::ALL {
+            let mut cpu = 0.0f32;
+            let mut main = None;
+            let mut call = None;
+            for process in self.system.processes().values() {
+                let name = normalized_name(process);
+                let command = process
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy().to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let hinted = app
+                    .call_command_hints()
+                    .iter()
+                    .any(|hint| command.contains(hint));
+                let is_app = app.process_names().contains(&name.as_str());
+                // 명령줄 힌트는 앱 프로세스에서만 봄 (Meet는 브라우저라 예외)
+                if app.call_process_names().contains(&name.as_str())
+                    || (hinted && (is_app || app.process_names().is_empty()))
+                {
+                    call.get_or_insert((process.pid(), name.clone()));
+                }
+                if is_app {
+                    cpu += process.cpu_usage();
+                    main.get_or_insert((process.pid(), name));
+                }
+            }
+
+            let busy = self.busy_checks.entry(app).or_default();
+            *busy = match main.is_some() && cpu >= CALL_CPU_PERCENT {
+                true => *busy + 1,
+                false => 0,
+            };
+            let found = call.or(main.filter(|_| *busy >= CALL_CPU_CHECKS));
+            if let Some((pid, process_name)) = found {
+                detected.insert(
+                    app,
+                    DetectedMeetingApp {
+                        app,
+                        name: app.display_name(),
+                        pid: pid.as_u32(),
+                        process_name,
+                    },
+                );
+            }
+        }
+        detected
+    }
+}
+
+/// 회의 앱이 통화에 들어가면 `meeting-app-detected` 이벤트 발행
+///
+/// 회의 앱의 창 제목은 플랫폼마다 읽는 방법이 달라, 회의 중에만 뜨는 보조
+/// 프로세스·회의 링크가 담긴 명령줄·지속적인 CPU 사용률로 통화 여부를 추정한다.
+/// 이미 캡처 중이면 알리지 않는다.
+pub fn spawn_watcher(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut detector = Detector::new();
+        // CPU 사용률은 두 번 측정한 차이로 계산
+        detector.scan();
+        loop {
+            std::thread::sleep(CHECK_INTERVAL);
+            if app.state::<SettingsStore>().get().ignore_meeting_apps {
+                continue;
+            }
+            let current = detector.scan();
+            let capturing = !app.state::<CaptureManager>().list().is_empty();
+
+            let watcher = app.state::<MeetingAppWatcher>();
+            let mut detected = watcher.detected.lock().unwrap();
+            for (meeting_app, found) in &current {
+                if detected.contains_key(meeting_app) || capturing {
+                    continue;
+                }
+                log::info!("회의 앱 통화 감지: {} ({})", found.name, found.process_name);
+                let _ = app.emit("meeting-app-detected", found);
+            }
+            *detected = current;
+        }
+    });
+}
+
+/// 지금 통화 중으로 보이는 회의 앱
+#[tauri::command]
+pub fn get_meeting_apps(watcher: State<'_, MeetingAppWatcher>) -> Vec<DetectedMeetingApp> {
+    watcher.detected.lock().unwrap().values().cloned().collect()
+}
@@ -0,0 +1,238 @@
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::hotkeys::{self, HotkeyAction};
+use crate::settings::SettingsStore;
+
+/// MIDI 입력 포트 (midir)
+mod ports;
+
+use ports::MidiPort;
+
+/// 새로 연결된 MIDI 입력을 찾는 간격
+const SCAN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// MIDI 노트 번호 범위
+pub const MIDI_NOTE_RANGE: std::ops::RangeInclusive<u8> = 0..=127;
+
+/// MIDI 입력 장치 (`list_midi_inputs` 응답 항목)
+#[derive(Debug, Clone, Serialize)]
+pub struct MidiInput {
+    /// 포트 ID (플랫폼마다 형식이 다름)
+    pub id: String,
+    pub name: String,
+    /// 지금 듣고 있는지
+    pub connected: bool,
+}
+
+/// `midi-note` 이벤트 페이로드 (지정 화면에서 누른 노트 확인용)
+#[derive(Debug, Clone, Serialize)]
+pub struct MidiNote {
+    pub input: String,
+    pub note: u8,
+    pub velocity: u8,
+    /// 노트에 지정된 동작
+    pub action: Option<HotkeyAction>,
+}
+
+/// 듣고 있는 MIDI 입력 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct MidiListener {
+    connected: Mutex<HashMap<String, (MidiInput, MidiPort)>>,
+}
+
+/// 바이트 흐름에서 노트 온 메시지만 골라냄 (러닝 스테이터스 지원)
+#[derive(Default)]
+struct NoteParser {
+    status: Option<u8>,
+    data: Vec<u8>,
+}
+
+impl NoteParser {
+    /// 바이트 하나를 넣고 노트 온이 완성되면 (노트, 세기) 반환
+    fn push(&mut self, byte: u8) -> Option<(u8, u8)> {
+        match byte {
+            // 클록 같은 리얼타임 메시지는 다른 메시지 사이에 끼어도 무시
+            0xF8..=0xFF => return None,
+            // 시스템 메시지는 채널 상태를 끊음
+            0xF0..=0xF7 => {
+                self.status = None;
+                self.data.clear();
+                return None;
+            }
+            0x80..=0xEF => {
+                self.status = Some(byte);
+                self.data.clear();
+                return None;
+            }
+            _ => {}
+        }
+
+        let status = self.status?;
+        self.data.push(byte);
+        // 프로그램 체인지·채널 프레셔는 데이터 한 바이트, 나머지는 두 바이트
+        let length = match status & 0xF0 {
+            0xC0 | 0xD0 => 1,
+            _ => 2,
+        };
+        if self.data.len() < length {
+            return None;
+        }
+        let data = std::mem::take(&mut self.data);
+        // 세기 0인 노트 온은 노트 오프
+        (status & 0xF0 == 0x90 && data[1] > 0).then(|| (data[0], data[1]))
+    }
+}
+
+/// MIDI 입력 연결을 감시하고 새 입력을 열어 노트를 받음 (분리된 입력은 닫음)
+pub fn spawn_listener(app: AppHandle) {
+    std::thread::spawn(move || {
+        // 열 수 없는 입력은 한 번만 알림
+        let mut failed = HashSet::new();
+        let mut listed = true;
+        loop {
+            let inputs = match ports::inputs() {
+                Ok(inputs) => {
+                    listed = true;
+                    inputs
+                }
+                Err(e) => {
+                    if std::mem::take(&mut listed) {
+                        log::warn!("MIDI 입력 목록을 읽을 수 없습니다: {}", e);
+                    }
+                    std::thread::sleep(SCAN_INTERVAL);
+                    continue;
+                }
+            };
+            let listener = app.state::<MidiListener>();
+            listener.connected.lock().unwrap().retain(|id, (input, _)| {
+                let present = inputs.iter().any(|current| &current.id == id);
+                if !present {
+                    log::info!("MIDI 입력 분리됨: {} ({})", input.name, input.id);
+                }
+                present
+            });
+
+            for input in inputs {
+                if listener.connected.lock().unwrap().contains_key(&input.id) {
+                    continue;
+                }
+                let port = {
+                    let (app, id) = (app.clone(), input.id.clone());
+                    let mut parser = NoteParser::default();
+                    ports::open(&input, move |message| {
+                        for (note, velocity) in message.iter().filter_map(|b| parser.push(*b)) {
+                            handle_note(&app, &id, note, velocity);
+                        }
+                    })
+                };
+                let port = match port {
+                    Ok(port) => port,
+                    Err(e) => {
+                        if failed.insert(input.id.clone()) {
+                            log::warn!("MIDI 입력을 열 수 없습니다 ({}): {}", input.name, e);
+                        }
+                        continue;
+                    }
+                };
+                failed.remove(&input.id);
+
+                log::info!("MIDI 입력 연결됨: {} ({})", input.name, input.id);
+                let input = MidiInput {
+                    connected: true,
+                    ..input
+                };
+                listener
+                    .connected
+                    .lock()
+                    .unwrap()
+                    .insert(input.id.clone(), (input, port));
+            }
+            std::thread::sleep(SCAN_INTERVAL);
+        }
+    });
+}
+
+/// 받은 노트를 알리고 지정한 동작 실행
+fn handle_note(app: &AppHandle, input: &str, note: u8, velocity: u8) {
+    let action = app
+        .state::<SettingsStore>()
+        .get()
+        .midi_bindings
+        .get(&note)
+        .copied();
+    let _ = app.emit(
+        "midi-note",
+        MidiNote {
+            input: input.to_string(),
+            note,
+            velocity,
+            action,
+        },
+    );
+    if let Some(action) = action {
+        hotkeys::trigger(app, action);
+    }
+}
+
+/// 연결된 MIDI 입력 목록
+#[tauri::command]
+pub fn list_midi_inputs(listener: State<'_, MidiListener>) -> Result<Vec<MidiInput>, String> {
+    let connected = listener.connected.lock().unwrap();
+    let mut inputs: Vec<_> = ports::inputs()?
+        .into_iter()
+        .map(|input| MidiInput {
+            connected: connected.contains_key(&input.id),
+            ..input
+        })
+        .collect();
+    inputs.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(inputs)
+}
+
+/// MIDI 노트에 단축키 동작 지정 (어느 입력에서 온 노트든 실행)
+#[tauri::command]
+pub fn bind_midi(
+    app: AppHandle,
+    settings: State<'_, SettingsStore>,
+    note: u8,
+    action: HotkeyAction,
+) -> Result<(), String> {
+    if !MIDI_NOTE_RANGE.contains(&note) {
+        return Err(format!(
+            "MIDI 노트 번호는 {}~{} 사이여야 합니다: {}",
+            MIDI_NOTE_RANGE.start(),
+            MIDI_NOTE_RANGE.end(),
+            note
+        ));
+    }
+    let updated = settings.modify(|s| {
+        s.midi_bindings.insert(note, action);
+    })?;
+    let _ = app.emit("settings-changed", &updated);
+
+    log::info!("MIDI 노트 지정: {} = {:?}", note, action);
+    Ok(())
+}
+
+/// MIDI 노트 지정 해제
+#[tauri::command]
+pub fn unbind_midi(
+    app: AppHandle,
+    settings: State<'_, SettingsStore>,
+    note: u8,
+) -> Result<(), String> {
+    if !settings.get().midi_bindings.contains_key(&note) {
+        return Ok(());
+    }
+    let updated = settings.modify(|s| {
+        s.midi_bindings.remove(&note);
+    })?;
+    let _ = app.emit("settings-changed", &updated);
+
+    log::info!("MIDI 노트 지정 해제: {}", note);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use midir::{Ignore, MidiInputConnection};
+
+use super::MidiInput;
+
+/// 운영체제 MIDI 서비스에 보이는 클라이언트 이름
+const CLIENT_NAME: &str = "Teu-Im";
+
+/// 열린 입력 (버리면 닫힘)
+pub struct MidiPort {
+    _connection: MidiInputConnection<()>,
+}
+
+fn client() -> Result<midir::MidiInput, String> {
+    midir::MidiInput::new(CLIENT_NAME).map_err(|e| e.to_string())
+}
+
+/// 연결된 MIDI 입력 목록 (midir로 찾아 모든 플랫폼에서 같게 동작)
+pub fn inputs() -> Result<Vec<MidiInput>, String> {
+    let client = client()?;
+    Ok(client
+        .ports()
+        .iter()
+        .filter_map(|port| {
+            Some(MidiInput {
+                id: port.id(),
+                name: client.port_name(port).ok()?,
+                connected: false,
+            })
+        })
+        .collect())
+}
+
+/// 입력을 열고 들어온 메시지마다 `on_message` 호출 (midir 스레드에서 불림)
+pub fn open(
+    input: &MidiInput,
+    mut on_message: impl FnMut(&[u8]) + Send + 'static,
+) -> Result<MidiPort, String> {
+    let mut client = client()?;
+    // 노트만 보므로 시스템 익스클루시브·클록·액티브 센싱은 받지 않음
+    client.ignore(Ignore::All);
+    let port = client
+        .find_port_by_id(input.id.clone())
+        .ok_or("MIDI 입력이 분리되었습니다")?;
+    let connection = client
+        .connect(
+            &port,
+            CLIENT_NAME,
+            move |_, message, _| on_message(message),
+            (),
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(MidiPort {
+        _connection: connection,
+    })
+}
@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::io::AsyncWriteExt;
+
+/// whisper.cpp 모델 저장소 (Hugging Face)
+const MODEL_REPO_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp";
+
+/// 저장소 파일 메타데이터 API (LFS SHA256 포함)
+const MODEL_TREE_API_URL: &str = "https://huggingface.co/api/models/ggerganov/whisper.cpp/tree/main";
+
+/// 진행률 이벤트 최소 간격
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 내려받을 수 있는 모델 목록 (이름, 대략적인 크기 MB)
+const MODEL_CATALOG: &[(&str, u64)] = &[
+    ("tiny", 75),
+    ("tiny.en", 75),
+    ("base", 142),
+    ("base.en", 142),
+    ("small", 466),
+    ("small.en", 466),
+    ("medium", 1_500),
+    ("medium.en", 1_500),
+    ("large-v3", 2_900),
+    ("large-v3-turbo", 1_600),
+    ("large-v3-turbo-q5_0", 547),
+];
+
+/// 모델 목록 항목
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelEntry {
+    pub name: String,
+    pub size_mb: u64,
+    pub downloaded: bool,
+    pub downloading: bool,
+    pub path: Option<String>,
+}
+
+/// `model-download-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct DownloadProgress {
+    pub name: String,
+    pub downloaded: u64,
+    pub total: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoFile {
+    path: String,
+    lfs: Option<LfsInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LfsInfo {
+    oid: String,
+}
+
+/// 모델 다운로드 관리자 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct ModelManager {
+    /// 진행 중인 다운로드의 취소 플래그
+    downloads: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+/// 모델 저장 폴더
+pub fn models_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("앱 데이터 폴더 조회 실패: {}", e))?
+        .join("models");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("모델 폴더 생성 실패: {}", e))?;
+    Ok(dir)
+}
+
+fn model_file_name(name: &str) -> String {
+    format!("ggml-{}.bin", name)
+}
+
+fn ensure_known_model(name: &str) -> Result<(), String> {
+    if MODEL_CATALOG.iter().any(|(n, _)| *n == name) {
+        Ok(())
+    } else {
+        Err(format!("알 수 없는 모델: {}", name))
+    }
+}
+
+/// 모델 이름(`large-v3` 등 목록의 이름)이나 파일 경로를 모델 파일 경로로 변환
+pub(crate) fn resolve_model_path(app: &AppHandle, model: &str) -> Result<PathBuf, String> {
+    if ensure_known_model(model).is_ok() {
+        let path = models_dir(app)?.join(model_file_name(model));
+        return if path.is_file() {
+            Ok(path)
+        } else {
+            Err(format!("모델을 먼저 내려받아야 합니다: {}", model))
+        };
+    }
+    let path = PathBuf::from(model);
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err(format!("모델 파일을 찾을 수 없습니다: {}", model))
+    }
+}
+
+/// 내려받을 수 있는 모델과 설치 상태 조회
+#[tauri::command]
+pub fn list_available_models(
+    app: AppHandle,
+    models: State<'_, ModelManager>,
+) -> Result<Vec<ModelEntry>, String> {
+    let dir = models_dir(&app)?;
+    let downloads = models.downloads.lock().unwrap();
+
+    Ok(MODEL_CATALOG
+        .iter()
+        .map(|(name, size_mb)| {
+            let path = dir.join(model_file_name(name));
+            let downloaded = path.is_file();
+            ModelEntry {
+                name: name.to_string(),
+                size_mb: *size_mb,
+                downloaded,
+                downloading: downloads.contains_key(*name),
+                path: downloaded.then(|| path.to_string_lossy().to_string()),
+            }
+        })
+        .collect())
+}
+
+/// 모델 다운로드 후 SHA256 검증, 저장된 경로 반환
+///
+/// 진행 상황은 `model-download-progress` 이벤트로 보낸다.
+#[tauri::command]
+pub async fn download_model(
+    app: AppHandle,
+    models: State<'_, ModelManager>,
+    name: String,
+) -> Result<String, String> {
+    ensure_known_model(&name)?;
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut downloads = models.downloads.lock().unwrap();
+        if downloads.contains_key(&name) {
+            return Err(format!("이미 다운로드 중입니다: {}", name));
+        }
+        downloads.insert(name.clone(), cancel.clone());
+    }
+
+    let result = download_and_verify(&app, &name, &cancel).await;
+    models.downloads.lock().unwrap().remove(&name);
+
+    match &result {
+        Ok(path) => log::info!("모델 다운로드 완료: {}", path),
+        Err(e) => log::error!("모델 다운로드 실패 ({}): {}", name, e),
+    }
+    result
+}
+
+async fn download_and_verify(app: &AppHandle, name: &str, cancel: &AtomicBool) -> Result<String, String> {
+    let file_name = model_file_name(name);
+    let dir = models_dir(app)?;
+    let final_path = dir.join(&file_name);
+    let part_path = dir.join(format!("{}.part", file_name));
+
+    let client = reqwest::Client::new();
+    let expected_sha256 = fetch_expected_sha256(&client, &file_name).await?;
+
+    let mut response = client
+        .get(format!("{}/resolve/main/{}", MODEL_REPO_URL, file_name))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("다운로드 요청 실패: {}", e))?;
+
+    let total = response.content_length();
+    let mut file = tokio::fs::File::create(&part_path)
+        .await
+        .map_err(|e| format!("임시 파일 생성 실패: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut downloaded = 0u64;
+    let mut last_progress = Instant::now();
+
+    let outcome: Result<(), String> = async {
+        while let Some(chunk) = response.chunk().await.map_err(|e| format!("다운로드 중단: {}", e))? {
+            if cancel.load(Ordering::SeqCst) {
+                return Err("다운로드가 취소되었습니다".to_string());
+            }
+
+            hasher.update(&chunk);
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+            downloaded += chunk.len() as u64;
+
+            if last_progress.elapsed() >= PROGRESS_INTERVAL {
+                last_progress = Instant::now();
+                let _ = app.emit("model-download-progress", DownloadProgress {
+                    name: name.to_string(),
+                    downloaded,
+                    total,
+                });
+            }
+        }
+        file.flush().await.map_err(|e| format!("파일 쓰기 실패: {}", e))?;
+
+        let actual = format!("{:x}", hasher.finalize_reset());
+        if actual != expected_sha256 {
+            return Err(format!("체크섬 불일치 (기대값 {}, 실제 {})", expected_sha256, actual));
+        }
+        Ok(())
+    }
+    .await;
+
+    drop(file);
+    if let Err(e) = outcome {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(e);
+    }
+
+    tokio::fs::rename(&part_path, &final_path)
+        .await
+        .map_err(|e| format!("모델 파일 이동 실패: {}", e))?;
+
+    let _ = app.emit("model-download-progress", DownloadProgress {
+        name: name.to_string(),
+        downloaded,
+        total: Some(downloaded),
+    });
+
+    Ok(final_path.to_string_lossy().to_string())
+}
+
+/// 저장소 메타데이터에서 파일의 SHA256(LFS oid) 조회
+async fn fetch_expected_sha256(client: &reqwest::Client, file_name: &str) -> Result<String, String> {
+    let files: Vec<RepoFile> = client
+        .get(MODEL_TREE_API_URL)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("모델 메타데이터 조회 실패: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("모델 메타데이터 파싱 실패: {}", e))?;
+
+    files
+        .into_iter()
+        .find(|f| f.path == file_name)
+        .and_then(|f| f.lfs)
+        .map(|lfs| lfs.oid.to_lowercase())
+        .ok_or_else(|| format!("모델 체크섬을 찾을 수 없습니다: {}", file_name))
+}
+
+/// 진행 중인 다운로드 취소
+#[tauri::command]
+pub fn cancel_download(models: State<'_, ModelManager>, name: String) -> Result<(), String> {
+    let downloads = models.downloads.lock().unwrap();
+    let cancel = downloads
+        .get(&name)
+        .ok_or_else(|| format!("다운로드 중이 아닙니다: {}", name))?;
+    cancel.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// 내려받은 모델 삭제
+#[tauri::command]
+pub fn delete_model(app: AppHandle, models: State<'_, ModelManager>, name: String) -> Result<(), String> {
+    ensure_known_model(&name)?;
+    if models.downloads.lock().unwrap().contains_key(&name) {
+        return Err(format!("다운로드 중인 모델은 삭제할 수 없습니다: {}", name));
+    }
+
+    let path = models_dir(&app)?.join(model_file_name(&name));
+    if !path.is_file() {
+        return Err(format!("설치되지 않은 모델입니다: {}", name));
+    }
+    std::fs::remove_file(&path).map_err(|e| format!("모델 삭제 실패: {}", e))?;
+
+    log::info!("모델 삭제됨: {}", name);
+    Ok(())
+}
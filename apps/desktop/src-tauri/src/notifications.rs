@@ -0,0 +1,131 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::audio::CaptureManager;
+use crate::i18n::{t, tf};
+use crate::settings::SettingsStore;
+
+/// 장시간 캡처 확인 주기
+const WATCH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 알림 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// 데스크톱 알림 사용 여부
+    pub enabled: bool,
+    /// 캡처가 이 시간(분)을 넘기면 알림 (생략하면 알리지 않음)
+    pub long_capture_minutes: Option<u32>,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            long_capture_minutes: Some(60),
+        }
+    }
+}
+
+/// 이미 보낸 알림 기록 (Tauri 관리 상태)
+///
+/// 같은 캡처 세션으로 알림이 반복되지 않게 한다.
+#[derive(Default)]
+pub struct Notifier {
+    long_captures: Mutex<HashSet<String>>,
+}
+
+/// 설정에서 알림을 켰을 때만 데스크톱 알림 표시
+fn notify(app: &AppHandle, title: &str, body: &str) {
+    let enabled = app
+        .try_state::<SettingsStore>()
+        .is_none_or(|store| store.get().notifications.enabled);
+    if !enabled {
+        return;
+    }
+
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        log::warn!("알림 표시 실패: {}", e);
+    }
+}
+
+/// 파일 가져오기 전사가 끝났음을 알림
+pub fn import_completed(app: &AppHandle, path: &str) {
+    let file = Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string());
+    notify(
+        app,
+        t("notification.import_done.title"),
+        &tf("notification.import_done.body", &[("file", &file)]),
+    );
+}
+
+/// 녹음 폴더의 남은 공간이 경고 한도 아래로 내려갔음을 알림
+pub fn low_disk(app: &AppHandle, free_mb: u64) {
+    notify(
+        app,
+        t("notification.low_disk.title"),
+        &tf(
+            "notification.low_disk.body",
+            &[("free_mb", &free_mb.to_string())],
+        ),
+    );
+}
+
+/// 캘린더 회의가 곧 시작함을 알림 (`auto_start`면 캡처를 자동으로 시작함을 알림)
+pub fn meeting_upcoming(app: &AppHandle, title: &str, auto_start: bool) {
+    let body = match auto_start {
+        true => "notification.meeting_auto.body",
+        false => "notification.meeting.body",
+    };
+    notify(
+        app,
+        t("notification.meeting.title"),
+        &tf(body, &[("title", title)]),
+    );
+}
+
+/// 장시간 캡처를 주기적으로 확인하는 스레드 시작
+pub fn spawn_watcher(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(WATCH_INTERVAL);
+        let settings = app.state::<SettingsStore>().get().notifications;
+        check_long_captures(&app, settings.long_capture_minutes);
+    });
+}
+
+fn check_long_captures(app: &AppHandle, limit_minutes: Option<u32>) {
+    let sessions = app.state::<CaptureManager>().list();
+    let notifier = app.state::<Notifier>();
+    let mut notified = notifier.long_captures.lock().unwrap();
+    notified.retain(|id| sessions.iter().any(|s| &s.id == id));
+
+    let Some(limit_minutes) = limit_minutes else {
+        return;
+    };
+    let now = Utc::now();
+    for session in sessions {
+        // 일시정지한 시간은 빼고 실제로 캡처한 시간만 셈
+        let minutes = ((now - session.started_at).num_milliseconds() - session.paused_ms) / 60_000;
+        if minutes >= limit_minutes as i64 && notified.insert(session.id.clone()) {
+            log::info!("장시간 캡처 알림: {} ({}분)", session.id, minutes);
+            notify(
+                app,
+                t("notification.long_capture.title"),
+                &tf(
+                    "notification.long_capture.body",
+                    &[("minutes", &minutes.to_string())],
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{
+    AppHandle, Emitter, LogicalPosition, LogicalSize, Manager, State, WebviewUrl,
+    WebviewWindowBuilder,
+};
+
+use crate::captions::{CaptionServer, CaptionsSnapshot};
+use crate::error::TeuImError;
+
+/// 자막 오버레이 창 라벨
+pub const OVERLAY_LABEL: &str = "caption-overlay";
+
+/// 오버레이 창이 불러오는 페이지
+const OVERLAY_URL: &str = "index.html?view=caption-overlay";
+
+/// 화면 아래쪽과 띄우는 간격 (논리 픽셀)
+const BOTTOM_MARGIN: f64 = 80.0;
+
+/// 자막 오버레이 표시 옵션
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CaptionOverlayOptions {
+    /// 창 위치 (논리 픽셀, 생략하면 주 모니터 아래쪽 가운데)
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: f64,
+    pub height: f64,
+    /// 확정 줄을 몇 줄까지 보여줄지 (현재 부분 결과는 별도)
+    pub max_lines: usize,
+    pub font_size: u32,
+    /// 배경 불투명도 (0~1)
+    pub background_opacity: f32,
+    /// 마우스 입력을 아래 창으로 통과시킬지
+    pub click_through: bool,
+}
+
+impl Default for CaptionOverlayOptions {
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: 960.0,
+            height: 160.0,
+            max_lines: 2,
+            font_size: 28,
+            background_opacity: 0.6,
+            click_through: true,
+        }
+    }
+}
+
+/// `caption-overlay-update` 이벤트 페이로드이자 `get_caption_overlay` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptionOverlayState {
+    pub options: CaptionOverlayOptions,
+    #[serde(flatten)]
+    pub captions: CaptionsSnapshot,
+}
+
+/// 열려 있는 자막 오버레이의 옵션 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct CaptionOverlay {
+    options: Mutex<Option<CaptionOverlayOptions>>,
+}
+
+/// 새 자막이 들어왔을 때 오버레이 창에 현재 자막을 보냄
+pub fn refresh(app: &AppHandle) {
+    let Some(options) = app
+        .state::<CaptionOverlay>()
+        .options
+        .lock()
+        .unwrap()
+        .clone()
+    else {
+        return;
+    };
+    let captions = app.state::<CaptionServer>().snapshot(options.max_lines);
+    let _ = app.emit_to(
+        OVERLAY_LABEL,
+        "caption-overlay-update",
+        CaptionOverlayState { options, captions },
+    );
+}
+
+/// 항상 위에 떠 있는 투명 자막 창을 열거나, 이미 열려 있으면 옵션만 갱신
+#[tauri::command]
+pub fn open_caption_overlay(
+    app: AppHandle,
+    overlay: State<'_, CaptionOverlay>,
+    options: Option<CaptionOverlayOptions>,
+) -> Result<(), TeuImError> {
+    let options = options.unwrap_or_default();
+    if options.width <= 0.0 || options.height <= 0.0 {
+        return Err(TeuImError::invalid_argument(
+            "오버레이 크기는 0보다 커야 합니다",
+        ));
+    }
+    if options.max_lines == 0 {
+        return Err(TeuImError::invalid_argument(
+            "max_lines는 1 이상이어야 합니다",
+        ));
+    }
+
+    let url = WebviewUrl::App(OVERLAY_URL.into());
+    let window = match app.get_webview_window(OVERLAY_LABEL) {
+        Some(window) => window,
+        None => WebviewWindowBuilder::new(&app, OVERLAY_LABEL, url)
+            .title(crate::i18n::t("overlay.title"))
+            .inner_size(options.width, options.height)
+            .decorations(false)
+            .transparent(true)
+            .shadow(false)
+            .always_on_top(true)
+            .visible_on_all_workspaces(true)
+            .skip_taskbar(true)
+            .resizable(false)
+            .focused(false)
+            .build()
+            .map_err(|e| format!("자막 오버레이 창 생성 실패: {}", e))?,
+    };
+
+    let (x, y) = match (options.x, options.y) {
+        (Some(x), Some(y)) => (x, y),
+        _ => default_position(&window, options.width, options.height),
+    };
+    window
+        .set_size(LogicalSize::new(options.width, options.height))
+        .and_then(|_| window.set_position(LogicalPosition::new(x, y)))
+        .and_then(|_| window.set_ignore_cursor_events(options.click_through))
+        .map_err(|e| format!("자막 오버레이 창 설정 실패: {}", e))?;
+    let _ = window.show();
+
+    *overlay.options.lock().unwrap() = Some(options);
+    refresh(&app);
+    Ok(())
+}
+
+/// 자막 오버레이 창 닫기
+#[tauri::command]
+pub fn close_caption_overlay(app: AppHandle, overlay: State<'_, CaptionOverlay>) {
+    *overlay.options.lock().unwrap() = None;
+    if let Some(window) = app.get_webview_window(OVERLAY_LABEL) {
+        let _ = window.close();
+    }
+}
+
+/// 오버레이 페이지가 처음 뜰 때 옵션과 현재 자막을 가져감 (열려 있지 않으면 None)
+#[tauri::command]
+pub fn get_caption_overlay(
+    overlay: State<'_, CaptionOverlay>,
+    captions: State<'_, CaptionServer>,
+) -> Option<CaptionOverlayState> {
+    let options = overlay.options.lock().unwrap().clone()?;
+    let captions = captions.snapshot(options.max_lines);
+    Some(CaptionOverlayState { options, captions })
+}
+
+/// 창이 있는 모니터(없으면 주 모니터)의 아래쪽 가운데
+fn default_position(window: &tauri::WebviewWindow, width: f64, height: f64) -> (f64, f64) {
+    let monitor = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .or_else(|| window.primary_monitor().ok().flatten());
+    let Some(monitor) = monitor else {
+        return (0.0, 0.0);
+    };
+
+    let scale = monitor.scale_factor();
+    let origin = monitor.position().to_logical::<f64>(scale);
+    let size = monitor.size().to_logical::<f64>(scale);
+    (
+        origin.x + (size.width - width) / 2.0,
+        origin.y + size.height - height - BOTTOM_MARGIN,
+    )
+}
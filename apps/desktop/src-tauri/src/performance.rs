@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use sysinfo::{Components, System};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::settings::SettingsStore;
+use crate::transcribe::{self, TranscriptionEngine};
+
+/// CPU·전원 상태 확인 간격
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 온도 센서가 임계값을 알려 주지 않을 때 과열로 보는 온도 (°C)
+const DEFAULT_HOT_CELSIUS: f32 = 90.0;
+
+/// 추론 성능 모드
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformanceMode {
+    #[default]
+    Normal,
+    /// 작은 모델과 긴 부분 결과 간격으로 부하를 줄임
+    Saving,
+}
+
+/// 절약 모드로 바꾼 이유
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformanceReason {
+    /// 배터리로 동작 중
+    Battery,
+    /// CPU 온도가 임계값 이상
+    Thermal,
+    /// CPU 사용률이 한도 이상
+    CpuLoad,
+    /// 사용자가 직접 지정
+    Manual,
+}
+
+/// 자동 성능 조절 설정
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PerformanceSettings {
+    /// 배터리·과열·높은 CPU 사용률에서 자동으로 절약 모드 전환
+    pub auto: bool,
+    /// 절약 모드로 바꿀 CPU 사용률 (%)
+    pub cpu_busy_percent: u32,
+    /// 절약 모드에서 쓸 작은 Whisper 모델 (생략하면 모델은 바꾸지 않음)
+    pub saving_model_path: Option<String>,
+    /// 절약 모드의 부분 결과 간격 (ms)
+    pub saving_step_ms: u32,
+}
+
+impl Default for PerformanceSettings {
+    fn default() -> Self {
+        Self {
+            auto: true,
+            cpu_busy_percent: 90,
+            saving_model_path: None,
+            saving_step_ms: 3000,
+        }
+    }
+}
+
+impl PerformanceSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=100).contains(&self.cpu_busy_percent) {
+            return Err(format!(
+                "CPU 사용률 한도는 1~100% 사이여야 합니다: {}",
+                self.cpu_busy_percent
+            ));
+        }
+        if self.saving_step_ms == 0 {
+            return Err("절약 모드 부분 결과 간격은 0보다 커야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 현재 성능 상태 (`performance-mode-changed` 이벤트 페이로드)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PerformanceStatus {
+    pub mode: PerformanceMode,
+    /// 절약 모드인 이유 (보통 모드면 비어 있음)
+    pub reasons: Vec<PerformanceReason>,
+    /// 수동 지정 중인지
+    pub overridden: bool,
+    pub on_battery: bool,
+    /// 전체 CPU 사용률 (%)
+    pub cpu_usage: f32,
+    /// 가장 뜨거운 센서 온도 (°C, 센서가 없으면 생략)
+    pub temperature: Option<f32>,
+}
+
+/// 시스템 상태 측정값
+#[derive(Debug, Clone, Copy, Default)]
+struct Sample {
+    on_battery: bool,
+    cpu_usage: f32,
+    temperature: Option<f32>,
+    /// 센서 중 하나라도 임계값에 가까운지
+    hot: bool,
+}
+
+/// 성능 모드 감시 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct PerformanceMonitor {
+    status: Mutex<PerformanceStatus>,
+    sample: Mutex<Sample>,
+    manual: Mutex<Option<PerformanceMode>>,
+    /// 절약 모드로 바꾸기 전에 쓰던 모델 (보통 모드로 돌아가면 다시 로드)
+    replaced_model: Mutex<Option<PathBuf>>,
+    /// 절약 모드의 부분 결과 간격 (ms, 보통 모드면 0)
+    saving_step_ms: AtomicU32,
+}
+
+/// 절약 모드의 부분 결과 간격 (보통 모드거나 감시 전이면 `None`)
+///
+/// 전사 작업자가 부분 결과를 낼 때마다 확인하므로 실행 중인 전사에도 바로 적용된다.
+pub fn saving_step_ms(app: &AppHandle) -> Option<u32> {
+    let step_ms = app
+        .try_state::<PerformanceMonitor>()?
+        .saving_step_ms
+        .load(Ordering::Relaxed);
+    (step_ms > 0).then_some(step_ms)
+}
+
+/// 시스템 상태 측정기
+struct Probe {
+    system: System,
+    components: Components,
+    batteries: Option<starship_battery::Manager>,
+}
+
+impl Probe {
+    fn new() -> Self {
+        let batteries = match starship_battery::Manager::new() {
+            Ok(manager) => Some(manager),
+            Err(e) => {
+                log::debug!("배터리 상태를 읽을 수 없음: {}", e);
+                None
+            }
+        };
+        Self {
+            system: System::new(),
+            components: Components::new_with_refreshed_list(),
+            batteries,
+        }
+    }
+
+    fn sample(&mut self) -> Sample {
+        self.system.refresh_cpu_usage();
+        self.components.refresh(false);
+
+        let on_battery = self.batteries.as_ref().is_some_and(|manager| {
+            manager.batteries().is_ok_and(|mut batteries| {
+                batteries.any(|battery| {
+                    battery.is_ok_and(|b| b.state() == starship_battery::State::Discharging)
+                })
+            })
+        });
+
+        let mut temperature: Option<f32> = None;
+        let mut hot = false;
+        for component in self.components.list() {
+            let Some(celsius) = component.temperature().filter(|c| c.is_finite()) else {
+                continue;
+            };
+            temperature = Some(temperature.map_or(celsius, |t| t.max(celsius)));
+            let limit = component
+                .critical()
+                .filter(|c| c.is_finite() && *c > 0.0)
+                .map_or(DEFAULT_HOT_CELSIUS, |critical| critical - 5.0);
+            hot |= celsius >= limit;
+        }
+
+        Sample {
+            on_battery,
+            cpu_usage: self.system.global_cpu_usage(),
+            temperature,
+            hot,
+        }
+    }
+}
+
+/// 상태를 갱신하고 모드가 바뀌었으면 `performance-mode-changed`로 알림
+fn update(app: &AppHandle, status: PerformanceStatus) {
+    let monitor = app.state::<PerformanceMonitor>();
+    let settings = app.state::<SettingsStore>().get().performance;
+    let step_ms = match status.mode {
+        PerformanceMode::Saving => settings.saving_step_ms,
+        PerformanceMode::Normal => 0,
+    };
+    monitor.saving_step_ms.store(step_ms, Ordering::Relaxed);
+
+    let changed = {
+        let mut current = monitor.status.lock().unwrap();
+        let changed = current.mode != status.mode || current.reasons != status.reasons;
+        *current = status.clone();
+        changed
+    };
+    if changed {
+        log::info!("성능 모드: {:?} ({:?})", status.mode, status.reasons);
+        let _ = app.emit("performance-mode-changed", &status);
+    }
+    sync_model(app, status.mode);
+}
+
+/// 전사 중이 아니면 모드에 맞는 모델로 교체 (모델을 로드해 둔 경우에만)
+///
+/// 절약 모드에서는 작은 모델로 바꾸고, 보통 모드로 돌아오면 쓰던 모델을 다시 로드한다.
+fn sync_model(app: &AppHandle, mode: PerformanceMode) {
+    let engine = app.state::<TranscriptionEngine>();
+    let Some(loaded) = engine.model_path() else {
+        return;
+    };
+    if engine.is_running() {
+        return;
+    }
+
+    let monitor = app.state::<PerformanceMonitor>();
+    let mut replaced = monitor.replaced_model.lock().unwrap();
+    let target = match mode {
+        PerformanceMode::Saving => {
+            let small = app
+                .state::<SettingsStore>()
+                .get()
+                .performance
+                .saving_model_path
+                .map(PathBuf::from);
+            match small {
+                Some(small) if replaced.is_none() && small != loaded => {
+                    *replaced = Some(loaded);
+                    small
+                }
+                _ => return,
+            }
+        }
+        PerformanceMode::Normal => match replaced.take() {
+            Some(original) => original,
+            None => return,
+        },
+    };
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        log::info!("성능 모드에 맞춰 모델 교체: {}", target.display());
+        let result = transcribe::load_model(
+            app.state::<TranscriptionEngine>(),
+            app.state::<SettingsStore>(),
+            target.to_string_lossy().into_owned(),
+        )
+        .await;
+        if let Err(e) = result {
+            log::warn!("모델 교체 실패: {}", e);
+        }
+    });
+}
+
+/// 측정값과 설정으로 모드 결정
+fn evaluate(app: &AppHandle, sample: Sample) -> PerformanceStatus {
+    let settings = app.state::<SettingsStore>().get().performance;
+    let manual = *app.state::<PerformanceMonitor>().manual.lock().unwrap();
+
+    let mut reasons = Vec::new();
+    let mode = match manual {
+        Some(mode) => {
+            if mode == PerformanceMode::Saving {
+                reasons.push(PerformanceReason::Manual);
+            }
+            mode
+        }
+        None if settings.auto => {
+            if sample.on_battery {
+                reasons.push(PerformanceReason::Battery);
+            }
+            if sample.hot {
+                reasons.push(PerformanceReason::Thermal);
+            }
+            if sample.cpu_usage >= settings.cpu_busy_percent as f32 {
+                reasons.push(PerformanceReason::CpuLoad);
+            }
+            if reasons.is_empty() {
+                PerformanceMode::Normal
+            } else {
+                PerformanceMode::Saving
+            }
+        }
+        None => PerformanceMode::Normal,
+    };
+
+    PerformanceStatus {
+        mode,
+        reasons,
+        overridden: manual.is_some(),
+        on_battery: sample.on_battery,
+        cpu_usage: sample.cpu_usage,
+        temperature: sample.temperature,
+    }
+}
+
+/// 백그라운드에서 CPU 사용률·전원·온도를 주기적으로 확인
+pub fn spawn_watcher(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut probe = Probe::new();
+        // CPU 사용률은 두 번 측정한 차이로 계산
+        probe.sample();
+        loop {
+            std::thread::sleep(CHECK_INTERVAL);
+            let sample = probe.sample();
+            *app.state::<PerformanceMonitor>().sample.lock().unwrap() = sample;
+            update(&app, evaluate(&app, sample));
+        }
+    });
+}
+
+/// 현재 성능 상태 조회
+#[tauri::command]
+pub fn get_performance_status(monitor: State<'_, PerformanceMonitor>) -> PerformanceStatus {
+    monitor.status.lock().unwrap().clone()
+}
+
+/// 성능 모드 직접 지정 (`None`이면 자동 조절로 돌아감)
+#[tauri::command]
+pub fn set_performance_mode(
+    app: AppHandle,
+    monitor: State<'_, PerformanceMonitor>,
+    mode: Option<PerformanceMode>,
+) -> PerformanceStatus {
+    *monitor.manual.lock().unwrap() = mode;
+    let sample = *monitor.sample.lock().unwrap();
+    let status = evaluate(&app, sample);
+    update(&app, status.clone());
+    status
+}
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::hotkeys::{self, HotkeyAction};
+use crate::playback::Player;
+use crate::settings::SettingsStore;
+
+/// HID 장치 읽기 (hidapi)
+mod hid;
+
+use hid::HidDevice;
+
+/// 새로 연결된 페달을 찾는 간격
+const SCAN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// 되감기 한 번에 돌아가는 길이 (ms)
+const REWIND_MS: i64 = 5_000;
+
+/// 보고서 하나에서 읽는 최대 버튼 수 (앞 8바이트)
+const MAX_BUTTONS: u8 = 64;
+
+/// 제조사·제품 ID로 아는 전사용 페달
+const KNOWN_PEDALS: &[(u16, u16)] = &[
+    // VEC Infinity IN-USB-1/2/3
+    (0x05F3, 0x00FF),
+];
+
+/// 장치 이름으로 페달을 알아보는 문자열 (소문자)
+const PEDAL_NAME_HINTS: &[&str] = &[
+    "footpedal",
+    "foot pedal",
+    "foot switch",
+    "footswitch",
+    "foot control",
+];
+
+/// 페달 버튼으로 실행할 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PedalAction {
+    /// 녹음 재생·일시정지
+    PlayPause,
+    /// 재생 위치 5초 되감기
+    Rewind,
+    /// 캡처 시작·중지 (단축키와 같은 `hotkey-triggered` 이벤트)
+    ToggleCapture,
+    /// 누르고 있는 동안 마이크 캡처 (눌러서 말하기)
+    PushToTalk,
+}
+
+/// 페달 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PedalSettings {
+    /// 버튼 번호(1부터) → 동작
+    pub bindings: BTreeMap<u8, PedalAction>,
+}
+
+impl Default for PedalSettings {
+    /// 흔한 3버튼 페달 배치 (왼쪽 되감기, 가운데 재생, 오른쪽 캡처)
+    fn default() -> Self {
+        Self {
+            bindings: BTreeMap::from([
+                (1, PedalAction::Rewind),
+                (2, PedalAction::PlayPause),
+                (3, PedalAction::ToggleCapture),
+            ]),
+        }
+    }
+}
+
+impl PedalSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        match self
+            .bindings
+            .keys()
+            .find(|b| !(1..=MAX_BUTTONS).contains(*b))
+        {
+            Some(button) => Err(format!(
+                "페달 버튼 번호는 1~{} 사이여야 합니다: {}",
+                MAX_BUTTONS, button
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// 눌러서 말하기에 지정한 버튼이 있는지
+    pub fn has_push_to_talk(&self) -> bool {
+        self.bindings
+            .values()
+            .any(|a| *a == PedalAction::PushToTalk)
+    }
+}
+
+/// 연결된 페달 (`pedal-connected`·`pedal-disconnected` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct FootPedal {
+    pub path: String,
+    pub name: String,
+    pub vendor_id: u16,
+    pub product_id: u16,
+}
+
+/// `pedal-pressed` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct PedalPressed {
+    pub button: u8,
+    /// 버튼에 지정된 동작 (없으면 `None`, 지정 화면에서 버튼 번호 확인용)
+    pub action: Option<PedalAction>,
+}
+
+/// 연결된 페달 목록 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Peripherals {
+    connected: Mutex<HashMap<String, FootPedal>>,
+}
+
+fn is_pedal(device: &HidDevice) -> bool {
+    let name = device.name.to_lowercase();
+    KNOWN_PEDALS.contains(&(device.vendor_id, device.product_id))
+        || PEDAL_NAME_HINTS.iter().any(|hint| name.contains(hint))
+}
+
+/// 페달 연결을 감시하고 연결된 페달마다 버튼을 읽는 스레드를 띄움
+pub fn spawn_watcher(app: AppHandle) {
+    std::thread::spawn(move || {
+        // 권한이 없어 열 수 없는 장치는 한 번만 알림
+        let mut failed = HashSet::new();
+        let mut listed = true;
+        loop {
+            let devices = match hid::devices() {
+                Ok(devices) => {
+                    listed = true;
+                    devices
+                }
+                Err(e) => {
+                    if std::mem::take(&mut listed) {
+                        log::warn!("HID 장치 목록을 읽을 수 없습니다: {}", e);
+                    }
+                    Vec::new()
+                }
+            };
+            for device in devices.into_iter().filter(is_pedal) {
+                let path = device.path_name();
+                let peripherals = app.state::<Peripherals>();
+                if peripherals.connected.lock().unwrap().contains_key(&path) {
+                    continue;
+                }
+                let reader = match hid::open(&device) {
+                    Ok(reader) => reader,
+                    Err(e) => {
+                        if failed.insert(path.clone()) {
+                            log::warn!("페달을 열 수 없습니다 ({}, {}): {}", device.name, path, e);
+                        }
+                        continue;
+                    }
+                };
+                failed.remove(&path);
+
+                let pedal = FootPedal {
+                    path: path.clone(),
+                    name: device.name.clone(),
+                    vendor_id: device.vendor_id,
+                    product_id: device.product_id,
+                };
+                log::info!("페달 연결됨: {} ({})", pedal.name, pedal.path);
+                peripherals
+                    .connected
+                    .lock()
+                    .unwrap()
+                    .insert(path.clone(), pedal.clone());
+                refresh(&app);
+                let _ = app.emit("pedal-connected", &pedal);
+
+                let app = app.clone();
+                std::thread::spawn(move || read_pedal(app, path, pedal, reader));
+            }
+            std::thread::sleep(SCAN_INTERVAL);
+        }
+    });
+}
+
+/// 페달이 분리될 때까지 입력 보고서를 읽어 버튼 변화를 처리
+fn read_pedal(app: AppHandle, path: String, pedal: FootPedal, reader: hidapi::HidDevice) {
+    let mut buffer = [0u8; 64];
+    let mut held = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) => {
+                log::debug!("페달 읽기 끝 ({}): {}", pedal.path, e);
+                break;
+            }
+        };
+        // 보고서의 각 비트가 버튼 하나 (첫 바이트 최하위 비트가 1번)
+        let buttons = buffer[..read.min(8)]
+            .iter()
+            .enumerate()
+            .fold(0u64, |bits, (i, byte)| bits | u64::from(*byte) << (i * 8));
+        handle_buttons(&app, held, buttons);
+        held = buttons;
+    }
+
+    // 누른 채 분리되면 뗀 것으로 처리
+    handle_buttons(&app, held, 0);
+    app.state::<Peripherals>()
+        .connected
+        .lock()
+        .unwrap()
+        .remove(&path);
+    refresh(&app);
+    log::info!("페달 분리됨: {} ({})", pedal.name, pedal.path);
+    let _ = app.emit("pedal-disconnected", &pedal);
+}
+
+fn handle_buttons(app: &AppHandle, previous: u64, current: u64) {
+    let changed = previous ^ current;
+    if changed == 0 {
+        return;
+    }
+    let bindings = app.state::<SettingsStore>().get().pedal.bindings;
+    for bit in 0..MAX_BUTTONS {
+        if changed & (1 << bit) == 0 {
+            continue;
+        }
+        let button = bit + 1;
+        let pressed = current & (1 << bit) != 0;
+        let action = bindings.get(&button).copied();
+        if pressed {
+            let _ = app.emit("pedal-pressed", PedalPressed { button, action });
+        }
+        match action {
+            Some(PedalAction::PushToTalk) => crate::ptt::set_pedal_held(app, pressed),
+            Some(action) if pressed => run(app, action),
+            _ => {}
+        }
+    }
+}
+
+fn run(app: &AppHandle, action: PedalAction) {
+    log::debug!("페달 동작 실행: {:?}", action);
+    let player = app.state::<Player>();
+    let result = match action {
+        PedalAction::PlayPause => player.toggle_pause(),
+        PedalAction::Rewind => player.rewind(REWIND_MS),
+        PedalAction::ToggleCapture => {
+            hotkeys::trigger(app, HotkeyAction::ToggleCapture);
+            Ok(())
+        }
+        PedalAction::PushToTalk => Ok(()),
+    };
+    // 재생 중인 세션이 없을 때 누른 재생 버튼은 무시
+    if let Err(e) = result {
+        log::debug!("페달 동작 무시 ({:?}): {}", action, e);
+    }
+}
+
+/// 페달 연결이나 버튼 지정이 바뀌면 눌러서 말하기에 페달을 쓸 수 있는지 다시 알림
+pub fn refresh(app: &AppHandle) {
+    let connected = !app
+        .state::<Peripherals>()
+        .connected
+        .lock()
+        .unwrap()
+        .is_empty();
+    let bound = app.state::<SettingsStore>().get().pedal.has_push_to_talk();
+    crate::ptt::set_pedal_ready(app, connected && bound);
+}
+
+/// 연결된 페달 목록
+#[tauri::command]
+pub fn list_foot_pedals(peripherals: State<'_, Peripherals>) -> Vec<FootPedal> {
+    let mut pedals: Vec<_> = peripherals
+        .connected
+        .lock()
+        .unwrap()
+        .values()
+        .cloned()
+        .collect();
+    pedals.sort_by(|a, b| a.path.cmp(&b.path));
+    pedals
+}
+
+/// 페달 버튼에 동작 지정 (`action`이 없으면 지정 해제)
+#[tauri::command]
+pub fn set_pedal_binding(
+    app: AppHandle,
+    settings: State<'_, SettingsStore>,
+    button: u8,
+    action: Option<PedalAction>,
+) -> Result<(), String> {
+    if !(1..=MAX_BUTTONS).contains(&button) {
+        return Err(format!(
+            "페달 버튼 번호는 1~{} 사이여야 합니다: {}",
+            MAX_BUTTONS, button
+        ));
+    }
+    let updated = settings.modify(|s| match action {
+        Some(action) => {
+            s.pedal.bindings.insert(button, action);
+        }
+        None => {
+            s.pedal.bindings.remove(&button);
+        }
+    })?;
+    refresh(&app);
+    let _ = app.emit("settings-changed", &updated);
+
+    log::info!("페달 버튼 지정: {} = {:?}", button, action);
+    Ok(())
+}
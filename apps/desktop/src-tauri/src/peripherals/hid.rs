@@ -0,0 +1,38 @@
+use hidapi::{HidApi, HidError, HidResult};
+use std::ffi::CString;
+
+/// 연결된 HID 장치
+#[derive(Debug, Clone)]
+pub struct HidDevice {
+    /// 보고서를 읽을 장치 경로 (플랫폼마다 형식이 다름)
+    pub path: CString,
+    pub name: String,
+    pub vendor_id: u16,
+    pub product_id: u16,
+}
+
+impl HidDevice {
+    /// 로그·화면 표시용 장치 경로
+    pub fn path_name(&self) -> String {
+        self.path.to_string_lossy().into_owned()
+    }
+}
+
+/// 연결된 HID 장치 목록 (hidapi로 찾아 모든 플랫폼에서 같게 동작)
+pub fn devices() -> HidResult<Vec<HidDevice>> {
+    let api = HidApi::new()?;
+    Ok(api
+        .device_list()
+        .map(|info| HidDevice {
+            path: info.path().to_owned(),
+            name: info.product_string().unwrap_or_default().to_string(),
+            vendor_id: info.vendor_id(),
+            product_id: info.product_id(),
+        })
+        .collect())
+}
+
+/// 입력 보고서를 읽을 수 있게 장치 열기 (읽기는 보고서 하나씩 막힘)
+pub fn open(device: &HidDevice) -> Result<hidapi::HidDevice, HidError> {
+    HidApi::new()?.open_path(&device.path)
+}
@@ -0,0 +1,618 @@
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SampleFormat, SizedSample, Stream, StreamConfig};
+use hound::WavReader;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::{CaptureManager, CaptureSource, MonoResampler, ReplayClip, REPLAY_SECS_RANGE};
+use crate::encryption::{self, RecordingReader};
+use crate::error::TeuImError;
+use crate::i18n::{t, tf};
+use crate::storage::Storage;
+use crate::transcribe::{self, TranscriptSegment, TranscriptionOptions, WHISPER_SAMPLE_RATE};
+
+/// `playback-position` 이벤트 간격
+const POSITION_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 출력 버퍼에 미리 채워 두는 분량 (ms)
+const BUFFER_AHEAD_MS: usize = 500;
+
+/// 파일에서 한 번에 읽는 프레임 수
+const READ_FRAMES: usize = 4096;
+
+/// `replay_last` 기본 길이 (초)
+const DEFAULT_REPLAY_SECS: u32 = 10;
+
+/// `playback-position` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct PlaybackPosition {
+    pub session_id: String,
+    pub position_ms: i64,
+    pub duration_ms: i64,
+    pub playing: bool,
+}
+
+/// `replay_last` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayResult {
+    /// 다시 들려준 캡처 세션
+    pub session_id: String,
+    /// 캡처 세션 기준 구간 시작 (ms, 일시정지 구간 제외)
+    pub start_ms: i64,
+    pub duration_ms: i64,
+    /// 다른 모델로 다시 전사한 구간 (`model`을 줬을 때만, 시간은 캡처 세션 기준)
+    pub segments: Option<Vec<TranscriptSegment>>,
+}
+
+/// 재생 스레드와 출력 콜백이 공유하는 상태
+#[derive(Default)]
+struct Shared {
+    /// 출력 레이트로 변환된 모노 샘플
+    buffer: VecDeque<f32>,
+    paused: bool,
+    stopped: bool,
+    seek_to: Option<i64>,
+    /// 마지막 탐색 위치 (ms)
+    base_ms: i64,
+    /// 마지막 탐색 이후 출력한 프레임 수
+    played_frames: u64,
+    /// 재생 스레드가 마지막으로 계산한 위치 (ms)
+    position_ms: i64,
+}
+
+struct ActivePlayback {
+    session_id: String,
+    shared: Arc<Mutex<Shared>>,
+    thread: JoinHandle<()>,
+}
+
+/// 녹음 재생기 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Player {
+    active: Mutex<Option<ActivePlayback>>,
+}
+
+impl Player {
+    fn stop(&self) -> bool {
+        let Some(active) = self.active.lock().unwrap().take() else {
+            return false;
+        };
+        active.shared.lock().unwrap().stopped = true;
+        if active.thread.join().is_err() {
+            log::error!("재생 스레드가 비정상 종료됨");
+        }
+        true
+    }
+
+    /// 재생 스레드를 띄우고 출력 스트림이 열릴 때까지 대기
+    fn launch<F>(
+        &self,
+        session_id: String,
+        shared: Arc<Mutex<Shared>>,
+        run: F,
+    ) -> Result<(), String>
+    where
+        F: FnOnce(Arc<Mutex<Shared>>, SyncSender<Result<(), String>>) + Send + 'static,
+    {
+        let (ready_tx, ready_rx) = mpsc::sync_channel(1);
+        let thread = {
+            let shared = shared.clone();
+            thread::spawn(move || run(shared, ready_tx))
+        };
+        match ready_rx.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                let _ = thread.join();
+                return Err(e);
+            }
+            Err(_) => return Err("재생 스레드가 시작 전에 종료됨".to_string()),
+        }
+
+        log::info!("재생 시작: {}", session_id);
+        *self.active.lock().unwrap() = Some(ActivePlayback {
+            session_id,
+            shared,
+            thread,
+        });
+        Ok(())
+    }
+
+    /// 메모리에 있는 오디오 구간 재생 (재생 중이던 것은 멈춤)
+    ///
+    /// 위치 이벤트와 일시정지·탐색·되감기는 녹음 재생과 같이 동작한다.
+    fn play_clip(
+        &self,
+        app: AppHandle,
+        session_id: String,
+        clip: ReplayClip,
+    ) -> Result<(), String> {
+        self.stop();
+        let shared = Arc::new(Mutex::new(Shared {
+            seek_to: Some(0),
+            ..Default::default()
+        }));
+        let label = session_id.clone();
+        self.launch(session_id, shared, move |shared, ready_tx| {
+            run_clip(app, label, clip, shared, ready_tx)
+        })
+    }
+
+    /// 재생 중인 세션 상태 변경
+    fn update(&self, f: impl FnOnce(&mut Shared)) -> Result<(), String> {
+        let active = self.active.lock().unwrap();
+        let active = active
+            .as_ref()
+            .filter(|a| !a.thread.is_finished())
+            .ok_or(t("error.not_playing"))?;
+        f(&mut active.shared.lock().unwrap());
+        Ok(())
+    }
+
+    /// 재생 중이면 일시정지, 일시정지 중이면 이어서 재생
+    pub fn toggle_pause(&self) -> Result<(), String> {
+        self.update(|shared| shared.paused = !shared.paused)
+    }
+
+    /// 지금 위치에서 `ms`만큼 뒤로 이동
+    pub fn rewind(&self, ms: i64) -> Result<(), String> {
+        self.update(|shared| {
+            let position = shared.seek_to.unwrap_or(shared.position_ms);
+            shared.seek_to = Some((position - ms).max(0));
+        })
+    }
+}
+
+/// 세션 녹음 재생 (`position`은 ms)
+///
+/// 이미 열려 있는 세션이면 일시정지를 풀고, `position`이 있으면 그 위치로 이동한다.
+#[tauri::command]
+pub fn play_session(
+    app: AppHandle,
+    player: State<'_, Player>,
+    storage: State<'_, Storage>,
+    session_id: String,
+    position: Option<i64>,
+) -> Result<(), String> {
+    let resumed = {
+        let active = player.active.lock().unwrap();
+        match active.as_ref() {
+            Some(a) if a.session_id == session_id && !a.thread.is_finished() => {
+                let mut shared = a.shared.lock().unwrap();
+                shared.paused = false;
+                if position.is_some() {
+                    shared.seek_to = position;
+                }
+                true
+            }
+            _ => false,
+        }
+    };
+    if resumed {
+        return Ok(());
+    }
+
+    player.stop();
+    let path = storage
+        .audio_path(&session_id)?
+        .ok_or(t("error.no_recording"))?;
+
+    let shared = Arc::new(Mutex::new(Shared {
+        seek_to: Some(position.unwrap_or(0)),
+        ..Default::default()
+    }));
+    let label = session_id.clone();
+    player.launch(session_id, shared, move |shared, ready_tx| {
+        run_playback(app, label, path, shared, ready_tx)
+    })
+}
+
+/// 재생 일시정지
+#[tauri::command]
+pub fn pause_playback(player: State<'_, Player>) -> Result<(), String> {
+    player.update(|shared| shared.paused = true)
+}
+
+/// 재생 위치 이동 (ms)
+#[tauri::command]
+pub fn seek(player: State<'_, Player>, position: i64) -> Result<(), String> {
+    player.update(|shared| shared.seek_to = Some(position))
+}
+
+/// 재생 중지
+#[tauri::command]
+pub fn stop_playback(player: State<'_, Player>) {
+    if player.stop() {
+        log::info!("재생 중지됨");
+    }
+}
+
+/// 캡처 중인 세션의 최근 오디오를 다시 재생 (`seconds`초, 기본 10초)
+///
+/// 통역사가 숫자나 이름을 놓쳤을 때 쓴다. `session_id`를 생략하면 실행 중인
+/// 마이크 캡처 세션을 쓴다. `model`(모델 목록의 이름이나 파일 경로)을 주면 같은
+/// 구간을 그 모델로 다시 전사해 함께 반환한다. 재생은 녹음 재생과 같은 재생기를
+/// 쓰므로 `pause_playback`·`stop_playback`으로 멈출 수 있다.
+#[tauri::command]
+pub async fn replay_last(
+    app: AppHandle,
+    player: State<'_, Player>,
+    manager: State<'_, CaptureManager>,
+    seconds: Option<u32>,
+    session_id: Option<String>,
+    model: Option<String>,
+    options: Option<TranscriptionOptions>,
+) -> Result<ReplayResult, TeuImError> {
+    let seconds = seconds.unwrap_or(DEFAULT_REPLAY_SECS);
+    if !REPLAY_SECS_RANGE.contains(&seconds) {
+        return Err(TeuImError::invalid_argument(tf(
+            "error.replay_seconds",
+            &[
+                ("min", &REPLAY_SECS_RANGE.start().to_string()),
+                ("max", &REPLAY_SECS_RANGE.end().to_string()),
+                ("seconds", &seconds.to_string()),
+            ],
+        )));
+    }
+
+    let sessions = manager.list();
+    let session = match session_id {
+        Some(id) => sessions.into_iter().find(|s| s.id == id),
+        None => sessions
+            .into_iter()
+            .find(|s| s.source == CaptureSource::Microphone),
+    }
+    .ok_or(t("error.no_capture_to_replay"))?;
+    let clip = manager
+        .replay()
+        .last(&session.id, seconds)
+        .ok_or(t("error.no_replay_audio"))?;
+
+    let mut result = ReplayResult {
+        session_id: session.id.clone(),
+        start_ms: clip.start_ms,
+        duration_ms: clip.duration_ms(),
+        segments: None,
+    };
+    player.play_clip(app.clone(), session.id, clip.clone())?;
+    log::info!(
+        "다시 듣기: {} ({}ms부터 {}ms)",
+        result.session_id,
+        result.start_ms,
+        result.duration_ms
+    );
+
+    if let Some(model) = model {
+        let options = options.unwrap_or_default();
+        let segments = tauri::async_runtime::spawn_blocking(move || {
+            retranscribe(&app, &model, &clip, options)
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+        result.segments = Some(segments);
+    }
+    Ok(result)
+}
+
+/// 구간을 `model`로 다시 전사 (블로킹, 시간은 캡처 세션 기준으로 옮김)
+fn retranscribe(
+    app: &AppHandle,
+    model: &str,
+    clip: &ReplayClip,
+    options: TranscriptionOptions,
+) -> Result<Vec<TranscriptSegment>, TeuImError> {
+    let (context, threads) = transcribe::open_model(app, model)?;
+    let options = TranscriptionOptions {
+        glossary: crate::glossary::hints(&app.state::<Storage>()),
+        threads: options.threads.or(Some(threads)),
+        ..options
+    };
+    let audio = MonoResampler::new(clip.sample_rate, WHISPER_SAMPLE_RATE)
+        .map_err(TeuImError::invalid_argument)?
+        .process(&clip.samples);
+
+    let cancel = AtomicBool::new(false);
+    let mut segments = Vec::new();
+    transcribe::transcribe_buffer(
+        &context,
+        &options,
+        &audio,
+        &cancel,
+        |segment| {
+            segments.push(TranscriptSegment {
+                start_ms: segment.start_ms + clip.start_ms,
+                end_ms: segment.end_ms + clip.start_ms,
+                words: segment
+                    .words
+                    .iter()
+                    .map(|word| word.shifted(clip.start_ms))
+                    .collect(),
+                ..segment
+            })
+        },
+        |_| {},
+    )?;
+    log::info!("다시 듣기 구간 재전사 완료: 구간 {}개", segments.len());
+    Ok(segments)
+}
+
+type Reader = WavReader<Box<dyn RecordingReader>>;
+
+/// 재생 스레드
+///
+/// 출력 스트림을 연 뒤 파일을 조금씩 읽어 버퍼를 채우고 위치 이벤트를 보낸다.
+/// 스트림은 이 스레드를 벗어날 수 없어(`!Send`) 여기서 소유한다.
+fn run_playback(
+    app: AppHandle,
+    session_id: String,
+    path: String,
+    shared: Arc<Mutex<Shared>>,
+    ready_tx: SyncSender<Result<(), String>>,
+) {
+    let opened = open_output(&app, &path, shared.clone());
+    let (mut reader, _stream, out_rate) = match opened {
+        Ok(opened) => {
+            let _ = ready_tx.send(Ok(()));
+            opened
+        }
+        Err(e) => {
+            let _ = ready_tx.send(Err(e));
+            return;
+        }
+    };
+
+    let spec = reader.spec();
+    let in_rate = spec.sample_rate;
+    let duration_ms = reader.duration() as i64 * 1000 / in_rate as i64;
+    let ahead = BUFFER_AHEAD_MS * out_rate as usize / 1000;
+
+    let mut resampler = None;
+    let mut eof = false;
+    let mut last_emit: Option<Instant> = None;
+
+    loop {
+        let (needs_data, position_ms, playing, finished) = {
+            let mut s = shared.lock().unwrap();
+            if s.stopped {
+                break;
+            }
+
+            if let Some(ms) = s.seek_to.take() {
+                let ms = ms.clamp(0, duration_ms);
+                let frame = (ms * in_rate as i64 / 1000) as u32;
+                if let Err(e) = reader.seek(frame) {
+                    log::error!("재생 위치 이동 실패: {}", e);
+                }
+                s.buffer.clear();
+                s.base_ms = ms;
+                s.played_frames = 0;
+                resampler = None;
+                eof = false;
+            }
+
+            let position_ms = s.base_ms + (s.played_frames * 1000 / out_rate as u64) as i64;
+            s.position_ms = position_ms.min(duration_ms);
+            let finished = eof && s.buffer.is_empty();
+            (
+                s.buffer.len() < ahead && !eof,
+                position_ms.min(duration_ms),
+                !s.paused && !finished,
+                finished,
+            )
+        };
+
+        if needs_data {
+            let samples = read_block(&mut reader);
+            if samples.is_empty() {
+                eof = true;
+            } else {
+                // 탐색하면 리샘플러 내부 버퍼도 버려야 해서 새로 만듦
+                if resampler.is_none() {
+                    match MonoResampler::new(in_rate, out_rate) {
+                        Ok(r) => resampler = Some(r),
+                        Err(e) => {
+                            log::error!("{}", e);
+                            break;
+                        }
+                    }
+                }
+                let converted = resampler
+                    .as_mut()
+                    .map(|r| r.process(&samples))
+                    .unwrap_or_default();
+                shared.lock().unwrap().buffer.extend(converted);
+            }
+            continue;
+        }
+
+        if finished || last_emit.is_none_or(|t| t.elapsed() >= POSITION_INTERVAL) {
+            last_emit = Some(Instant::now());
+            let _ = app.emit(
+                "playback-position",
+                PlaybackPosition {
+                    session_id: session_id.clone(),
+                    position_ms,
+                    duration_ms,
+                    playing,
+                },
+            );
+        }
+
+        if finished {
+            log::info!("재생 끝: {}", session_id);
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// 메모리 구간 재생 스레드 (구간 전체를 한 번에 출력 레이트로 변환해 둠)
+fn run_clip(
+    app: AppHandle,
+    session_id: String,
+    clip: ReplayClip,
+    shared: Arc<Mutex<Shared>>,
+    ready_tx: SyncSender<Result<(), String>>,
+) {
+    let (_stream, out_rate) = match open_device(shared.clone()) {
+        Ok(opened) => {
+            let _ = ready_tx.send(Ok(()));
+            opened
+        }
+        Err(e) => {
+            let _ = ready_tx.send(Err(e));
+            return;
+        }
+    };
+    let samples = match MonoResampler::new(clip.sample_rate, out_rate) {
+        Ok(mut resampler) => resampler.process(&clip.samples),
+        Err(e) => {
+            log::error!("{}", e);
+            return;
+        }
+    };
+    let duration_ms = clip.duration_ms();
+    let mut last_emit: Option<Instant> = None;
+
+    loop {
+        let (position_ms, playing, finished) = {
+            let mut s = shared.lock().unwrap();
+            if s.stopped {
+                break;
+            }
+
+            if let Some(ms) = s.seek_to.take() {
+                let ms = ms.clamp(0, duration_ms);
+                let start = (ms as usize * out_rate as usize / 1000).min(samples.len());
+                s.buffer = samples[start..].iter().copied().collect();
+                s.base_ms = ms;
+                s.played_frames = 0;
+            }
+
+            let position_ms = s.base_ms + (s.played_frames * 1000 / out_rate as u64) as i64;
+            s.position_ms = position_ms.min(duration_ms);
+            let finished = s.buffer.is_empty();
+            (
+                position_ms.min(duration_ms),
+                !s.paused && !finished,
+                finished,
+            )
+        };
+
+        if finished || last_emit.is_none_or(|t| t.elapsed() >= POSITION_INTERVAL) {
+            last_emit = Some(Instant::now());
+            let _ = app.emit(
+                "playback-position",
+                PlaybackPosition {
+                    session_id: session_id.clone(),
+                    position_ms,
+                    duration_ms,
+                    playing,
+                },
+            );
+        }
+
+        if finished {
+            log::info!("다시 듣기 끝: {}", session_id);
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// WAV 파일(암호화했으면 복호화하며 읽기)과 기본 출력 장치 스트림 열기
+fn open_output(
+    app: &AppHandle,
+    path: &str,
+    shared: Arc<Mutex<Shared>>,
+) -> Result<(Reader, Stream, u32), String> {
+    let reader = WavReader::new(encryption::open_recording(app, path)?)
+        .map_err(|e| format!("녹음 파일 열기 실패: {}", e))?;
+    let (stream, out_rate) = open_device(shared)?;
+    Ok((reader, stream, out_rate))
+}
+
+/// 기본 출력 장치 스트림을 열고 재생 시작
+fn open_device(shared: Arc<Mutex<Shared>>) -> Result<(Stream, u32), String> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or("기본 출력 장치를 찾을 수 없습니다")?;
+    let config = device
+        .default_output_config()
+        .map_err(|e| format!("출력 장치 설정 조회 실패: {}", e))?;
+    let out_rate = config.sample_rate().0;
+    let stream_config: StreamConfig = config.clone().into();
+
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_output_stream::<f32>(&device, &stream_config, shared),
+        SampleFormat::I16 => build_output_stream::<i16>(&device, &stream_config, shared),
+        SampleFormat::U16 => build_output_stream::<u16>(&device, &stream_config, shared),
+        format => Err(format!("지원하지 않는 출력 형식: {:?}", format)),
+    }?;
+    stream
+        .play()
+        .map_err(|e| format!("재생 시작 실패: {}", e))?;
+
+    Ok((stream, out_rate))
+}
+
+fn build_output_stream<T>(
+    device: &cpal::Device,
+    config: &StreamConfig,
+    shared: Arc<Mutex<Shared>>,
+) -> Result<Stream, String>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    device
+        .build_output_stream(
+            config,
+            move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+                let mut s = shared.lock().unwrap();
+                // 일시정지나 탐색 대기 중에는 무음
+                let silent = s.paused || s.seek_to.is_some();
+                for frame in data.chunks_mut(channels) {
+                    let sample = if silent { None } else { s.buffer.pop_front() };
+                    if sample.is_some() {
+                        s.played_frames += 1;
+                    }
+                    frame.fill(T::from_sample(sample.unwrap_or(0.0)));
+                }
+            },
+            |err| log::error!("재생 스트림 에러: {}", err),
+            None,
+        )
+        .map_err(|e| format!("출력 스트림 생성 실패: {}", e))
+}
+
+/// 파일에서 다음 블록을 읽어 첫 채널만 모노 f32로 반환 (끝이면 빈 벡터)
+fn read_block(reader: &mut Reader) -> Vec<f32> {
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let count = READ_FRAMES * channels;
+
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .take(count)
+            .filter_map(Result::ok)
+            .collect(),
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .take(count)
+                .filter_map(Result::ok)
+                .map(|s| s as f32 / scale)
+                .collect()
+        }
+    };
+
+    interleaved.chunks(channels).map(|frame| frame[0]).collect()
+}
@@ -0,0 +1,54 @@
+use keepawake::KeepAwake;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::audio::CaptureManager;
+use crate::settings::SettingsStore;
+use crate::transcribe::TranscriptionEngine;
+
+/// 캡처·전사 중 절전 방지 (Tauri 관리 상태)
+///
+/// 잡고 있는 동안 화면 꺼짐과 유휴 절전을 막고, 놓으면(drop) 운영체제 설정으로 돌아간다.
+#[derive(Default)]
+pub struct PowerGuard {
+    awake: Mutex<Option<KeepAwake>>,
+}
+
+/// 캡처/전사 상태나 설정이 바뀌었을 수 있을 때 호출해 절전 방지를 잡거나 놓음
+pub fn refresh(app: &AppHandle) {
+    let Some(guard) = app.try_state::<PowerGuard>() else {
+        return;
+    };
+
+    let active = !app.state::<CaptureManager>().list().is_empty()
+        || app.state::<TranscriptionEngine>().is_running();
+    let allowed = app
+        .try_state::<SettingsStore>()
+        .is_some_and(|store| store.get().allow_sleep);
+    let wanted = active && !allowed;
+
+    let mut awake = guard.awake.lock().unwrap();
+    if wanted == awake.is_some() {
+        return;
+    }
+    if !wanted {
+        *awake = None;
+        log::info!("절전 방지 해제");
+        return;
+    }
+
+    match keepawake::Builder::default()
+        .display(true)
+        .idle(true)
+        .reason("실시간 캡처·전사 중")
+        .app_name("Teu-Im")
+        .app_reverse_domain("com.teu-im.desktop")
+        .create()
+    {
+        Ok(handle) => {
+            *awake = Some(handle);
+            log::info!("캡처 중 절전 방지 시작");
+        }
+        Err(e) => log::warn!("절전 방지 설정 실패: {}", e),
+    }
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::audio::CaptureManager;
+use crate::peripherals::PedalSettings;
+use crate::settings::SettingsStore;
+
+/// 플랫폼별 키 상태 읽기 (폴링 스레드 하나에서만 열고 씀)
+mod keyboard;
+
+use keyboard::Keyboard;
+
+/// 키 상태 확인 간격 (누르고 떼는 동작이 반영되기까지의 최대 지연)
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// 눌러서 말하기가 꺼져 있거나 키를 읽을 수 없을 때 설정 확인 간격
+const IDLE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 눌러서 말하기에 쓸 수 있는 키
+///
+/// 글자·숫자 키는 누르고 있는 동안 다른 앱에 입력되므로 받지 않는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PttKey {
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    ScrollLock,
+    Pause,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    LeftCtrl,
+    RightCtrl,
+    LeftShift,
+    RightShift,
+    LeftAlt,
+    RightAlt,
+    LeftMeta,
+    RightMeta,
+}
+
+impl PttKey {
+    /// 기능 키 번호 (F1이면 1)
+    fn function_number(self) -> Option<u8> {
+        use PttKey::*;
+        let keys = [
+            F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15, F16, F17, F18, F19,
+            F20, F21, F22, F23, F24,
+        ];
+        keys.iter()
+            .position(|key| *key == self)
+            .map(|index| index as u8 + 1)
+    }
+}
+
+/// 눌러서 말하기 설정
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PushToTalkSettings {
+    /// 켜면 마이크 캡처는 키(또는 페달 버튼)를 누르고 있는 동안만 스트리밍·전사
+    pub enabled: bool,
+    /// 생략하면 페달 버튼으로만 누름
+    pub key: Option<PttKey>,
+}
+
+impl PushToTalkSettings {
+    pub fn validate(&self, pedal: &PedalSettings) -> Result<(), String> {
+        if self.enabled && self.key.is_none() && !pedal.has_push_to_talk() {
+            return Err("눌러서 말하기 키나 페달 버튼을 지정해야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// `push-to-talk` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct PushToTalkState {
+    /// 키나 페달 버튼을 누르고 있음 (마이크 입력이 흐름)
+    pub held: bool,
+}
+
+/// 눌러서 말하기 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct PushToTalk {
+    settings: Mutex<PushToTalkSettings>,
+    /// 눌러서 말하기에 지정한 페달 버튼을 누르고 있음
+    pedal_held: AtomicBool,
+    /// 눌러서 말하기에 버튼을 지정한 페달이 연결돼 있음
+    pedal_ready: AtomicBool,
+}
+
+/// 설정이 바뀌면 호출 (끄면 마이크 입력을 바로 다시 흘려보냄)
+pub fn apply(app: &AppHandle, settings: &PushToTalkSettings) {
+    *app.state::<PushToTalk>().settings.lock().unwrap() = *settings;
+    if !settings.enabled {
+        app.state::<CaptureManager>()
+            .push_to_talk()
+            .store(false, Ordering::Relaxed);
+    }
+}
+
+/// 페달 버튼을 누르거나 뗌
+pub fn set_pedal_held(app: &AppHandle, held: bool) {
+    app.state::<PushToTalk>()
+        .pedal_held
+        .store(held, Ordering::Relaxed);
+}
+
+/// 눌러서 말하기에 페달을 쓸 수 있는지 (없으면 지정한 키로만 판단)
+pub fn set_pedal_ready(app: &AppHandle, ready: bool) {
+    let ptt = app.state::<PushToTalk>();
+    ptt.pedal_ready.store(ready, Ordering::Relaxed);
+    if !ready {
+        ptt.pedal_held.store(false, Ordering::Relaxed);
+    }
+}
+
+/// 지정한 키 상태를 짧은 간격으로 읽어 마이크 캡처를 열고 닫음
+///
+/// 전역 단축키 이벤트는 키 반복·포커스에 따라 늦거나 빠지는 경우가 있어
+/// 키 상태를 직접 확인한다. 키를 읽을 수 없고 페달도 없으면 캡처를 막지 않는다.
+pub fn spawn_poller(app: AppHandle) {
+    apply(&app, &app.state::<SettingsStore>().get().push_to_talk);
+    std::thread::spawn(move || {
+        let released = app.state::<CaptureManager>().push_to_talk();
+        let mut keyboard: Option<Keyboard> = None;
+        let mut unavailable = false;
+        let mut held = None;
+        loop {
+            let ptt = app.state::<PushToTalk>();
+            let settings = *ptt.settings.lock().unwrap();
+            let key = settings.key.filter(|_| !unavailable);
+            let pedal_ready = ptt.pedal_ready.load(Ordering::Relaxed);
+            if !settings.enabled || (key.is_none() && !pedal_ready) {
+                released.store(false, Ordering::Relaxed);
+                held = None;
+                std::thread::sleep(IDLE_INTERVAL);
+                continue;
+            }
+
+            if key.is_some() && keyboard.is_none() {
+                match Keyboard::open() {
+                    Ok(opened) => keyboard = Some(opened),
+                    Err(e) => {
+                        log::warn!("눌러서 말하기 키를 읽을 수 없습니다: {}", e);
+                        unavailable = true;
+                        continue;
+                    }
+                }
+            }
+            let pressed = key
+                .is_some_and(|key| keyboard.as_ref().is_some_and(|k| k.is_pressed(key)))
+                || (pedal_ready && ptt.pedal_held.load(Ordering::Relaxed));
+            released.store(!pressed, Ordering::Relaxed);
+            if held != Some(pressed) {
+                held = Some(pressed);
+                log::debug!("눌러서 말하기: {}", if pressed { "누름" } else { "뗌" });
+                let _ = app.emit("push-to-talk", PushToTalkState { held: pressed });
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
@@ -0,0 +1,208 @@
+use super::PttKey;
+
+#[cfg(target_os = "linux")]
+pub use x11::Keyboard;
+
+#[cfg(target_os = "windows")]
+pub use windows::Keyboard;
+
+#[cfg(target_os = "macos")]
+pub use macos::Keyboard;
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+pub use unsupported::Keyboard;
+
+/// X11 키맵 조회 (Wayland에서는 XWayland를 거치므로 X 앱에 포커스가 있을 때만 보임)
+#[cfg(target_os = "linux")]
+mod x11 {
+    use std::ffi::CString;
+    use std::os::raw::c_char;
+    use std::ptr;
+    use x11_dl::xlib::{Display, Xlib};
+
+    use super::PttKey;
+
+    pub struct Keyboard {
+        xlib: Xlib,
+        display: *mut Display,
+    }
+
+    impl Keyboard {
+        pub fn open() -> Result<Self, String> {
+            let xlib = Xlib::open().map_err(|e| format!("libX11을 열 수 없습니다: {}", e))?;
+            let display = unsafe { (xlib.XOpenDisplay)(ptr::null()) };
+            if display.is_null() {
+                return Err("X 디스플레이에 연결할 수 없습니다".to_string());
+            }
+            Ok(Self { xlib, display })
+        }
+
+        pub fn is_pressed(&self, key: PttKey) -> bool {
+            let Ok(name) = CString::new(keysym_name(key)) else {
+                return false;
+            };
+            let keycode = unsafe {
+                let keysym = (self.xlib.XStringToKeysym)(name.as_ptr());
+                (self.xlib.XKeysymToKeycode)(self.display, keysym)
+            };
+            if keycode == 0 {
+                return false;
+            }
+            // 키코드별 비트 (32바이트 × 8)
+            let mut keys = [0 as c_char; 32];
+            unsafe { (self.xlib.XQueryKeymap)(self.display, keys.as_mut_ptr()) };
+            keys[keycode as usize / 8] as u8 & (1 << (keycode % 8)) != 0
+        }
+    }
+
+    impl Drop for Keyboard {
+        fn drop(&mut self) {
+            unsafe { (self.xlib.XCloseDisplay)(self.display) };
+        }
+    }
+
+    fn keysym_name(key: PttKey) -> String {
+        if let Some(number) = key.function_number() {
+            return format!("F{}", number);
+        }
+        let name = match key {
+            PttKey::ScrollLock => "Scroll_Lock",
+            PttKey::Pause => "Pause",
+            PttKey::Insert => "Insert",
+            PttKey::Home => "Home",
+            PttKey::End => "End",
+            PttKey::PageUp => "Prior",
+            PttKey::PageDown => "Next",
+            PttKey::LeftCtrl => "Control_L",
+            PttKey::RightCtrl => "Control_R",
+            PttKey::LeftShift => "Shift_L",
+            PttKey::RightShift => "Shift_R",
+            PttKey::LeftAlt => "Alt_L",
+            PttKey::RightAlt => "Alt_R",
+            PttKey::LeftMeta => "Super_L",
+            PttKey::RightMeta => "Super_R",
+            _ => "",
+        };
+        name.to_string()
+    }
+}
+
+/// Win32 비동기 키 상태 조회
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::PttKey;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetAsyncKeyState(key: i32) -> i16;
+    }
+
+    pub struct Keyboard;
+
+    impl Keyboard {
+        pub fn open() -> Result<Self, String> {
+            Ok(Self)
+        }
+
+        pub fn is_pressed(&self, key: PttKey) -> bool {
+            // 최상위 비트가 지금 눌려 있는지
+            unsafe { GetAsyncKeyState(virtual_key(key)) < 0 }
+        }
+    }
+
+    fn virtual_key(key: PttKey) -> i32 {
+        if let Some(number) = key.function_number() {
+            return 0x6F + i32::from(number);
+        }
+        match key {
+            PttKey::ScrollLock => 0x91,
+            PttKey::Pause => 0x13,
+            PttKey::Insert => 0x2D,
+            PttKey::Home => 0x24,
+            PttKey::End => 0x23,
+            PttKey::PageUp => 0x21,
+            PttKey::PageDown => 0x22,
+            PttKey::LeftCtrl => 0xA2,
+            PttKey::RightCtrl => 0xA3,
+            PttKey::LeftShift => 0xA0,
+            PttKey::RightShift => 0xA1,
+            PttKey::LeftAlt => 0xA4,
+            PttKey::RightAlt => 0xA5,
+            PttKey::LeftMeta => 0x5B,
+            PttKey::RightMeta => 0x5C,
+            _ => 0,
+        }
+    }
+}
+
+/// Quartz 이벤트 소스 키 상태 조회 (입력 모니터링 권한 필요)
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::PttKey;
+
+    /// `kCGEventSourceStateCombinedSessionState`
+    const COMBINED_SESSION_STATE: i32 = 0;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        fn CGEventSourceKeyState(state: i32, key: u16) -> bool;
+    }
+
+    pub struct Keyboard;
+
+    impl Keyboard {
+        pub fn open() -> Result<Self, String> {
+            Ok(Self)
+        }
+
+        pub fn is_pressed(&self, key: PttKey) -> bool {
+            key_code(key)
+                .is_some_and(|code| unsafe { CGEventSourceKeyState(COMBINED_SESSION_STATE, code) })
+        }
+    }
+
+    /// 가상 키 코드 (맥 키보드에 없는 키는 `None`)
+    fn key_code(key: PttKey) -> Option<u16> {
+        const FUNCTION_KEYS: [u16; 20] = [
+            0x7A, 0x78, 0x63, 0x76, 0x60, 0x61, 0x62, 0x64, 0x65, 0x6D, 0x67, 0x6F, 0x69, 0x6B,
+            0x71, 0x6A, 0x40, 0x4F, 0x50, 0x5A,
+        ];
+        if let Some(number) = key.function_number() {
+            return FUNCTION_KEYS.get(usize::from(number) - 1).copied();
+        }
+        match key {
+            // Help 키 자리
+            PttKey::Insert => Some(0x72),
+            PttKey::Home => Some(0x73),
+            PttKey::End => Some(0x77),
+            PttKey::PageUp => Some(0x74),
+            PttKey::PageDown => Some(0x79),
+            PttKey::LeftCtrl => Some(0x3B),
+            PttKey::RightCtrl => Some(0x3E),
+            PttKey::LeftShift => Some(0x38),
+            PttKey::RightShift => Some(0x3C),
+            PttKey::LeftAlt => Some(0x3A),
+            PttKey::RightAlt => Some(0x3D),
+            PttKey::LeftMeta => Some(0x37),
+            PttKey::RightMeta => Some(0x36),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+mod unsupported {
+    use super::PttKey;
+
+    pub struct Keyboard;
+
+    impl Keyboard {
+        pub fn open() -> Result<Self, String> {
+            Err("이 플랫폼에서는 키 상태를 읽을 수 없습니다".to_string())
+        }
+
+        pub fn is_pressed(&self, _key: PttKey) -> bool {
+            false
+        }
+    }
+}
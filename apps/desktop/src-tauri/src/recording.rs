@@ -0,0 +1,408 @@
+use hound::{SampleFormat, WavSpec, WavWriter};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, State};
+
+use crate::audio::{CaptureManager, CaptureSource};
+use crate::error::TeuImError;
+use crate::i18n::t;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+/// 기본 프리롤 길이 (초)
+pub const DEFAULT_PREROLL_SECONDS: u32 = 5;
+
+/// 프리롤 길이 허용 범위 (초)
+pub const PREROLL_SECONDS_RANGE: std::ops::RangeInclusive<u32> = 1..=60;
+
+/// WAV 파일 샘플 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingFormat {
+    #[default]
+    Pcm16,
+    Float32,
+}
+
+/// 녹음 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStatus {
+    pub session_id: String,
+    pub path: String,
+    pub format: RecordingFormat,
+    pub sample_rate: u32,
+    pub duration_secs: f64,
+    pub paused: bool,
+}
+
+/// 녹음 종료 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingResult {
+    pub path: String,
+    pub duration_secs: f64,
+}
+
+struct ActiveRecording {
+    session_id: String,
+    path: PathBuf,
+    format: RecordingFormat,
+    sample_rate: u32,
+    writer: WavWriter<BufWriter<File>>,
+    frames: u64,
+    paused: bool,
+    /// 마지막으로 헤더를 갱신해 디스크에 내린 시각
+    last_flush: Instant,
+}
+
+impl ActiveRecording {
+    fn append(&mut self, samples: &[i16]) {
+        let result = match self.format {
+            RecordingFormat::Pcm16 => samples.iter().try_for_each(|s| self.writer.write_sample(*s)),
+            RecordingFormat::Float32 => samples
+                .iter()
+                .try_for_each(|s| self.writer.write_sample(*s as f32 / 32768.0)),
+        };
+
+        match result {
+            Ok(()) => self.frames += samples.len() as u64,
+            Err(e) => log::error!("녹음 파일 쓰기 실패: {}", e),
+        }
+    }
+
+    fn duration_secs(&self) -> f64 {
+        self.frames as f64 / self.sample_rate as f64
+    }
+
+    fn status(&self) -> RecordingStatus {
+        RecordingStatus {
+            session_id: self.session_id.clone(),
+            path: self.path.to_string_lossy().to_string(),
+            format: self.format,
+            sample_rate: self.sample_rate,
+            duration_secs: self.duration_secs(),
+            paused: self.paused,
+        }
+    }
+}
+
+/// 대기 중인 캡처 세션의 최근 오디오 (녹음을 시작하면 파일 앞에 붙임)
+struct Preroll {
+    session_id: String,
+    sample_rate: u32,
+    seconds: u32,
+    samples: VecDeque<i16>,
+}
+
+impl Preroll {
+    fn capacity(&self) -> usize {
+        self.sample_rate as usize * self.seconds as usize
+    }
+
+    fn push(&mut self, samples: &[i16]) {
+        self.samples.extend(samples);
+        let excess = self.samples.len().saturating_sub(self.capacity());
+        self.samples.drain(..excess);
+    }
+}
+
+/// 캡처 스트림을 WAV 파일로 기록하는 녹음기
+///
+/// 캡처 콜백과 명령 핸들러가 같은 녹음 상태를 공유하도록 복제 가능한 핸들로 둔다.
+#[derive(Clone, Default)]
+pub struct Recorder {
+    active: Arc<Mutex<Option<ActiveRecording>>>,
+    preroll: Arc<Mutex<Option<Preroll>>>,
+}
+
+impl Recorder {
+    fn start(
+        &self,
+        session_id: String,
+        sample_rate: u32,
+        path: PathBuf,
+        format: RecordingFormat,
+    ) -> Result<RecordingStatus, String> {
+        let mut active = self.active.lock().unwrap();
+        if active.is_some() {
+            return Err(t("error.already_recording").to_string());
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("녹음 폴더 생성 실패: {}", e))?;
+        }
+
+        let spec = match format {
+            RecordingFormat::Pcm16 => WavSpec {
+                channels: 1,
+                sample_rate,
+                bits_per_sample: 16,
+                sample_format: SampleFormat::Int,
+            },
+            RecordingFormat::Float32 => WavSpec {
+                channels: 1,
+                sample_rate,
+                bits_per_sample: 32,
+                sample_format: SampleFormat::Float,
+            },
+        };
+        let writer = WavWriter::create(&path, spec).map_err(|e| format!("녹음 파일 생성 실패: {}", e))?;
+
+        let mut recording = ActiveRecording {
+            session_id,
+            path,
+            format,
+            sample_rate,
+            writer,
+            frames: 0,
+            paused: false,
+            last_flush: Instant::now(),
+        };
+
+        // 대기 중에 모아 둔 오디오를 앞에 붙여 첫 문장이 잘리지 않게 함
+        let mut preroll = self.preroll.lock().unwrap();
+        if let Some(preroll) = preroll
+            .as_mut()
+            .filter(|p| p.session_id == recording.session_id && p.sample_rate == sample_rate)
+        {
+            let samples: Vec<i16> = preroll.samples.drain(..).collect();
+            log::info!(
+                "프리롤 {:.1}초를 녹음 앞에 붙임",
+                samples.len() as f64 / sample_rate as f64
+            );
+            recording.append(&samples);
+        }
+
+        let status = recording.status();
+        *active = Some(recording);
+        Ok(status)
+    }
+
+    /// 캡처 콜백에서 샘플 기록 (해당 세션을 녹음 중일 때만, 아니면 프리롤에 보관)
+    pub fn write(&self, session_id: &str, samples: &[i16]) {
+        let mut active = self.active.lock().unwrap();
+        let Some(recording) = active
+            .as_mut()
+            .filter(|recording| recording.session_id == session_id)
+        else {
+            if let Some(preroll) = self.preroll.lock().unwrap().as_mut() {
+                if preroll.session_id == session_id {
+                    preroll.push(samples);
+                }
+            }
+            return;
+        };
+        if recording.paused {
+            return;
+        }
+
+        recording.append(samples);
+
+        // 주기적으로 헤더를 갱신해 두면 비정상 종료돼도 그때까지는 재생 가능
+        if recording.last_flush.elapsed() >= crate::journal::JOURNAL_INTERVAL {
+            recording.last_flush = Instant::now();
+            if let Err(e) = recording.writer.flush() {
+                log::warn!("녹음 파일 체크포인트 실패: {}", e);
+            }
+        }
+    }
+
+    /// 캡처 세션의 최근 `seconds`초를 계속 보관 (다른 세션을 대기 중이었으면 바꿈)
+    pub fn arm_preroll(&self, session_id: String, sample_rate: u32, seconds: u32) {
+        *self.preroll.lock().unwrap() = Some(Preroll {
+            session_id,
+            sample_rate,
+            seconds,
+            samples: VecDeque::new(),
+        });
+    }
+
+    /// 캡처 세션이 끝나면 프리롤 해제
+    pub fn disarm_preroll(&self, session_id: &str) {
+        let mut preroll = self.preroll.lock().unwrap();
+        if preroll.as_ref().is_some_and(|p| p.session_id == session_id) {
+            *preroll = None;
+        }
+    }
+
+    /// 대기 중인 프리롤 길이 변경
+    fn set_preroll_seconds(&self, seconds: u32) {
+        if let Some(preroll) = self.preroll.lock().unwrap().as_mut() {
+            preroll.seconds = seconds;
+            preroll.push(&[]);
+        }
+    }
+
+    fn set_paused(&self, paused: bool) -> Result<RecordingStatus, String> {
+        let mut active = self.active.lock().unwrap();
+        let recording = active.as_mut().ok_or(t("error.not_recording"))?;
+        recording.paused = paused;
+        Ok(recording.status())
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.active.lock().unwrap().is_some()
+    }
+
+    /// 녹음 중인 파일 경로
+    pub fn path(&self) -> Option<PathBuf> {
+        self.active.lock().unwrap().as_ref().map(|r| r.path.clone())
+    }
+
+    /// 녹음 파일 헤더를 마무리하고 닫음
+    pub fn stop(&self) -> Result<RecordingResult, String> {
+        let recording = self.active.lock().unwrap().take().ok_or(t("error.not_recording"))?;
+        let duration_secs = recording.duration_secs();
+        let path = recording.path.to_string_lossy().to_string();
+
+        recording
+            .writer
+            .finalize()
+            .map_err(|e| format!("녹음 파일 마무리 실패: {}", e))?;
+
+        log::info!("녹음 종료: {} ({:.1}초)", path, duration_secs);
+        Ok(RecordingResult {
+            path,
+            duration_secs,
+        })
+    }
+}
+
+/// 녹음 시작
+///
+/// `session_id`를 생략하면 가장 먼저 시작된 마이크 캡처 세션을 녹음한다.
+/// 상대 경로는 데이터 폴더의 녹음 폴더 기준이다.
+/// 캡처 이벤트는 녹음과 무관하게 계속 전송된다. 녹음 중 저장 공간이 중지
+/// 한도에 닿으면 파일을 마무리하고 `recording-stopped`를 보낸다.
+#[tauri::command]
+pub fn start_recording(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    storage: State<'_, Storage>,
+    path: String,
+    format: Option<RecordingFormat>,
+    session_id: Option<String>,
+    storage_session_id: Option<String>,
+) -> Result<RecordingStatus, String> {
+    let sessions = manager.list();
+    let session = match session_id {
+        Some(id) => sessions.into_iter().find(|s| s.id == id),
+        None => sessions
+            .into_iter()
+            .find(|s| s.source == CaptureSource::Microphone),
+    }
+    .ok_or(t("error.no_capture_to_record"))?;
+
+    // 남은 공간이 중지 한도 아래면 쓰다가 실패하기 전에 미리 거절
+    let path = crate::data_dir::recording_path(&app, &path)?;
+    crate::disk::check_before_recording(&app, &path)?;
+
+    let status = manager.recorder().start(
+        session.id,
+        session.sample_rate,
+        path,
+        format.unwrap_or_default(),
+    )?;
+    log::info!("녹음 시작: {}", status.path);
+
+    // 저장소 세션에 연결해 두면 나중에 전사 결과와 함께 재생할 수 있음
+    // 연결하지 못하면 어느 세션에도 속하지 않는 파일이 남지 않게 녹음을 되돌림
+    if let Some(id) = storage_session_id {
+        if let Err(e) = storage.set_audio_path(&id, &status.path) {
+            if let Ok(result) = manager.recorder().stop() {
+                let _ = std::fs::remove_file(&result.path);
+            }
+            return Err(e.into());
+        }
+    }
+    Ok(status)
+}
+
+/// 녹음 일시정지
+#[tauri::command]
+pub fn pause_recording(manager: State<'_, CaptureManager>) -> Result<RecordingStatus, String> {
+    manager.recorder().set_paused(true)
+}
+
+/// 녹음 재개
+#[tauri::command]
+pub fn resume_recording(manager: State<'_, CaptureManager>) -> Result<RecordingStatus, String> {
+    manager.recorder().set_paused(false)
+}
+
+/// 녹음 종료 후 파일 경로와 길이 반환
+#[tauri::command]
+pub fn stop_recording(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+) -> Result<RecordingResult, String> {
+    let result = manager.recorder().stop()?;
+    // 암호화를 켰으면 마친 녹음을 백그라운드에서 암호화
+    crate::encryption::spawn_encrypt_recordings(app);
+    Ok(result)
+}
+
+/// 녹음 대기 (캡처를 켜 두고 최근 오디오를 프리롤로 보관)
+///
+/// 이 장치를 이미 캡처 중이면 그 세션을 쓴다. 이후 이 세션으로 녹음을
+/// 시작하면 보관한 오디오(설정의 `preroll_seconds`, 기본 5초)를 파일 앞에 붙여
+/// 시작 버튼을 누르기 직전의 첫 문장이 잘리지 않는다. 캡처 세션 ID를 반환한다.
+#[tauri::command]
+pub fn arm_capture(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    store: State<'_, SettingsStore>,
+    device_id: String,
+) -> Result<String, TeuImError> {
+    let existing = manager
+        .list()
+        .into_iter()
+        .find(|s| s.source == CaptureSource::Microphone && s.device_id == device_id);
+    let session = match existing {
+        Some(session) => session,
+        None => {
+            let id = crate::audio::start_audio_capture(
+                app,
+                manager.clone(),
+                device_id,
+                None,
+                None,
+                None,
+                None,
+            )?;
+            manager
+                .list()
+                .into_iter()
+                .find(|s| s.id == id)
+                .ok_or(TeuImError::CaptureSessionNotFound { session_id: id })?
+        }
+    };
+
+    let seconds = store
+        .get()
+        .preroll_seconds
+        .unwrap_or(DEFAULT_PREROLL_SECONDS);
+    manager
+        .recorder()
+        .arm_preroll(session.id.clone(), session.sample_rate, seconds);
+    log::info!("녹음 대기: {} (프리롤 {}초)", session.id, seconds);
+    Ok(session.id)
+}
+
+/// 프리롤 길이 설정 (초, 대기 중인 캡처에도 바로 적용)
+#[tauri::command]
+pub fn set_preroll_seconds(
+    app: AppHandle,
+    manager: State<'_, CaptureManager>,
+    store: State<'_, SettingsStore>,
+    seconds: u32,
+) -> Result<u32, TeuImError> {
+    let settings = store.modify(|settings| settings.preroll_seconds = Some(seconds))?;
+    manager.recorder().set_preroll_seconds(seconds);
+    let _ = app.emit("settings-changed", &settings);
+    Ok(seconds)
+}
@@ -0,0 +1,342 @@
+use hound::WavReader;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::{CaptureManager, MonoResampler};
+use crate::encryption;
+use crate::error::TeuImError;
+use crate::i18n::{t, tf};
+use crate::settings::SettingsStore;
+use crate::storage::{RefinedTranscript, Storage, StoredSegment};
+use crate::transcribe::{self, TranscriptionOptions, WHISPER_SAMPLE_RATE};
+
+/// 녹음 파일을 읽어 리샘플러에 넘기는 단위 (프레임)
+const READ_FRAMES: usize = 16_384;
+
+/// 다시 전사 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefineStatus {
+    /// 다른 세션을 다시 전사하는 중이라 대기
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl RefineStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// 모르는 값은 실패로 취급
+    pub fn parse(status: &str) -> Self {
+        match status {
+            "queued" => Self::Queued,
+            "running" => Self::Running,
+            "completed" => Self::Completed,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Failed,
+        }
+    }
+
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// `refine-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct RefineProgress {
+    pub refined_id: String,
+    pub session_id: String,
+    pub status: RefineStatus,
+    /// 전사 진행률 (0~1)
+    pub progress: f32,
+    pub error: Option<String>,
+}
+
+/// 세션 다시 전사 대기열 (Tauri 관리 상태)
+///
+/// 큰 모델은 메모리와 연산을 많이 쓰므로 한 번에 한 세션씩 처리한다.
+#[derive(Default)]
+pub struct Refiner {
+    /// 대기 중이거나 실행 중인 세션 → 취소 플래그
+    pending: Mutex<HashMap<String, Arc<AtomicBool>>>,
+    /// 실행 순서를 지키는 잠금
+    turn: Mutex<()>,
+}
+
+/// 세션 녹음 다시 전사 예약, 만들어진 다시 전사 ID 반환
+fn start(
+    app: &AppHandle,
+    session_id: &str,
+    model: &str,
+    options: TranscriptionOptions,
+) -> Result<String, TeuImError> {
+    let storage = app.state::<Storage>();
+    let path = storage
+        .audio_path(session_id)?
+        .ok_or(t("error.no_recording"))?;
+    let recording = app.state::<CaptureManager>().recorder().path();
+    if recording.is_some_and(|recording| recording == Path::new(&path)) {
+        return Err(t("error.recording_in_progress").into());
+    }
+    crate::models::resolve_model_path(app, model).map_err(TeuImError::invalid_argument)?;
+
+    let refiner = app.state::<Refiner>();
+    let mut pending = refiner.pending.lock().unwrap();
+    if pending.contains_key(session_id) {
+        return Err(TeuImError::invalid_argument(tf(
+            "error.already_refining",
+            &[("id", session_id)],
+        )));
+    }
+    let refined_id = storage.create_refined_transcript(session_id, model)?;
+    let cancel = Arc::new(AtomicBool::new(false));
+    pending.insert(session_id.to_string(), cancel.clone());
+    drop(pending);
+
+    log::info!("세션 다시 전사 예약: {} ({})", session_id, model);
+    let job = Job {
+        app: app.clone(),
+        refined_id: refined_id.clone(),
+        session_id: session_id.to_string(),
+        model: model.to_string(),
+        path,
+        options,
+        cancel,
+    };
+    job.report(RefineStatus::Queued, 0.0, None);
+    std::thread::spawn(move || job.run());
+    Ok(refined_id)
+}
+
+/// 다시 전사 작업 하나
+struct Job {
+    app: AppHandle,
+    refined_id: String,
+    session_id: String,
+    model: String,
+    path: String,
+    options: TranscriptionOptions,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Job {
+    fn report(&self, status: RefineStatus, progress: f32, error: Option<String>) {
+        let _ = self.app.emit(
+            "refine-progress",
+            RefineProgress {
+                refined_id: self.refined_id.clone(),
+                session_id: self.session_id.clone(),
+                status,
+                progress,
+                error,
+            },
+        );
+    }
+
+    fn run(self) {
+        let refiner = self.app.state::<Refiner>();
+        let result = {
+            let _turn = refiner.turn.lock().unwrap();
+            if self.cancel.load(Ordering::Relaxed) {
+                Err(TeuImError::from("취소됨"))
+            } else {
+                self.set_status(RefineStatus::Running, None);
+                self.report(RefineStatus::Running, 0.0, None);
+                self.refine()
+            }
+        };
+        refiner.pending.lock().unwrap().remove(&self.session_id);
+
+        let (status, error) = match result {
+            Ok(()) => (RefineStatus::Completed, None),
+            Err(_) if self.cancel.load(Ordering::Relaxed) => (RefineStatus::Cancelled, None),
+            Err(e) => (RefineStatus::Failed, Some(e.to_string())),
+        };
+        match &error {
+            Some(e) => log::error!("세션 다시 전사 실패 ({}): {}", self.session_id, e),
+            None => log::info!("세션 다시 전사 끝: {} ({:?})", self.session_id, status),
+        }
+        self.set_status(status, error.as_deref());
+        let progress = if status == RefineStatus::Completed {
+            1.0
+        } else {
+            0.0
+        };
+        self.report(status, progress, error);
+    }
+
+    fn set_status(&self, status: RefineStatus, error: Option<&str>) {
+        if let Err(e) =
+            self.app
+                .state::<Storage>()
+                .set_refined_status(&self.refined_id, status, error)
+        {
+            log::error!("다시 전사 상태 저장 실패: {}", e);
+        }
+    }
+
+    /// 녹음을 읽어 모델로 전사하고 구간마다 실시간 구간과 이어 저장
+    fn refine(&self) -> Result<(), TeuImError> {
+        let audio = load_recording(&self.app, &self.path, &self.cancel)?;
+        let (context, threads) = transcribe::open_model(&self.app, &self.model)?;
+
+        let storage = self.app.state::<Storage>();
+        let live = storage.get_session(&self.session_id)?.segments;
+        let options = TranscriptionOptions {
+            glossary: crate::glossary::hints(&storage),
+            threads: self.options.threads.or(Some(threads)),
+            ..self.options.clone()
+        };
+
+        // 진행률 이벤트는 1% 단위로만
+        let mut reported = 0.0;
+        transcribe::transcribe_buffer(
+            &context,
+            &options,
+            &audio,
+            &self.cancel,
+            |segment| {
+                let linked = overlapping(&live, segment.start_ms, segment.end_ms);
+                if let Err(e) = storage.insert_refined_segment(&self.refined_id, linked, &segment) {
+                    log::error!("다시 전사 구간 저장 실패: {}", e);
+                }
+            },
+            |progress| {
+                if progress - reported >= 0.01 {
+                    reported = progress;
+                    self.report(RefineStatus::Running, progress.min(1.0), None);
+                }
+            },
+        )?;
+        Ok(())
+    }
+}
+
+/// 시간이 가장 많이 겹치는 실시간 구간 ID
+fn overlapping(live: &[StoredSegment], start_ms: i64, end_ms: i64) -> Option<&str> {
+    live.iter()
+        .map(|segment| {
+            let overlap = segment.end_ms.min(end_ms) - segment.start_ms.max(start_ms);
+            (segment, overlap)
+        })
+        .filter(|(_, overlap)| *overlap > 0)
+        .max_by_key(|(_, overlap)| *overlap)
+        .map(|(segment, _)| segment.id.as_str())
+}
+
+/// 녹음 파일(암호화했으면 복호화하며)을 16kHz 모노로 읽기 (첫 채널만)
+fn load_recording(app: &AppHandle, path: &str, cancel: &AtomicBool) -> Result<Vec<f32>, String> {
+    let mut reader = WavReader::new(encryption::open_recording(app, path)?)
+        .map_err(|e| format!("녹음 파일 열기 실패: {}", e))?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let scale = (1i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+    let mut resampler = MonoResampler::new(spec.sample_rate, WHISPER_SAMPLE_RATE)?;
+
+    let mut samples: Box<dyn Iterator<Item = f32>> = match spec.sample_format {
+        hound::SampleFormat::Float => Box::new(
+            reader
+                .samples::<f32>()
+                .step_by(channels)
+                .filter_map(Result::ok),
+        ),
+        hound::SampleFormat::Int => Box::new(
+            reader
+                .samples::<i32>()
+                .step_by(channels)
+                .filter_map(Result::ok)
+                .map(move |s| s as f32 / scale),
+        ),
+    };
+
+    let mut audio = Vec::new();
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Err("취소됨".to_string());
+        }
+        let block: Vec<f32> = samples.by_ref().take(READ_FRAMES).collect();
+        if block.is_empty() {
+            break;
+        }
+        audio.extend(resampler.process(&block));
+    }
+    Ok(audio)
+}
+
+/// 실시간 전사가 끝난 세션을 설정한 모델로 다시 전사 (`refine_model`을 정했을 때만)
+pub fn after_session(app: &AppHandle, session_id: &str) {
+    let Some(model) = app.state::<SettingsStore>().get().refine_model else {
+        return;
+    };
+    match start(app, session_id, &model, TranscriptionOptions::default()) {
+        Ok(refined_id) => log::debug!("세션 자동 다시 전사: {} ({})", session_id, refined_id),
+        Err(e) => log::info!("세션 자동 다시 전사 생략 ({}): {}", session_id, e),
+    }
+}
+
+/// 앱을 시작할 때 지난 실행에서 끝나지 못한 다시 전사를 실패로 정리
+pub fn recover(app: &AppHandle) {
+    match app.state::<Storage>().interrupt_refinements() {
+        Ok(0) => {}
+        Ok(count) => log::warn!("끝나지 못한 다시 전사 {}건을 실패로 표시함", count),
+        Err(e) => log::error!("다시 전사 기록 정리 실패: {}", e),
+    }
+}
+
+/// 끝난 세션의 녹음을 더 큰 모델로 다시 전사 (백그라운드)
+///
+/// `model`은 모델 목록의 이름이나 파일 경로다. 실시간 결과는 그대로 두고
+/// 구간마다 시간이 가장 많이 겹치는 실시간 구간과 이어 따로 저장한다. 진행 상황은
+/// `refine-progress`로 보내고, 만들어진 다시 전사 ID를 바로 반환한다.
+#[tauri::command]
+pub fn refine_session(
+    app: AppHandle,
+    session_id: String,
+    model: String,
+    options: Option<TranscriptionOptions>,
+) -> Result<String, TeuImError> {
+    start(&app, &session_id, &model, options.unwrap_or_default())
+}
+
+/// 세션 다시 전사 취소 (대기 중이거나 실행 중일 때만)
+#[tauri::command]
+pub fn cancel_refinement(refiner: State<'_, Refiner>, session_id: String) -> Result<(), String> {
+    let pending = refiner.pending.lock().unwrap();
+    let cancel = pending.get(&session_id).ok_or(t("error.not_refining"))?;
+    cancel.store(true, Ordering::Relaxed);
+    log::info!("세션 다시 전사 취소 요청: {}", session_id);
+    Ok(())
+}
+
+/// 세션의 다시 전사 기록 (오래된 순, 구간은 `get_refined_transcript`로 조회)
+#[tauri::command]
+pub fn list_refined_transcripts(
+    storage: State<'_, Storage>,
+    session_id: String,
+) -> Result<Vec<RefinedTranscript>, TeuImError> {
+    storage.refined_transcripts(&session_id)
+}
+
+/// 다시 전사 결과 조회 (구간 포함)
+#[tauri::command]
+pub fn get_refined_transcript(
+    storage: State<'_, Storage>,
+    refined_id: String,
+) -> Result<RefinedTranscript, TeuImError> {
+    storage.refined_transcript(&refined_id)
+}
@@ -0,0 +1,634 @@
+use quinn::crypto::rustls::{QuicClientConfig, QuicServerConfig};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc as async_mpsc;
+
+use crate::audio::{self, CaptureManager, CaptureSource};
+use crate::error::TeuImError;
+
+/// 원격 마이크 연결을 구분하는 ALPN
+const ALPN: &[u8] = b"teuim-remote-mic/1";
+
+/// 자체 서명 인증서 이름 (신뢰는 페어링 코드의 지문으로 확인)
+const SERVER_NAME: &str = "teuim-remote-mic";
+
+/// 페어링 코드에 담는 인증서 지문 길이 (바이트, SHA-256 전체)
+const FINGERPRINT_LEN: usize = 32;
+
+/// 페어링 코드에 담는 접속 토큰 길이 (바이트)
+const TOKEN_LEN: usize = 8;
+
+/// 연결이 조용해도 끊기지 않게 보내는 keep-alive 간격
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 첫 인사 메시지 최대 크기
+const MAX_HELLO_BYTES: usize = 4 * 1024;
+
+/// 오디오 블록 최대 크기 (넘으면 잘못된 송신기로 보고 끊음)
+const MAX_BLOCK_BYTES: usize = 1024 * 1024;
+
+/// 송신 대기열에 쌓아 두는 블록 수 (넘치면 새 블록을 버림)
+const SEND_QUEUE_BLOCKS: usize = 256;
+
+/// 허용하는 송신기 샘플레이트
+const SAMPLE_RATE_RANGE: std::ops::RangeInclusive<u32> = 8_000..=192_000;
+
+/// 정상 종료 / 페어링 거부 시 연결 종료 코드
+const CLOSE_DONE: u32 = 0;
+const CLOSE_REJECTED: u32 = 1;
+
+/// 송신기가 연결 직후 보내는 인사
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteHello {
+    /// 페어링 코드의 접속 토큰
+    token: String,
+    sample_rate: u32,
+    device_name: String,
+}
+
+/// `start_remote_listener` 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteListenerInfo {
+    pub port: u16,
+    /// 송신기에 입력할 코드 (인증서 지문 + 접속 토큰)
+    pub pairing_code: String,
+}
+
+/// `connect_remote_source` 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteSourceInfo {
+    pub addr: String,
+    /// 보내는 로컬 캡처 세션
+    pub session_id: String,
+}
+
+/// `remote-mic-connected` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteMicConnected {
+    /// 원격 오디오로 만든 캡처 세션
+    pub session_id: String,
+    pub peer: String,
+    pub device_name: String,
+}
+
+/// `remote-source-disconnected` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteSourceDisconnected {
+    pub addr: String,
+    pub message: String,
+}
+
+struct RunningListener {
+    port: u16,
+    pairing_code: String,
+    endpoint: quinn::Endpoint,
+}
+
+struct ConnectedSource {
+    addr: SocketAddr,
+    connection: quinn::Connection,
+}
+
+/// 원격 마이크 상태 (Tauri 관리 상태)
+///
+/// 주 인스턴스는 QUIC 리스너를 열어 송신기마다 캡처 세션을 만들고,
+/// 보조 인스턴스는 로컬 캡처 세션 오디오를 주 인스턴스로 보낸다.
+/// 연결은 TLS 1.3으로 암호화되며 페어링 코드의 인증서 지문으로 상대를 확인한다.
+#[derive(Default)]
+pub struct RemoteMic {
+    listener: Mutex<Option<RunningListener>>,
+    source: Mutex<Option<ConnectedSource>>,
+}
+
+struct TapTarget {
+    session_id: String,
+    tx: async_mpsc::Sender<Vec<f32>>,
+    overflowed: bool,
+}
+
+/// 캡처 세션 오디오를 원격 마이크 연결로 넘기는 송신단
+///
+/// 버스 소비자와 명령 핸들러가 같은 대상을 공유하도록 복제 가능한 핸들로 둔다.
+#[derive(Clone, Default)]
+pub struct RemoteTap {
+    target: Arc<Mutex<Option<TapTarget>>>,
+}
+
+impl RemoteTap {
+    /// 연결된 세션의 샘플만 송신 대기열로 전달
+    pub fn write(&self, session_id: &str, samples: &[f32]) {
+        let mut target = self.target.lock().unwrap();
+        let Some(tap) = target.as_mut() else {
+            return;
+        };
+        if tap.session_id != session_id {
+            return;
+        }
+
+        match tap.tx.try_send(samples.to_vec()) {
+            Ok(()) => tap.overflowed = false,
+            Err(async_mpsc::error::TrySendError::Full(_)) => {
+                if !tap.overflowed {
+                    log::warn!("원격 마이크 전송이 밀려 오디오를 버림");
+                }
+                tap.overflowed = true;
+            }
+            // 전송 작업이 끝났으면 연결 해제
+            Err(async_mpsc::error::TrySendError::Closed(_)) => *target = None,
+        }
+    }
+
+    fn attach(&self, session_id: String, tx: async_mpsc::Sender<Vec<f32>>) {
+        *self.target.lock().unwrap() = Some(TapTarget {
+            session_id,
+            tx,
+            overflowed: false,
+        });
+    }
+
+    fn detach(&self) {
+        self.target.lock().unwrap().take();
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 인증서 지문 (SHA-256)
+fn fingerprint(cert: &CertificateDer<'_>) -> String {
+    hex(&Sha256::digest(cert.as_ref()))
+}
+
+/// 4자리씩 끊은 대문자 페어링 코드
+fn format_pairing_code(fingerprint: &str, token: &str) -> String {
+    let digits = format!("{}{}", fingerprint, token).to_ascii_uppercase();
+    digits
+        .as_bytes()
+        .chunks(4)
+        .map(|group| String::from_utf8_lossy(group).into_owned())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// 페어링 코드를 (인증서 지문, 접속 토큰)으로 분리
+fn parse_pairing_code(code: &str) -> Result<(String, String), TeuImError> {
+    let digits: String = code
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if digits.len() != (FINGERPRINT_LEN + TOKEN_LEN) * 2
+        || !digits.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return Err(TeuImError::invalid_argument("잘못된 페어링 코드입니다"));
+    }
+    let (fingerprint, token) = digits.split_at(FINGERPRINT_LEN * 2);
+    Ok((fingerprint.to_string(), token.to_string()))
+}
+
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::ring::default_provider())
+}
+
+fn transport() -> Arc<quinn::TransportConfig> {
+    let mut transport = quinn::TransportConfig::default();
+    transport.keep_alive_interval(Some(KEEP_ALIVE_INTERVAL));
+    Arc::new(transport)
+}
+
+/// 자체 서명 인증서로 서버 설정 생성 (인증서 지문과 함께 반환)
+fn server_config() -> Result<(quinn::ServerConfig, String), String> {
+    let certified = rcgen::generate_simple_self_signed(vec![SERVER_NAME.to_string()])
+        .map_err(|e| format!("인증서 생성 실패: {}", e))?;
+    let cert = certified.cert.der().clone();
+    let fingerprint = fingerprint(&cert);
+    let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()));
+
+    let mut crypto = rustls::ServerConfig::builder_with_provider(provider())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .map_err(|e| e.to_string())?
+        .with_no_client_auth()
+        .with_single_cert(vec![cert], key)
+        .map_err(|e| e.to_string())?;
+    crypto.alpn_protocols = vec![ALPN.to_vec()];
+    let crypto = QuicServerConfig::try_from(crypto).map_err(|e| e.to_string())?;
+
+    let mut config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
+    config.transport_config(transport());
+    Ok((config, fingerprint))
+}
+
+/// 페어링 코드의 지문과 같은 인증서만 믿는 클라이언트 설정
+fn client_config(fingerprint: String) -> Result<quinn::ClientConfig, String> {
+    let provider = provider();
+    let mut crypto = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .map_err(|e| e.to_string())?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(PinnedCertVerifier {
+            fingerprint,
+            provider,
+        }))
+        .with_no_client_auth();
+    crypto.alpn_protocols = vec![ALPN.to_vec()];
+    let crypto = QuicClientConfig::try_from(crypto).map_err(|e| e.to_string())?;
+
+    let mut config = quinn::ClientConfig::new(Arc::new(crypto));
+    config.transport_config(transport());
+    Ok(config)
+}
+
+/// 인증서 지문으로 서버를 확인하는 검증기 (자체 서명 인증서용)
+#[derive(Debug)]
+struct PinnedCertVerifier {
+    fingerprint: String,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if fingerprint(end_entity) == self.fingerprint {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::General(
+                "인증서 지문이 페어링 코드와 다릅니다".to_string(),
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// 길이(u32 LE)를 앞에 붙여 메시지 하나 전송
+async fn write_frame(send: &mut quinn::SendStream, payload: &[u8]) -> Result<(), String> {
+    send.write_all(&(payload.len() as u32).to_le_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    send.write_all(payload).await.map_err(|e| e.to_string())
+}
+
+/// 메시지 하나 수신 (상대가 스트림을 닫았으면 `None`)
+async fn read_frame(recv: &mut quinn::RecvStream, max: usize) -> Result<Option<Vec<u8>>, String> {
+    let mut len = [0u8; 4];
+    match recv.read_exact(&mut len).await {
+        Ok(()) => {}
+        Err(quinn::ReadExactError::FinishedEarly(0)) => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    }
+    let len = u32::from_le_bytes(len) as usize;
+    if len > max {
+        return Err(format!("메시지가 너무 큽니다: {} 바이트", len));
+    }
+    let mut payload = vec![0; len];
+    recv.read_exact(&mut payload)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(Some(payload))
+}
+
+/// 송신기 하나 처리 (페어링 확인 후 캡처 세션으로 등록하고 오디오 전달)
+async fn serve(app: AppHandle, incoming: quinn::Incoming, token: String) -> Result<(), String> {
+    let connection = incoming.await.map_err(|e| e.to_string())?;
+    let peer = connection.remote_address();
+    let (mut send, mut recv) = connection.accept_bi().await.map_err(|e| e.to_string())?;
+
+    let hello = read_frame(&mut recv, MAX_HELLO_BYTES)
+        .await?
+        .ok_or("인사 없이 연결이 닫힘")?;
+    let hello: RemoteHello = serde_json::from_slice(&hello).map_err(|e| e.to_string())?;
+    // 응답 시간으로 토큰을 한 자리씩 맞춰 보지 못하게 상수 시간으로 비교
+    if !bool::from(hello.token.as_bytes().ct_eq(token.as_bytes())) {
+        connection.close(CLOSE_REJECTED.into(), b"pairing");
+        return Err(format!("페어링 코드가 맞지 않는 연결 거부: {}", peer));
+    }
+    if !SAMPLE_RATE_RANGE.contains(&hello.sample_rate) {
+        connection.close(CLOSE_REJECTED.into(), b"sample rate");
+        return Err(format!("지원하지 않는 샘플레이트: {}", hello.sample_rate));
+    }
+
+    let device_name = format!("{} ({})", hello.device_name, peer.ip());
+    let (tx, rx) = mpsc::channel();
+    let started = audio::start_remote_capture(
+        app.clone(),
+        &app.state::<CaptureManager>(),
+        format!("remote:{}", peer),
+        device_name.clone(),
+        hello.sample_rate,
+        rx,
+    );
+    let session_id = match started {
+        Ok(session_id) => session_id,
+        Err(e) => {
+            connection.close(CLOSE_REJECTED.into(), b"capture");
+            return Err(e.to_string());
+        }
+    };
+
+    // 1바이트 응답으로 연결 수락을 알림
+    send.write_all(&[1]).await.map_err(|e| e.to_string())?;
+    let _ = send.finish();
+    log::info!("원격 마이크 연결됨: {} ({})", device_name, session_id);
+    let _ = app.emit(
+        "remote-mic-connected",
+        RemoteMicConnected {
+            session_id,
+            peer: peer.to_string(),
+            device_name,
+        },
+    );
+
+    loop {
+        let block = match read_frame(&mut recv, MAX_BLOCK_BYTES).await {
+            Ok(Some(block)) => block,
+            Ok(None) => break,
+            Err(e) => {
+                log::info!("원격 마이크 연결 종료: {} ({})", peer, e);
+                break;
+            }
+        };
+        let samples = block
+            .chunks_exact(2)
+            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]) as f32 / 32768.0)
+            .collect();
+        // 캡처 세션이 중지됐으면 송신기도 끊음
+        if tx.send(samples).is_err() {
+            connection.close(CLOSE_DONE.into(), b"stopped");
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// 캡처 세션 오디오를 주 인스턴스로 전송 (연결 해제나 오류까지)
+async fn stream_audio(
+    app: AppHandle,
+    endpoint: quinn::Endpoint,
+    connection: quinn::Connection,
+    mut send: quinn::SendStream,
+    mut rx: async_mpsc::Receiver<Vec<f32>>,
+) {
+    let addr = connection.remote_address();
+    let mut result = Ok(());
+    while let Some(block) = rx.recv().await {
+        let payload: Vec<u8> = block
+            .iter()
+            .flat_map(|sample| audio::quantize(*sample).to_le_bytes())
+            .collect();
+        result = write_frame(&mut send, &payload).await;
+        if result.is_err() {
+            break;
+        }
+    }
+    drop(rx);
+
+    {
+        let remote = app.state::<RemoteMic>();
+        let mut source = remote.source.lock().unwrap();
+        if source
+            .as_ref()
+            .is_some_and(|source| source.connection.stable_id() == connection.stable_id())
+        {
+            *source = None;
+        }
+    }
+
+    match result {
+        Ok(()) => {
+            let _ = send.finish();
+            connection.close(CLOSE_DONE.into(), b"done");
+            endpoint.wait_idle().await;
+            log::info!("원격 마이크 전송 종료: {}", addr);
+        }
+        Err(e) => {
+            log::warn!("원격 마이크 전송 끊김: {} ({})", addr, e);
+            let _ = app.emit(
+                "remote-source-disconnected",
+                RemoteSourceDisconnected {
+                    addr: addr.to_string(),
+                    message: e,
+                },
+            );
+        }
+    }
+}
+
+/// 원격 마이크 리스너 시작 (`port`가 0이면 빈 포트 자동 선택)
+///
+/// 송신기가 붙을 때마다 `remote` 소스의 캡처 세션을 만들고
+/// `remote-mic-connected`를 보낸다. 송신기에 입력할 페어링 코드를 반환한다.
+#[tauri::command]
+pub async fn start_remote_listener(
+    app: AppHandle,
+    remote: State<'_, RemoteMic>,
+    port: u16,
+) -> Result<RemoteListenerInfo, TeuImError> {
+    if let Some(running) = remote.listener.lock().unwrap().as_ref() {
+        return Err(TeuImError::from(format!(
+            "원격 마이크 리스너가 이미 실행 중입니다 (포트 {})",
+            running.port
+        )));
+    }
+
+    let (config, fingerprint) = server_config()?;
+    let endpoint = quinn::Endpoint::server(config, SocketAddr::from(([0, 0, 0, 0], port)))
+        .map_err(|e| TeuImError::from(format!("원격 마이크 리스너 시작 실패: {}", e)))?;
+    let port = endpoint.local_addr().map_err(|e| e.to_string())?.port();
+
+    let mut token = [0u8; TOKEN_LEN];
+    provider()
+        .secure_random
+        .fill(&mut token)
+        .map_err(|_| "접속 토큰 생성 실패")?;
+    let token = hex(&token);
+    let pairing_code = format_pairing_code(&fingerprint, &token);
+
+    let accepting = endpoint.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(incoming) = accepting.accept().await {
+            let (app, token) = (app.clone(), token.clone());
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = serve(app, incoming, token).await {
+                    log::warn!("원격 마이크 연결 실패: {}", e);
+                }
+            });
+        }
+    });
+
+    log::info!("원격 마이크 리스너 시작: 포트 {}", port);
+    *remote.listener.lock().unwrap() = Some(RunningListener {
+        port,
+        pairing_code: pairing_code.clone(),
+        endpoint,
+    });
+    Ok(RemoteListenerInfo { port, pairing_code })
+}
+
+/// 원격 마이크 리스너 중지 (연결된 송신기의 캡처 세션도 끊김)
+#[tauri::command]
+pub fn stop_remote_listener(remote: State<'_, RemoteMic>) {
+    if let Some(running) = remote.listener.lock().unwrap().take() {
+        running.endpoint.close(CLOSE_DONE.into(), b"stopped");
+        log::info!("원격 마이크 리스너 중지 (포트 {})", running.port);
+    }
+}
+
+/// 실행 중인 원격 마이크 리스너 조회
+#[tauri::command]
+pub fn get_remote_listener(remote: State<'_, RemoteMic>) -> Option<RemoteListenerInfo> {
+    remote
+        .listener
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|running| RemoteListenerInfo {
+            port: running.port,
+            pairing_code: running.pairing_code.clone(),
+        })
+}
+
+/// 이 인스턴스의 마이크를 다른 인스턴스로 전송 시작
+///
+/// `addr`는 주 인스턴스의 `호스트:포트`, `pairing_code`는 주 인스턴스의
+/// `start_remote_listener`가 돌려준 코드다. `session_id`를 생략하면 실행 중인
+/// 마이크 캡처 세션을 보낸다. 연결이 끊기면 `remote-source-disconnected`를 보낸다.
+#[tauri::command]
+pub async fn connect_remote_source(
+    app: AppHandle,
+    remote: State<'_, RemoteMic>,
+    manager: State<'_, CaptureManager>,
+    addr: String,
+    pairing_code: String,
+    session_id: Option<String>,
+) -> Result<RemoteSourceInfo, TeuImError> {
+    if let Some(source) = remote.source.lock().unwrap().as_ref() {
+        return Err(TeuImError::from(format!(
+            "이미 원격 마이크로 보내는 중입니다 ({})",
+            source.addr
+        )));
+    }
+    let (fingerprint, token) = parse_pairing_code(&pairing_code)?;
+
+    let sessions = manager.list();
+    let session = match session_id {
+        Some(id) => sessions.into_iter().find(|s| s.id == id),
+        None => sessions
+            .into_iter()
+            .find(|s| s.source == CaptureSource::Microphone),
+    }
+    .ok_or("원격으로 보낼 캡처 세션이 없습니다")?;
+
+    let addr = tokio::net::lookup_host(&addr)
+        .await
+        .map_err(|e| {
+            TeuImError::invalid_argument(format!("주소를 찾을 수 없습니다: {} ({})", addr, e))
+        })?
+        .next()
+        .ok_or_else(|| {
+            TeuImError::invalid_argument(format!("주소를 찾을 수 없습니다: {}", addr))
+        })?;
+    let bind = match addr {
+        SocketAddr::V4(_) => SocketAddr::from(([0, 0, 0, 0], 0)),
+        SocketAddr::V6(_) => SocketAddr::from(([0u16; 8], 0)),
+    };
+    let mut endpoint = quinn::Endpoint::client(bind).map_err(|e| e.to_string())?;
+    endpoint.set_default_client_config(client_config(fingerprint)?);
+
+    let connect_error = |e: String| TeuImError::from(format!("원격 마이크 연결 실패: {}", e));
+    let connection = endpoint
+        .connect(addr, SERVER_NAME)
+        .map_err(|e| connect_error(e.to_string()))?
+        .await
+        .map_err(|e| connect_error(e.to_string()))?;
+    let (mut send, mut recv) = connection
+        .open_bi()
+        .await
+        .map_err(|e| connect_error(e.to_string()))?;
+    let hello = RemoteHello {
+        token,
+        sample_rate: session.sample_rate,
+        device_name: session.device_name.clone(),
+    };
+    let hello = serde_json::to_vec(&hello).map_err(|e| e.to_string())?;
+    write_frame(&mut send, &hello)
+        .await
+        .map_err(connect_error)?;
+    let mut accepted = [0u8; 1];
+    if recv.read_exact(&mut accepted).await.is_err() {
+        return Err(connect_error(
+            "주 인스턴스가 연결을 거부했습니다 (페어링 코드 확인)".to_string(),
+        ));
+    }
+
+    let (tx, rx) = async_mpsc::channel(SEND_QUEUE_BLOCKS);
+    manager.remote_tap().attach(session.id.clone(), tx);
+    *remote.source.lock().unwrap() = Some(ConnectedSource {
+        addr,
+        connection: connection.clone(),
+    });
+    tauri::async_runtime::spawn(stream_audio(app, endpoint, connection, send, rx));
+
+    log::info!("원격 마이크 전송 시작: {} -> {}", session.id, addr);
+    Ok(RemoteSourceInfo {
+        addr: addr.to_string(),
+        session_id: session.id,
+    })
+}
+
+/// 원격 마이크 전송 중지
+#[tauri::command]
+pub fn disconnect_remote_source(remote: State<'_, RemoteMic>, manager: State<'_, CaptureManager>) {
+    // 송신단을 떼면 전송 작업이 남은 오디오를 보내고 연결을 닫음
+    manager.remote_tap().detach();
+    if let Some(source) = remote.source.lock().unwrap().take() {
+        log::info!("원격 마이크 전송 중지: {}", source.addr);
+    }
+}
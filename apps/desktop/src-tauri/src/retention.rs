@@ -0,0 +1,372 @@
+use chrono::{DateTime, Duration as DateDuration, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::error::TeuImError;
+use crate::i18n::tf;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+/// 앱 시작 뒤 첫 정책 적용까지 기다리는 시간
+const FIRST_RUN_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// 정책을 다시 적용하는 간격
+const RUN_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 보관 일수 범위
+const DAYS_RANGE: std::ops::RangeInclusive<u32> = 1..=36_500;
+
+/// 삭제 기록 기본 조회 개수
+const DEFAULT_LOG_LIMIT: u32 = 200;
+
+/// 자동 삭제 정책 (기본은 아무것도 지우지 않음)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionSettings {
+    /// 끝난 지 이 일수가 지난 세션의 녹음 파일 삭제 (전사는 유지)
+    pub delete_audio_after_days: Option<u32>,
+    /// 끝난 지 이 일수가 지난 세션을 전사·녹음까지 모두 삭제
+    pub delete_sessions_after_days: Option<u32>,
+    /// 전사나 녹음을 내보낸 세션은 바로 모두 삭제
+    pub purge_after_export: bool,
+}
+
+impl RetentionSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        for days in [
+            self.delete_audio_after_days,
+            self.delete_sessions_after_days,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !DAYS_RANGE.contains(&days) {
+                return Err(tf(
+                    "error.retention_days",
+                    &[
+                        ("min", &DAYS_RANGE.start().to_string()),
+                        ("max", &DAYS_RANGE.end().to_string()),
+                        ("days", &days.to_string()),
+                    ],
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.delete_audio_after_days.is_none()
+            && self.delete_sessions_after_days.is_none()
+            && !self.purge_after_export
+    }
+}
+
+/// 삭제한 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionAction {
+    /// 녹음 파일만 삭제 (전사 유지)
+    Audio,
+    /// 세션 전체 삭제
+    Session,
+}
+
+impl RetentionAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Audio => "audio",
+            Self::Session => "session",
+        }
+    }
+
+    pub fn parse(action: &str) -> Self {
+        match action {
+            "audio" => Self::Audio,
+            _ => Self::Session,
+        }
+    }
+}
+
+/// 삭제한 이유 (적용된 정책)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionReason {
+    /// `delete_audio_after_days`
+    AudioAge,
+    /// `delete_sessions_after_days`
+    SessionAge,
+    /// `purge_after_export`
+    Exported,
+}
+
+impl RetentionReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AudioAge => "audio_age",
+            Self::SessionAge => "session_age",
+            Self::Exported => "exported",
+        }
+    }
+
+    pub fn parse(reason: &str) -> Self {
+        match reason {
+            "audio_age" => Self::AudioAge,
+            "exported" => Self::Exported,
+            _ => Self::SessionAge,
+        }
+    }
+}
+
+/// 정책을 적용할 세션
+#[derive(Debug, Clone)]
+pub struct RetentionCandidate {
+    pub id: String,
+    pub title: Option<String>,
+    pub ended_at: DateTime<Utc>,
+    pub audio_path: Option<String>,
+    pub exported_at: Option<DateTime<Utc>>,
+}
+
+/// 자동 삭제 기록 (감사용)
+#[derive(Debug, Clone, Serialize)]
+pub struct RetentionLogEntry {
+    pub id: i64,
+    pub deleted_at: DateTime<Utc>,
+    pub session_id: String,
+    pub session_title: Option<String>,
+    pub action: RetentionAction,
+    pub reason: RetentionReason,
+    /// 지운 녹음 파일 경로
+    pub audio_path: Option<String>,
+}
+
+/// 한 번 적용한 결과 (`retention-applied` 이벤트 페이로드)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RetentionResult {
+    pub audio_deleted: usize,
+    pub sessions_deleted: usize,
+}
+
+/// 정책 적용 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Retention {
+    /// 백그라운드 적용과 `run_retention_now`가 겹치지 않게
+    running: Mutex<()>,
+}
+
+/// 녹음 파일 삭제 (다른 세션도 쓰는 파일이면 남김)
+fn remove_audio(storage: &Storage, candidate: &RetentionCandidate) -> Result<(), String> {
+    let Some(path) = &candidate.audio_path else {
+        return Ok(());
+    };
+    if storage.audio_path_shared(path, &candidate.id)? {
+        return Ok(());
+    }
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("녹음 파일 삭제 실패: {} ({})", path, e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 세션 하나에 적용할 정책 (세션 삭제가 녹음 삭제보다 우선)
+fn decide(
+    settings: &RetentionSettings,
+    candidate: &RetentionCandidate,
+    now: DateTime<Utc>,
+) -> Option<(RetentionAction, RetentionReason)> {
+    let older_than = |days: Option<u32>| {
+        days.is_some_and(|days| candidate.ended_at < now - DateDuration::days(days.into()))
+    };
+    if settings.purge_after_export && candidate.exported_at.is_some() {
+        Some((RetentionAction::Session, RetentionReason::Exported))
+    } else if older_than(settings.delete_sessions_after_days) {
+        Some((RetentionAction::Session, RetentionReason::SessionAge))
+    } else if candidate.audio_path.is_some() && older_than(settings.delete_audio_after_days) {
+        Some((RetentionAction::Audio, RetentionReason::AudioAge))
+    } else {
+        None
+    }
+}
+
+/// 보존 정책 적용 (삭제할 때마다 감사 기록을 남김)
+fn run(app: &AppHandle) -> Result<RetentionResult, TeuImError> {
+    let settings = app.state::<SettingsStore>().get().retention;
+    let mut result = RetentionResult::default();
+    if settings.is_empty() {
+        return Ok(result);
+    }
+
+    let retention = app.state::<Retention>();
+    let _running = retention.running.lock().unwrap();
+    let storage = app.state::<Storage>();
+    let now = Utc::now();
+    for candidate in storage.retention_candidates()? {
+        let Some((action, reason)) = decide(&settings, &candidate, now) else {
+            continue;
+        };
+        if let Err(e) = remove_audio(&storage, &candidate) {
+            log::warn!("{}", e);
+            continue;
+        }
+        match action {
+            RetentionAction::Audio => {
+                storage.clear_audio_path(&candidate.id)?;
+                result.audio_deleted += 1;
+            }
+            RetentionAction::Session => {
+                storage.delete_session(&candidate.id)?;
+                result.sessions_deleted += 1;
+            }
+        }
+        storage.add_retention_log(&candidate, action, reason)?;
+        log::info!(
+            "보존 정책으로 삭제: {} ({}, {})",
+            candidate.id,
+            action.as_str(),
+            reason.as_str()
+        );
+    }
+
+    if result.audio_deleted > 0 || result.sessions_deleted > 0 {
+        let _ = app.emit("retention-applied", &result);
+    }
+    Ok(result)
+}
+
+fn run_logged(app: &AppHandle) {
+    match run(app) {
+        Ok(_) | Err(TeuImError::DatabaseLocked) => {}
+        Err(e) => log::warn!("보존 정책 적용 실패: {}", e),
+    }
+}
+
+/// 주기적으로 보존 정책 적용 (잠긴 데이터베이스는 건너뜀)
+pub fn spawn_enforcer(app: AppHandle) {
+    thread::spawn(move || {
+        thread::sleep(FIRST_RUN_DELAY);
+        loop {
+            run_logged(&app);
+            thread::sleep(RUN_INTERVAL);
+        }
+    });
+}
+
+/// 세션을 내보낸 뒤 호출 (내보낸 시각 기록, "내보낸 뒤 삭제"면 바로 적용)
+pub fn on_exported(app: &AppHandle, session_id: &str) {
+    if let Err(e) = app.state::<Storage>().mark_exported(session_id) {
+        log::warn!("내보낸 시각 기록 실패: {}", e);
+        return;
+    }
+    if app
+        .state::<SettingsStore>()
+        .get()
+        .retention
+        .purge_after_export
+    {
+        let app = app.clone();
+        thread::spawn(move || run_logged(&app));
+    }
+}
+
+/// 보존 정책 바로 적용
+#[tauri::command]
+pub async fn run_retention_now(app: AppHandle) -> Result<RetentionResult, TeuImError> {
+    tauri::async_runtime::spawn_blocking(move || run(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// 자동 삭제 기록 조회 (최근 것부터, 기본 200개)
+#[tauri::command]
+pub fn get_retention_log(
+    storage: State<'_, Storage>,
+    limit: Option<u32>,
+) -> Result<Vec<RetentionLogEntry>, TeuImError> {
+    storage.retention_log(limit.unwrap_or(DEFAULT_LOG_LIMIT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(days_ago: i64, audio: bool, exported: bool) -> RetentionCandidate {
+        let now = Utc::now();
+        RetentionCandidate {
+            id: "session".to_string(),
+            title: None,
+            ended_at: now - DateDuration::days(days_ago),
+            audio_path: audio.then(|| "recording.wav".to_string()),
+            exported_at: exported.then_some(now),
+        }
+    }
+
+    fn settings(audio_days: Option<u32>, session_days: Option<u32>) -> RetentionSettings {
+        RetentionSettings {
+            delete_audio_after_days: audio_days,
+            delete_sessions_after_days: session_days,
+            purge_after_export: false,
+        }
+    }
+
+    #[test]
+    fn keeps_everything_without_policy() {
+        let decision = decide(
+            &settings(None, None),
+            &candidate(1_000, true, true),
+            Utc::now(),
+        );
+        assert_eq!(decision, None);
+    }
+
+    #[test]
+    fn deletes_old_audio_only() {
+        let settings = settings(Some(30), None);
+        let now = Utc::now();
+        assert_eq!(
+            decide(&settings, &candidate(31, true, false), now),
+            Some((RetentionAction::Audio, RetentionReason::AudioAge))
+        );
+        assert_eq!(decide(&settings, &candidate(29, true, false), now), None);
+        // 녹음이 이미 없으면 할 일이 없음
+        assert_eq!(decide(&settings, &candidate(31, false, false), now), None);
+    }
+
+    #[test]
+    fn session_age_wins_over_audio_age() {
+        let decision = decide(
+            &settings(Some(30), Some(90)),
+            &candidate(100, true, false),
+            Utc::now(),
+        );
+        assert_eq!(
+            decision,
+            Some((RetentionAction::Session, RetentionReason::SessionAge))
+        );
+    }
+
+    #[test]
+    fn purges_exported_sessions_right_away() {
+        let settings = RetentionSettings {
+            purge_after_export: true,
+            ..Default::default()
+        };
+        let now = Utc::now();
+        assert_eq!(
+            decide(&settings, &candidate(0, true, true), now),
+            Some((RetentionAction::Session, RetentionReason::Exported))
+        );
+        assert_eq!(decide(&settings, &candidate(0, true, false), now), None);
+    }
+
+    #[test]
+    fn validates_day_range() {
+        assert!(settings(Some(1), Some(36_500)).validate().is_ok());
+        assert!(settings(Some(0), None).validate().is_err());
+        assert!(settings(None, Some(36_501)).validate().is_err());
+    }
+}
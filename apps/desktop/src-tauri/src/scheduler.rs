@@ -0,0 +1,528 @@
+use chrono::{DateTime, Duration as DateDuration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+use crate::audio::{self, CaptureManager};
+use crate::error::TeuImError;
+use crate::recording;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::stt::SttConfig;
+use crate::transcribe::{self, TranscriptionEngine, TranscriptionOptions};
+
+/// 예약 시각을 확인하는 간격
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 예약 길이 허용 범위 (초, 1분~24시간)
+pub const DURATION_SECS_RANGE: std::ops::RangeInclusive<u32> = 60..=86_400;
+
+/// 예약 녹음 옵션
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleOptions {
+    /// 세션 제목 (전사할 때만 쓰임)
+    pub title: Option<String>,
+    /// 실시간 전사 (결과는 세션으로 저장)
+    pub transcribe: bool,
+    /// 데이터 폴더의 recordings에 WAV로 녹음
+    pub record: bool,
+    /// 전사 언어 (예: ko, en)
+    pub language: Option<String>,
+}
+
+impl Default for ScheduleOptions {
+    fn default() -> Self {
+        Self {
+            title: None,
+            transcribe: true,
+            record: true,
+            language: None,
+        }
+    }
+}
+
+/// 예약 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleStatus {
+    /// 시작 시각을 기다리는 중
+    Pending,
+    /// 캡처 중
+    Running,
+    /// 정한 길이만큼 캡처를 마침
+    Completed,
+    /// 캡처를 시작하지 못함
+    Failed,
+    /// 앱이 꺼져 있어 예약 시간이 지나감
+    Missed,
+    Cancelled,
+}
+
+impl ScheduleStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Missed => "missed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(status: &str) -> Self {
+        match status {
+            "pending" => Self::Pending,
+            "running" => Self::Running,
+            "completed" => Self::Completed,
+            "missed" => Self::Missed,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// 예약 녹음
+#[derive(Debug, Clone, Serialize)]
+pub struct Schedule {
+    pub id: String,
+    pub start_time: DateTime<Utc>,
+    pub duration_secs: u32,
+    /// 캡처할 입력 장치 (`None`이면 시작할 때의 기본 장치)
+    pub device_id: Option<String>,
+    pub options: ScheduleOptions,
+    pub status: ScheduleStatus,
+    pub created_at: DateTime<Utc>,
+    /// 예약으로 만든 저장소 세션 (전사했을 때만)
+    pub session_id: Option<String>,
+    /// 실패 이유
+    pub error: Option<String>,
+    /// 캘린더 회의로 만든 예약이면 회의 ID
+    pub calendar_event: Option<String>,
+}
+
+impl Schedule {
+    pub fn end_time(&self) -> DateTime<Utc> {
+        self.start_time + DateDuration::seconds(self.duration_secs.into())
+    }
+}
+
+/// 예약 시작·종료 이벤트 페이로드 (`schedule-started`, `schedule-finished`)
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleEvent {
+    pub schedule_id: String,
+    pub status: ScheduleStatus,
+    pub session_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// 진행 중인 예약이 시작한 것들 (끝날 때 그대로 멈춤)
+struct RunningSchedule {
+    capture_id: String,
+    end_time: DateTime<Utc>,
+    session_id: Option<String>,
+    transcribing: bool,
+    recording_path: Option<PathBuf>,
+}
+
+/// 예약 녹음 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Scheduler {
+    running: Mutex<HashMap<String, RunningSchedule>>,
+}
+
+fn emit(app: &AppHandle, event: &str, schedule_id: &str, status: ScheduleStatus) {
+    let _ = app.emit(
+        event,
+        ScheduleEvent {
+            schedule_id: schedule_id.to_string(),
+            status,
+            session_id: None,
+            error: None,
+        },
+    );
+}
+
+/// 캡처 세션에 전사·녹음 연결
+async fn begin(
+    app: &AppHandle,
+    schedule: &Schedule,
+    run: &mut RunningSchedule,
+) -> Result<(), TeuImError> {
+    if schedule.options.transcribe {
+        crate::cli::ensure_model(app, None).await?;
+        let options = TranscriptionOptions {
+            session_id: Some(run.capture_id.clone()),
+            language: schedule.options.language.clone(),
+            ..Default::default()
+        };
+        let session_id = transcribe::start_transcription(
+            app.clone(),
+            app.state::<TranscriptionEngine>(),
+            app.state::<CaptureManager>(),
+            app.state::<Storage>(),
+            app.state::<SttConfig>(),
+            Some(options),
+        )?;
+        run.transcribing = true;
+        if let Some(title) = &schedule.options.title {
+            app.state::<Storage>()
+                .set_session_title(&session_id, title)?;
+        }
+        run.session_id = Some(session_id);
+    }
+
+    if schedule.options.record {
+        let status = recording::start_recording(
+            app.clone(),
+            app.state::<CaptureManager>(),
+            app.state::<Storage>(),
+            format!("schedule-{}.wav", schedule.id),
+            None,
+            Some(run.capture_id.clone()),
+            run.session_id.clone(),
+        )?;
+        run.recording_path = Some(PathBuf::from(status.path));
+    }
+    Ok(())
+}
+
+/// 예약 캡처 시작
+async fn launch(app: &AppHandle, schedule: &Schedule) -> Result<(), TeuImError> {
+    let device_id = schedule
+        .device_id
+        .clone()
+        .or_else(|| app.state::<SettingsStore>().get().default_device_id)
+        .unwrap_or_else(|| "default".to_string());
+    let capture_id = audio::start_audio_capture(
+        app.clone(),
+        app.state::<CaptureManager>(),
+        device_id,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let mut run = RunningSchedule {
+        capture_id,
+        end_time: schedule.end_time(),
+        session_id: None,
+        transcribing: false,
+        recording_path: None,
+    };
+    if let Err(e) = begin(app, schedule, &mut run).await {
+        halt(app, &run);
+        return Err(e);
+    }
+
+    log::info!("예약 녹음 시작: {} ({})", schedule.id, run.capture_id);
+    let session_id = run.session_id.clone();
+    let scheduler = app.state::<Scheduler>();
+    scheduler
+        .running
+        .lock()
+        .unwrap()
+        .insert(schedule.id.clone(), run);
+    app.state::<Storage>().set_schedule_status(
+        &schedule.id,
+        ScheduleStatus::Running,
+        session_id.as_deref(),
+        None,
+    )?;
+    let _ = app.emit(
+        "schedule-started",
+        ScheduleEvent {
+            schedule_id: schedule.id.clone(),
+            status: ScheduleStatus::Running,
+            session_id,
+            error: None,
+        },
+    );
+    crate::tray::refresh(app);
+    Ok(())
+}
+
+/// 예약이 시작한 전사·녹음·캡처 중지 (사용자가 먼저 멈춘 것은 건너뜀)
+fn halt(app: &AppHandle, run: &RunningSchedule) {
+    let manager = app.state::<CaptureManager>();
+    if run.transcribing {
+        if let Err(e) = transcribe::stop_transcription(
+            app.clone(),
+            app.state::<TranscriptionEngine>(),
+            manager.clone(),
+        ) {
+            log::warn!("예약 전사 중지 실패: {}", e);
+        }
+    }
+    if run.recording_path.is_some() && manager.recorder().path() == run.recording_path {
+        if let Err(e) = recording::stop_recording(app.clone(), manager.clone()) {
+            log::warn!("예약 녹음 중지 실패: {}", e);
+        }
+    }
+    match manager.stop(&run.capture_id) {
+        Ok(()) | Err(TeuImError::CaptureSessionNotFound { .. }) => {}
+        Err(e) => log::warn!("예약 캡처 중지 실패: {}", e),
+    }
+    crate::tray::refresh(app);
+}
+
+/// 진행 중인 예약을 멈추고 상태 기록
+fn finish(app: &AppHandle, id: &str, run: RunningSchedule, status: ScheduleStatus) {
+    halt(app, &run);
+    if let Err(e) = app
+        .state::<Storage>()
+        .set_schedule_status(id, status, None, None)
+    {
+        log::warn!("예약 상태 기록 실패: {}", e);
+    }
+    log::info!("예약 녹음 종료: {} ({})", id, status.as_str());
+    let _ = app.emit(
+        "schedule-finished",
+        ScheduleEvent {
+            schedule_id: id.to_string(),
+            status,
+            session_id: run.session_id,
+            error: None,
+        },
+    );
+}
+
+/// 끝날 시각이 된 예약을 마치고 시작 시각이 된 예약 시작
+async fn tick(app: &AppHandle) -> Result<(), TeuImError> {
+    let now = Utc::now();
+    let ended: Vec<_> = {
+        let scheduler = app.state::<Scheduler>();
+        let mut running = scheduler.running.lock().unwrap();
+        let ids: Vec<_> = running
+            .iter()
+            .filter(|(_, run)| run.end_time <= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.into_iter()
+            .filter_map(|id| running.remove(&id).map(|run| (id, run)))
+            .collect()
+    };
+    for (id, run) in ended {
+        finish(app, &id, run, ScheduleStatus::Completed);
+    }
+
+    let due: Vec<_> = app
+        .state::<Storage>()
+        .schedules()?
+        .into_iter()
+        .filter(|s| s.status == ScheduleStatus::Pending && s.start_time <= now)
+        .collect();
+    for schedule in due {
+        // 앱이 꺼져 있던 동안 지나간 예약은 남은 시간만큼만 캡처
+        if schedule.end_time() <= now {
+            app.state::<Storage>().set_schedule_status(
+                &schedule.id,
+                ScheduleStatus::Missed,
+                None,
+                None,
+            )?;
+            log::warn!("예약 시간이 지나 건너뜀: {}", schedule.id);
+            emit(
+                app,
+                "schedule-finished",
+                &schedule.id,
+                ScheduleStatus::Missed,
+            );
+            continue;
+        }
+        if let Err(e) = launch(app, &schedule).await {
+            log::error!("예약 녹음 시작 실패: {} ({})", schedule.id, e);
+            let error = e.to_string();
+            app.state::<Storage>().set_schedule_status(
+                &schedule.id,
+                ScheduleStatus::Failed,
+                None,
+                Some(&error),
+            )?;
+            let _ = app.emit(
+                "schedule-finished",
+                ScheduleEvent {
+                    schedule_id: schedule.id.clone(),
+                    status: ScheduleStatus::Failed,
+                    session_id: None,
+                    error: Some(error),
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+/// 앱이 캡처 중에 꺼졌던 예약을 실패로 기록
+fn recover(app: &AppHandle) -> Result<(), TeuImError> {
+    let storage = app.state::<Storage>();
+    for schedule in storage.schedules()? {
+        if schedule.status == ScheduleStatus::Running {
+            storage.set_schedule_status(
+                &schedule.id,
+                ScheduleStatus::Failed,
+                None,
+                Some("앱이 종료되어 예약 녹음이 중단됨"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// 예약 시각마다 캡처를 시작하고 정한 길이가 지나면 중지 (잠긴 데이터베이스는 건너뜀)
+pub fn spawn_runner(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut recovered = false;
+        loop {
+            let result = match recovered {
+                true => tick(&app).await,
+                false => recover(&app).map(|()| recovered = true),
+            };
+            match result {
+                Ok(()) | Err(TeuImError::DatabaseLocked) => {}
+                Err(e) => log::warn!("예약 녹음 확인 실패: {}", e),
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// 예약 확인 후 저장
+pub(crate) fn add(
+    storage: &Storage,
+    start_time: DateTime<Utc>,
+    duration_secs: u32,
+    device_id: Option<String>,
+    mut options: ScheduleOptions,
+    calendar_event: Option<String>,
+) -> Result<Schedule, TeuImError> {
+    if !DURATION_SECS_RANGE.contains(&duration_secs) {
+        return Err(TeuImError::invalid_argument(format!(
+            "예약 길이는 {}~{}초여야 합니다: {}",
+            DURATION_SECS_RANGE.start(),
+            DURATION_SECS_RANGE.end(),
+            duration_secs
+        )));
+    }
+    if !options.transcribe && !options.record {
+        return Err(TeuImError::invalid_argument(
+            "전사와 녹음 중 하나는 켜야 합니다",
+        ));
+    }
+    options.title = options
+        .title
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty());
+
+    let schedule = Schedule {
+        id: Uuid::new_v4().to_string(),
+        start_time,
+        duration_secs,
+        device_id: device_id.filter(|id| !id.is_empty()),
+        options,
+        status: ScheduleStatus::Pending,
+        created_at: Utc::now(),
+        session_id: None,
+        error: None,
+        calendar_event,
+    };
+    if schedule.end_time() <= Utc::now() {
+        return Err(TeuImError::invalid_argument(
+            "이미 지난 시간은 예약할 수 없습니다",
+        ));
+    }
+    storage.add_schedule(&schedule)?;
+    log::info!(
+        "녹음 예약: {} ({}, {}초)",
+        schedule.id,
+        schedule.start_time,
+        duration_secs
+    );
+    Ok(schedule)
+}
+
+/// 녹음 예약 (`start_time`에 `device_id` 장치로 `duration_secs`초 동안 캡처)
+///
+/// 옵션을 생략하면 전사와 녹음을 모두 한다. 앱이 실행 중이어야 시작된다.
+#[tauri::command]
+pub fn schedule_recording(
+    storage: State<'_, Storage>,
+    start_time: DateTime<Utc>,
+    duration_secs: u32,
+    device_id: Option<String>,
+    options: Option<ScheduleOptions>,
+) -> Result<Schedule, TeuImError> {
+    add(
+        &storage,
+        start_time,
+        duration_secs,
+        device_id,
+        options.unwrap_or_default(),
+        None,
+    )
+}
+
+/// 예약 목록 (시작 시각 순, 끝난 예약 포함)
+#[tauri::command]
+pub fn list_schedules(storage: State<'_, Storage>) -> Result<Vec<Schedule>, TeuImError> {
+    storage.schedules()
+}
+
+/// 예약 취소 (캡처 중이면 바로 중지)
+#[tauri::command]
+pub fn cancel_schedule(app: AppHandle, schedule_id: String) -> Result<Schedule, TeuImError> {
+    let storage = app.state::<Storage>();
+    let find = || {
+        storage
+            .schedules()?
+            .into_iter()
+            .find(|s| s.id == schedule_id)
+            .ok_or_else(|| {
+                TeuImError::invalid_argument(format!("예약을 찾을 수 없습니다: {}", schedule_id))
+            })
+    };
+
+    let schedule = find()?;
+    match schedule.status {
+        ScheduleStatus::Pending => {
+            storage.set_schedule_status(&schedule_id, ScheduleStatus::Cancelled, None, None)?;
+            log::info!("예약 취소: {}", schedule_id);
+            emit(
+                &app,
+                "schedule-finished",
+                &schedule_id,
+                ScheduleStatus::Cancelled,
+            );
+        }
+        ScheduleStatus::Running => {
+            let run = app
+                .state::<Scheduler>()
+                .running
+                .lock()
+                .unwrap()
+                .remove(&schedule_id);
+            match run {
+                Some(run) => finish(&app, &schedule_id, run, ScheduleStatus::Cancelled),
+                None => storage.set_schedule_status(
+                    &schedule_id,
+                    ScheduleStatus::Cancelled,
+                    None,
+                    None,
+                )?,
+            }
+        }
+        _ => {
+            return Err(TeuImError::invalid_argument(format!(
+                "이미 끝난 예약입니다: {}",
+                schedule_id
+            )))
+        }
+    }
+    find()
+}
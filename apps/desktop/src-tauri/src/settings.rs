@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, State};
+
+use crate::audio::{CHUNK_MS_RANGE, SILENCE_WARNING_SECS_RANGE};
+use crate::calendar::CalendarSource;
+use crate::compute::ComputeSettings;
+use crate::disk::DiskGuardSettings;
+use crate::encryption::EncryptionSettings;
+use crate::hotkeys::HotkeyAction;
+use crate::i18n::Locale;
+use crate::jobs::JOB_WORKERS_RANGE;
+use crate::llm::LlmConfig;
+use crate::midi::MIDI_NOTE_RANGE;
+use crate::notifications::NotificationSettings;
+use crate::performance::PerformanceSettings;
+use crate::peripherals::PedalSettings;
+use crate::ptt::PushToTalkSettings;
+use crate::recording::PREROLL_SECONDS_RANGE;
+use crate::retention::RetentionSettings;
+use crate::sync::SyncSettings;
+use crate::telemetry::TelemetrySettings;
+use crate::updater::UpdateChannel;
+
+/// 화면 테마
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// 사용자 설정 (`settings-changed` 이벤트 페이로드)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// 기본 입력 장치 ID
+    pub default_device_id: Option<String>,
+    /// 번역 대상 언어 코드
+    pub target_language: Option<String>,
+    /// 시작할 때 로드할 Whisper 모델 경로
+    pub model_path: Option<String>,
+    /// 오디오 청크 길이 (ms, 생략하면 캡처 기본값)
+    pub chunk_ms: Option<u32>,
+    /// 일괄 전사에서 동시에 처리할 파일 수 (생략하면 1)
+    pub job_workers: Option<u32>,
+    /// 대기 중인 캡처에서 녹음 앞에 붙일 길이 (초, 생략하면 5초)
+    pub preroll_seconds: Option<u32>,
+    /// 마이크 입력이 이 시간(초) 넘게 무음이면 경고 (생략하면 30초)
+    pub silence_warning_secs: Option<u32>,
+    pub theme: Theme,
+    /// 동작별 전역 단축키
+    pub hotkeys: BTreeMap<HotkeyAction, String>,
+    /// 캡처·전사 중에도 화면 꺼짐과 시스템 절전 허용 (기본은 막음)
+    pub allow_sleep: bool,
+    /// 백엔드 문자열 표시 언어 (생략하면 시스템 언어)
+    pub locale: Option<Locale>,
+    /// 데스크톱 알림
+    pub notifications: NotificationSettings,
+    /// 녹음 저장 공간 경고·중지 한도
+    pub disk_guard: DiskGuardSettings,
+    /// 세션 데이터베이스와 녹음 폴더 위치 (생략하면 앱 데이터 폴더)
+    pub data_dir: Option<String>,
+    /// 요약·제목 제안·번역 다듬기에 쓸 LLM 제공자
+    pub llm: Option<LlmConfig>,
+    /// 로컬 추론 연산 장치 (CPU/GPU)와 스레드 수
+    pub compute: ComputeSettings,
+    /// 배터리·과열 시 자동 성능 절약
+    pub performance: PerformanceSettings,
+    /// 자동 업데이트 채널
+    pub update_channel: UpdateChannel,
+    /// 익명 사용 통계 (기본은 꺼짐)
+    pub telemetry: TelemetrySettings,
+    /// 사용자가 동의한 충돌 보고서를 받을 주소
+    pub crash_report_endpoint: Option<String>,
+    /// 데이터베이스·녹음 암호화
+    pub encryption: EncryptionSettings,
+    /// 오래된 녹음·세션 자동 삭제
+    pub retention: RetentionSettings,
+    /// 다른 기기와 세션 동기화
+    pub sync: SyncSettings,
+    /// 회의를 읽어 올 캘린더
+    pub calendars: Vec<CalendarSource>,
+    /// Zoom·Teams·Meet 통화 감지 끄기 (기본은 감지해 캡처를 제안)
+    pub ignore_meeting_apps: bool,
+    /// 키를 누르고 있는 동안만 마이크 캡처
+    pub push_to_talk: PushToTalkSettings,
+    /// 전사용 페달 버튼별 동작
+    pub pedal: PedalSettings,
+    /// MIDI 노트별 단축키 동작
+    pub midi_bindings: BTreeMap<u8, HotkeyAction>,
+    /// 실시간 전사가 끝난 세션을 다시 전사할 모델 (이름이나 경로, 생략하면 하지 않음)
+    pub refine_model: Option<String>,
+}
+
+impl Settings {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(chunk_ms) = self.chunk_ms {
+            if !CHUNK_MS_RANGE.contains(&chunk_ms) {
+                return Err(format!(
+                    "청크 길이는 {}~{}ms 사이여야 합니다: {}",
+                    CHUNK_MS_RANGE.start(),
+                    CHUNK_MS_RANGE.end(),
+                    chunk_ms
+                ));
+            }
+        }
+        if let Some(workers) = self.job_workers {
+            if !JOB_WORKERS_RANGE.contains(&workers) {
+                return Err(format!(
+                    "동시 작업 수는 {}~{} 사이여야 합니다: {}",
+                    JOB_WORKERS_RANGE.start(),
+                    JOB_WORKERS_RANGE.end(),
+                    workers
+                ));
+            }
+        }
+        if let Some(seconds) = self.preroll_seconds {
+            if !PREROLL_SECONDS_RANGE.contains(&seconds) {
+                return Err(format!(
+                    "프리롤 길이는 {}~{}초 사이여야 합니다: {}",
+                    PREROLL_SECONDS_RANGE.start(),
+                    PREROLL_SECONDS_RANGE.end(),
+                    seconds
+                ));
+            }
+        }
+        if let Some(seconds) = self.silence_warning_secs {
+            if !SILENCE_WARNING_SECS_RANGE.contains(&seconds) {
+                return Err(format!(
+                    "무음 경고 시간은 {}~{}초 사이여야 합니다: {}",
+                    SILENCE_WARNING_SECS_RANGE.start(),
+                    SILENCE_WARNING_SECS_RANGE.end(),
+                    seconds
+                ));
+            }
+        }
+        self.disk_guard.validate()?;
+        self.compute.validate()?;
+        self.performance.validate()?;
+        self.telemetry.validate()?;
+        self.retention.validate()?;
+        self.sync.validate()?;
+        self.pedal.validate()?;
+        if let Some(note) = self
+            .midi_bindings
+            .keys()
+            .find(|note| !MIDI_NOTE_RANGE.contains(note))
+        {
+            return Err(format!(
+                "MIDI 노트 번호는 {}~{} 사이여야 합니다: {}",
+                MIDI_NOTE_RANGE.start(),
+                MIDI_NOTE_RANGE.end(),
+                note
+            ));
+        }
+        self.push_to_talk.validate(&self.pedal)?;
+        for calendar in &self.calendars {
+            calendar.validate()?;
+        }
+        if let Some(endpoint) = &self.crash_report_endpoint {
+            let url = reqwest::Url::parse(endpoint)
+                .map_err(|e| format!("잘못된 충돌 보고서 주소: {} ({})", endpoint, e))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err(format!(
+                    "충돌 보고서 주소는 http(s)여야 합니다: {}",
+                    endpoint
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 설정 파일 저장소 (Tauri 관리 상태)
+pub struct SettingsStore {
+    path: PathBuf,
+    current: Mutex<Settings>,
+}
+
+impl SettingsStore {
+    /// 설정 파일 로드 (없거나 읽을 수 없으면 기본값)
+    pub fn load(path: &Path) -> Self {
+        let settings = match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                log::warn!("설정 파일이 손상되어 기본값 사용: {}", e);
+                Settings::default()
+            }),
+            Err(_) => Settings::default(),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            current: Mutex::new(settings),
+        }
+    }
+
+    pub fn get(&self) -> Settings {
+        self.current.lock().unwrap().clone()
+    }
+
+    /// 일부 항목만 담긴 JSON을 현재 설정에 덮어써 저장
+    fn update(&self, patch: serde_json::Value) -> Result<Settings, String> {
+        let serde_json::Value::Object(mut patch) = patch else {
+            return Err("설정은 객체여야 합니다".to_string());
+        };
+        // 단축키는 실제 등록과 함께 바뀌어야 하므로 set_hotkey/clear_hotkey로만 변경
+        patch.remove("hotkeys");
+        // 데이터 폴더는 파일을 옮겨야 하므로 set_data_directory로만 변경
+        patch.remove("data_dir");
+        // LLM 제공자는 키 확인과 함께 바뀌어야 하므로 configure_llm으로만 변경
+        patch.remove("llm");
+        // 암호화는 키 생성·재암호화와 함께 바뀌어야 하므로 enable_encryption/disable_encryption으로만 변경
+        patch.remove("encryption");
+        // 동기화 저장소는 키체인의 비밀 값과 함께 바뀌어야 하므로 set_sync_config로만 변경
+        patch.remove("sync");
+        // 캘린더도 키체인의 암호·토큰과 함께 바뀌어야 하므로 set_calendar/remove_calendar로만 변경
+        patch.remove("calendars");
+
+        let mut current = self.current.lock().unwrap();
+        let mut merged = serde_json::to_value(&*current).map_err(|e| e.to_string())?;
+        if let serde_json::Value::Object(fields) = &mut merged {
+            fields.extend(patch);
+        }
+
+        let settings: Settings =
+            serde_json::from_value(merged).map_err(|e| format!("잘못된 설정: {}", e))?;
+        settings.validate()?;
+        self.save(&settings)?;
+
+        *current = settings.clone();
+        Ok(settings)
+    }
+
+    /// 현재 설정을 코드에서 직접 수정해 저장
+    pub fn modify(&self, f: impl FnOnce(&mut Settings)) -> Result<Settings, String> {
+        let mut current = self.current.lock().unwrap();
+        let mut settings = current.clone();
+        f(&mut settings);
+        settings.validate()?;
+        self.save(&settings)?;
+
+        *current = settings.clone();
+        Ok(settings)
+    }
+
+    fn reset(&self) -> Result<Settings, String> {
+        let mut current = self.current.lock().unwrap();
+        // 데이터 폴더와 암호화 설정은 초기화하면 세션을 찾거나 열 수 없게 되므로 유지
+        let settings = Settings {
+            data_dir: current.data_dir.clone(),
+            encryption: current.encryption.clone(),
+            ..Settings::default()
+        };
+        self.save(&settings)?;
+        *current = settings.clone();
+        Ok(settings)
+    }
+
+    /// 임시 파일에 쓴 뒤 교체해 도중에 꺼져도 파일이 깨지지 않게 함
+    fn save(&self, settings: &Settings) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("설정 폴더 생성 실패: {}", e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, contents).map_err(|e| format!("설정 저장 실패: {}", e))?;
+        std::fs::rename(&tmp_path, &self.path).map_err(|e| format!("설정 저장 실패: {}", e))?;
+        Ok(())
+    }
+}
+
+/// 현재 설정 조회
+#[tauri::command]
+pub fn get_settings(store: State<'_, SettingsStore>) -> Settings {
+    store.get()
+}
+
+/// 설정 일부 변경 (바뀐 설정은 모든 창에 `settings-changed`로 알림)
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    patch: serde_json::Value,
+) -> Result<Settings, String> {
+    let settings = store.update(patch)?;
+    // 동시 작업 수가 늘었으면 대기 중인 작업을 바로 시작
+    crate::jobs::pump(&app);
+    crate::power::refresh(&app);
+    crate::i18n::apply(&app, settings.locale);
+    crate::telemetry::apply(&app, &settings.telemetry);
+    crate::ptt::apply(&app, &settings.push_to_talk);
+    crate::peripherals::refresh(&app);
+    let _ = app.emit("settings-changed", &settings);
+    Ok(settings)
+}
+
+/// 설정 초기화
+#[tauri::command]
+pub fn reset_settings(app: AppHandle, store: State<'_, SettingsStore>) -> Result<Settings, String> {
+    let settings = store.reset()?;
+    crate::hotkeys::unregister_all(&app);
+    crate::llm::init(&app, settings.llm.as_ref());
+    crate::power::refresh(&app);
+    crate::i18n::apply(&app, settings.locale);
+    crate::telemetry::apply(&app, &settings.telemetry);
+    crate::ptt::apply(&app, &settings.push_to_talk);
+    crate::peripherals::refresh(&app);
+    let _ = app.emit("settings-changed", &settings);
+    log::info!("설정 초기화됨");
+    Ok(settings)
+}
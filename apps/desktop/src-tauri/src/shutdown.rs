@@ -0,0 +1,89 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use tauri::{AppHandle, Manager, RunEvent, WindowEvent};
+
+use crate::audio::CaptureManager;
+use crate::transcribe::TranscriptionEngine;
+
+/// 종료 전 정리 진행 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Shutdown {
+    started: AtomicBool,
+    finished: AtomicBool,
+}
+
+/// 앱 이벤트 루프 콜백
+///
+/// 캡처·전사·녹음 중에 종료 요청이 오면 종료를 미루고, 별도 스레드에서 스트림을
+/// 멈추고 남은 오디오를 확정·저장한 뒤 다시 종료한다. 정리를 건너뛴 채
+/// 프로세스가 끝나면 녹음 파일 헤더와 DB 행이 중간 상태로 남는다.
+pub fn on_run_event(app: &AppHandle, event: RunEvent) {
+    match event {
+        RunEvent::ExitRequested { code, api, .. } => {
+            let shutdown = app.state::<Shutdown>();
+            if shutdown.finished.load(Ordering::SeqCst) || !is_busy(app) {
+                return;
+            }
+            api.prevent_exit();
+            if shutdown.started.swap(true, Ordering::SeqCst) {
+                return;
+            }
+
+            let app = app.clone();
+            thread::spawn(move || {
+                finish(&app);
+                app.state::<Shutdown>()
+                    .finished
+                    .store(true, Ordering::SeqCst);
+                app.exit(code.unwrap_or(0));
+            });
+        }
+        // 메인 창이 닫히면 자막 오버레이 같은 보조 창이 남아 있어도 종료
+        RunEvent::WindowEvent {
+            label,
+            event: WindowEvent::Destroyed,
+            ..
+        } if label == "main" => app.exit(0),
+        // 종료를 미룰 수 없는 경로로 끝나는 경우를 위한 마지막 정리
+        RunEvent::Exit => {
+            if !app.state::<Shutdown>().started.swap(true, Ordering::SeqCst) {
+                finish(app);
+            }
+            crate::telemetry::end_session(app);
+        }
+        _ => {}
+    }
+}
+
+/// 정리가 필요한 작업이 실행 중인지
+pub(crate) fn is_busy(app: &AppHandle) -> bool {
+    let manager = app.state::<CaptureManager>();
+    !manager.list().is_empty()
+        || manager.recorder().is_recording()
+        || app.state::<TranscriptionEngine>().is_running()
+}
+
+/// 캡처 중지 → 전사 마무리 → 녹음 파일 마무리 순으로 정리
+///
+/// 캡처를 먼저 멈춰야 버스 소비자가 밀린 샘플을 전사·녹음으로 모두 넘긴다.
+/// 전사 작업자는 멈출 때 남은 오디오를 확정하고 저장소 세션을 마무리한다.
+fn finish(app: &AppHandle) {
+    log::info!("종료 전 캡처 정리 시작");
+    let manager = app.state::<CaptureManager>();
+    manager.stop_all();
+
+    if app
+        .state::<TranscriptionEngine>()
+        .stop(&manager.transcription_feed())
+    {
+        log::info!("종료 전 전사 중지됨");
+    }
+
+    let recorder = manager.recorder();
+    if recorder.is_recording() {
+        if let Err(e) = recorder.stop() {
+            log::error!("종료 전 녹음 마무리 실패: {}", e);
+        }
+    }
+    log::info!("종료 전 캡처 정리 완료");
+}
@@ -0,0 +1,2653 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use tauri::State;
+use uuid::Uuid;
+
+use crate::audio::{CaptureSource, SessionAudioStats};
+use crate::bundle::{BundleManifest, BundleTranscript};
+use crate::error::TeuImError;
+use crate::glossary::{GlossaryTerm, GlossaryTermInput};
+use crate::refine::RefineStatus;
+use crate::retention::{RetentionAction, RetentionCandidate, RetentionLogEntry, RetentionReason};
+use crate::scheduler::{Schedule, ScheduleOptions, ScheduleStatus};
+use crate::sync::SyncState;
+use crate::timecode::FrameRate;
+use crate::transcribe::{TranscriptSegment, TranscriptWord};
+
+/// 순서대로 적용되는 스키마 마이그레이션 (`PRAGMA user_version`으로 적용 여부 관리)
+const MIGRATIONS: &[&str] = &[
+    include_str!("storage/migrations/001_initial_schema.sql"),
+    include_str!("storage/migrations/002_segment_speaker.sql"),
+    include_str!("storage/migrations/003_session_audio.sql"),
+    include_str!("storage/migrations/004_segment_search.sql"),
+    include_str!("storage/migrations/005_glossary.sql"),
+    include_str!("storage/migrations/006_segment_revisions.sql"),
+    include_str!("storage/migrations/007_segment_confidence.sql"),
+    include_str!("storage/migrations/008_word_timestamps.sql"),
+    include_str!("storage/migrations/009_session_marks.sql"),
+    include_str!("storage/migrations/010_session_organization.sql"),
+    include_str!("storage/migrations/011_import_fingerprint.sql"),
+    include_str!("storage/migrations/012_media_alignment.sql"),
+    include_str!("storage/migrations/013_retention.sql"),
+    include_str!("storage/migrations/014_sync_state.sql"),
+    include_str!("storage/migrations/015_schedules.sql"),
+    include_str!("storage/migrations/016_calendar_schedules.sql"),
+    include_str!("storage/migrations/017_session_audio_stats.sql"),
+    include_str!("storage/migrations/018_refined_transcripts.sql"),
+];
+
+/// 세션 요약
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionSummary {
+    pub id: String,
+    pub title: Option<String>,
+    pub device_name: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_ms: i64,
+    /// 연결된 녹음 파일 (재생용)
+    pub audio_path: Option<String>,
+    pub segment_count: i64,
+    /// 속한 프로젝트 (폴더)
+    pub project_id: Option<String>,
+    /// 태그 (가나다순)
+    pub tags: Vec<String>,
+}
+
+/// 세션 목록 조건 (모든 조건을 만족하는 세션만)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SessionQuery {
+    /// 제목에 포함된 문자열
+    pub text: Option<String>,
+    pub project_id: Option<String>,
+    /// 이 태그를 모두 가진 세션만 (대소문자 무시)
+    pub tags: Vec<String>,
+    /// 이 시각 이후에 시작한 세션만
+    pub started_after: Option<DateTime<Utc>>,
+    /// 이 시각 이전에 시작한 세션만
+    pub started_before: Option<DateTime<Utc>>,
+}
+
+/// 세션을 묶는 프로젝트 (폴더)
+#[derive(Debug, Clone, Serialize)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub session_count: i64,
+}
+
+/// 사용 중인 태그와 세션 수
+#[derive(Debug, Clone, Serialize)]
+pub struct TagCount {
+    pub tag: String,
+    pub session_count: i64,
+}
+
+/// 구간 번역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Translation {
+    pub language: String,
+    pub text: String,
+}
+
+/// 저장된 전사 구간
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredSegment {
+    pub id: String,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    /// 인식 확신도 (0~1)
+    pub confidence: Option<f32>,
+    pub words: Vec<TranscriptWord>,
+    pub translations: Vec<Translation>,
+}
+
+/// 세션 상세 (구간 포함)
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionDetail {
+    #[serde(flatten)]
+    pub session: SessionSummary,
+    pub segments: Vec<StoredSegment>,
+    /// 타임라인 구간 표시 (시작 시간순)
+    pub marks: Vec<SessionMark>,
+}
+
+/// 타임라인 구간 표시 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkKind {
+    /// 마이크를 음소거한 구간
+    Mute,
+    /// 입력이 클리핑된 구간 (전사를 믿기 어려울 수 있음)
+    Clipping,
+}
+
+impl MarkKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Mute => "mute",
+            Self::Clipping => "clipping",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "mute" => Some(Self::Mute),
+            "clipping" => Some(Self::Clipping),
+            _ => None,
+        }
+    }
+}
+
+/// 세션 타임라인의 구간 표시
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionMark {
+    pub id: String,
+    pub kind: MarkKind,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// 따로 녹화한 영상과의 타임코드 정렬
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaAlignment {
+    pub session_id: String,
+    pub media_path: String,
+    /// 전사 0ms가 놓이는 영상 타임코드
+    pub offset: String,
+    pub frame_rate: FrameRate,
+    /// `offset`을 실제 시간으로 바꾼 값 (ms)
+    pub offset_ms: i64,
+}
+
+/// 세션이 끝난 뒤 녹음을 다시 전사한 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct RefinedTranscript {
+    pub id: String,
+    pub session_id: String,
+    /// 모델 목록의 이름이나 파일 경로
+    pub model: String,
+    pub status: RefineStatus,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 목록 조회에서는 비어 있음
+    pub segments: Vec<RefinedSegment>,
+}
+
+/// 다시 전사한 구간
+#[derive(Debug, Clone, Serialize)]
+pub struct RefinedSegment {
+    pub id: String,
+    /// 시간이 가장 많이 겹치는 실시간 구간 (없으면 `None`)
+    pub segment_id: Option<String>,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub confidence: Option<f32>,
+    pub words: Vec<TranscriptWord>,
+}
+
+/// 전사 버전 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionKind {
+    /// 실시간 전사 결과 (편집하기 전 원본)
+    Live,
+    /// 세션이 끝난 뒤 다시 전사한 결과
+    Refined,
+    /// 사람이 편집한 현재 상태
+    Edited,
+}
+
+/// 세션의 전사 버전
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptVersion {
+    /// `live:<세션 ID>`, `edited:<세션 ID>`, `refined:<다시 전사 ID>`
+    pub id: String,
+    pub session_id: String,
+    pub kind: VersionKind,
+    /// 다시 전사에 쓴 모델 (다시 전사 버전만)
+    pub model: Option<String>,
+    /// 다시 전사 상태 (다시 전사 버전만)
+    pub status: Option<RefineStatus>,
+    /// 만든 시각 (편집 버전은 마지막으로 편집한 시각)
+    pub created_at: DateTime<Utc>,
+    pub segment_count: i64,
+}
+
+/// 전사 버전의 구간
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionSegment {
+    pub id: String,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    /// 단어별 결과 (실시간 버전에서 편집한 구간은 비어 있음)
+    pub words: Vec<TranscriptWord>,
+}
+
+/// 구간 리비전 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionKind {
+    /// 처음 편집하기 전의 ASR 결과
+    Original,
+    Edit,
+    Split,
+    Merge,
+    /// 실행 취소·다시 실행으로 되돌린 상태
+    Restore,
+}
+
+impl RevisionKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Original => "original",
+            Self::Edit => "edit",
+            Self::Split => "split",
+            Self::Merge => "merge",
+            Self::Restore => "restore",
+        }
+    }
+
+    fn parse(kind: &str) -> Self {
+        match kind {
+            "edit" => Self::Edit,
+            "split" => Self::Split,
+            "merge" => Self::Merge,
+            "restore" => Self::Restore,
+            _ => Self::Original,
+        }
+    }
+}
+
+/// 구간의 한 시점 상태
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentRevision {
+    pub id: i64,
+    pub segment_id: String,
+    pub kind: RevisionKind,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 실행 취소용으로 보관하는 구간 행 전체 상태 (병합된 구간 포함)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentSnapshot {
+    pub id: String,
+    pub session_id: String,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub merged_into: Option<String>,
+    pub confidence: Option<f32>,
+    pub words: Vec<TranscriptWord>,
+}
+
+/// 전사 검색 조건
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SearchFilters {
+    pub session_id: Option<String>,
+    pub speaker: Option<String>,
+    /// 이 시각 이후에 시작한 세션만
+    pub started_after: Option<DateTime<Utc>>,
+    /// 이 시각 이전에 시작한 세션만
+    pub started_before: Option<DateTime<Utc>>,
+    /// 최대 결과 수 (기본 50)
+    pub limit: Option<u32>,
+}
+
+/// 전사 검색 결과 (관련도 순)
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub session_id: String,
+    pub session_title: Option<String>,
+    pub segment_id: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+    /// 일치한 부분을 `**`로 감싼 발췌
+    pub snippet: String,
+}
+
+/// 검색 결과 기본 개수
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+
+/// 태그 최대 길이 (글자 수)
+const MAX_TAG_CHARS: usize = 50;
+
+/// SQLite 세션 저장소 (Tauri 관리 상태)
+pub struct Storage {
+    conn: Mutex<Connection>,
+    path: Mutex<PathBuf>,
+    /// SQLCipher 키 (암호화하지 않았으면 `None`)
+    key: Mutex<Option<String>>,
+    /// 잠긴 동안에는 `conn`이 빈 메모리 DB이고 모든 조회를 거절
+    locked: AtomicBool,
+}
+
+const SESSION_COLUMNS: &str = "s.id, s.title, s.device_name, s.started_at, s.ended_at, s.duration_ms,
+     s.audio_path,
+     (SELECT COUNT(*) FROM segments WHERE session_id = s.id AND merged_into IS NULL),
+     s.project_id,
+     (SELECT json_group_array(tag) FROM
+        (SELECT tag FROM session_tags WHERE session_id = s.id ORDER BY tag))";
+
+fn session_from_row(row: &rusqlite::Row) -> rusqlite::Result<SessionSummary> {
+    Ok(SessionSummary {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        device_name: row.get(2)?,
+        started_at: row.get(3)?,
+        ended_at: row.get(4)?,
+        duration_ms: row.get(5)?,
+        audio_path: row.get(6)?,
+        segment_count: row.get(7)?,
+        project_id: row.get(8)?,
+        tags: serde_json::from_str(&row.get::<_, String>(9)?).unwrap_or_default(),
+    })
+}
+
+impl Storage {
+    /// 데이터베이스 열기 (없으면 생성 후 마이그레이션)
+    ///
+    /// 암호화한 데이터베이스는 SQLCipher `key`가 있어야 열린다.
+    pub fn open(path: &Path, key: Option<&str>) -> Result<Self, TeuImError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| TeuImError::from(format!("데이터 폴더 생성 실패: {}", e)))?;
+        }
+
+        let conn = connect(path, key)?;
+        log::info!("세션 저장소 열림: {}", path.display());
+        Ok(Self {
+            conn: Mutex::new(conn),
+            path: Mutex::new(path.to_path_buf()),
+            key: Mutex::new(key.map(str::to_string)),
+            locked: AtomicBool::new(false),
+        })
+    }
+
+    /// 잠긴 상태로 시작 (`unlock`에 키를 넘기기 전까지 모든 조회 거절)
+    pub fn locked(path: &Path) -> Result<Self, TeuImError> {
+        log::info!("세션 저장소 잠김: {}", path.display());
+        Ok(Self {
+            conn: Mutex::new(Connection::open_in_memory()?),
+            path: Mutex::new(path.to_path_buf()),
+            key: Mutex::new(None),
+            locked: AtomicBool::new(true),
+        })
+    }
+
+    /// 잠기지 않은 연결
+    fn conn(&self) -> Result<MutexGuard<'_, Connection>, TeuImError> {
+        let conn = self.conn.lock().unwrap();
+        if self.locked.load(Ordering::SeqCst) {
+            return Err(TeuImError::DatabaseLocked);
+        }
+        Ok(conn)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::SeqCst)
+    }
+
+    /// 연결을 닫고 잠금 (키는 메모리에서 지움)
+    pub fn lock(&self) -> Result<(), TeuImError> {
+        let mut conn = self.conn.lock().unwrap();
+        *conn = Connection::open_in_memory()?;
+        *self.key.lock().unwrap() = None;
+        self.locked.store(true, Ordering::SeqCst);
+        log::info!("세션 저장소 잠금");
+        Ok(())
+    }
+
+    /// 키로 데이터베이스를 다시 열어 잠금 해제
+    pub fn unlock(&self, key: &str) -> Result<(), TeuImError> {
+        let mut conn = self.conn.lock().unwrap();
+        let path = self.path.lock().unwrap().clone();
+        *conn = connect(&path, Some(key)).map_err(|e| {
+            log::warn!("세션 저장소 잠금 해제 실패: {}", e);
+            TeuImError::invalid_argument("데이터베이스 키가 맞지 않습니다")
+        })?;
+        *self.key.lock().unwrap() = Some(key.to_string());
+        self.locked.store(false, Ordering::SeqCst);
+        log::info!("세션 저장소 잠금 해제");
+        Ok(())
+    }
+
+    /// 데이터베이스를 새 키로 다시 씀 (`None`이면 암호화 해제)
+    ///
+    /// 새 키로 만든 사본이 완성된 뒤에만 원본을 바꾼다.
+    pub fn rekey(&self, key: Option<&str>) -> Result<(), TeuImError> {
+        let mut conn = self.conn()?;
+        let path = self.path.lock().unwrap().clone();
+        let tmp_path = path.with_extension("db.tmp");
+        let _ = std::fs::remove_file(&tmp_path);
+
+        if let Err(e) = export_database(&conn, &tmp_path, key) {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+
+        // 기존 연결을 닫아 WAL을 정리한 뒤 교체
+        *conn = Connection::open_in_memory()?;
+        let replaced = std::fs::rename(&tmp_path, &path)
+            .map_err(|e| TeuImError::from(format!("데이터베이스 교체 실패: {}", e)));
+        if replaced.is_ok() {
+            for suffix in ["-wal", "-shm"] {
+                let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+            }
+            *self.key.lock().unwrap() = key.map(str::to_string);
+        } else {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+
+        let current = self.key.lock().unwrap().clone();
+        match connect(&path, current.as_deref()) {
+            Ok(reopened) => *conn = reopened,
+            Err(e) => {
+                self.locked.store(true, Ordering::SeqCst);
+                return Err(e);
+            }
+        }
+        replaced?;
+        log::info!(
+            "세션 저장소 {}",
+            if key.is_some() {
+                "암호화"
+            } else {
+                "암호화 해제"
+            }
+        );
+        Ok(())
+    }
+
+    /// 데이터베이스를 `path`로 옮기면서 `moved`(이전 경로, 새 경로)대로 녹음 경로 변경
+    ///
+    /// 잠금을 쥔 채 새 위치에 사본을 만들고 연결을 바꾸므로 그 사이의 쓰기가
+    /// 빠지지 않는다. 도중에 실패하면 기존 데이터베이스를 계속 쓴다.
+    pub fn relocate(&self, path: &Path, moved: &[(String, String)]) -> Result<(), TeuImError> {
+        let mut conn = self.conn()?;
+        let key = self.key.lock().unwrap().clone();
+        let tmp_path = path.with_extension("db.tmp");
+        let _ = std::fs::remove_file(&tmp_path);
+
+        let copy = || -> Result<Connection, TeuImError> {
+            export_database(&conn, &tmp_path, key.as_deref())?;
+            let mut copy = open_keyed(&tmp_path, key.as_deref())?;
+            let tx = copy.transaction()?;
+            for (from, to) in moved {
+                tx.execute(
+                    "UPDATE sessions SET audio_path = ?2 WHERE audio_path = ?1",
+                    params![from, to],
+                )?;
+            }
+            tx.commit()?;
+            drop(copy);
+
+            std::fs::rename(&tmp_path, path)
+                .map_err(|e| TeuImError::from(format!("데이터베이스 이동 실패: {}", e)))?;
+            connect(path, key.as_deref())
+        };
+
+        match copy() {
+            Ok(relocated) => {
+                *conn = relocated;
+                *self.path.lock().unwrap() = path.to_path_buf();
+                log::info!("세션 저장소 이동: {}", path.display());
+                Ok(())
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(&tmp_path);
+                Err(e)
+            }
+        }
+    }
+
+    pub fn create_session(
+        &self,
+        title: Option<String>,
+        device_name: Option<String>,
+    ) -> Result<SessionSummary, TeuImError> {
+        let session = SessionSummary {
+            id: Uuid::new_v4().to_string(),
+            title,
+            device_name,
+            started_at: Utc::now(),
+            ended_at: None,
+            duration_ms: 0,
+            audio_path: None,
+            segment_count: 0,
+            project_id: None,
+            tags: Vec::new(),
+        };
+
+        self.conn()?.execute(
+            "INSERT INTO sessions (id, title, device_name, started_at) VALUES (?1, ?2, ?3, ?4)",
+            params![session.id, session.title, session.device_name, session.started_at],
+        )?;
+
+        Ok(session)
+    }
+
+    /// 묶음 파일에서 읽은 세션을 `id`로 저장 (구간·번역·표시·태그·프로젝트 포함)
+    ///
+    /// 프로젝트는 같은 이름이 있으면 그곳에, 없으면 새로 만들어 분류한다.
+    pub fn import_session(
+        &self,
+        id: &str,
+        manifest: &BundleManifest,
+        transcript: &BundleTranscript,
+    ) -> Result<(), TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+
+        let project_id = match manifest.project.as_deref().map(str::trim) {
+            Some(name) if !name.is_empty() => {
+                let existing: Option<String> = tx
+                    .query_row("SELECT id FROM projects WHERE name = ?1", [name], |row| {
+                        row.get(0)
+                    })
+                    .optional()?;
+                match existing {
+                    Some(project_id) => Some(project_id),
+                    None => {
+                        let project_id = Uuid::new_v4().to_string();
+                        tx.execute(
+                            "INSERT INTO projects (id, name, created_at) VALUES (?1, ?2, ?3)",
+                            params![project_id, name, Utc::now()],
+                        )?;
+                        Some(project_id)
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        tx.execute(
+            "INSERT INTO sessions
+                 (id, title, device_name, started_at, ended_at, duration_ms, project_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                id,
+                manifest.title,
+                manifest.device_name,
+                manifest.started_at,
+                manifest.ended_at.unwrap_or(manifest.started_at),
+                manifest.duration_ms,
+                project_id
+            ],
+        )?;
+        for segment in &transcript.segments {
+            let segment_id = Uuid::new_v4().to_string();
+            tx.execute(
+                "INSERT INTO segments
+                     (id, session_id, text, start_ms, end_ms, speaker, confidence, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    segment_id,
+                    id,
+                    segment.text,
+                    segment.start_ms,
+                    segment.end_ms,
+                    segment.speaker,
+                    segment.confidence,
+                    Utc::now()
+                ],
+            )?;
+            save_words(&tx, &segment_id, &segment.words)?;
+            for translation in &segment.translations {
+                tx.execute(
+                    "INSERT OR REPLACE INTO translations (segment_id, language, text, created_at)
+                         VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        segment_id,
+                        translation.language,
+                        translation.text,
+                        Utc::now()
+                    ],
+                )?;
+            }
+        }
+        for mark in &transcript.marks {
+            tx.execute(
+                "INSERT INTO session_marks (id, session_id, kind, start_ms, end_ms, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    Uuid::new_v4().to_string(),
+                    id,
+                    mark.kind.as_str(),
+                    mark.start_ms,
+                    mark.end_ms.max(mark.start_ms),
+                    Utc::now()
+                ],
+            )?;
+        }
+        for tag in &manifest.tags {
+            tx.execute(
+                "INSERT OR IGNORE INTO session_tags (session_id, tag) VALUES (?1, ?2)",
+                params![id, normalize_tag(tag)?],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 세션 종료 시각과 길이 기록
+    pub fn finish_session(&self, id: &str, duration_ms: i64) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "UPDATE sessions SET ended_at = ?2, duration_ms = MAX(duration_ms, ?3) WHERE id = ?1",
+            params![id, Utc::now(), duration_ms],
+        )?;
+        Ok(())
+    }
+
+    /// 가져온 파일의 내용 해시 기록
+    pub fn set_source_fingerprint(&self, id: &str, sha256: &str) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "UPDATE sessions SET source_sha256 = ?2 WHERE id = ?1",
+            params![id, sha256],
+        )?;
+        Ok(())
+    }
+
+    /// 같은 내용의 파일을 가져와 완료된 세션 (가장 최근 것)
+    pub fn find_by_fingerprint(&self, sha256: &str) -> Result<Option<String>, TeuImError> {
+        Ok(self
+            .conn()?
+            .query_row(
+                "SELECT id FROM sessions
+                 WHERE source_sha256 = ?1 AND ended_at IS NOT NULL
+                 ORDER BY started_at DESC LIMIT 1",
+                [sha256],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// 영상 정렬 저장 (이미 있으면 교체)
+    pub fn set_media_alignment(
+        &self,
+        alignment: &MediaAlignment,
+    ) -> Result<MediaAlignment, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, &alignment.session_id)?;
+        conn.execute(
+            "INSERT INTO media_alignments
+                (session_id, media_path, offset_timecode, frame_rate, offset_ms, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(session_id) DO UPDATE SET
+                media_path = excluded.media_path,
+                offset_timecode = excluded.offset_timecode,
+                frame_rate = excluded.frame_rate,
+                offset_ms = excluded.offset_ms,
+                updated_at = excluded.updated_at",
+            params![
+                alignment.session_id,
+                alignment.media_path,
+                alignment.offset,
+                alignment.frame_rate.as_str(),
+                alignment.offset_ms,
+                Utc::now(),
+            ],
+        )?;
+        Ok(alignment.clone())
+    }
+
+    /// 세션의 영상 정렬 (없으면 `None`)
+    pub fn media_alignment(&self, session_id: &str) -> Result<Option<MediaAlignment>, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        let alignment = conn
+            .query_row(
+                "SELECT media_path, offset_timecode, frame_rate, offset_ms
+                 FROM media_alignments WHERE session_id = ?1",
+                [session_id],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, i64>(3)?,
+                    ))
+                },
+            )
+            .optional()?;
+        Ok(alignment.and_then(|(media_path, offset, rate, offset_ms)| {
+            Some(MediaAlignment {
+                session_id: session_id.to_string(),
+                media_path,
+                offset,
+                frame_rate: FrameRate::parse(&rate)?,
+                offset_ms,
+            })
+        }))
+    }
+
+    pub fn clear_media_alignment(&self, session_id: &str) -> Result<(), TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        conn.execute(
+            "DELETE FROM media_alignments WHERE session_id = ?1",
+            [session_id],
+        )?;
+        Ok(())
+    }
+
+    /// 세션 오디오 통계 저장 (이미 있으면 교체)
+    pub fn set_session_audio_stats(&self, stats: &SessionAudioStats) -> Result<(), TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, &stats.session_id)?;
+        conn.execute(
+            "INSERT INTO session_audio_stats
+                (session_id, source, duration_ms, average_db, peak_db, noise_floor_db, snr_db,
+                 clipping_count, silence_percent, dropped_buffers, dropped_chunks, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+             ON CONFLICT(session_id) DO UPDATE SET
+                source = excluded.source,
+                duration_ms = excluded.duration_ms,
+                average_db = excluded.average_db,
+                peak_db = excluded.peak_db,
+                noise_floor_db = excluded.noise_floor_db,
+                snr_db = excluded.snr_db,
+                clipping_count = excluded.clipping_count,
+                silence_percent = excluded.silence_percent,
+                dropped_buffers = excluded.dropped_buffers,
+                dropped_chunks = excluded.dropped_chunks,
+                updated_at = excluded.updated_at",
+            params![
+                stats.session_id,
+                stats.source.as_str(),
+                stats.duration_ms,
+                stats.average_db,
+                stats.peak_db,
+                stats.noise_floor_db,
+                stats.snr_db,
+                stats.clipping_count as i64,
+                stats.silence_percent,
+                stats.dropped_buffers as i64,
+                stats.dropped_chunks as i64,
+                stats.updated_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 세션 오디오 통계 (캡처로 전사하지 않은 세션은 `None`)
+    pub fn session_audio_stats(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<SessionAudioStats>, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        let stats = conn
+            .query_row(
+                "SELECT source, duration_ms, average_db, peak_db, noise_floor_db, snr_db,
+                        clipping_count, silence_percent, dropped_buffers, dropped_chunks,
+                        updated_at
+                 FROM session_audio_stats WHERE session_id = ?1",
+                [session_id],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        SessionAudioStats {
+                            session_id: session_id.to_string(),
+                            source: CaptureSource::Microphone,
+                            duration_ms: row.get(1)?,
+                            average_db: row.get(2)?,
+                            peak_db: row.get(3)?,
+                            noise_floor_db: row.get(4)?,
+                            snr_db: row.get(5)?,
+                            clipping_count: row.get::<_, i64>(6)? as u64,
+                            silence_percent: row.get(7)?,
+                            dropped_buffers: row.get::<_, i64>(8)? as u64,
+                            dropped_chunks: row.get::<_, i64>(9)? as u64,
+                            updated_at: row.get(10)?,
+                        },
+                    ))
+                },
+            )
+            .optional()?;
+        Ok(stats.and_then(|(source, stats)| {
+            Some(SessionAudioStats {
+                source: CaptureSource::parse(&source)?,
+                ..stats
+            })
+        }))
+    }
+
+    /// 세션 다시 전사 기록 추가 (대기 상태로 시작), 새 ID 반환
+    pub fn create_refined_transcript(
+        &self,
+        session_id: &str,
+        model: &str,
+    ) -> Result<String, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        let id = Uuid::new_v4().to_string();
+        conn.execute(
+            "INSERT INTO refined_transcripts (id, session_id, model, status, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                id,
+                session_id,
+                model,
+                RefineStatus::Queued.as_str(),
+                Utc::now()
+            ],
+        )?;
+        Ok(id)
+    }
+
+    /// 다시 전사 상태 변경 (끝난 상태면 완료 시각도 기록)
+    pub fn set_refined_status(
+        &self,
+        id: &str,
+        status: RefineStatus,
+        error: Option<&str>,
+    ) -> Result<(), TeuImError> {
+        let completed_at = status.is_finished().then(Utc::now);
+        self.conn()?.execute(
+            "UPDATE refined_transcripts SET status = ?2, error = ?3, completed_at = ?4
+                 WHERE id = ?1",
+            params![id, status.as_str(), error, completed_at],
+        )?;
+        Ok(())
+    }
+
+    /// 앱이 꺼지며 끝나지 못한 다시 전사를 실패로 표시, 표시한 수 반환
+    pub fn interrupt_refinements(&self) -> Result<usize, TeuImError> {
+        let updated = self.conn()?.execute(
+            "UPDATE refined_transcripts SET status = ?1, error = ?2, completed_at = ?3
+                 WHERE status IN (?4, ?5)",
+            params![
+                RefineStatus::Failed.as_str(),
+                "앱이 종료되어 중단됨",
+                Utc::now(),
+                RefineStatus::Queued.as_str(),
+                RefineStatus::Running.as_str()
+            ],
+        )?;
+        Ok(updated)
+    }
+
+    /// 다시 전사한 구간 저장
+    pub fn insert_refined_segment(
+        &self,
+        refined_id: &str,
+        segment_id: Option<&str>,
+        segment: &TranscriptSegment,
+    ) -> Result<(), TeuImError> {
+        let words = serde_json::to_string(&segment.words).map_err(|e| e.to_string())?;
+        self.conn()?.execute(
+            "INSERT INTO refined_segments
+                 (id, refined_id, segment_id, text, start_ms, end_ms, speaker, confidence, words)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                segment.id,
+                refined_id,
+                segment_id,
+                segment.text,
+                segment.start_ms,
+                segment.end_ms,
+                segment.speaker,
+                segment.confidence,
+                words
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 세션의 다시 전사 기록 (오래된 순, 구간 제외)
+    pub fn refined_transcripts(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<RefinedTranscript>, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM refined_transcripts WHERE session_id = ?1 ORDER BY created_at",
+            REFINED_COLUMNS
+        ))?;
+        let transcripts = stmt
+            .query_map([session_id], refined_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(transcripts)
+    }
+
+    /// 다시 전사 결과 하나 (구간 포함)
+    pub fn refined_transcript(&self, id: &str) -> Result<RefinedTranscript, TeuImError> {
+        let conn = self.conn()?;
+        let mut transcript = conn
+            .query_row(
+                &format!(
+                    "SELECT {} FROM refined_transcripts WHERE id = ?1",
+                    REFINED_COLUMNS
+                ),
+                [id],
+                refined_from_row,
+            )
+            .optional()?
+            .ok_or_else(|| {
+                TeuImError::invalid_argument(format!("다시 전사 결과가 없습니다: {}", id))
+            })?;
+        transcript.segments = load_refined_segments(&conn, id)?;
+        Ok(transcript)
+    }
+
+    /// 세션의 전사 버전 목록 (만든 순)
+    ///
+    /// 실시간 버전은 항상 있고, 편집 버전은 구간을 한 번이라도 고쳤을 때만,
+    /// 다시 전사 버전은 다시 전사할 때마다 생긴다.
+    pub fn transcript_versions(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<TranscriptVersion>, TeuImError> {
+        let conn = self.conn()?;
+        let session = load_summary(&conn, session_id)?;
+
+        let live_count: i64 = conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM segments s WHERE s.session_id = ?1 AND {}",
+                LIVE_SEGMENT_FILTER
+            ),
+            [session_id],
+            |row| row.get(0),
+        )?;
+        let mut versions = vec![TranscriptVersion {
+            id: format!("live:{}", session_id),
+            session_id: session_id.to_string(),
+            kind: VersionKind::Live,
+            model: None,
+            status: None,
+            created_at: session.started_at,
+            segment_count: live_count,
+        }];
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {},
+                    (SELECT COUNT(*) FROM refined_segments WHERE refined_id = refined_transcripts.id)
+                 FROM refined_transcripts WHERE session_id = ?1 ORDER BY created_at",
+            REFINED_COLUMNS
+        ))?;
+        let refined = stmt
+            .query_map([session_id], |row| {
+                Ok((refined_from_row(row)?, row.get(7)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        versions.extend(
+            refined
+                .into_iter()
+                .map(|(refined, count)| TranscriptVersion {
+                    id: format!("refined:{}", refined.id),
+                    session_id: session_id.to_string(),
+                    kind: VersionKind::Refined,
+                    model: Some(refined.model),
+                    status: Some(refined.status),
+                    created_at: refined.created_at,
+                    segment_count: count,
+                }),
+        );
+
+        let edited_at: Option<DateTime<Utc>> = conn.query_row(
+            "SELECT MAX(r.created_at) FROM segment_revisions r
+                 JOIN segments s ON s.id = r.segment_id WHERE s.session_id = ?1",
+            [session_id],
+            |row| row.get(0),
+        )?;
+        if let Some(edited_at) = edited_at {
+            versions.push(TranscriptVersion {
+                id: format!("edited:{}", session_id),
+                session_id: session_id.to_string(),
+                kind: VersionKind::Edited,
+                model: None,
+                status: None,
+                created_at: edited_at,
+                segment_count: session.segment_count,
+            });
+        }
+
+        versions.sort_by_key(|version| version.created_at);
+        Ok(versions)
+    }
+
+    /// 전사 버전 하나와 그 구간 (시작 시간순)
+    pub fn transcript_version(
+        &self,
+        version_id: &str,
+    ) -> Result<(TranscriptVersion, Vec<VersionSegment>), TeuImError> {
+        let unknown =
+            || TeuImError::invalid_argument(format!("알 수 없는 전사 버전: {}", version_id));
+        let (kind, id) = version_id.split_once(':').ok_or_else(unknown)?;
+        let session_id = match kind {
+            "live" | "edited" => id.to_string(),
+            "refined" => self
+                .conn()?
+                .query_row(
+                    "SELECT session_id FROM refined_transcripts WHERE id = ?1",
+                    [id],
+                    |row| row.get(0),
+                )
+                .optional()?
+                .ok_or_else(unknown)?,
+            _ => return Err(unknown()),
+        };
+        let version = self
+            .transcript_versions(&session_id)?
+            .into_iter()
+            .find(|version| version.id == version_id)
+            .ok_or_else(unknown)?;
+
+        let conn = self.conn()?;
+        let segments = match version.kind {
+            VersionKind::Live => live_segments(&conn, &session_id)?,
+            VersionKind::Edited => {
+                let mut stmt = conn.prepare(
+                    "SELECT id, text, start_ms, end_ms FROM segments
+                         WHERE session_id = ?1 AND merged_into IS NULL ORDER BY start_ms",
+                )?;
+                let mut segments = stmt
+                    .query_map([&session_id], version_segment_from_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
+                for segment in &mut segments {
+                    segment.words = load_words(&conn, &segment.id)?;
+                }
+                segments
+            }
+            VersionKind::Refined => load_refined_segments(&conn, id)?
+                .into_iter()
+                .map(|segment| VersionSegment {
+                    id: segment.id,
+                    text: segment.text,
+                    start_ms: segment.start_ms,
+                    end_ms: segment.end_ms,
+                    words: segment.words,
+                })
+                .collect(),
+        };
+        Ok((version, segments))
+    }
+
+    /// 세션에 기록된 길이 (ms)
+    pub fn session_duration(&self, id: &str) -> Result<i64, TeuImError> {
+        self.conn()?
+            .query_row("SELECT duration_ms FROM sessions WHERE id = ?1", [id], |row| row.get(0))
+            .optional()?
+            .ok_or_else(|| TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            })
+    }
+
+    /// 세션에 연결된 녹음 파일 경로
+    pub fn audio_path(&self, id: &str) -> Result<Option<String>, TeuImError> {
+        self.conn()?
+            .query_row(
+                "SELECT audio_path FROM sessions WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            })
+    }
+
+    /// 세션에 연결된 녹음 파일 경로 목록 (중복 제외)
+    pub fn audio_paths(&self) -> Result<Vec<String>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt =
+            conn.prepare("SELECT DISTINCT audio_path FROM sessions WHERE audio_path IS NOT NULL")?;
+        let paths = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(paths)
+    }
+
+    /// 녹음 파일 경로 바꾸기 (같은 파일을 가리키는 세션 모두)
+    pub fn replace_audio_path(&self, from: &str, to: &str) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "UPDATE sessions SET audio_path = ?2 WHERE audio_path = ?1",
+            params![from, to],
+        )?;
+        Ok(())
+    }
+
+    /// 다른 세션도 이 녹음 파일을 쓰는지 (`except` 세션 제외)
+    pub fn audio_path_shared(&self, path: &str, except: &str) -> Result<bool, TeuImError> {
+        Ok(self.conn()?.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sessions WHERE audio_path = ?1 AND id != ?2)",
+            params![path, except],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// 세션의 녹음 파일 연결 해제 (파일은 지우지 않음)
+    pub fn clear_audio_path(&self, id: &str) -> Result<(), TeuImError> {
+        self.conn()?
+            .execute("UPDATE sessions SET audio_path = NULL WHERE id = ?1", [id])?;
+        Ok(())
+    }
+
+    /// 세션에 녹음 파일 연결
+    pub fn set_audio_path(&self, id: &str, path: &str) -> Result<(), TeuImError> {
+        let updated = self.conn()?.execute(
+            "UPDATE sessions SET audio_path = ?2 WHERE id = ?1",
+            params![id, path],
+        )?;
+        if updated == 0 {
+            return Err(TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn insert_segment(
+        &self,
+        session_id: &str,
+        segment: &TranscriptSegment,
+    ) -> Result<(), TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT INTO segments
+                 (id, session_id, text, start_ms, end_ms, speaker, confidence, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                segment.id,
+                session_id,
+                segment.text,
+                segment.start_ms,
+                segment.end_ms,
+                segment.speaker,
+                segment.confidence,
+                Utc::now()
+            ],
+        )?;
+        save_words(&tx, &segment.id, &segment.words)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 구간 번역 저장 (같은 언어의 기존 번역은 교체)
+    pub fn insert_translation(
+        &self,
+        segment_id: &str,
+        language: &str,
+        text: &str,
+    ) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "INSERT OR REPLACE INTO translations (segment_id, language, text, created_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+            params![segment_id, language, text, Utc::now()],
+        )?;
+        Ok(())
+    }
+
+    pub fn list_sessions(&self, query: &SessionQuery) -> Result<Vec<SessionSummary>, TeuImError> {
+        let mut tags: Vec<String> = query
+            .tags
+            .iter()
+            .filter_map(|t| normalize_tag(t).ok())
+            .collect();
+        tags.sort_by_key(|tag| tag.to_lowercase());
+        tags.dedup_by_key(|tag| tag.to_lowercase());
+        let tags = (!tags.is_empty()).then(|| serde_json::Value::from(tags).to_string());
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sessions s
+                 WHERE (?1 IS NULL OR s.title LIKE '%' || ?1 || '%')
+                   AND (?2 IS NULL OR s.project_id = ?2)
+                   AND (?3 IS NULL OR json_array_length(?3) =
+                        (SELECT COUNT(*) FROM session_tags t, json_each(?3) j
+                          WHERE t.session_id = s.id AND t.tag = j.value))
+                   AND (?4 IS NULL OR s.started_at >= ?4)
+                   AND (?5 IS NULL OR s.started_at < ?5)
+                 ORDER BY s.started_at DESC",
+            SESSION_COLUMNS
+        ))?;
+        let sessions = stmt
+            .query_map(
+                params![
+                    query
+                        .text
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|t| !t.is_empty()),
+                    query.project_id,
+                    tags,
+                    query.started_after,
+                    query.started_before
+                ],
+                session_from_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(sessions)
+    }
+
+    /// 세션에 태그 추가 후 세션의 태그 목록 반환 (이미 있으면 그대로)
+    pub fn add_tag(&self, session_id: &str, tag: &str) -> Result<Vec<String>, TeuImError> {
+        let tag = normalize_tag(tag)?;
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        conn.execute(
+            "INSERT OR IGNORE INTO session_tags (session_id, tag) VALUES (?1, ?2)",
+            params![session_id, tag],
+        )?;
+        session_tags(&conn, session_id)
+    }
+
+    /// 세션에서 태그 제거 후 세션의 태그 목록 반환
+    pub fn remove_tag(&self, session_id: &str, tag: &str) -> Result<Vec<String>, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        conn.execute(
+            "DELETE FROM session_tags WHERE session_id = ?1 AND tag = ?2",
+            params![session_id, tag.trim()],
+        )?;
+        session_tags(&conn, session_id)
+    }
+
+    /// 사용 중인 태그 목록 (가나다순)
+    pub fn list_tags(&self) -> Result<Vec<TagCount>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt =
+            conn.prepare("SELECT tag, COUNT(*) FROM session_tags GROUP BY tag ORDER BY tag")?;
+        let tags = stmt
+            .query_map([], |row| {
+                Ok(TagCount {
+                    tag: row.get(0)?,
+                    session_count: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(tags)
+    }
+
+    pub fn list_projects(&self) -> Result<Vec<Project>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT p.id, p.name, p.created_at,
+                    (SELECT COUNT(*) FROM sessions WHERE project_id = p.id)
+                 FROM projects p ORDER BY p.name",
+        )?;
+        let projects = stmt
+            .query_map([], |row| {
+                Ok(Project {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    created_at: row.get(2)?,
+                    session_count: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(projects)
+    }
+
+    pub fn create_project(&self, name: &str) -> Result<Project, TeuImError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(TeuImError::invalid_argument("프로젝트 이름을 입력하세요"));
+        }
+        let project = Project {
+            id: Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            created_at: Utc::now(),
+            session_count: 0,
+        };
+        self.conn()?
+            .execute(
+                "INSERT INTO projects (id, name, created_at) VALUES (?1, ?2, ?3)",
+                params![project.id, project.name, project.created_at],
+            )
+            .map_err(|e| match e.sqlite_error_code() {
+                Some(rusqlite::ErrorCode::ConstraintViolation) => {
+                    TeuImError::invalid_argument(format!("이미 있는 프로젝트입니다: {}", name))
+                }
+                _ => e.into(),
+            })?;
+        Ok(project)
+    }
+
+    /// 프로젝트 삭제 (속한 세션은 남기고 분류만 해제)
+    pub fn delete_project(&self, id: &str) -> Result<(), TeuImError> {
+        let deleted = self
+            .conn()?
+            .execute("DELETE FROM projects WHERE id = ?1", [id])?;
+        if deleted == 0 {
+            return Err(project_not_found(id));
+        }
+        Ok(())
+    }
+
+    /// 세션을 프로젝트로 옮김 (`None`이면 프로젝트에서 뺌)
+    pub fn move_session_to_project(
+        &self,
+        session_id: &str,
+        project_id: Option<&str>,
+    ) -> Result<SessionSummary, TeuImError> {
+        let conn = self.conn()?;
+        ensure_session(&conn, session_id)?;
+        if let Some(project_id) = project_id {
+            let exists = conn
+                .query_row("SELECT 1 FROM projects WHERE id = ?1", [project_id], |_| {
+                    Ok(())
+                })
+                .optional()?;
+            if exists.is_none() {
+                return Err(project_not_found(project_id));
+            }
+        }
+        conn.execute(
+            "UPDATE sessions SET project_id = ?2 WHERE id = ?1",
+            params![session_id, project_id],
+        )?;
+        load_summary(&conn, session_id)
+    }
+
+    /// 세션 제목 변경 (빈 문자열이면 제목 없음)
+    pub fn set_session_title(&self, id: &str, title: &str) -> Result<SessionSummary, TeuImError> {
+        let conn = self.conn()?;
+        let title = (!title.is_empty()).then_some(title);
+        let updated = conn.execute(
+            "UPDATE sessions SET title = ?2 WHERE id = ?1",
+            params![id, title],
+        )?;
+        if updated == 0 {
+            return Err(TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            });
+        }
+        load_summary(&conn, id)
+    }
+
+    /// ID로 구간 여러 개 조회 (번역 포함, 시간순)
+    pub fn get_segments(&self, ids: &[String]) -> Result<Vec<StoredSegment>, TeuImError> {
+        let conn = self.conn()?;
+        let mut seen = std::collections::HashSet::new();
+        let mut segments = ids
+            .iter()
+            .filter(|id| seen.insert(id.as_str()))
+            .map(|id| {
+                let (_, mut segment) = load_segment(&conn, id)?;
+                segment.translations = load_translations(&conn, id)?;
+                Ok(segment)
+            })
+            .collect::<Result<Vec<_>, TeuImError>>()?;
+        segments.sort_by_key(|segment| segment.start_ms);
+        Ok(segments)
+    }
+
+    pub fn get_session(&self, id: &str) -> Result<SessionDetail, TeuImError> {
+        let conn = self.conn()?;
+
+        let session = conn
+            .query_row(
+                &format!("SELECT {} FROM sessions s WHERE s.id = ?1", SESSION_COLUMNS),
+                [id],
+                session_from_row,
+            )
+            .optional()?
+            .ok_or_else(|| TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            })?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, text, start_ms, end_ms, speaker, confidence FROM segments
+                 WHERE session_id = ?1 AND merged_into IS NULL ORDER BY start_ms",
+        )?;
+        let mut segments = stmt
+            .query_map([id], |row| {
+                Ok(StoredSegment {
+                    id: row.get(0)?,
+                    text: row.get(1)?,
+                    start_ms: row.get(2)?,
+                    end_ms: row.get(3)?,
+                    speaker: row.get(4)?,
+                    confidence: row.get(5)?,
+                    words: Vec::new(),
+                    translations: Vec::new(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        for segment in &mut segments {
+            segment.words = load_words(&conn, &segment.id)?;
+        }
+
+        for segment in &mut segments {
+            segment.translations = load_translations(&conn, &segment.id)?;
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT id, kind, start_ms, end_ms FROM session_marks
+                 WHERE session_id = ?1 ORDER BY start_ms",
+        )?;
+        let marks = stmt
+            .query_map([id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter_map(|(id, kind, start_ms, end_ms)| {
+                Some(SessionMark {
+                    id,
+                    kind: MarkKind::parse(&kind)?,
+                    start_ms,
+                    end_ms,
+                })
+            })
+            .collect();
+
+        Ok(SessionDetail {
+            session,
+            segments,
+            marks,
+        })
+    }
+
+    /// 세션 타임라인에 구간 표시 추가
+    pub fn add_session_mark(
+        &self,
+        session_id: &str,
+        kind: MarkKind,
+        start_ms: i64,
+        end_ms: i64,
+    ) -> Result<SessionMark, TeuImError> {
+        let mark = SessionMark {
+            id: Uuid::new_v4().to_string(),
+            kind,
+            start_ms,
+            end_ms: end_ms.max(start_ms),
+        };
+        self.conn()?.execute(
+            "INSERT INTO session_marks (id, session_id, kind, start_ms, end_ms, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                mark.id,
+                session_id,
+                kind.as_str(),
+                mark.start_ms,
+                mark.end_ms,
+                Utc::now()
+            ],
+        )?;
+        Ok(mark)
+    }
+
+    /// 모든 세션의 전사 구간 전문 검색
+    pub fn search(
+        &self,
+        query: &str,
+        filters: &SearchFilters,
+    ) -> Result<Vec<SearchHit>, TeuImError> {
+        let query =
+            fts_query(query).ok_or_else(|| TeuImError::invalid_argument("검색어를 입력하세요"))?;
+
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT g.session_id, s.title, g.id, g.start_ms, g.end_ms, g.speaker, g.text,
+                    snippet(segments_fts, 0, '**', '**', '…', 16)
+                 FROM segments_fts
+                 JOIN segments g ON g.rowid = segments_fts.rowid
+                 JOIN sessions s ON s.id = g.session_id
+                 WHERE segments_fts MATCH ?1
+                   AND g.merged_into IS NULL
+                   AND (?2 IS NULL OR g.session_id = ?2)
+                   AND (?3 IS NULL OR g.speaker = ?3)
+                   AND (?4 IS NULL OR s.started_at >= ?4)
+                   AND (?5 IS NULL OR s.started_at < ?5)
+                 ORDER BY segments_fts.rank
+                 LIMIT ?6",
+        )?;
+        let hits = stmt
+            .query_map(
+                params![
+                    query,
+                    filters.session_id,
+                    filters.speaker,
+                    filters.started_after,
+                    filters.started_before,
+                    filters.limit.unwrap_or(DEFAULT_SEARCH_LIMIT)
+                ],
+                |row| {
+                    Ok(SearchHit {
+                        session_id: row.get(0)?,
+                        session_title: row.get(1)?,
+                        segment_id: row.get(2)?,
+                        start_ms: row.get(3)?,
+                        end_ms: row.get(4)?,
+                        speaker: row.get(5)?,
+                        text: row.get(6)?,
+                        snippet: row.get(7)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(hits)
+    }
+
+    /// 구간 텍스트 수정 (원본은 리비전으로 보관)
+    pub fn update_segment(&self, id: &str, text: &str) -> Result<StoredSegment, TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+
+        let (_, before) = load_segment(&tx, id)?;
+        let after = StoredSegment {
+            text: text.trim().to_string(),
+            ..before.clone()
+        };
+        tx.execute(
+            "UPDATE segments SET text = ?2 WHERE id = ?1",
+            params![id, after.text],
+        )?;
+        record_revision(&tx, &before, &after, RevisionKind::Edit)?;
+
+        tx.commit()?;
+        Ok(after)
+    }
+
+    /// 구간을 텍스트 위치(`position`번째 글자)에서 둘로 나눔
+    ///
+    /// `split_ms`를 생략하면 글자 수 비율로 나눌 시각을 정한다.
+    /// 앞 구간은 기존 ID를 유지하고, 뒤 구간은 새 ID로 만든다.
+    pub fn split_segment(
+        &self,
+        id: &str,
+        position: usize,
+        split_ms: Option<i64>,
+    ) -> Result<(StoredSegment, StoredSegment), TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+
+        let (session_id, before) = load_segment(&tx, id)?;
+        let chars = before.text.chars().count();
+        if position == 0 || position >= chars {
+            return Err(TeuImError::invalid_argument(format!(
+                "나눌 위치는 1~{} 사이여야 합니다: {}",
+                chars.saturating_sub(1),
+                position
+            )));
+        }
+        let byte_index = before
+            .text
+            .char_indices()
+            .nth(position)
+            .map(|(index, _)| index)
+            .unwrap_or(before.text.len());
+        let first_text = before.text[..byte_index].trim().to_string();
+        // 앞 구간 텍스트의 단어 수만큼을 앞 구간 단어로 봄
+        let word_split = first_text
+            .split_whitespace()
+            .count()
+            .min(before.words.len());
+
+        // 단어 시간이 있으면 뒤 구간 첫 단어 시작에서, 없으면 글자 수 비율로 나눔
+        let split_ms = split_ms
+            .or_else(|| {
+                before
+                    .words
+                    .get(word_split)
+                    .and_then(|word| word.start_ms)
+                    .filter(|ms| *ms > before.start_ms && *ms < before.end_ms)
+            })
+            .unwrap_or_else(|| {
+                before.start_ms + (before.end_ms - before.start_ms) * position as i64 / chars as i64
+            });
+        if split_ms <= before.start_ms || split_ms >= before.end_ms {
+            return Err(TeuImError::invalid_argument(format!(
+                "나눌 시각은 구간 안이어야 합니다: {}",
+                split_ms
+            )));
+        }
+        let first = StoredSegment {
+            text: first_text,
+            end_ms: split_ms,
+            words: before.words[..word_split].to_vec(),
+            ..before.clone()
+        };
+        let second = StoredSegment {
+            id: Uuid::new_v4().to_string(),
+            text: before.text[byte_index..].trim().to_string(),
+            start_ms: split_ms,
+            words: before.words[word_split..].to_vec(),
+            ..before.clone()
+        };
+
+        tx.execute(
+            "UPDATE segments SET text = ?2, end_ms = ?3 WHERE id = ?1",
+            params![first.id, first.text, first.end_ms],
+        )?;
+        tx.execute(
+            "INSERT INTO segments
+                 (id, session_id, text, start_ms, end_ms, speaker, confidence, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                second.id,
+                session_id,
+                second.text,
+                second.start_ms,
+                second.end_ms,
+                second.speaker,
+                second.confidence,
+                Utc::now()
+            ],
+        )?;
+        save_words(&tx, &first.id, &first.words)?;
+        save_words(&tx, &second.id, &second.words)?;
+        record_revision(&tx, &before, &first, RevisionKind::Split)?;
+        // 뒤 구간의 원본은 나누기 전 구간 전체
+        let origin = StoredSegment {
+            id: second.id.clone(),
+            ..before.clone()
+        };
+        record_revision(&tx, &origin, &second, RevisionKind::Split)?;
+
+        tx.commit()?;
+        Ok((first, second))
+    }
+
+    /// 같은 세션의 구간들을 시간 순으로 이어 하나로 합침
+    ///
+    /// 가장 앞 구간이 남고, 나머지는 삭제하지 않고 병합됨으로 표시해 이력을 보존한다.
+    pub fn merge_segments(&self, ids: &[String]) -> Result<StoredSegment, TeuImError> {
+        if ids.len() < 2 {
+            return Err(TeuImError::invalid_argument(
+                "합칠 구간을 두 개 이상 지정하세요",
+            ));
+        }
+
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+
+        let mut segments = ids
+            .iter()
+            .map(|id| load_segment(&tx, id))
+            .collect::<Result<Vec<_>, _>>()?;
+        if segments
+            .iter()
+            .any(|(session_id, _)| *session_id != segments[0].0)
+        {
+            return Err(TeuImError::invalid_argument(
+                "같은 세션의 구간만 합칠 수 있습니다",
+            ));
+        }
+        segments.sort_by_key(|(_, segment)| segment.start_ms);
+        segments.dedup_by(|a, b| a.1.id == b.1.id);
+
+        let before = segments[0].1.clone();
+        let confidences: Vec<f32> = segments
+            .iter()
+            .filter_map(|(_, segment)| segment.confidence)
+            .collect();
+        let merged = StoredSegment {
+            text: segments
+                .iter()
+                .map(|(_, segment)| segment.text.as_str())
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+            end_ms: segments
+                .iter()
+                .map(|(_, segment)| segment.end_ms)
+                .max()
+                .unwrap_or(before.end_ms),
+            confidence: (!confidences.is_empty())
+                .then(|| confidences.iter().sum::<f32>() / confidences.len() as f32),
+            words: segments
+                .iter()
+                .flat_map(|(_, segment)| segment.words.iter().cloned())
+                .collect(),
+            ..before.clone()
+        };
+
+        tx.execute(
+            "UPDATE segments SET text = ?2, end_ms = ?3, confidence = ?4 WHERE id = ?1",
+            params![merged.id, merged.text, merged.end_ms, merged.confidence],
+        )?;
+        save_words(&tx, &merged.id, &merged.words)?;
+        for (_, segment) in &segments[1..] {
+            tx.execute(
+                "UPDATE segments SET merged_into = ?2 WHERE id = ?1",
+                params![segment.id, merged.id],
+            )?;
+        }
+        record_revision(&tx, &before, &merged, RevisionKind::Merge)?;
+
+        tx.commit()?;
+        Ok(merged)
+    }
+
+    /// 구간 행 상태 조회 (없으면 `None`)
+    pub fn segment_snapshot(&self, id: &str) -> Result<Option<SegmentSnapshot>, TeuImError> {
+        let conn = self.conn()?;
+        let snapshot = conn
+            .query_row(
+                "SELECT id, session_id, text, start_ms, end_ms, speaker, merged_into, confidence
+                     FROM segments WHERE id = ?1",
+                [id],
+                |row| {
+                    Ok(SegmentSnapshot {
+                        id: row.get(0)?,
+                        session_id: row.get(1)?,
+                        text: row.get(2)?,
+                        start_ms: row.get(3)?,
+                        end_ms: row.get(4)?,
+                        speaker: row.get(5)?,
+                        merged_into: row.get(6)?,
+                        confidence: row.get(7)?,
+                        words: Vec::new(),
+                    })
+                },
+            )
+            .optional()?;
+        let Some(mut snapshot) = snapshot else {
+            return Ok(None);
+        };
+        snapshot.words = load_words(&conn, id)?;
+        Ok(Some(snapshot))
+    }
+
+    /// 구간들을 저장해 둔 상태로 되돌림 (`None`이면 행 삭제)
+    ///
+    /// 편집 이력이 있는 구간은 내용이 바뀌었을 때 `restore` 리비전으로 남긴다.
+    pub fn restore_segments(
+        &self,
+        states: &[(String, Option<SegmentSnapshot>)],
+    ) -> Result<(), TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        let now = Utc::now();
+
+        for (id, state) in states {
+            let Some(state) = state else {
+                tx.execute("DELETE FROM segments WHERE id = ?1", [id])?;
+                continue;
+            };
+            tx.execute(
+                "INSERT INTO segments
+                     (id, session_id, text, start_ms, end_ms, speaker, merged_into, confidence,
+                      created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                     ON CONFLICT(id) DO UPDATE SET
+                         text = excluded.text,
+                         start_ms = excluded.start_ms,
+                         end_ms = excluded.end_ms,
+                         merged_into = excluded.merged_into,
+                         confidence = excluded.confidence",
+                params![
+                    state.id,
+                    state.session_id,
+                    state.text,
+                    state.start_ms,
+                    state.end_ms,
+                    state.speaker,
+                    state.merged_into,
+                    state.confidence,
+                    now
+                ],
+            )?;
+            save_words(&tx, &state.id, &state.words)?;
+            tx.execute(
+                "INSERT INTO segment_revisions (segment_id, kind, text, start_ms, end_ms, created_at)
+                     SELECT ?1, ?2, ?3, ?4, ?5, ?6
+                     WHERE EXISTS (SELECT 1 FROM segment_revisions WHERE segment_id = ?1)
+                       AND NOT EXISTS (
+                         SELECT 1 FROM segment_revisions
+                         WHERE id = (SELECT MAX(id) FROM segment_revisions WHERE segment_id = ?1)
+                           AND text = ?3 AND start_ms = ?4 AND end_ms = ?5
+                     )",
+                params![
+                    state.id,
+                    RevisionKind::Restore.as_str(),
+                    state.text,
+                    state.start_ms,
+                    state.end_ms,
+                    now
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 구간 리비전 목록 (오래된 순, 편집한 적 없으면 빈 목록)
+    pub fn segment_history(&self, id: &str) -> Result<Vec<SegmentRevision>, TeuImError> {
+        let conn = self.conn()?;
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM segments WHERE id = ?1)",
+            [id],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(TeuImError::SegmentNotFound {
+                segment_id: id.to_string(),
+            });
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT id, segment_id, kind, text, start_ms, end_ms, created_at
+                 FROM segment_revisions WHERE segment_id = ?1 ORDER BY id",
+        )?;
+        let revisions = stmt
+            .query_map([id], |row| {
+                Ok(SegmentRevision {
+                    id: row.get(0)?,
+                    segment_id: row.get(1)?,
+                    kind: RevisionKind::parse(&row.get::<_, String>(2)?),
+                    text: row.get(3)?,
+                    start_ms: row.get(4)?,
+                    end_ms: row.get(5)?,
+                    created_at: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(revisions)
+    }
+
+    pub fn list_glossary(&self) -> Result<Vec<GlossaryTerm>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, term, translation, note, enabled FROM glossary_terms ORDER BY term",
+        )?;
+        let terms = stmt
+            .query_map([], glossary_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(terms)
+    }
+
+    pub fn add_glossary_term(&self, input: GlossaryTermInput) -> Result<GlossaryTerm, TeuImError> {
+        let term = GlossaryTerm {
+            id: Uuid::new_v4().to_string(),
+            term: input.term,
+            translation: input.translation,
+            note: input.note,
+            enabled: input.enabled,
+        };
+
+        self.conn()?
+            .execute(
+                "INSERT INTO glossary_terms (id, term, translation, note, enabled, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    term.id,
+                    term.term,
+                    term.translation,
+                    term.note,
+                    term.enabled,
+                    Utc::now()
+                ],
+            )
+            .map_err(|e| duplicate_term(e, &term.term))?;
+        Ok(term)
+    }
+
+    /// 여러 용어를 한 트랜잭션으로 추가 (같은 용어가 있으면 대역어·메모 갱신)
+    pub fn import_glossary(&self, terms: Vec<GlossaryTermInput>) -> Result<usize, TeuImError> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO glossary_terms (id, term, translation, note, enabled, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                     ON CONFLICT (term) DO UPDATE SET
+                       translation = COALESCE(excluded.translation, translation),
+                       note = COALESCE(excluded.note, note)",
+            )?;
+            for term in &terms {
+                stmt.execute(params![
+                    Uuid::new_v4().to_string(),
+                    term.term,
+                    term.translation,
+                    term.note,
+                    term.enabled,
+                    Utc::now()
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(terms.len())
+    }
+
+    pub fn update_glossary_term(
+        &self,
+        id: &str,
+        input: GlossaryTermInput,
+    ) -> Result<GlossaryTerm, TeuImError> {
+        let updated = self
+            .conn()?
+            .execute(
+                "UPDATE glossary_terms SET term = ?2, translation = ?3, note = ?4, enabled = ?5
+                     WHERE id = ?1",
+                params![id, input.term, input.translation, input.note, input.enabled],
+            )
+            .map_err(|e| duplicate_term(e, &input.term))?;
+        if updated == 0 {
+            return Err(TeuImError::GlossaryTermNotFound {
+                term_id: id.to_string(),
+            });
+        }
+
+        Ok(GlossaryTerm {
+            id: id.to_string(),
+            term: input.term,
+            translation: input.translation,
+            note: input.note,
+            enabled: input.enabled,
+        })
+    }
+
+    pub fn delete_glossary_term(&self, id: &str) -> Result<(), TeuImError> {
+        let deleted = self
+            .conn()?
+            .execute("DELETE FROM glossary_terms WHERE id = ?1", [id])?;
+        if deleted == 0 {
+            return Err(TeuImError::GlossaryTermNotFound {
+                term_id: id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// 전사나 녹음을 내보낸 시각 기록 (보존 정책의 "내보낸 뒤 삭제"용)
+    pub fn mark_exported(&self, id: &str) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "UPDATE sessions SET exported_at = ?2 WHERE id = ?1",
+            params![id, Utc::now()],
+        )?;
+        Ok(())
+    }
+
+    /// 보존 정책을 적용할 세션 (끝난 세션만)
+    pub fn retention_candidates(&self) -> Result<Vec<RetentionCandidate>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, ended_at, audio_path, exported_at FROM sessions
+                 WHERE ended_at IS NOT NULL",
+        )?;
+        let candidates = stmt
+            .query_map([], |row| {
+                Ok(RetentionCandidate {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    ended_at: row.get(2)?,
+                    audio_path: row.get(3)?,
+                    exported_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(candidates)
+    }
+
+    /// 자동 삭제 기록 추가
+    pub fn add_retention_log(
+        &self,
+        candidate: &RetentionCandidate,
+        action: RetentionAction,
+        reason: RetentionReason,
+    ) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "INSERT INTO retention_log
+                 (deleted_at, session_id, session_title, action, reason, audio_path)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                Utc::now(),
+                candidate.id,
+                candidate.title,
+                action.as_str(),
+                reason.as_str(),
+                candidate.audio_path,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 자동 삭제 기록 (최근 것부터 `limit`개)
+    pub fn retention_log(&self, limit: u32) -> Result<Vec<RetentionLogEntry>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, deleted_at, session_id, session_title, action, reason, audio_path
+                 FROM retention_log ORDER BY id DESC LIMIT ?1",
+        )?;
+        let entries = stmt
+            .query_map([limit], |row| {
+                Ok(RetentionLogEntry {
+                    id: row.get(0)?,
+                    deleted_at: row.get(1)?,
+                    session_id: row.get(2)?,
+                    session_title: row.get(3)?,
+                    action: RetentionAction::parse(&row.get::<_, String>(4)?),
+                    reason: RetentionReason::parse(&row.get::<_, String>(5)?),
+                    audio_path: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// 끝난 세션 ID 목록 (진행 중인 세션은 동기화하지 않음)
+    pub fn ended_session_ids(&self) -> Result<Vec<String>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare("SELECT id FROM sessions WHERE ended_at IS NOT NULL")?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ids)
+    }
+
+    /// 세션별 마지막 동기화 상태
+    pub fn sync_states(&self) -> Result<Vec<SyncState>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt =
+            conn.prepare("SELECT session_id, local_hash, remote_revision FROM sync_state")?;
+        let states = stmt
+            .query_map([], |row| {
+                Ok(SyncState {
+                    session_id: row.get(0)?,
+                    local_hash: row.get(1)?,
+                    remote_revision: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(states)
+    }
+
+    /// 동기화 상태 기록 (있으면 교체)
+    pub fn set_sync_state(
+        &self,
+        session_id: &str,
+        local_hash: &str,
+        remote_revision: &str,
+    ) -> Result<(), TeuImError> {
+        self.conn()?.execute(
+            "INSERT OR REPLACE INTO sync_state (session_id, local_hash, remote_revision, synced_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+            params![session_id, local_hash, remote_revision, Utc::now()],
+        )?;
+        Ok(())
+    }
+
+    /// 모든 동기화 상태 삭제 (동기화 저장소를 바꿨을 때)
+    pub fn clear_sync_states(&self) -> Result<(), TeuImError> {
+        self.conn()?.execute("DELETE FROM sync_state", [])?;
+        Ok(())
+    }
+
+    pub fn remove_sync_state(&self, session_id: &str) -> Result<(), TeuImError> {
+        self.conn()?
+            .execute("DELETE FROM sync_state WHERE session_id = ?1", [session_id])?;
+        Ok(())
+    }
+
+    pub fn add_schedule(&self, schedule: &Schedule) -> Result<(), TeuImError> {
+        let options = serde_json::to_string(&schedule.options).map_err(|e| e.to_string())?;
+        self.conn()?.execute(
+            "INSERT INTO schedules
+                 (id, start_time, duration_secs, device_id, options, status, created_at,
+                 calendar_event)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                schedule.id,
+                schedule.start_time,
+                schedule.duration_secs,
+                schedule.device_id,
+                options,
+                schedule.status.as_str(),
+                schedule.created_at,
+                schedule.calendar_event,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// 예약 목록 (시작 시각 순)
+    pub fn schedules(&self) -> Result<Vec<Schedule>, TeuImError> {
+        let conn = self.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, start_time, duration_secs, device_id, options, status, created_at,
+                 session_id, error, calendar_event
+                 FROM schedules ORDER BY start_time",
+        )?;
+        let schedules = stmt
+            .query_map([], |row| {
+                let options: String = row.get(4)?;
+                Ok(Schedule {
+                    id: row.get(0)?,
+                    start_time: row.get(1)?,
+                    duration_secs: row.get(2)?,
+                    device_id: row.get(3)?,
+                    options: serde_json::from_str::<ScheduleOptions>(&options).unwrap_or_default(),
+                    status: ScheduleStatus::parse(&row.get::<_, String>(5)?),
+                    created_at: row.get(6)?,
+                    session_id: row.get(7)?,
+                    error: row.get(8)?,
+                    calendar_event: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(schedules)
+    }
+
+    /// 예약 상태 갱신 (`session_id`는 `None`이면 기존 값 유지)
+    pub fn set_schedule_status(
+        &self,
+        id: &str,
+        status: ScheduleStatus,
+        session_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), TeuImError> {
+        let updated = self.conn()?.execute(
+            "UPDATE schedules SET status = ?2, session_id = COALESCE(?3, session_id), error = ?4
+                 WHERE id = ?1",
+            params![id, status.as_str(), session_id, error],
+        )?;
+        if updated == 0 {
+            return Err(TeuImError::invalid_argument(format!(
+                "예약을 찾을 수 없습니다: {}",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn delete_session(&self, id: &str) -> Result<(), TeuImError> {
+        let deleted = self
+            .conn()?
+            .execute("DELETE FROM sessions WHERE id = ?1", [id])?;
+        if deleted == 0 {
+            return Err(TeuImError::SessionNotFound {
+                session_id: id.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// 실시간 전사로 만들어진 구간 (분할로 새로 생긴 구간 제외, 병합된 구간 포함)
+const LIVE_SEGMENT_FILTER: &str = "COALESCE(
+        (SELECT kind FROM segment_revisions WHERE segment_id = s.id ORDER BY id LIMIT 1),
+        'original') = 'original'";
+
+/// 실시간 버전 구간 (편집한 구간은 처음 기록한 원본 상태로)
+fn live_segments(conn: &Connection, session_id: &str) -> Result<Vec<VersionSegment>, TeuImError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT s.id,
+                COALESCE(r.text, s.text),
+                COALESCE(r.start_ms, s.start_ms) AS start,
+                COALESCE(r.end_ms, s.end_ms),
+                r.id IS NOT NULL
+             FROM segments s
+             LEFT JOIN segment_revisions r ON r.id =
+                (SELECT MIN(id) FROM segment_revisions WHERE segment_id = s.id)
+             WHERE s.session_id = ?1 AND {}
+             ORDER BY start",
+        LIVE_SEGMENT_FILTER
+    ))?;
+    let rows = stmt
+        .query_map([session_id], |row| {
+            Ok((version_segment_from_row(row)?, row.get::<_, bool>(4)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut segments = Vec::with_capacity(rows.len());
+    for (mut segment, edited) in rows {
+        // 편집하면 단어 목록도 바뀌므로 손대지 않은 구간만 단어를 채움
+        if !edited {
+            segment.words = load_words(conn, &segment.id)?;
+        }
+        segments.push(segment);
+    }
+    Ok(segments)
+}
+
+fn version_segment_from_row(row: &rusqlite::Row) -> rusqlite::Result<VersionSegment> {
+    Ok(VersionSegment {
+        id: row.get(0)?,
+        text: row.get(1)?,
+        start_ms: row.get(2)?,
+        end_ms: row.get(3)?,
+        words: Vec::new(),
+    })
+}
+
+/// 다시 전사한 구간 (시작 시간순)
+fn load_refined_segments(
+    conn: &Connection,
+    refined_id: &str,
+) -> Result<Vec<RefinedSegment>, TeuImError> {
+    let mut stmt = conn.prepare(
+        "SELECT id, segment_id, text, start_ms, end_ms, speaker, confidence, words
+             FROM refined_segments WHERE refined_id = ?1 ORDER BY start_ms",
+    )?;
+    let segments = stmt
+        .query_map([refined_id], |row| {
+            let words: String = row.get(7)?;
+            Ok(RefinedSegment {
+                id: row.get(0)?,
+                segment_id: row.get(1)?,
+                text: row.get(2)?,
+                start_ms: row.get(3)?,
+                end_ms: row.get(4)?,
+                speaker: row.get(5)?,
+                confidence: row.get(6)?,
+                words: serde_json::from_str(&words).unwrap_or_default(),
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(segments)
+}
+
+const REFINED_COLUMNS: &str = "id, session_id, model, status, error, created_at, completed_at";
+
+fn refined_from_row(row: &rusqlite::Row) -> rusqlite::Result<RefinedTranscript> {
+    Ok(RefinedTranscript {
+        id: row.get(0)?,
+        session_id: row.get(1)?,
+        model: row.get(2)?,
+        status: RefineStatus::parse(&row.get::<_, String>(3)?),
+        error: row.get(4)?,
+        created_at: row.get(5)?,
+        completed_at: row.get(6)?,
+        segments: Vec::new(),
+    })
+}
+
+fn glossary_from_row(row: &rusqlite::Row) -> rusqlite::Result<GlossaryTerm> {
+    Ok(GlossaryTerm {
+        id: row.get(0)?,
+        term: row.get(1)?,
+        translation: row.get(2)?,
+        note: row.get(3)?,
+        enabled: row.get(4)?,
+    })
+}
+
+/// 구간 하나와 소속 세션 ID 조회 (병합돼 사라진 구간은 제외)
+fn load_segment(conn: &Connection, id: &str) -> Result<(String, StoredSegment), TeuImError> {
+    conn.query_row(
+        "SELECT session_id, id, text, start_ms, end_ms, speaker, confidence FROM segments
+             WHERE id = ?1 AND merged_into IS NULL",
+        [id],
+        |row| {
+            Ok((
+                row.get(0)?,
+                StoredSegment {
+                    id: row.get(1)?,
+                    text: row.get(2)?,
+                    start_ms: row.get(3)?,
+                    end_ms: row.get(4)?,
+                    speaker: row.get(5)?,
+                    confidence: row.get(6)?,
+                    words: Vec::new(),
+                    translations: Vec::new(),
+                },
+            ))
+        },
+    )
+    .optional()?
+    .ok_or_else(|| TeuImError::SegmentNotFound {
+        segment_id: id.to_string(),
+    })
+    .and_then(|(session_id, mut segment)| {
+        segment.words = load_words(conn, id)?;
+        Ok((session_id, segment))
+    })
+}
+
+/// 구간의 번역 (언어순)
+fn load_translations(conn: &Connection, segment_id: &str) -> Result<Vec<Translation>, TeuImError> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT language, text FROM translations WHERE segment_id = ?1 ORDER BY language",
+    )?;
+    let translations = stmt
+        .query_map([segment_id], |row| {
+            Ok(Translation {
+                language: row.get(0)?,
+                text: row.get(1)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(translations)
+}
+
+/// 구간의 단어 목록 (순서대로)
+fn load_words(conn: &Connection, segment_id: &str) -> Result<Vec<TranscriptWord>, TeuImError> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT text, confidence, start_ms, end_ms FROM segment_words
+             WHERE segment_id = ?1 ORDER BY position",
+    )?;
+    let words = stmt
+        .query_map([segment_id], |row| {
+            Ok(TranscriptWord {
+                text: row.get(0)?,
+                confidence: row.get(1)?,
+                start_ms: row.get(2)?,
+                end_ms: row.get(3)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(words)
+}
+
+/// 구간의 단어 목록을 통째로 교체
+fn save_words(
+    conn: &Connection,
+    segment_id: &str,
+    words: &[TranscriptWord],
+) -> Result<(), TeuImError> {
+    conn.execute(
+        "DELETE FROM segment_words WHERE segment_id = ?1",
+        [segment_id],
+    )?;
+    let mut stmt = conn.prepare_cached(
+        "INSERT INTO segment_words (segment_id, position, text, confidence, start_ms, end_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    for (position, word) in words.iter().enumerate() {
+        stmt.execute(params![
+            segment_id,
+            position as i64,
+            word.text,
+            word.confidence,
+            word.start_ms,
+            word.end_ms
+        ])?;
+    }
+    Ok(())
+}
+
+/// 구간의 현재 상태를 리비전으로 기록
+///
+/// 처음 바뀌는 구간이면 바뀌기 전의 ASR 결과를 `original`로 먼저 남긴다.
+fn record_revision(
+    conn: &Connection,
+    before: &StoredSegment,
+    after: &StoredSegment,
+    kind: RevisionKind,
+) -> Result<(), TeuImError> {
+    let has_history: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM segment_revisions WHERE segment_id = ?1)",
+        [&before.id],
+        |row| row.get(0),
+    )?;
+
+    let mut stmt = conn.prepare(
+        "INSERT INTO segment_revisions (segment_id, kind, text, start_ms, end_ms, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )?;
+    let now = Utc::now();
+    if !has_history {
+        stmt.execute(params![
+            before.id,
+            RevisionKind::Original.as_str(),
+            before.text,
+            before.start_ms,
+            before.end_ms,
+            now
+        ])?;
+    }
+    stmt.execute(params![
+        after.id,
+        kind.as_str(),
+        after.text,
+        after.start_ms,
+        after.end_ms,
+        now
+    ])?;
+    Ok(())
+}
+
+fn load_summary(conn: &Connection, id: &str) -> Result<SessionSummary, TeuImError> {
+    conn.query_row(
+        &format!("SELECT {} FROM sessions s WHERE s.id = ?1", SESSION_COLUMNS),
+        [id],
+        session_from_row,
+    )
+    .optional()?
+    .ok_or_else(|| TeuImError::SessionNotFound {
+        session_id: id.to_string(),
+    })
+}
+
+fn ensure_session(conn: &Connection, id: &str) -> Result<(), TeuImError> {
+    conn.query_row("SELECT 1 FROM sessions WHERE id = ?1", [id], |_| Ok(()))
+        .optional()?
+        .ok_or_else(|| TeuImError::SessionNotFound {
+            session_id: id.to_string(),
+        })
+}
+
+fn session_tags(conn: &Connection, session_id: &str) -> Result<Vec<String>, TeuImError> {
+    let mut stmt =
+        conn.prepare("SELECT tag FROM session_tags WHERE session_id = ?1 ORDER BY tag")?;
+    let tags = stmt
+        .query_map([session_id], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tags)
+}
+
+/// 앞뒤 공백을 없앤 태그 (비었거나 너무 길거나 줄바꿈이 있으면 오류)
+fn normalize_tag(tag: &str) -> Result<String, TeuImError> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(TeuImError::invalid_argument("태그를 입력하세요"));
+    }
+    if tag.chars().count() > MAX_TAG_CHARS || tag.contains(['\n', '\r']) {
+        return Err(TeuImError::invalid_argument(format!(
+            "태그는 줄바꿈 없이 {}자 이하여야 합니다",
+            MAX_TAG_CHARS
+        )));
+    }
+    Ok(tag.to_string())
+}
+
+fn project_not_found(id: &str) -> TeuImError {
+    TeuImError::invalid_argument(format!("프로젝트를 찾을 수 없습니다: {}", id))
+}
+
+/// 같은 용어가 이미 있으면 인자 오류로 변환
+fn duplicate_term(err: rusqlite::Error, term: &str) -> TeuImError {
+    match err.sqlite_error_code() {
+        Some(rusqlite::ErrorCode::ConstraintViolation) => {
+            TeuImError::invalid_argument(format!("이미 있는 용어입니다: {}", term))
+        }
+        _ => err.into(),
+    }
+}
+
+/// 검색어를 FTS5 질의로 변환
+///
+/// 공백으로 나눈 단어를 모두 포함하는 구간을 찾는다. 조사가 붙은 한국어
+/// 어절도 찾을 수 있게 단어마다 접두어 검색으로 바꾸고, 따옴표로 감싸
+/// FTS5 문법 문자는 그대로 검색한다.
+fn fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// 파일 열기 (`key`가 있으면 다른 작업보다 먼저 SQLCipher 키 지정)
+fn open_keyed(path: &Path, key: Option<&str>) -> Result<Connection, TeuImError> {
+    let conn = Connection::open(path)?;
+    if let Some(key) = key {
+        conn.pragma_update(None, "key", key)?;
+    }
+    Ok(conn)
+}
+
+/// 연결 설정 후 마이그레이션까지 마친 연결
+fn connect(path: &Path, key: Option<&str>) -> Result<Connection, TeuImError> {
+    let mut conn = open_keyed(path, key)?;
+    conn.execute_batch("PRAGMA foreign_keys = ON; PRAGMA journal_mode = WAL;")?;
+    migrate(&mut conn)?;
+    Ok(conn)
+}
+
+/// 데이터베이스 전체를 `key`로 암호화한 사본으로 내보냄 (`None`이면 평문)
+fn export_database(conn: &Connection, dest: &Path, key: Option<&str>) -> Result<(), TeuImError> {
+    conn.execute(
+        "ATTACH DATABASE ?1 AS export KEY ?2",
+        params![dest.to_string_lossy(), key.unwrap_or("")],
+    )?;
+    let exported = (|| -> rusqlite::Result<()> {
+        conn.query_row("SELECT sqlcipher_export('export')", [], |_| Ok(()))?;
+        // sqlcipher_export는 스키마 버전을 옮기지 않음
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        conn.pragma_update(Some("export"), "user_version", version)
+    })();
+    conn.execute("DETACH DATABASE export", [])?;
+    Ok(exported?)
+}
+
+/// 아직 적용되지 않은 마이그레이션을 트랜잭션으로 적용
+fn migrate(conn: &mut Connection) -> Result<(), TeuImError> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+    for (index, sql) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(sql)?;
+        tx.pragma_update(None, "user_version", index as i64 + 1)?;
+        tx.commit()?;
+        log::info!("데이터베이스 마이그레이션 적용: {}", index + 1);
+    }
+
+    Ok(())
+}
+
+/// 새 세션 생성
+#[tauri::command]
+pub fn create_session(
+    storage: State<'_, Storage>,
+    title: Option<String>,
+    device_name: Option<String>,
+) -> Result<SessionSummary, TeuImError> {
+    storage.create_session(title, device_name)
+}
+
+/// 세션 목록 조회 (최근 순, 제목·프로젝트·태그·기간으로 거를 수 있음)
+#[tauri::command]
+pub fn list_sessions(
+    storage: State<'_, Storage>,
+    query: Option<SessionQuery>,
+) -> Result<Vec<SessionSummary>, TeuImError> {
+    storage.list_sessions(&query.unwrap_or_default())
+}
+
+/// 세션에 태그 추가 (세션의 태그 목록 반환)
+#[tauri::command]
+pub fn add_tag(
+    storage: State<'_, Storage>,
+    session_id: String,
+    tag: String,
+) -> Result<Vec<String>, TeuImError> {
+    storage.add_tag(&session_id, &tag)
+}
+
+/// 세션에서 태그 제거 (세션의 태그 목록 반환)
+#[tauri::command]
+pub fn remove_tag(
+    storage: State<'_, Storage>,
+    session_id: String,
+    tag: String,
+) -> Result<Vec<String>, TeuImError> {
+    storage.remove_tag(&session_id, &tag)
+}
+
+/// 사용 중인 태그와 태그별 세션 수
+#[tauri::command]
+pub fn list_tags(storage: State<'_, Storage>) -> Result<Vec<TagCount>, TeuImError> {
+    storage.list_tags()
+}
+
+/// 프로젝트 목록 (이름순)
+#[tauri::command]
+pub fn list_projects(storage: State<'_, Storage>) -> Result<Vec<Project>, TeuImError> {
+    storage.list_projects()
+}
+
+/// 새 프로젝트 만들기
+#[tauri::command]
+pub fn create_project(storage: State<'_, Storage>, name: String) -> Result<Project, TeuImError> {
+    storage.create_project(&name)
+}
+
+/// 프로젝트 삭제 (속한 세션은 프로젝트 없음으로 남음)
+#[tauri::command]
+pub fn delete_project(storage: State<'_, Storage>, id: String) -> Result<(), TeuImError> {
+    storage.delete_project(&id)
+}
+
+/// 세션을 프로젝트로 옮김 (`project_id`를 생략하면 프로젝트에서 뺌)
+#[tauri::command]
+pub fn move_session_to_project(
+    storage: State<'_, Storage>,
+    session_id: String,
+    project_id: Option<String>,
+) -> Result<SessionSummary, TeuImError> {
+    storage.move_session_to_project(&session_id, project_id.as_deref())
+}
+
+/// 세션 상세 조회 (구간과 번역 포함)
+#[tauri::command]
+pub fn get_session(storage: State<'_, Storage>, id: String) -> Result<SessionDetail, TeuImError> {
+    storage.get_session(&id)
+}
+
+/// 세션 오디오 통계 조회 (평균 레벨·SNR 추정·클리핑·무음 비율·유실, 없으면 `None`)
+#[tauri::command]
+pub fn get_session_stats(
+    storage: State<'_, Storage>,
+    id: String,
+) -> Result<Option<SessionAudioStats>, TeuImError> {
+    storage.session_audio_stats(&id)
+}
+
+/// 세션 삭제 (구간과 번역도 함께 삭제)
+#[tauri::command]
+pub fn delete_session(storage: State<'_, Storage>, id: String) -> Result<(), TeuImError> {
+    storage.delete_session(&id)
+}
+
+/// 모든 세션의 전사 결과 검색 (관련도 순)
+#[tauri::command]
+pub fn search_transcripts(
+    storage: State<'_, Storage>,
+    query: String,
+    filters: Option<SearchFilters>,
+) -> Result<Vec<SearchHit>, TeuImError> {
+    storage.search(&query, &filters.unwrap_or_default())
+}
+
+/// 전사 구간 편집 이력 조회
+#[tauri::command]
+pub fn get_segment_history(
+    storage: State<'_, Storage>,
+    id: String,
+) -> Result<Vec<SegmentRevision>, TeuImError> {
+    storage.segment_history(&id)
+}
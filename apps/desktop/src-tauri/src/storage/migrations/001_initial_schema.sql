@@ -0,0 +1,34 @@
+-- Migration: 001_initial_schema
+-- Description: 로컬 세션 저장소 초기 스키마
+-- Tables: sessions, segments, translations
+
+-- 녹음/전사 세션
+CREATE TABLE IF NOT EXISTS sessions (
+  id TEXT PRIMARY KEY,
+  title TEXT,
+  device_name TEXT,
+  started_at TEXT NOT NULL,
+  ended_at TEXT,
+  duration_ms INTEGER NOT NULL DEFAULT 0
+);
+
+-- 확정된 전사 구간
+CREATE TABLE IF NOT EXISTS segments (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+  text TEXT NOT NULL,
+  start_ms INTEGER NOT NULL,
+  end_ms INTEGER NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_segments_session ON segments(session_id, start_ms);
+
+-- 구간별 번역 (언어당 하나)
+CREATE TABLE IF NOT EXISTS translations (
+  segment_id TEXT NOT NULL REFERENCES segments(id) ON DELETE CASCADE,
+  language TEXT NOT NULL,
+  text TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  PRIMARY KEY (segment_id, language)
+);
@@ -0,0 +1,5 @@
+-- Migration: 002_segment_speaker
+-- Description: 전사 구간에 화자 라벨 추가
+-- Tables: segments
+
+ALTER TABLE segments ADD COLUMN speaker TEXT;
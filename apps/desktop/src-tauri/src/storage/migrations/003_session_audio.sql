@@ -0,0 +1,5 @@
+-- Migration: 003_session_audio
+-- Description: 세션에 녹음 파일 경로 연결
+-- Tables: sessions
+
+ALTER TABLE sessions ADD COLUMN audio_path TEXT;
@@ -0,0 +1,27 @@
+-- Migration: 004_segment_search
+-- Description: 전사 구간 전문 검색 (FTS5, segments 테이블을 원본으로 하는 외부 콘텐츠 색인)
+-- Tables: segments_fts
+
+CREATE VIRTUAL TABLE IF NOT EXISTS segments_fts USING fts5(
+  text,
+  content = 'segments',
+  content_rowid = 'rowid',
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- 구간 추가/삭제/수정 시 색인 동기화 (세션 삭제로 인한 연쇄 삭제 포함)
+CREATE TRIGGER IF NOT EXISTS segments_fts_insert AFTER INSERT ON segments BEGIN
+  INSERT INTO segments_fts (rowid, text) VALUES (new.rowid, new.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS segments_fts_delete AFTER DELETE ON segments BEGIN
+  INSERT INTO segments_fts (segments_fts, rowid, text) VALUES ('delete', old.rowid, old.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS segments_fts_update AFTER UPDATE OF text ON segments BEGIN
+  INSERT INTO segments_fts (segments_fts, rowid, text) VALUES ('delete', old.rowid, old.text);
+  INSERT INTO segments_fts (rowid, text) VALUES (new.rowid, new.text);
+END;
+
+-- 기존 구간 색인
+INSERT INTO segments_fts (segments_fts) VALUES ('rebuild');
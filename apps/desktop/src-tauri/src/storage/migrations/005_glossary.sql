@@ -0,0 +1,13 @@
+-- Migration: 005_glossary
+-- Description: 고유명사·전문 용어 사전 (전사 엔진 힌트로 사용)
+-- Tables: glossary_terms
+
+CREATE TABLE IF NOT EXISTS glossary_terms (
+  id TEXT PRIMARY KEY,
+  term TEXT NOT NULL UNIQUE,
+  -- 통역용 대역어
+  translation TEXT,
+  note TEXT,
+  enabled INTEGER NOT NULL DEFAULT 1,
+  created_at TEXT NOT NULL
+);
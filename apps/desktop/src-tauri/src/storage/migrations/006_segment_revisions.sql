@@ -0,0 +1,19 @@
+-- Migration: 006_segment_revisions
+-- Description: 전사 구간 편집 이력 (원본 ASR 결과를 덮어쓰지 않고 리비전으로 보관)
+-- Tables: segments, segment_revisions
+
+-- 다른 구간에 병합된 구간 (목록·검색에서 제외하되 이력은 남김)
+ALTER TABLE segments ADD COLUMN merged_into TEXT;
+
+CREATE TABLE IF NOT EXISTS segment_revisions (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  segment_id TEXT NOT NULL REFERENCES segments(id) ON DELETE CASCADE,
+  -- original, edit, split, merge
+  kind TEXT NOT NULL,
+  text TEXT NOT NULL,
+  start_ms INTEGER NOT NULL,
+  end_ms INTEGER NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_revisions_segment ON segment_revisions(segment_id, id);
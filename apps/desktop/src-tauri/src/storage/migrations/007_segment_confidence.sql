@@ -0,0 +1,15 @@
+-- Migration: 007_segment_confidence
+-- Description: 전사 구간·단어별 인식 확신도
+-- Tables: segments, segment_words
+
+-- 구간 전체 확신도 (0~1, 엔진이 주지 않으면 NULL)
+ALTER TABLE segments ADD COLUMN confidence REAL;
+
+CREATE TABLE IF NOT EXISTS segment_words (
+  segment_id TEXT NOT NULL REFERENCES segments(id) ON DELETE CASCADE,
+  -- 구간 안에서의 단어 순서 (0부터)
+  position INTEGER NOT NULL,
+  text TEXT NOT NULL,
+  confidence REAL,
+  PRIMARY KEY (segment_id, position)
+);
@@ -0,0 +1,7 @@
+-- Migration: 008_word_timestamps
+-- Description: 단어별 시작·끝 시각 (재생 중 단어 강조, 자막 분할용)
+-- Tables: segment_words
+
+-- 구간과 같은 기준의 경과 시간 (ms), 엔진이 주지 않으면 NULL
+ALTER TABLE segment_words ADD COLUMN start_ms INTEGER;
+ALTER TABLE segment_words ADD COLUMN end_ms INTEGER;
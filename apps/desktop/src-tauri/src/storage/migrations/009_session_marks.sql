@@ -0,0 +1,16 @@
+-- Migration: 009_session_marks
+-- Description: 세션 타임라인 구간 표시 (음소거 등)
+-- Tables: session_marks
+
+CREATE TABLE IF NOT EXISTS session_marks (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+  -- 표시 종류 (mute)
+  kind TEXT NOT NULL,
+  -- 전사 구간과 같은 기준의 시간 (ms)
+  start_ms INTEGER NOT NULL,
+  end_ms INTEGER NOT NULL,
+  created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_session_marks_session ON session_marks(session_id, start_ms);
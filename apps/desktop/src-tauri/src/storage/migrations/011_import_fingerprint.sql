@@ -0,0 +1,7 @@
+-- Migration: 011_import_fingerprint
+-- Description: 가져온 파일의 내용 해시 (같은 파일 중복 전사 방지)
+-- Tables: sessions
+
+ALTER TABLE sessions ADD COLUMN source_sha256 TEXT;
+
+CREATE INDEX idx_sessions_source_sha256 ON sessions(source_sha256);
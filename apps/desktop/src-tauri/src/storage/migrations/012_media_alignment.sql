@@ -0,0 +1,15 @@
+-- Migration: 012_media_alignment
+-- Description: 따로 녹화한 영상과의 타임코드 정렬
+-- Tables: media_alignments
+
+CREATE TABLE IF NOT EXISTS media_alignments (
+  session_id TEXT PRIMARY KEY REFERENCES sessions(id) ON DELETE CASCADE,
+  media_path TEXT NOT NULL,
+  -- 전사 0ms가 놓이는 영상 타임코드 (입력한 그대로)
+  offset_timecode TEXT NOT NULL,
+  -- 프레임 레이트 (예: 25, 29.97df)
+  frame_rate TEXT NOT NULL,
+  -- 타임코드를 실제 시간으로 바꾼 값 (ms)
+  offset_ms INTEGER NOT NULL,
+  updated_at TEXT NOT NULL
+);
@@ -0,0 +1,20 @@
+-- Migration: 013_retention
+-- Description: 내보낸 시각 기록과 자동 삭제 기록
+-- Tables: sessions, retention_log
+
+ALTER TABLE sessions ADD COLUMN exported_at TEXT;
+
+CREATE TABLE IF NOT EXISTS retention_log (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  deleted_at TEXT NOT NULL,
+  -- 세션이 삭제된 뒤에도 남도록 외래 키를 두지 않음
+  session_id TEXT NOT NULL,
+  session_title TEXT,
+  -- audio | session
+  action TEXT NOT NULL,
+  -- audio_age | session_age | exported
+  reason TEXT NOT NULL,
+  audio_path TEXT
+);
+
+CREATE INDEX idx_retention_log_deleted_at ON retention_log(deleted_at);
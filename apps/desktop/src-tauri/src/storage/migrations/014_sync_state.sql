@@ -0,0 +1,13 @@
+-- Migration: 014_sync_state
+-- Description: 세션별 마지막 동기화 상태 (로컬·원격 변경 감지용)
+-- Tables: sync_state
+
+CREATE TABLE IF NOT EXISTS sync_state (
+  -- 세션을 지운 뒤에도 원격 삭제를 알리도록 외래 키를 두지 않음
+  session_id TEXT PRIMARY KEY,
+  -- 마지막 동기화 때 로컬 세션 내용 지문
+  local_hash TEXT NOT NULL,
+  -- 마지막 동기화 때 원격 색인의 리비전
+  remote_revision TEXT NOT NULL,
+  synced_at TEXT NOT NULL
+);
@@ -0,0 +1,21 @@
+-- Migration: 015_schedules
+-- Description: 예약 녹음 (정한 시각에 캡처·전사·녹음 자동 시작)
+-- Tables: schedules
+
+CREATE TABLE IF NOT EXISTS schedules (
+  id TEXT PRIMARY KEY,
+  start_time TEXT NOT NULL,
+  duration_secs INTEGER NOT NULL,
+  -- 비워 두면 시작할 때의 기본 입력 장치
+  device_id TEXT,
+  -- ScheduleOptions JSON
+  options TEXT NOT NULL,
+  -- pending | running | completed | failed | missed | cancelled
+  status TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  -- 예약으로 만든 저장소 세션 (전사했을 때만)
+  session_id TEXT,
+  error TEXT
+);
+
+CREATE INDEX idx_schedules_start_time ON schedules(start_time);
@@ -0,0 +1,8 @@
+-- Migration: 016_calendar_schedules
+-- Description: 캘린더 회의로 만든 예약 표시 (같은 회의를 두 번 예약하지 않게)
+-- Tables: schedules
+
+-- {캘린더 ID}:{일정 UID}:{시작 시각}
+ALTER TABLE schedules ADD COLUMN calendar_event TEXT;
+
+CREATE UNIQUE INDEX idx_schedules_calendar_event ON schedules(calendar_event);
@@ -0,0 +1,20 @@
+-- Migration: 017_session_audio_stats
+-- Description: 세션별 오디오 통계 (녹음 환경 비교용)
+-- Tables: session_audio_stats
+
+CREATE TABLE IF NOT EXISTS session_audio_stats (
+  session_id TEXT PRIMARY KEY REFERENCES sessions(id) ON DELETE CASCADE,
+  -- 캡처 소스 (microphone / system / remote)
+  source TEXT NOT NULL,
+  duration_ms INTEGER NOT NULL,
+  average_db REAL NOT NULL,
+  peak_db REAL NOT NULL,
+  noise_floor_db REAL NOT NULL,
+  -- 오디오가 짧아 추정하지 못하면 NULL
+  snr_db REAL,
+  clipping_count INTEGER NOT NULL,
+  silence_percent REAL NOT NULL,
+  dropped_buffers INTEGER NOT NULL,
+  dropped_chunks INTEGER NOT NULL,
+  updated_at TEXT NOT NULL
+);
@@ -0,0 +1,33 @@
+-- Migration: 018_refined_transcripts
+-- Description: 세션이 끝난 뒤 더 큰 모델로 다시 전사한 결과 (실시간 결과는 그대로 두고 따로 보관)
+-- Tables: refined_transcripts, refined_segments
+
+CREATE TABLE IF NOT EXISTS refined_transcripts (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+  -- 모델 목록의 이름이나 파일 경로
+  model TEXT NOT NULL,
+  -- queued, running, completed, failed, cancelled
+  status TEXT NOT NULL,
+  error TEXT,
+  created_at TEXT NOT NULL,
+  completed_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_refined_transcripts_session ON refined_transcripts(session_id, created_at);
+
+CREATE TABLE IF NOT EXISTS refined_segments (
+  id TEXT PRIMARY KEY,
+  refined_id TEXT NOT NULL REFERENCES refined_transcripts(id) ON DELETE CASCADE,
+  -- 시간이 가장 많이 겹치는 실시간 구간 (겹치는 구간이 없으면 NULL)
+  segment_id TEXT REFERENCES segments(id) ON DELETE SET NULL,
+  text TEXT NOT NULL,
+  start_ms INTEGER NOT NULL,
+  end_ms INTEGER NOT NULL,
+  speaker TEXT,
+  confidence REAL,
+  -- 단어별 결과 (JSON 배열)
+  words TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX IF NOT EXISTS idx_refined_segments_refined ON refined_segments(refined_id, start_ms);
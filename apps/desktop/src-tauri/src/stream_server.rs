@@ -0,0 +1,244 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager, State};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::error::TeuImError;
+use crate::transcribe::TranscriptSegment;
+use crate::translate::TranslationEvent;
+
+/// 느린 클라이언트를 위해 쌓아 두는 메시지 수 (넘치면 오래된 것부터 버림)
+const BROADCAST_CAPACITY: usize = 256;
+
+/// 외부 클라이언트로 보내는 메시지 (`type` 필드로 구분)
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamMessage {
+    Partial(TranscriptSegment),
+    Final(TranscriptSegment),
+    Translation(TranslationEvent),
+}
+
+struct RunningServer {
+    port: u16,
+    shutdown: watch::Sender<bool>,
+}
+
+/// 실시간 자막 WebSocket 서버 (Tauri 관리 상태)
+///
+/// OBS 오버레이나 보조 앱이 `ws://127.0.0.1:PORT`에 붙어 전사 구간과
+/// 번역을 JSON으로 받는다. 외부 노출을 막기 위해 루프백에만 바인딩하고,
+/// 아무 웹 페이지나 붙지 못하게 브라우저 연결은 허용한 `Origin`만 받는다.
+pub struct StreamServer {
+    tx: broadcast::Sender<String>,
+    running: Mutex<Option<RunningServer>>,
+}
+
+impl Default for StreamServer {
+    fn default() -> Self {
+        Self {
+            tx: broadcast::channel(BROADCAST_CAPACITY).0,
+            running: Mutex::new(None),
+        }
+    }
+}
+
+/// 연결된 클라이언트에 메시지 전송 (서버가 꺼져 있으면 아무것도 하지 않음)
+pub fn publish(app: &AppHandle, message: StreamMessage) {
+    let server = app.state::<StreamServer>();
+    if server.tx.receiver_count() == 0 {
+        return;
+    }
+    match serde_json::to_string(&message) {
+        Ok(json) => {
+            let _ = server.tx.send(json);
+        }
+        Err(e) => log::error!("스트림 메시지 직렬화 실패: {}", e),
+    }
+}
+
+/// 핸드셰이크의 `Origin`이 허용 목록에 있는지 (브라우저가 아니면 `Origin`이 없어 허용)
+fn origin_allowed(origin: Option<&str>, allowed: &[String]) -> bool {
+    match origin {
+        None => true,
+        Some(origin) => {
+            let origin = origin.trim_end_matches('/');
+            allowed
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(origin))
+        }
+    }
+}
+
+/// 스트림 서버 시작 후 실제 포트 반환 (`port`가 0이면 빈 포트 자동 선택)
+///
+/// 브라우저에서 붙는 클라이언트는 `allowed_origins`에 넣은 `Origin`(예: `http://localhost:8080`)만
+/// 받는다. 생략하면 `Origin` 헤더를 보내지 않는 앱·스크립트만 붙을 수 있다.
+#[tauri::command]
+pub async fn start_stream_server(
+    server: State<'_, StreamServer>,
+    port: u16,
+    allowed_origins: Option<Vec<String>>,
+) -> Result<u16, TeuImError> {
+    let allowed_origins: Arc<[String]> = allowed_origins
+        .unwrap_or_default()
+        .iter()
+        .map(|origin| origin.trim().trim_end_matches('/').to_string())
+        .filter(|origin| !origin.is_empty())
+        .collect();
+    if let Some(running) = server.running.lock().unwrap().as_ref() {
+        return Err(TeuImError::from(format!(
+            "스트림 서버가 이미 실행 중입니다 (포트 {})",
+            running.port
+        )));
+    }
+
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], port)))
+        .await
+        .map_err(|e| TeuImError::from(format!("스트림 서버 시작 실패: {}", e)))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| TeuImError::from(e.to_string()))?
+        .port();
+
+    let (shutdown, shutdown_rx) = watch::channel(false);
+    {
+        let mut running = server.running.lock().unwrap();
+        if running.is_some() {
+            return Err("스트림 서버가 이미 실행 중입니다".into());
+        }
+        *running = Some(RunningServer { port, shutdown });
+    }
+
+    tauri::async_runtime::spawn(accept_loop(
+        listener,
+        server.tx.clone(),
+        allowed_origins,
+        shutdown_rx,
+    ));
+    log::info!("스트림 서버 시작: ws://127.0.0.1:{}", port);
+    Ok(port)
+}
+
+/// 스트림 서버 중지 (연결된 클라이언트도 모두 끊음)
+#[tauri::command]
+pub fn stop_stream_server(server: State<'_, StreamServer>) {
+    if let Some(running) = server.running.lock().unwrap().take() {
+        let _ = running.shutdown.send(true);
+        log::info!("스트림 서버 중지: 포트 {}", running.port);
+    }
+}
+
+async fn accept_loop(
+    listener: TcpListener,
+    tx: broadcast::Sender<String>,
+    allowed_origins: Arc<[String]>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, addr)) => {
+                    let client = serve_client(
+                        stream,
+                        addr,
+                        allowed_origins.clone(),
+                        tx.subscribe(),
+                        shutdown.clone(),
+                    );
+                    tauri::async_runtime::spawn(client);
+                }
+                Err(e) => log::warn!("스트림 클라이언트 연결 수락 실패: {}", e),
+            },
+        }
+    }
+}
+
+async fn serve_client(
+    stream: TcpStream,
+    addr: SocketAddr,
+    allowed_origins: Arc<[String]>,
+    mut rx: broadcast::Receiver<String>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    // 오류 응답 타입은 tungstenite 콜백 서명이 정함
+    #[allow(clippy::result_large_err)]
+    let check_origin = |request: &Request, response: Response| {
+        let origin = request
+            .headers()
+            .get("origin")
+            .map(|origin| origin.to_str().unwrap_or_default());
+        if origin_allowed(origin, &allowed_origins) {
+            return Ok(response);
+        }
+        log::warn!(
+            "허용하지 않은 Origin의 스트림 연결 거부 ({}): {}",
+            addr,
+            origin.unwrap_or_default()
+        );
+        let mut rejected = ErrorResponse::new(Some("Origin not allowed".to_string()));
+        *rejected.status_mut() = StatusCode::FORBIDDEN;
+        Err(rejected)
+    };
+    let mut ws = match tokio_tungstenite::accept_hdr_async(stream, check_origin).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            log::warn!("WebSocket 핸드셰이크 실패 ({}): {}", addr, e);
+            return;
+        }
+    };
+    log::info!("스트림 클라이언트 연결: {}", addr);
+
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => {
+                let _ = ws.close(None).await;
+                break;
+            }
+            message = rx.recv() => match message {
+                Ok(json) => {
+                    if ws.send(Message::text(json)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    log::warn!("스트림 클라이언트가 밀려 메시지 {}개 건너뜀: {}", skipped, addr);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // 클라이언트 메시지는 무시하고 연결 종료만 감지
+            incoming = ws.next() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    log::info!("스트림 클라이언트 연결 종료: {}", addr);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clients_without_origin_are_allowed() {
+        assert!(origin_allowed(None, &[]));
+    }
+
+    #[test]
+    fn browser_origin_must_be_listed() {
+        let allowed = vec!["http://localhost:8080".to_string()];
+        assert!(origin_allowed(Some("http://localhost:8080"), &allowed));
+        assert!(origin_allowed(Some("HTTP://LOCALHOST:8080/"), &allowed));
+        assert!(!origin_allowed(Some("https://evil.example"), &allowed));
+        assert!(!origin_allowed(Some("null"), &allowed));
+        assert!(!origin_allowed(Some("http://localhost:8080"), &[]));
+    }
+}
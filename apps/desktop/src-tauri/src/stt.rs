@@ -0,0 +1,337 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::State;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::credentials;
+use crate::transcribe::TranscriptWord;
+
+mod azure;
+mod deepgram;
+mod google;
+
+/// 클라우드 STT 입력 샘플레이트
+pub const STT_SAMPLE_RATE: u32 = 16_000;
+
+/// 입력이 끝난 뒤 남은 결과를 기다리는 최대 시간
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 연결이 끊겼을 때 연속으로 다시 연결해 보는 최대 횟수
+const MAX_RECONNECTS: u32 = 5;
+
+/// 첫 재연결 대기 시간 (시도마다 두 배, `MAX_RECONNECT_DELAY`까지)
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+/// 이보다 오래 유지된 연결이 끊기면 재연결 횟수를 처음부터 다시 셈
+const STABLE_CONNECTION: Duration = Duration::from_secs(30);
+
+/// 클라우드 STT로 보내는 입력 (16kHz 모노)
+pub enum SttInput {
+    Audio(Vec<i16>),
+    /// 발화가 끝났으니 지금까지 보낸 오디오를 확정
+    Flush,
+}
+
+/// 클라우드 STT 인식 결과 (시간은 스트림 시작부터의 경과 시간, ms)
+pub struct SttResult {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub is_final: bool,
+    /// 인식 확신도 (0~1, 제공자가 주지 않으면 없음)
+    pub confidence: Option<f32>,
+    pub words: Vec<TranscriptWord>,
+}
+
+impl SttResult {
+    /// 결과 시간을 `offset_ms`만큼 옮김
+    fn shifted(self, offset_ms: i64) -> Self {
+        Self {
+            start_ms: self.start_ms + offset_ms,
+            end_ms: self.end_ms + offset_ms,
+            words: self
+                .words
+                .iter()
+                .map(|word| word.shifted(offset_ms))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+pub type SttFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// 클라우드 음성 인식 제공자
+pub trait SttProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// 입력 채널이 닫힐 때까지 오디오를 보내고 인식 결과를 `results`로 전달
+    ///
+    /// `hints`는 용어집 용어로, 제공자가 지원하면 인식 가중치를 높인다.
+    fn stream(
+        &self,
+        audio: UnboundedReceiver<SttInput>,
+        results: UnboundedSender<SttResult>,
+        hints: Vec<String>,
+    ) -> SttFuture;
+}
+
+/// 전사 엔진 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SttProviderKind {
+    /// 로컬 whisper.cpp
+    Local,
+    Deepgram,
+    Azure,
+    Google,
+}
+
+impl SttProviderKind {
+    /// 키체인에 API 키를 저장하는 이름
+    fn credential_name(self) -> &'static str {
+        match self {
+            SttProviderKind::Local => "local",
+            SttProviderKind::Deepgram => "deepgram",
+            SttProviderKind::Azure => "azure",
+            SttProviderKind::Google => "google",
+        }
+    }
+}
+
+/// 클라우드 제공자 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SttOptions {
+    /// 생략하면 키체인에 저장된 키 사용
+    pub api_key: Option<String>,
+    /// Azure 리전 (예: `koreacentral`)
+    pub region: Option<String>,
+    /// 인식 언어 코드 (예: `ko-KR`)
+    pub language: Option<String>,
+    /// 제공자별 모델 이름
+    pub model: Option<String>,
+}
+
+/// 선택된 전사 제공자 (Tauri 관리 상태, 없으면 로컬 엔진)
+#[derive(Default)]
+pub struct SttConfig {
+    provider: Mutex<Option<Arc<dyn SttProvider>>>,
+}
+
+impl SttConfig {
+    pub fn current(&self) -> Option<Arc<dyn SttProvider>> {
+        self.provider.lock().unwrap().clone()
+    }
+}
+
+/// 전사에 쓸 제공자 설정
+///
+/// 다음 `start_transcription`부터 적용되며, 결과는 로컬 엔진과 같은
+/// `transcript-partial` / `transcript-final` 이벤트로 전달된다.
+#[tauri::command]
+pub fn configure_stt_provider(
+    config: State<'_, SttConfig>,
+    provider: SttProviderKind,
+    options: Option<SttOptions>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_default();
+    let api_key = || -> Result<String, String> {
+        match options.api_key.clone().filter(|key| !key.is_empty()) {
+            Some(key) => Ok(key),
+            None => credentials::api_key(provider.credential_name())?
+                .ok_or_else(|| "API 키가 필요합니다".to_string()),
+        }
+    };
+
+    let selected: Option<Arc<dyn SttProvider>> = match provider {
+        SttProviderKind::Local => None,
+        SttProviderKind::Deepgram => Some(Arc::new(deepgram::Deepgram {
+            api_key: api_key()?,
+            language: options.language.clone(),
+            model: options.model.clone(),
+        })),
+        SttProviderKind::Azure => Some(Arc::new(azure::Azure {
+            api_key: api_key()?,
+            region: options.region.clone().ok_or("Azure 리전이 필요합니다")?,
+            language: options.language.clone(),
+        })),
+        SttProviderKind::Google => Some(Arc::new(google::Google {
+            api_key: api_key()?,
+            language: options.language.clone(),
+            model: options.model.clone(),
+        })),
+    };
+
+    log::info!("전사 제공자 설정: {:?}", provider);
+    *config.provider.lock().unwrap() = selected;
+    Ok(())
+}
+
+/// 일시적인 네트워크·제공자 오류가 나면 다시 연결하며 스트리밍
+///
+/// 연결이 끊기면 대기 시간을 늘려 가며 `MAX_RECONNECTS`번까지 새로 연결하고,
+/// 그동안 들어온 입력은 새 연결로 보낸다. 새 연결의 결과 시간은 앞선 연결로 보낸
+/// 오디오 길이만큼 옮긴다. `on_error`에는 오류 메시지, 시도 횟수, 재연결 여부를 넘긴다.
+pub async fn stream_with_retry(
+    provider: Arc<dyn SttProvider>,
+    mut audio: UnboundedReceiver<SttInput>,
+    results: UnboundedSender<SttResult>,
+    hints: Vec<String>,
+    on_error: impl Fn(&str, u32, bool),
+) -> Result<(), String> {
+    let mut attempt = 0;
+    let mut offset_ms = 0;
+    loop {
+        let (input_tx, input_rx) = mpsc::unbounded_channel();
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+        let mut stream = provider.stream(input_rx, result_tx, hints.clone());
+        let mut input_tx = Some(input_tx);
+        let mut sent_samples = 0;
+        let connected = Instant::now();
+
+        let outcome = loop {
+            tokio::select! {
+                outcome = &mut stream => break outcome,
+                input = audio.recv(), if input_tx.is_some() => match (input, &input_tx) {
+                    (Some(input), Some(tx)) => {
+                        if let SttInput::Audio(samples) = &input {
+                            sent_samples += samples.len();
+                        }
+                        let _ = tx.send(input);
+                    }
+                    // 입력이 끝났으니 제공자에도 알림
+                    _ => input_tx = None,
+                },
+                Some(result) = result_rx.recv() => {
+                    let _ = results.send(result.shifted(offset_ms));
+                }
+            }
+        };
+        while let Ok(result) = result_rx.try_recv() {
+            let _ = results.send(result.shifted(offset_ms));
+        }
+
+        // 입력이 끝난 뒤의 종료는 정상 (오류도 다시 연결해 봐야 보낼 오디오가 없음)
+        if input_tx.is_none() {
+            return outcome;
+        }
+        let error = outcome
+            .err()
+            .unwrap_or_else(|| "STT 서버가 연결을 닫았습니다".to_string());
+        if connected.elapsed() >= STABLE_CONNECTION {
+            attempt = 0;
+        }
+        attempt += 1;
+        if attempt > MAX_RECONNECTS {
+            on_error(&error, attempt - 1, false);
+            return Err(error);
+        }
+
+        let delay = (RECONNECT_DELAY * 2u32.pow(attempt - 1)).min(MAX_RECONNECT_DELAY);
+        log::warn!(
+            "{} 연결 끊김, {}ms 뒤 다시 연결 ({}/{}): {}",
+            provider.name(),
+            delay.as_millis(),
+            attempt,
+            MAX_RECONNECTS,
+            error
+        );
+        on_error(&error, attempt, true);
+        tokio::time::sleep(delay).await;
+        offset_ms += (sent_samples * 1000 / STT_SAMPLE_RATE as usize) as i64;
+    }
+}
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// 인증 헤더를 붙여 WebSocket 연결
+async fn connect(url: &str, headers: &[(&'static str, String)]) -> Result<WsStream, String> {
+    let mut request = url.into_client_request().map_err(|e| e.to_string())?;
+    for (name, value) in headers {
+        let value = HeaderValue::from_str(value).map_err(|e| e.to_string())?;
+        request.headers_mut().insert(*name, value);
+    }
+
+    let (stream, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(|e| format!("STT 서버 연결 실패: {}", e))?;
+    Ok(stream)
+}
+
+/// 입력과 서버 응답을 함께 처리하는 공통 WebSocket 루프
+///
+/// `encode`는 입력을 보낼 메시지로, `decode`는 받은 텍스트 메시지를 결과로 바꾼다.
+/// `decode`가 `Err(())`를 돌려주면 서버가 스트림을 끝낸 것으로 본다.
+async fn run_socket(
+    stream: WsStream,
+    mut audio: UnboundedReceiver<SttInput>,
+    results: UnboundedSender<SttResult>,
+    mut encode: impl FnMut(Option<SttInput>) -> Vec<Message> + Send,
+    decode: impl Fn(&str) -> Result<Option<SttResult>, ()> + Send,
+) -> Result<(), String> {
+    let (mut sink, mut incoming) = stream.split();
+
+    let handle = |message: Option<Result<Message, _>>| -> Result<bool, String> {
+        match message {
+            Some(Ok(Message::Text(text))) => match decode(text.as_str()) {
+                Ok(Some(result)) => {
+                    let _ = results.send(result);
+                    Ok(true)
+                }
+                Ok(None) => Ok(true),
+                Err(()) => Ok(false),
+            },
+            Some(Ok(Message::Close(_))) | None => Ok(false),
+            Some(Ok(_)) => Ok(true),
+            Some(Err(e)) => Err(format!("STT 연결 오류: {}", e)),
+        }
+    };
+
+    loop {
+        tokio::select! {
+            input = audio.recv() => {
+                let closing = input.is_none();
+                for message in encode(input) {
+                    sink.send(message).await.map_err(|e| format!("STT 전송 실패: {}", e))?;
+                }
+                if closing {
+                    break;
+                }
+            }
+            message = incoming.next() => {
+                if !handle(message)? {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // 입력이 끝난 뒤 서버가 보내는 나머지 결과 수신
+    let drain = async {
+        while handle(incoming.next().await)? {}
+        Ok::<_, String>(())
+    };
+    match tokio::time::timeout(DRAIN_TIMEOUT, drain).await {
+        Ok(result) => result?,
+        Err(_) => log::warn!("STT 마지막 결과 대기 시간 초과"),
+    }
+
+    let _ = sink.close().await;
+    Ok(())
+}
+
+/// 16비트 PCM 리틀 엔디언 바이트열
+fn pcm_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+}
@@ -0,0 +1,219 @@
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
+
+use super::{
+    connect, pcm_bytes, run_socket, SttFuture, SttInput, SttProvider, SttResult, STT_SAMPLE_RATE,
+};
+use crate::transcribe::TranscriptWord;
+
+/// 언어를 지정하지 않았을 때 인식 언어
+const DEFAULT_LANGUAGE: &str = "ko-KR";
+
+/// Azure Speech 시간 단위 (100ns 틱)
+const TICKS_PER_MS: i64 = 10_000;
+
+/// Azure Speech 실시간 인식 (WebSocket 음성 프로토콜)
+pub struct Azure {
+    pub api_key: String,
+    pub region: String,
+    pub language: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Hypothesis {
+    text: String,
+    offset: i64,
+    duration: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Phrase {
+    recognition_status: String,
+    offset: i64,
+    duration: i64,
+    /// `format=detailed` 응답의 후보 목록 (확신도 순)
+    #[serde(default, rename = "NBest")]
+    n_best: Vec<Candidate>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Candidate {
+    display: String,
+    confidence: Option<f32>,
+    /// `wordLevelTimestamps=true`일 때만 있음 (구두점 없는 어휘 형태)
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Word {
+    word: String,
+    offset: i64,
+    duration: i64,
+}
+
+impl SttProvider for Azure {
+    fn name(&self) -> &'static str {
+        "Azure"
+    }
+
+    fn stream(
+        &self,
+        audio: UnboundedReceiver<SttInput>,
+        results: UnboundedSender<SttResult>,
+        hints: Vec<String>,
+    ) -> SttFuture {
+        let url = format!(
+            "wss://{}.stt.speech.microsoft.com/speech/recognition/conversation/cognitiveservices/v1?language={}&format=detailed&wordLevelTimestamps=true",
+            self.region,
+            self.language.as_deref().unwrap_or(DEFAULT_LANGUAGE)
+        );
+        let headers = [
+            ("Ocp-Apim-Subscription-Key", self.api_key.clone()),
+            ("X-ConnectionId", Uuid::new_v4().simple().to_string()),
+        ];
+
+        Box::pin(async move {
+            let stream = connect(&url, &headers).await?;
+
+            let request_id = Uuid::new_v4().simple().to_string();
+            let mut sent_header = false;
+            let mut hints = hints;
+            let encode = move |input: Option<SttInput>| match input {
+                Some(SttInput::Audio(samples)) => {
+                    // 첫 오디오 메시지에는 WAV 헤더가 있어야 함
+                    let mut payload = Vec::new();
+                    if !sent_header {
+                        payload.extend(wav_header());
+                        sent_header = true;
+                    }
+                    payload.extend(pcm_bytes(&samples));
+                    let audio = audio_message(&request_id, &payload);
+                    // 용어 목록은 첫 오디오보다 먼저 보내야 적용됨
+                    if hints.is_empty() {
+                        vec![audio]
+                    } else {
+                        let hints = std::mem::take(&mut hints);
+                        vec![context_message(&request_id, &hints), audio]
+                    }
+                }
+                // 발화 구분은 서버가 침묵으로 판단
+                Some(SttInput::Flush) => Vec::new(),
+                // 빈 오디오 메시지가 입력 종료 신호
+                None => vec![audio_message(&request_id, &[])],
+            };
+
+            run_socket(stream, audio, results, encode, decode).await
+        })
+    }
+}
+
+/// `Path` 헤더에 따라 응답 본문 해석 (`turn.end`는 스트림 종료)
+fn decode(text: &str) -> Result<Option<SttResult>, ()> {
+    let (headers, body) = text.split_once("\r\n\r\n").unwrap_or((text, ""));
+    let path = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("Path:"))
+        .map(str::trim)
+        .unwrap_or_default();
+
+    match path {
+        "speech.hypothesis" => Ok(serde_json::from_str::<Hypothesis>(body)
+            .ok()
+            .map(|h| result(h.text, h.offset, h.duration, false, None))),
+        "speech.phrase" => Ok(serde_json::from_str::<Phrase>(body)
+            .ok()
+            .filter(|p| p.recognition_status == "Success")
+            .and_then(|p| {
+                let best = p.n_best.into_iter().next()?;
+                if best.display.is_empty() {
+                    return None;
+                }
+                let mut phrase = result(best.display, p.offset, p.duration, true, best.confidence);
+                phrase.words = best
+                    .words
+                    .into_iter()
+                    .map(|word| TranscriptWord {
+                        text: word.word,
+                        confidence: None,
+                        start_ms: Some(word.offset / TICKS_PER_MS),
+                        end_ms: Some((word.offset + word.duration) / TICKS_PER_MS),
+                    })
+                    .collect();
+                Some(phrase)
+            })),
+        "turn.end" => Err(()),
+        _ => Ok(None),
+    }
+}
+
+fn result(
+    text: String,
+    offset: i64,
+    duration: i64,
+    is_final: bool,
+    confidence: Option<f32>,
+) -> SttResult {
+    SttResult {
+        text,
+        start_ms: offset / TICKS_PER_MS,
+        end_ms: (offset + duration) / TICKS_PER_MS,
+        is_final,
+        confidence,
+        words: Vec::new(),
+    }
+}
+
+/// `[헤더 길이 u16 BE][헤더][오디오]` 형식의 바이너리 메시지
+fn audio_message(request_id: &str, payload: &[u8]) -> Message {
+    let headers = format!(
+        "Path: audio\r\nX-RequestId: {}\r\nX-Timestamp: {}\r\nContent-Type: audio/x-wav\r\n",
+        request_id,
+        Utc::now().to_rfc3339()
+    );
+
+    let mut message = Vec::with_capacity(2 + headers.len() + payload.len());
+    message.extend((headers.len() as u16).to_be_bytes());
+    message.extend(headers.as_bytes());
+    message.extend(payload);
+    Message::binary(message)
+}
+
+/// 구문 목록(Phrase List) 메시지
+fn context_message(request_id: &str, hints: &[String]) -> Message {
+    let items: Vec<_> = hints.iter().map(|hint| json!({ "Text": hint })).collect();
+    let body = json!({ "dgi": { "Groups": [{ "Type": "Generic", "Items": items }] } });
+    Message::text(format!(
+        "Path: speech.context\r\nX-RequestId: {}\r\nX-Timestamp: {}\r\nContent-Type: application/json\r\n\r\n{}",
+        request_id,
+        Utc::now().to_rfc3339(),
+        body
+    ))
+}
+
+/// 길이를 알 수 없는 스트림용 16kHz 모노 16비트 WAV 헤더
+fn wav_header() -> Vec<u8> {
+    let byte_rate = STT_SAMPLE_RATE * 2;
+    let mut header = Vec::with_capacity(44);
+    header.extend(b"RIFF");
+    header.extend(0u32.to_le_bytes());
+    header.extend(b"WAVEfmt ");
+    header.extend(16u32.to_le_bytes());
+    header.extend(1u16.to_le_bytes()); // PCM
+    header.extend(1u16.to_le_bytes()); // 모노
+    header.extend(STT_SAMPLE_RATE.to_le_bytes());
+    header.extend(byte_rate.to_le_bytes());
+    header.extend(2u16.to_le_bytes());
+    header.extend(16u16.to_le_bytes());
+    header.extend(b"data");
+    header.extend(0u32.to_le_bytes());
+    header
+}
@@ -0,0 +1,144 @@
+use serde::Deserialize;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::{
+    connect, pcm_bytes, run_socket, SttFuture, SttInput, SttProvider, SttResult, STT_SAMPLE_RATE,
+};
+use crate::transcribe::TranscriptWord;
+
+const LISTEN_URL: &str = "wss://api.deepgram.com/v1/listen";
+
+/// Deepgram 실시간 스트리밍 API
+pub struct Deepgram {
+    pub api_key: String,
+    pub language: Option<String>,
+    pub model: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    start: f64,
+    #[serde(default)]
+    duration: f64,
+    #[serde(default)]
+    is_final: bool,
+    channel: Option<Channel>,
+}
+
+#[derive(Deserialize)]
+struct Channel {
+    alternatives: Vec<Alternative>,
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    transcript: String,
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<Word>,
+}
+
+#[derive(Deserialize)]
+struct Word {
+    word: String,
+    /// `punctuate=true`일 때만 있음
+    punctuated_word: Option<String>,
+    confidence: Option<f32>,
+    /// 스트림 시작 기준 초
+    start: Option<f64>,
+    end: Option<f64>,
+}
+
+impl SttProvider for Deepgram {
+    fn name(&self) -> &'static str {
+        "Deepgram"
+    }
+
+    fn stream(
+        &self,
+        audio: UnboundedReceiver<SttInput>,
+        results: UnboundedSender<SttResult>,
+        hints: Vec<String>,
+    ) -> SttFuture {
+        let mut url = format!(
+            "{}?encoding=linear16&sample_rate={}&channels=1&interim_results=true&punctuate=true",
+            LISTEN_URL, STT_SAMPLE_RATE
+        );
+        if let Some(language) = &self.language {
+            url.push_str(&format!("&language={}", language));
+        }
+        if let Some(model) = &self.model {
+            url.push_str(&format!("&model={}", model));
+        }
+        // Nova-3는 keyterm, 이전 모델은 keywords로 용어 가중치 지정
+        let hint_param = match &self.model {
+            Some(model) if model.starts_with("nova-3") => "keyterm",
+            _ => "keywords",
+        };
+        let url = match reqwest::Url::parse(&url) {
+            Ok(mut parsed) => {
+                for hint in &hints {
+                    parsed.query_pairs_mut().append_pair(hint_param, hint);
+                }
+                parsed.to_string()
+            }
+            Err(_) => url,
+        };
+        let auth = format!("Token {}", self.api_key);
+
+        Box::pin(async move {
+            let stream = connect(&url, &[("Authorization", auth)]).await?;
+            run_socket(stream, audio, results, encode, decode).await
+        })
+    }
+}
+
+fn encode(input: Option<SttInput>) -> Vec<Message> {
+    match input {
+        Some(SttInput::Audio(samples)) => vec![Message::binary(pcm_bytes(&samples))],
+        Some(SttInput::Flush) => vec![Message::text(r#"{"type":"Finalize"}"#)],
+        None => vec![Message::text(r#"{"type":"CloseStream"}"#)],
+    }
+}
+
+fn decode(text: &str) -> Result<Option<SttResult>, ()> {
+    let Ok(response) = serde_json::from_str::<Response>(text) else {
+        return Ok(None);
+    };
+    if response.kind != "Results" {
+        return Ok(None);
+    }
+
+    let Some(alternative) = response
+        .channel
+        .and_then(|channel| channel.alternatives.into_iter().next())
+    else {
+        return Ok(None);
+    };
+    let transcript = alternative.transcript.trim().to_string();
+    if transcript.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(SttResult {
+        text: transcript,
+        start_ms: (response.start * 1000.0) as i64,
+        end_ms: ((response.start + response.duration) * 1000.0) as i64,
+        is_final: response.is_final,
+        confidence: alternative.confidence,
+        words: alternative
+            .words
+            .into_iter()
+            .map(|word| TranscriptWord {
+                text: word.punctuated_word.unwrap_or(word.word),
+                confidence: word.confidence,
+                start_ms: word.start.map(|secs| (secs * 1000.0) as i64),
+                end_ms: word.end.map(|secs| (secs * 1000.0) as i64),
+            })
+            .collect(),
+    }))
+}
@@ -0,0 +1,195 @@
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+use super::{pcm_bytes, SttFuture, SttInput, SttProvider, SttResult, STT_SAMPLE_RATE};
+use crate::transcribe::TranscriptWord;
+
+const RECOGNIZE_URL: &str = "https://speech.googleapis.com/v1/speech:recognize";
+
+/// 발화 종료 신호가 없어도 이 길이가 되면 인식 요청 (동기 API 한도는 60초)
+const MAX_CHUNK_MS: usize = 15_000;
+
+/// 언어를 지정하지 않았을 때 인식 언어
+const DEFAULT_LANGUAGE: &str = "ko-KR";
+
+/// Google Cloud Speech-to-Text
+///
+/// 스트리밍 API는 gRPC 전용이라 REST `speech:recognize`를 발화 단위로 호출한다.
+/// 부분 결과는 없고 확정 결과만 전달된다.
+pub struct Google {
+    pub api_key: String,
+    pub language: Option<String>,
+    pub model: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(default)]
+    results: Vec<RecognitionResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecognitionResult {
+    alternatives: Vec<Alternative>,
+    result_end_time: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    #[serde(default)]
+    transcript: String,
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<WordInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WordInfo {
+    word: String,
+    confidence: Option<f32>,
+    start_time: Option<String>,
+    end_time: Option<String>,
+}
+
+impl SttProvider for Google {
+    fn name(&self) -> &'static str {
+        "Google"
+    }
+
+    fn stream(
+        &self,
+        mut audio: UnboundedReceiver<SttInput>,
+        results: UnboundedSender<SttResult>,
+        hints: Vec<String>,
+    ) -> SttFuture {
+        let api_key = self.api_key.clone();
+        let mut config = json!({
+            "encoding": "LINEAR16",
+            "sampleRateHertz": STT_SAMPLE_RATE,
+            "languageCode": self.language.as_deref().unwrap_or(DEFAULT_LANGUAGE),
+            "enableAutomaticPunctuation": true,
+            "enableWordConfidence": true,
+            "enableWordTimeOffsets": true,
+        });
+        if let Some(model) = &self.model {
+            config["model"] = json!(model);
+        }
+        if !hints.is_empty() {
+            config["speechContexts"] = json!([{ "phrases": hints }]);
+        }
+
+        Box::pin(async move {
+            let client = reqwest::Client::new();
+            let max_chunk = MAX_CHUNK_MS * STT_SAMPLE_RATE as usize / 1000;
+            let mut buffer: Vec<i16> = Vec::new();
+            let mut offset_ms: i64 = 0;
+
+            loop {
+                let input = audio.recv().await;
+                let closing = input.is_none();
+                let flush = match input {
+                    Some(SttInput::Audio(samples)) => {
+                        buffer.extend(samples);
+                        buffer.len() >= max_chunk
+                    }
+                    Some(SttInput::Flush) | None => !buffer.is_empty(),
+                };
+
+                if flush {
+                    let chunk_ms = (buffer.len() * 1000 / STT_SAMPLE_RATE as usize) as i64;
+                    for result in recognize(&client, &api_key, &config, &buffer).await? {
+                        let _ = results.send(SttResult {
+                            start_ms: offset_ms + result.start_ms,
+                            end_ms: offset_ms + result.end_ms.min(chunk_ms),
+                            words: result
+                                .words
+                                .iter()
+                                .map(|word| word.shifted(offset_ms))
+                                .collect(),
+                            ..result
+                        });
+                    }
+                    offset_ms += chunk_ms;
+                    buffer.clear();
+                }
+
+                if closing {
+                    return Ok(());
+                }
+            }
+        })
+    }
+}
+
+/// 구간 하나를 인식해 구간 시작 기준 결과 반환
+///
+/// API 키는 URL이 아닌 헤더로 보내고, 오류에서도 URL을 지워 로그에 남지 않게 한다.
+async fn recognize(
+    client: &reqwest::Client,
+    api_key: &str,
+    config: &serde_json::Value,
+    samples: &[i16],
+) -> Result<Vec<SttResult>, String> {
+    let content = base64::engine::general_purpose::STANDARD.encode(pcm_bytes(samples));
+    let response = client
+        .post(RECOGNIZE_URL)
+        .header("x-goog-api-key", api_key)
+        .json(&json!({ "config": config, "audio": { "content": content } }))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Google STT 요청 실패: {}", e.without_url()))?
+        .json::<Response>()
+        .await
+        .map_err(|e| format!("Google STT 응답 해석 실패: {}", e.without_url()))?;
+
+    let mut start_ms = 0;
+    let mut segments = Vec::new();
+    for result in response.results {
+        let end_ms = result
+            .result_end_time
+            .as_deref()
+            .and_then(duration_ms)
+            .unwrap_or(start_ms);
+        let alternative = result.alternatives.into_iter().next();
+        let text = alternative
+            .as_ref()
+            .map(|a| a.transcript.trim().to_string())
+            .unwrap_or_default();
+
+        if let Some(alternative) = alternative.filter(|_| !text.is_empty()) {
+            segments.push(SttResult {
+                text,
+                start_ms,
+                end_ms,
+                is_final: true,
+                confidence: alternative.confidence,
+                words: alternative
+                    .words
+                    .into_iter()
+                    .map(|word| TranscriptWord {
+                        text: word.word,
+                        confidence: word.confidence,
+                        start_ms: word.start_time.as_deref().and_then(duration_ms),
+                        end_ms: word.end_time.as_deref().and_then(duration_ms),
+                    })
+                    .collect(),
+            });
+        }
+        start_ms = end_ms;
+    }
+    Ok(segments)
+}
+
+/// `"1.500s"` 형식의 시간을 ms로
+fn duration_ms(value: &str) -> Option<i64> {
+    value
+        .trim_end_matches('s')
+        .parse::<f64>()
+        .ok()
+        .map(|secs| (secs * 1000.0) as i64)
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, State};
+
+use crate::error::TeuImError;
+use crate::llm::{self, ChatRequest};
+use crate::storage::Storage;
+
+/// 모델에 넘길 전사의 최대 길이 (글자 수, 넘으면 뒷부분을 잘라냄)
+const MAX_TRANSCRIPT_CHARS: usize = 60_000;
+
+/// 요약 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStyle {
+    /// 핵심 요약, 할 일, 결정 사항 모두
+    #[default]
+    Full,
+    /// 핵심 요약 글머리표만
+    Bullets,
+    /// 할 일(담당자·기한 포함)만
+    ActionItems,
+    /// 결정 사항만
+    Decisions,
+}
+
+impl SummaryStyle {
+    fn instructions(self) -> &'static str {
+        match self {
+            Self::Full => {
+                "다음 세 부분으로 정리하세요: '## 요약'(핵심 내용 글머리표 3~7개), \
+                 '## 할 일'(담당자와 기한이 언급됐으면 함께), '## 결정 사항'. \
+                 해당 내용이 없는 부분은 '없음'으로 쓰세요."
+            }
+            Self::Bullets => "핵심 내용을 글머리표 3~7개로 요약하세요.",
+            Self::ActionItems => {
+                "회의에서 나온 할 일을 글머리표로 정리하세요. 담당자와 기한이 \
+                 언급됐으면 함께 쓰고, 없으면 '없음'으로 쓰세요."
+            }
+            Self::Decisions => {
+                "회의에서 확정된 결정 사항을 글머리표로 정리하세요. 없으면 '없음'으로 쓰세요."
+            }
+        }
+    }
+}
+
+/// `summary-chunk` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryChunk {
+    pub session_id: String,
+    /// 이번에 생성된 텍스트 조각
+    pub text: String,
+}
+
+/// 요약 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionSummaryText {
+    pub session_id: String,
+    pub text: String,
+    /// 전사가 길어 뒷부분을 빼고 요약했는지
+    pub truncated: bool,
+}
+
+/// 모델에 넘길 `[분:초] 화자: 내용` 형식의 전사
+///
+/// `max_chars`를 넘는 뒷부분은 빼고, 뺐는지를 함께 반환한다.
+pub(crate) fn transcript_text(
+    storage: &Storage,
+    session_id: &str,
+    max_chars: usize,
+) -> Result<(String, bool), TeuImError> {
+    let detail = storage.get_session(session_id)?;
+    let mut text = String::new();
+    let mut chars = 0;
+    let mut truncated = false;
+    for segment in &detail.segments {
+        let seconds = segment.start_ms / 1000;
+        let line = match &segment.speaker {
+            Some(speaker) => format!(
+                "[{:02}:{:02}] {}: {}\n",
+                seconds / 60,
+                seconds % 60,
+                speaker,
+                segment.text
+            ),
+            None => format!(
+                "[{:02}:{:02}] {}\n",
+                seconds / 60,
+                seconds % 60,
+                segment.text
+            ),
+        };
+        chars += line.chars().count();
+        if chars > max_chars {
+            truncated = true;
+            break;
+        }
+        text.push_str(&line);
+    }
+    if text.is_empty() {
+        return Err(TeuImError::invalid_argument(
+            "전사 내용이 없어 요약할 수 없습니다",
+        ));
+    }
+    Ok((text, truncated))
+}
+
+/// 저장된 전사를 설정된 LLM으로 요약 (생성되는 대로 `summary-chunk` 이벤트로 보냄)
+#[tauri::command]
+pub async fn summarize_session(
+    app: AppHandle,
+    storage: State<'_, Storage>,
+    session_id: String,
+    style: Option<SummaryStyle>,
+) -> Result<SessionSummaryText, TeuImError> {
+    let provider = llm::provider(&app)
+        .ok_or_else(|| TeuImError::invalid_argument("요약하려면 먼저 LLM 제공자를 설정하세요"))?;
+    let (transcript, truncated) = transcript_text(&storage, &session_id, MAX_TRANSCRIPT_CHARS)?;
+
+    let request = ChatRequest {
+        system: format!(
+            "당신은 회의록 작성자입니다. 주어진 전사를 전사와 같은 언어로 정리합니다. {}",
+            style.unwrap_or_default().instructions()
+        ),
+        user: transcript,
+        max_tokens: None,
+    };
+    let emitter = app.clone();
+    let chunk_session = session_id.clone();
+    let on_delta = Box::new(move |delta: &str| {
+        let _ = emitter.emit(
+            "summary-chunk",
+            SummaryChunk {
+                session_id: chunk_session.clone(),
+                text: delta.to_string(),
+            },
+        );
+    });
+
+    log::info!("세션 요약 시작: {} ({})", session_id, provider.name());
+    let text = provider.complete(request, on_delta).await?;
+    log::info!(
+        "세션 요약 완료: {} ({}자)",
+        session_id,
+        text.chars().count()
+    );
+    Ok(SessionSummaryText {
+        session_id,
+        text: text.trim().to_string(),
+        truncated,
+    })
+}
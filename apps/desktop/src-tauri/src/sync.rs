@@ -0,0 +1,951 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use reqwest::{Body, Method, RequestBuilder, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::bundle;
+use crate::credentials;
+use crate::encryption;
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+
+/// 키체인에 두는 동기화 비밀 값 (S3 비밀 키 또는 WebDAV 암호)
+const SECRET_ENTRY: &str = "sync-secret";
+
+/// 원격 색인 파일 (세션별 리비전과 삭제 표시)
+const INDEX_KEY: &str = "index.json";
+
+/// 세션 묶음 파일을 두는 원격 폴더
+const SESSIONS_DIR: &str = "sessions";
+
+/// 색인·삭제 요청 제한 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 묶음 파일 전송 제한 시간 (녹음이 길면 수백 MB)
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// 자동 동기화 간격을 확인하는 주기
+const SCHEDULE_POLL: Duration = Duration::from_secs(60);
+
+/// 자동 동기화 간격 범위 (분)
+const INTERVAL_RANGE: std::ops::RangeInclusive<u32> = 5..=1440;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 동기화 저장소
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyncBackend {
+    /// S3 호환 버킷 (AWS S3, MinIO, Cloudflare R2 등, 경로 방식 주소 사용)
+    S3 {
+        /// 예: `https://s3.ap-northeast-2.amazonaws.com`
+        endpoint: String,
+        region: String,
+        bucket: String,
+        access_key_id: String,
+        /// 버킷 안 폴더 (예: `teu-im/`)
+        #[serde(default)]
+        prefix: String,
+    },
+    /// WebDAV 공유 폴더 (Nextcloud 등)
+    Webdav {
+        /// 동기화에 쓸 폴더 주소
+        url: String,
+        #[serde(default)]
+        username: Option<String>,
+    },
+}
+
+/// 세션 동기화 설정 (`set_sync_config`로만 변경)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncSettings {
+    /// 동기화 저장소 (생략하면 동기화 안 함)
+    pub backend: Option<SyncBackend>,
+    /// 자동 동기화 간격 (분, 생략하면 `sync_now`로만)
+    pub interval_minutes: Option<u32>,
+}
+
+fn validate_url(url: &str) -> Result<Url, String> {
+    let parsed = Url::parse(url).map_err(|e| format!("잘못된 동기화 주소: {} ({})", url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("동기화 주소는 http(s)여야 합니다: {}", url));
+    }
+    Ok(parsed)
+}
+
+impl SyncSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.backend {
+            Some(SyncBackend::S3 {
+                endpoint,
+                region,
+                bucket,
+                access_key_id,
+                ..
+            }) => {
+                validate_url(endpoint)?;
+                if region.trim().is_empty()
+                    || bucket.trim().is_empty()
+                    || access_key_id.trim().is_empty()
+                {
+                    return Err("S3 리전, 버킷, 액세스 키 ID를 입력하세요".to_string());
+                }
+            }
+            Some(SyncBackend::Webdav { url, .. }) => {
+                validate_url(url)?;
+            }
+            None => {}
+        }
+        if let Some(minutes) = self.interval_minutes {
+            if !INTERVAL_RANGE.contains(&minutes) {
+                return Err(format!(
+                    "자동 동기화 간격은 {}~{}분이어야 합니다: {}",
+                    INTERVAL_RANGE.start(),
+                    INTERVAL_RANGE.end(),
+                    minutes
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 세션별 마지막 동기화 상태
+#[derive(Debug, Clone)]
+pub struct SyncState {
+    pub session_id: String,
+    /// 마지막 동기화 때 로컬 세션 내용 지문
+    pub local_hash: String,
+    /// 마지막 동기화 때 원격 색인의 리비전
+    pub remote_revision: String,
+}
+
+/// 원격 색인 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RemoteEntry {
+    /// 올린 기기의 세션 내용 지문 (삭제 표시면 `deleted-<시각>`)
+    revision: String,
+    updated_at: DateTime<Utc>,
+    /// 마지막으로 올린 기기 이름
+    device: Option<String>,
+    title: Option<String>,
+    /// 다른 기기에서 지운 세션
+    #[serde(default)]
+    deleted: bool,
+}
+
+/// 원격 색인 (세션 ID별)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RemoteIndex {
+    #[serde(default)]
+    sessions: BTreeMap<String, RemoteEntry>,
+}
+
+/// 동기화 단계
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncPhase {
+    /// 원격 색인 읽기와 변경 비교
+    Index,
+    Upload,
+    Download,
+    Delete,
+    Done,
+}
+
+/// `sync-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncProgress {
+    pub phase: SyncPhase,
+    pub session_id: Option<String>,
+    /// 처리한 세션 수 (`total` 중)
+    pub current: usize,
+    pub total: usize,
+}
+
+/// 양쪽에서 모두 바뀐 세션 (`resolve_sync_conflict`로 해결)
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub session_id: String,
+    pub local_title: Option<String>,
+    pub remote_title: Option<String>,
+    pub remote_device: Option<String>,
+    pub remote_updated_at: DateTime<Utc>,
+    /// 다른 기기에서 지운 세션
+    pub remote_deleted: bool,
+}
+
+/// 동기화 결과
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncResult {
+    pub uploaded: usize,
+    pub downloaded: usize,
+    /// 다른 기기에서 지워 여기서도 지운 세션 수
+    pub deleted_local: usize,
+    /// 여기서 지워 원격에서도 지운 세션 수
+    pub deleted_remote: usize,
+    pub conflicts: Vec<SyncConflict>,
+    /// 실패한 세션 (나머지 세션은 계속 동기화함)
+    pub failures: Vec<SyncFailure>,
+}
+
+/// 동기화하지 못한 세션 (다음 동기화 때 다시 시도)
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncFailure {
+    pub session_id: String,
+    pub message: String,
+}
+
+/// 충돌 해결 방법
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    /// 이 기기의 세션으로 원격을 덮어씀
+    KeepLocal,
+    /// 원격 세션으로 이 기기의 세션을 덮어씀
+    KeepRemote,
+}
+
+/// 동기화 실행 상태 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct SyncService {
+    running: AtomicBool,
+}
+
+/// 세션 하나에 할 일
+enum Action {
+    Upload { id: String, hash: String },
+    Download { id: String, revision: String },
+    DeleteLocal { id: String },
+    DeleteRemote { id: String },
+}
+
+impl Action {
+    fn session_id(&self) -> &str {
+        match self {
+            Action::Upload { id, .. }
+            | Action::Download { id, .. }
+            | Action::DeleteLocal { id }
+            | Action::DeleteRemote { id } => id,
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC은 모든 키 길이를 받음");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// S3 경로 인코딩 (`/`는 그대로)
+fn encode_path(path: &str) -> String {
+    let mut encoded = String::new();
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn bundle_key(session_id: &str) -> String {
+    format!("{}/{}.teuim", SESSIONS_DIR, session_id)
+}
+
+/// 원격 저장소 연결
+enum Remote {
+    S3 {
+        client: reqwest::Client,
+        endpoint: String,
+        region: String,
+        bucket: String,
+        prefix: String,
+        access_key_id: String,
+        secret: String,
+    },
+    Webdav {
+        client: reqwest::Client,
+        base: Url,
+        username: Option<String>,
+        password: Option<String>,
+    },
+}
+
+impl Remote {
+    fn new(backend: &SyncBackend) -> Result<Self, TeuImError> {
+        let secret = credentials::secret(SECRET_ENTRY)?;
+        let client = reqwest::Client::new();
+        Ok(match backend {
+            SyncBackend::S3 {
+                endpoint,
+                region,
+                bucket,
+                access_key_id,
+                prefix,
+            } => Self::S3 {
+                client,
+                endpoint: endpoint.trim_end_matches('/').to_string(),
+                region: region.trim().to_string(),
+                bucket: bucket.trim().to_string(),
+                prefix: prefix.trim_matches('/').to_string(),
+                access_key_id: access_key_id.trim().to_string(),
+                secret: secret
+                    .ok_or_else(|| TeuImError::invalid_argument("S3 비밀 키가 없습니다"))?,
+            },
+            SyncBackend::Webdav { url, username } => {
+                let mut base = validate_url(url)?;
+                if !base.path().ends_with('/') {
+                    base.set_path(&format!("{}/", base.path()));
+                }
+                Self::Webdav {
+                    client,
+                    base,
+                    username: username.clone(),
+                    password: secret,
+                }
+            }
+        })
+    }
+
+    /// `key`에 대한 요청 (S3는 서명 V4, 내용 해시는 생략)
+    fn request(&self, method: Method, key: &str) -> Result<RequestBuilder, TeuImError> {
+        match self {
+            Self::S3 {
+                client,
+                endpoint,
+                region,
+                bucket,
+                prefix,
+                access_key_id,
+                secret,
+            } => {
+                let object = if prefix.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{}/{}", prefix, key)
+                };
+                let url = Url::parse(&format!(
+                    "{}/{}/{}",
+                    endpoint,
+                    encode_path(bucket),
+                    encode_path(&object)
+                ))
+                .map_err(|e| e.to_string())?;
+                let host = match (url.host_str(), url.port()) {
+                    (Some(host), Some(port)) => format!("{}:{}", host, port),
+                    (Some(host), None) => host.to_string(),
+                    (None, _) => return Err("잘못된 S3 주소".into()),
+                };
+
+                let now = Utc::now();
+                let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+                let date = now.format("%Y%m%d").to_string();
+                let scope = format!("{}/{}/s3/aws4_request", date, region);
+                let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+                let canonical_request = format!(
+                    "{}\n{}\n\nhost:{}\nx-amz-content-sha256:UNSIGNED-PAYLOAD\nx-amz-date:{}\n\n{}\nUNSIGNED-PAYLOAD",
+                    method,
+                    url.path(),
+                    host,
+                    amz_date,
+                    signed_headers
+                );
+                let string_to_sign = format!(
+                    "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                    amz_date,
+                    scope,
+                    hex(&Sha256::digest(canonical_request.as_bytes()))
+                );
+                let key = [date.as_str(), region.as_str(), "s3", "aws4_request"]
+                    .iter()
+                    .fold(format!("AWS4{}", secret).into_bytes(), |key, part| {
+                        hmac(&key, part)
+                    });
+                let signature = hex(&hmac(&key, &string_to_sign));
+
+                Ok(client
+                    .request(method, url)
+                    .header("x-amz-content-sha256", "UNSIGNED-PAYLOAD")
+                    .header("x-amz-date", amz_date)
+                    .header(
+                        "authorization",
+                        format!(
+                            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                            access_key_id, scope, signed_headers, signature
+                        ),
+                    ))
+            }
+            Self::Webdav {
+                client,
+                base,
+                username,
+                password,
+            } => {
+                let url = base.join(key).map_err(|e| e.to_string())?;
+                let request = client.request(method, url);
+                Ok(match username {
+                    Some(username) => request.basic_auth(username, password.as_ref()),
+                    None => request,
+                })
+            }
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<reqwest::Response, TeuImError> {
+        request
+            .send()
+            .await
+            .map_err(|e| TeuImError::from(format!("동기화 저장소 연결 실패: {}", e)))
+    }
+
+    /// 색인 읽기 (없으면 빈 색인, ETag는 덮어쓰기 확인용)
+    async fn get_index(&self) -> Result<(RemoteIndex, Option<String>), TeuImError> {
+        let response = self
+            .send(
+                self.request(Method::GET, INDEX_KEY)?
+                    .timeout(REQUEST_TIMEOUT),
+            )
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok((RemoteIndex::default(), None));
+        }
+        let response = response
+            .error_for_status()
+            .map_err(|e| format!("동기화 색인 읽기 실패: {}", e))?;
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+        let index = serde_json::from_slice(&bytes)
+            .map_err(|e| format!("동기화 색인이 손상되었습니다: {}", e))?;
+        Ok((index, etag))
+    }
+
+    /// 색인 쓰기 (읽은 뒤 다른 기기가 바꿨으면 실패)
+    async fn put_index(&self, index: &RemoteIndex, etag: Option<&str>) -> Result<(), TeuImError> {
+        let body = serde_json::to_vec_pretty(index).map_err(|e| e.to_string())?;
+        let request = self
+            .request(Method::PUT, INDEX_KEY)?
+            .timeout(REQUEST_TIMEOUT)
+            .header("content-type", "application/json")
+            .body(body);
+        let request = match etag {
+            Some(etag) => request.header(IF_MATCH, etag),
+            None => request.header(IF_NONE_MATCH, "*"),
+        };
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::PRECONDITION_FAILED {
+            return Err(TeuImError::invalid_argument(
+                "다른 기기가 동시에 동기화했습니다. 다시 동기화하세요",
+            ));
+        }
+        response
+            .error_for_status()
+            .map_err(|e| format!("동기화 색인 저장 실패: {}", e))?;
+        Ok(())
+    }
+
+    /// WebDAV 폴더 만들기 (이미 있으면 무시, S3는 폴더가 필요 없음)
+    async fn ensure_dir(&self, dir: &str) -> Result<(), TeuImError> {
+        if let Self::S3 { .. } = self {
+            return Ok(());
+        }
+        let method = Method::from_bytes(b"MKCOL").expect("올바른 메서드 이름");
+        let response = self
+            .send(
+                self.request(method, &format!("{}/", dir))?
+                    .timeout(REQUEST_TIMEOUT),
+            )
+            .await?;
+        // 405: 이미 있음
+        if response.status().is_success() || response.status() == StatusCode::METHOD_NOT_ALLOWED {
+            return Ok(());
+        }
+        Err(format!("동기화 폴더 생성 실패: {}", response.status()).into())
+    }
+
+    async fn upload(&self, key: &str, path: &Path) -> Result<(), TeuImError> {
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| e.to_string())?;
+        let length = file.metadata().await.map_err(|e| e.to_string())?.len();
+        let request = self
+            .request(Method::PUT, key)?
+            .timeout(TRANSFER_TIMEOUT)
+            .header("content-length", length)
+            .body(Body::from(file));
+        self.send(request)
+            .await?
+            .error_for_status()
+            .map_err(|e| format!("세션 올리기 실패: {}", e))?;
+        Ok(())
+    }
+
+    /// 원격 파일을 `dest`에 받음 (없으면 `false`)
+    async fn download(&self, key: &str, dest: &Path) -> Result<bool, TeuImError> {
+        let mut response = self
+            .send(self.request(Method::GET, key)?.timeout(TRANSFER_TIMEOUT))
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        response = response
+            .error_for_status()
+            .map_err(|e| format!("세션 받기 실패: {}", e))?;
+
+        use tokio::io::AsyncWriteExt;
+        let mut file = tokio::fs::File::create(dest)
+            .await
+            .map_err(|e| e.to_string())?;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("세션 받기 실패: {}", e))?
+        {
+            file.write_all(&chunk).await.map_err(|e| e.to_string())?;
+        }
+        file.flush().await.map_err(|e| e.to_string())?;
+        Ok(true)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), TeuImError> {
+        let response = self
+            .send(self.request(Method::DELETE, key)?.timeout(REQUEST_TIMEOUT))
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        response
+            .error_for_status()
+            .map_err(|e| format!("원격 세션 삭제 실패: {}", e))?;
+        Ok(())
+    }
+}
+
+fn progress(
+    app: &AppHandle,
+    phase: SyncPhase,
+    session_id: Option<&str>,
+    current: usize,
+    total: usize,
+) {
+    let _ = app.emit(
+        "sync-progress",
+        SyncProgress {
+            phase,
+            session_id: session_id.map(str::to_string),
+            current,
+            total,
+        },
+    );
+}
+
+/// 로컬 변경과 원격 색인을 비교해 할 일과 충돌 목록 만들기
+fn plan(
+    storage: &Storage,
+    index: &RemoteIndex,
+) -> Result<(Vec<Action>, Vec<SyncConflict>), TeuImError> {
+    let mut states: HashMap<String, SyncState> = storage
+        .sync_states()?
+        .into_iter()
+        .map(|state| (state.session_id.clone(), state))
+        .collect();
+    let ids = storage.ended_session_ids()?;
+    let known: HashSet<String> = ids.iter().chain(states.keys()).cloned().collect();
+    let mut actions = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for id in ids {
+        let hash = bundle::fingerprint(storage, &id)?;
+        let state = states.remove(&id);
+        let remote = index.sessions.get(&id);
+        let (local_changed, remote_changed) = match (&state, remote) {
+            (None, None) => (true, false),
+            (None, Some(entry)) => (true, !entry.deleted),
+            (Some(_), None) => (true, false),
+            (Some(state), Some(entry)) => (
+                hash != state.local_hash,
+                entry.revision != state.remote_revision,
+            ),
+        };
+        match (local_changed, remote_changed, remote) {
+            (false, false, _) => {}
+            (true, false, _) => actions.push(Action::Upload { id, hash }),
+            (false, true, Some(entry)) if entry.deleted => actions.push(Action::DeleteLocal { id }),
+            (false, true, Some(entry)) => actions.push(Action::Download {
+                id,
+                revision: entry.revision.clone(),
+            }),
+            (_, _, remote) => {
+                let remote = remote.expect("원격이 바뀌었으면 색인 항목이 있음");
+                conflicts.push(SyncConflict {
+                    local_title: storage.get_session(&id)?.session.title,
+                    session_id: id,
+                    remote_title: remote.title.clone(),
+                    remote_device: remote.device.clone(),
+                    remote_updated_at: remote.updated_at,
+                    remote_deleted: remote.deleted,
+                });
+            }
+        }
+    }
+
+    // 동기화한 적 있지만 이 기기에서 지운 세션
+    for (id, state) in states {
+        match index.sessions.get(&id) {
+            Some(entry) if !entry.deleted => {
+                if entry.revision == state.remote_revision {
+                    actions.push(Action::DeleteRemote { id });
+                } else {
+                    // 지운 뒤 다른 기기에서 고쳤으면 내용을 잃지 않게 다시 받음
+                    actions.push(Action::Download {
+                        id,
+                        revision: entry.revision.clone(),
+                    });
+                }
+            }
+            _ => storage.remove_sync_state(&id)?,
+        }
+    }
+
+    // 다른 기기에서 새로 올린 세션
+    for (id, entry) in &index.sessions {
+        if !entry.deleted && !known.contains(id) {
+            actions.push(Action::Download {
+                id: id.clone(),
+                revision: entry.revision.clone(),
+            });
+        }
+    }
+    Ok((actions, conflicts))
+}
+
+/// 로컬 세션과 녹음 지우기 (없으면 무시)
+fn delete_local(storage: &Storage, id: &str) -> Result<(), TeuImError> {
+    if let Ok(Some(path)) = storage.audio_path(id) {
+        if !storage.audio_path_shared(&path, id)? {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+    match storage.delete_session(id) {
+        Ok(()) | Err(TeuImError::SessionNotFound { .. }) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T, TeuImError> + Send + 'static,
+) -> Result<T, TeuImError> {
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+async fn sync(app: &AppHandle, backend: &SyncBackend) -> Result<SyncResult, TeuImError> {
+    progress(app, SyncPhase::Index, None, 0, 0);
+    let remote = Remote::new(backend)?;
+    let (mut index, etag) = remote.get_index().await?;
+    let (actions, conflicts) = {
+        let app = app.clone();
+        let index = index.clone();
+        blocking(move || plan(&app.state::<Storage>(), &index)).await?
+    };
+
+    let work_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| e.to_string())?
+        .join("sync");
+    std::fs::create_dir_all(&work_dir).map_err(|e| e.to_string())?;
+    if actions
+        .iter()
+        .any(|action| matches!(action, Action::Upload { .. }))
+    {
+        remote.ensure_dir(SESSIONS_DIR).await?;
+    }
+
+    let device = sysinfo::System::host_name();
+    let mut result = SyncResult {
+        conflicts,
+        ..Default::default()
+    };
+    // 색인을 저장한 뒤에 기록할 동기화 상태 (세션 ID, 올린 지문)
+    let mut uploaded = Vec::new();
+    let mut deleted_remote = Vec::new();
+    let total = actions.len();
+    for (position, action) in actions.into_iter().enumerate() {
+        let current = position + 1;
+        let id = action.session_id().to_string();
+        // 한 세션이 실패해도 나머지를 계속하고, 끝난 작업은 색인에 남김
+        let outcome: Result<(), TeuImError> = async {
+            match action {
+                Action::Upload { id, hash } => {
+                    progress(app, SyncPhase::Upload, Some(&id), current, total);
+                    let path = work_dir.join(format!("{}.teuim", id));
+                    let title = {
+                        let (app, id, path) = (app.clone(), id.clone(), path.clone());
+                        blocking(move || {
+                            bundle::write_bundle(&app, &id, &path)?;
+                            encryption::seal_bundle(&app, &path)?;
+                            Ok(app.state::<Storage>().get_session(&id)?.session.title)
+                        })
+                        .await
+                    };
+                    let uploaded_result = match title {
+                        Ok(title) => remote.upload(&bundle_key(&id), &path).await.map(|_| title),
+                        Err(e) => Err(e),
+                    };
+                    let _ = std::fs::remove_file(&path);
+                    let title = uploaded_result?;
+                    index.sessions.insert(
+                        id.clone(),
+                        RemoteEntry {
+                            revision: hash.clone(),
+                            updated_at: Utc::now(),
+                            device: device.clone(),
+                            title,
+                            deleted: false,
+                        },
+                    );
+                    uploaded.push((id, hash));
+                    result.uploaded += 1;
+                }
+                Action::Download { id, revision } => {
+                    progress(app, SyncPhase::Download, Some(&id), current, total);
+                    let path = work_dir.join(format!("{}.teuim", id));
+                    if !remote.download(&bundle_key(&id), &path).await? {
+                        log::warn!("원격 색인에 있는 세션 파일이 없습니다: {}", id);
+                        return Ok(());
+                    }
+                    let replaced = {
+                        let (app, id, path) = (app.clone(), id.clone(), path.clone());
+                        blocking(move || {
+                            let storage = app.state::<Storage>();
+                            encryption::open_bundle(&app, &path)?;
+                            // 받은 묶음이 온전한지 확인한 뒤에만 로컬 세션을 바꿈
+                            bundle::check_bundle(&path)?;
+                            delete_local(&storage, &id)?;
+                            bundle::read_bundle(&app, &path, Some(&id))?;
+                            let hash = bundle::fingerprint(&storage, &id)?;
+                            storage.set_sync_state(&id, &hash, &revision)
+                        })
+                        .await
+                    };
+                    let _ = std::fs::remove_file(&path);
+                    replaced?;
+                    result.downloaded += 1;
+                }
+                Action::DeleteLocal { id } => {
+                    progress(app, SyncPhase::Delete, Some(&id), current, total);
+                    let storage = app.state::<Storage>();
+                    delete_local(&storage, &id)?;
+                    storage.remove_sync_state(&id)?;
+                    result.deleted_local += 1;
+                }
+                Action::DeleteRemote { id } => {
+                    progress(app, SyncPhase::Delete, Some(&id), current, total);
+                    remote.delete(&bundle_key(&id)).await?;
+                    let title = index
+                        .sessions
+                        .get(&id)
+                        .and_then(|entry| entry.title.clone());
+                    index.sessions.insert(
+                        id.clone(),
+                        RemoteEntry {
+                            revision: format!("deleted-{}", Utc::now().timestamp_millis()),
+                            updated_at: Utc::now(),
+                            device: device.clone(),
+                            title,
+                            deleted: true,
+                        },
+                    );
+                    deleted_remote.push(id);
+                    result.deleted_remote += 1;
+                }
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = outcome {
+            log::warn!("세션 동기화 실패 ({}): {}", id, e);
+            result.failures.push(SyncFailure {
+                session_id: id,
+                message: e.to_string(),
+            });
+        }
+    }
+
+    if !uploaded.is_empty() || !deleted_remote.is_empty() {
+        remote.put_index(&index, etag.as_deref()).await?;
+        let storage = app.state::<Storage>();
+        for (id, hash) in &uploaded {
+            storage.set_sync_state(id, hash, hash)?;
+        }
+        for id in &deleted_remote {
+            storage.remove_sync_state(id)?;
+        }
+    }
+    if result.downloaded > 0 {
+        // 암호화를 켰으면 받은 녹음도 암호화
+        encryption::spawn_encrypt_recordings(app.clone());
+    }
+
+    progress(app, SyncPhase::Done, None, total, total);
+    log::info!(
+        "동기화 완료: 올림 {}, 받음 {}, 로컬 삭제 {}, 원격 삭제 {}, 충돌 {}, 실패 {}",
+        result.uploaded,
+        result.downloaded,
+        result.deleted_local,
+        result.deleted_remote,
+        result.conflicts.len(),
+        result.failures.len()
+    );
+    Ok(result)
+}
+
+/// 동기화 한 번 실행 (이미 실행 중이면 실패)
+async fn run(app: &AppHandle) -> Result<SyncResult, TeuImError> {
+    let backend = app
+        .state::<SettingsStore>()
+        .get()
+        .sync
+        .backend
+        .ok_or_else(|| TeuImError::invalid_argument("동기화 저장소가 설정되지 않았습니다"))?;
+    let service = app.state::<SyncService>();
+    if service.running.swap(true, Ordering::SeqCst) {
+        return Err(TeuImError::invalid_argument("이미 동기화 중입니다"));
+    }
+    let result = sync(app, &backend).await;
+    service.running.store(false, Ordering::SeqCst);
+    result
+}
+
+/// 설정한 간격마다 자동 동기화 (잠긴 데이터베이스는 건너뜀)
+pub fn spawn_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_run = Instant::now();
+        loop {
+            tokio::time::sleep(SCHEDULE_POLL).await;
+            let settings = app.state::<SettingsStore>().get().sync;
+            let Some(minutes) = settings.interval_minutes else {
+                continue;
+            };
+            if settings.backend.is_none()
+                || last_run.elapsed() < Duration::from_secs(u64::from(minutes) * 60)
+            {
+                continue;
+            }
+            last_run = Instant::now();
+            match run(&app).await {
+                Ok(_) | Err(TeuImError::DatabaseLocked) => {}
+                Err(e) => log::warn!("자동 동기화 실패: {}", e),
+            }
+        }
+    });
+}
+
+/// 동기화 저장소 설정 (`backend`가 `None`이면 동기화 끄기)
+///
+/// `secret`은 S3 비밀 키 또는 WebDAV 암호로, OS 키체인에 저장한다 (생략하면 기존 값 유지).
+/// 저장소를 바꾸면 이전 저장소와의 동기화 기록을 지운다.
+#[tauri::command]
+pub fn set_sync_config(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    storage: State<'_, Storage>,
+    backend: Option<SyncBackend>,
+    secret: Option<String>,
+    interval_minutes: Option<u32>,
+) -> Result<SyncSettings, TeuImError> {
+    let settings = SyncSettings {
+        backend,
+        interval_minutes,
+    };
+    settings.validate()?;
+    if store.get().sync.backend != settings.backend {
+        storage.clear_sync_states()?;
+    }
+
+    match (&settings.backend, secret) {
+        (None, _) => credentials::delete_secret(SECRET_ENTRY)?,
+        (Some(_), Some(secret)) if !secret.is_empty() => {
+            credentials::set_secret(SECRET_ENTRY, &secret)?
+        }
+        _ => {}
+    }
+    let updated = store.modify(|current| current.sync = settings)?;
+    let _ = app.emit("settings-changed", &updated);
+    log::info!(
+        "동기화 설정 변경: {}",
+        match &updated.sync.backend {
+            Some(SyncBackend::S3 { .. }) => "S3",
+            Some(SyncBackend::Webdav { .. }) => "WebDAV",
+            None => "꺼짐",
+        }
+    );
+    Ok(updated.sync)
+}
+
+/// 지금 동기화 (진행 상황은 `sync-progress` 이벤트로 알림)
+///
+/// 저장 데이터 암호화를 켰으면 올리는 묶음도 같은 키로 암호화한다.
+#[tauri::command]
+pub async fn sync_now(app: AppHandle) -> Result<SyncResult, TeuImError> {
+    run(&app).await
+}
+
+/// 충돌한 세션을 한쪽으로 맞춘 뒤 다시 동기화
+#[tauri::command]
+pub async fn resolve_sync_conflict(
+    app: AppHandle,
+    session_id: String,
+    keep: ConflictResolution,
+) -> Result<SyncResult, TeuImError> {
+    {
+        let storage = app.state::<Storage>();
+        let hash = bundle::fingerprint(&storage, &session_id)?;
+        // 한쪽만 바뀐 것으로 기록해 다음 동기화에서 그쪽으로 덮어쓰게 함
+        match keep {
+            ConflictResolution::KeepLocal => {
+                let remote_revision = remote_revision(&app, &session_id).await?;
+                storage.set_sync_state(&session_id, "", &remote_revision)?
+            }
+            ConflictResolution::KeepRemote => storage.set_sync_state(&session_id, &hash, "")?,
+        }
+    }
+    run(&app).await
+}
+
+/// 원격 색인의 세션 리비전 (없으면 빈 문자열)
+async fn remote_revision(app: &AppHandle, session_id: &str) -> Result<String, TeuImError> {
+    let backend = app
+        .state::<SettingsStore>()
+        .get()
+        .sync
+        .backend
+        .ok_or_else(|| TeuImError::invalid_argument("동기화 저장소가 설정되지 않았습니다"))?;
+    let (index, _) = Remote::new(&backend)?.get_index().await?;
+    Ok(index
+        .sessions
+        .get(session_id)
+        .map(|entry| entry.revision.clone())
+        .unwrap_or_default())
+}
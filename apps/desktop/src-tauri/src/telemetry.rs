@@ -0,0 +1,350 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+
+/// 첫 전송 시도까지 기다리는 시간
+const FIRST_FLUSH_DELAY: Duration = Duration::from_secs(60);
+
+/// 사용 통계를 묶어 보내는 간격
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 오프라인 동안 보관할 최대 보고서 수 (넘으면 오래된 것부터 버림)
+const MAX_QUEUED_REPORTS: usize = 100;
+
+/// 전송 요청 제한 시간
+const SEND_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 익명 사용 통계 설정 (기본은 꺼짐)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetrySettings {
+    /// 사용자가 동의했을 때만 켬
+    pub enabled: bool,
+    /// 보고서를 받을 주소 (생략하면 보내지 않고 쌓아 둠)
+    pub endpoint: Option<String>,
+}
+
+impl TelemetrySettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(endpoint) = &self.endpoint {
+            let url = reqwest::Url::parse(endpoint)
+                .map_err(|e| format!("잘못된 통계 전송 주소: {} ({})", endpoint, e))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err(format!("통계 전송 주소는 http(s)여야 합니다: {}", endpoint));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 한 번에 보내는 익명 사용 통계
+///
+/// 기능(명령) 이름별 횟수와 세션 수만 담고, 인자·전사 내용·장치 이름은 담지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryReport {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    /// 기능별 사용 횟수
+    pub feature_usage: BTreeMap<String, u64>,
+    /// 시작한 앱 세션 수
+    pub sessions: u32,
+    /// 정상 종료 없이 끝난 이전 세션 수 (충돌 없는 세션 비율 계산용)
+    pub crashed_sessions: u32,
+}
+
+/// 통계 상태 (`get_telemetry_status` 응답)
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetryStatus {
+    pub enabled: bool,
+    pub endpoint: Option<String>,
+    /// 보내지 못하고 쌓인 보고서 수
+    pub queued_reports: usize,
+    /// 다음에 보낼 내용 미리보기
+    pub pending: TelemetryReport,
+}
+
+/// 아직 보고서로 묶지 않은 집계
+struct Pending {
+    since: DateTime<Utc>,
+    feature_usage: BTreeMap<String, u64>,
+    sessions: u32,
+    crashed_sessions: u32,
+}
+
+impl Default for Pending {
+    fn default() -> Self {
+        Self {
+            since: Utc::now(),
+            feature_usage: BTreeMap::new(),
+            sessions: 0,
+            crashed_sessions: 0,
+        }
+    }
+}
+
+impl Pending {
+    fn is_empty(&self) -> bool {
+        self.feature_usage.is_empty() && self.sessions == 0 && self.crashed_sessions == 0
+    }
+
+    fn report(&self) -> TelemetryReport {
+        TelemetryReport {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            period_start: self.since,
+            period_end: Utc::now(),
+            feature_usage: self.feature_usage.clone(),
+            sessions: self.sessions,
+            crashed_sessions: self.crashed_sessions,
+        }
+    }
+}
+
+/// 사용 통계 집계 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct Telemetry {
+    enabled: AtomicBool,
+    pending: Mutex<Pending>,
+    /// 보관 파일 읽기·쓰기 잠금
+    queue_lock: Mutex<()>,
+}
+
+fn telemetry_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("telemetry"))
+        .map_err(|e| e.to_string())
+}
+
+/// 실행 중 표시 파일 (정상 종료하면 지움)
+fn running_marker(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(telemetry_dir(app)?.join("running"))
+}
+
+fn queue_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(telemetry_dir(app)?.join("queue.jsonl"))
+}
+
+fn read_queue(app: &AppHandle) -> Vec<TelemetryReport> {
+    let Ok(contents) =
+        queue_path(app).and_then(|path| std::fs::read_to_string(path).map_err(|e| e.to_string()))
+    else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn write_queue(app: &AppHandle, reports: &[TelemetryReport]) -> Result<(), String> {
+    let path = queue_path(app)?;
+    if reports.is_empty() {
+        return match std::fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+            _ => Ok(()),
+        };
+    }
+    let mut contents = Vec::new();
+    for report in reports {
+        serde_json::to_writer(&mut contents, report).map_err(|e| e.to_string())?;
+        contents.push(b'\n');
+    }
+    std::fs::write(&path, contents).map_err(|e| e.to_string())
+}
+
+/// 앱 시작 시 호출 (이전 세션의 비정상 종료 확인, 주기 전송 시작)
+pub fn init(app: &AppHandle) {
+    let settings = app.state::<SettingsStore>().get().telemetry;
+    let telemetry = app.state::<Telemetry>();
+    telemetry.enabled.store(settings.enabled, Ordering::Relaxed);
+
+    let marker = match telemetry_dir(app).and_then(|dir| {
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        running_marker(app)
+    }) {
+        Ok(marker) => marker,
+        Err(e) => {
+            log::warn!("사용 통계 폴더를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    let crashed = marker.exists();
+    if let Err(e) = std::fs::File::create(&marker) {
+        log::warn!("실행 중 표시 파일을 만들 수 없음: {}", e);
+    }
+    if settings.enabled {
+        let mut pending = telemetry.pending.lock().unwrap();
+        pending.sessions += 1;
+        pending.crashed_sessions += u32::from(crashed);
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(FIRST_FLUSH_DELAY).await;
+        loop {
+            flush(&app).await;
+            tokio::time::sleep(FLUSH_INTERVAL).await;
+        }
+    });
+}
+
+/// 정상 종료 표시 (다음 실행에서 비정상 종료로 세지 않음)
+pub fn end_session(app: &AppHandle) {
+    if let Ok(marker) = running_marker(app) {
+        let _ = std::fs::remove_file(marker);
+    }
+}
+
+/// 설정이 바뀌면 호출 (끄면 쌓인 집계와 보고서를 모두 지움)
+pub fn apply(app: &AppHandle, settings: &TelemetrySettings) {
+    let telemetry = app.state::<Telemetry>();
+    let was_enabled = telemetry.enabled.swap(settings.enabled, Ordering::Relaxed);
+    if was_enabled && !settings.enabled {
+        *telemetry.pending.lock().unwrap() = Pending::default();
+        let _guard = telemetry.queue_lock.lock().unwrap();
+        if let Err(e) = write_queue(app, &[]) {
+            log::warn!("쌓인 사용 통계 삭제 실패: {}", e);
+        }
+        log::info!("익명 사용 통계 꺼짐");
+    } else if !was_enabled && settings.enabled {
+        // 켠 시점의 세션부터 셈
+        telemetry.pending.lock().unwrap().sessions += 1;
+        log::info!("익명 사용 통계 켜짐");
+    }
+}
+
+/// 명령 처리기를 감싸 기능(명령)별 사용 횟수를 셈 (통계가 켜진 경우에만)
+pub fn count_commands(
+    handler: impl Fn(Invoke) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        if let Some(telemetry) = invoke.message.webview_ref().try_state::<Telemetry>() {
+            if telemetry.enabled.load(Ordering::Relaxed) {
+                *telemetry
+                    .pending
+                    .lock()
+                    .unwrap()
+                    .feature_usage
+                    .entry(invoke.message.command().to_string())
+                    .or_default() += 1;
+            }
+        }
+        handler(invoke)
+    }
+}
+
+/// 쌓인 집계를 보고서로 묶어 보관하고, 보관된 보고서를 차례로 전송
+///
+/// 오프라인이거나 전송 주소가 없으면 보고서는 다음 시도까지 보관 파일에 남는다.
+async fn flush(app: &AppHandle) {
+    let telemetry = app.state::<Telemetry>();
+    if !telemetry.enabled.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let queued = {
+        let report = {
+            let mut pending = telemetry.pending.lock().unwrap();
+            let report = (!pending.is_empty()).then(|| pending.report());
+            *pending = Pending::default();
+            report
+        };
+        let _guard = telemetry.queue_lock.lock().unwrap();
+        let mut queued = read_queue(app);
+        queued.extend(report);
+        let overflow = queued.len().saturating_sub(MAX_QUEUED_REPORTS);
+        queued.drain(..overflow);
+        if let Err(e) = write_queue(app, &queued) {
+            log::warn!("사용 통계 보관 실패: {}", e);
+        }
+        queued
+    };
+
+    let Some(endpoint) = app.state::<SettingsStore>().get().telemetry.endpoint else {
+        return;
+    };
+    if queued.is_empty() {
+        return;
+    }
+
+    let client = reqwest::Client::new();
+    let mut sent = 0;
+    for report in &queued {
+        let result = client
+            .post(&endpoint)
+            .timeout(SEND_TIMEOUT)
+            .json(report)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => sent += 1,
+            Err(e) => {
+                log::debug!("사용 통계 전송 보류: {}", e);
+                break;
+            }
+        }
+    }
+    if sent == 0 {
+        return;
+    }
+
+    // 전송하는 동안 통계를 껐으면 보관 파일은 이미 지워졌음
+    if !telemetry.enabled.load(Ordering::Relaxed) {
+        return;
+    }
+    let _guard = telemetry.queue_lock.lock().unwrap();
+    let mut remaining = read_queue(app);
+    remaining.drain(..sent.min(remaining.len()));
+    if let Err(e) = write_queue(app, &remaining) {
+        log::warn!("사용 통계 보관 실패: {}", e);
+    }
+    log::info!("사용 통계 보고서 {}개 전송", sent);
+}
+
+/// 통계 켜기/끄기 (끄면 아직 보내지 않은 통계도 지움)
+#[tauri::command]
+pub fn set_telemetry_enabled(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    enabled: bool,
+) -> Result<(), TeuImError> {
+    let settings = store.modify(|settings| settings.telemetry.enabled = enabled)?;
+    apply(&app, &settings.telemetry);
+    let _ = app.emit("settings-changed", &settings);
+    Ok(())
+}
+
+/// 통계 설정과 다음에 보낼 내용 조회
+#[tauri::command]
+pub fn get_telemetry_status(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    telemetry: State<'_, Telemetry>,
+) -> TelemetryStatus {
+    let settings = store.get().telemetry;
+    let queued_reports = {
+        let _guard = telemetry.queue_lock.lock().unwrap();
+        read_queue(&app).len()
+    };
+    TelemetryStatus {
+        enabled: settings.enabled,
+        endpoint: settings.endpoint,
+        queued_reports,
+        pending: telemetry.pending.lock().unwrap().report(),
+    }
+}
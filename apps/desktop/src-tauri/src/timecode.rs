@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::State;
+
+use crate::error::TeuImError;
+use crate::storage::{MediaAlignment, Storage};
+
+/// 영상 프레임 레이트 (NTSC 계열은 1000/1001 배속)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameRate {
+    #[serde(rename = "23.976")]
+    Fps23_976,
+    #[serde(rename = "24")]
+    Fps24,
+    #[serde(rename = "25")]
+    Fps25,
+    #[serde(rename = "29.97")]
+    Fps29_97,
+    /// 29.97 드롭 프레임 (`HH:MM:SS;FF`)
+    #[serde(rename = "29.97df")]
+    Fps29_97Drop,
+    #[serde(rename = "30")]
+    Fps30,
+    #[serde(rename = "50")]
+    Fps50,
+    #[serde(rename = "59.94")]
+    Fps59_94,
+    /// 59.94 드롭 프레임
+    #[serde(rename = "59.94df")]
+    Fps59_94Drop,
+    #[serde(rename = "60")]
+    Fps60,
+}
+
+impl FrameRate {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Fps23_976 => "23.976",
+            Self::Fps24 => "24",
+            Self::Fps25 => "25",
+            Self::Fps29_97 => "29.97",
+            Self::Fps29_97Drop => "29.97df",
+            Self::Fps30 => "30",
+            Self::Fps50 => "50",
+            Self::Fps59_94 => "59.94",
+            Self::Fps59_94Drop => "59.94df",
+            Self::Fps60 => "60",
+        }
+    }
+
+    pub(crate) fn parse(rate: &str) -> Option<Self> {
+        [
+            Self::Fps23_976,
+            Self::Fps24,
+            Self::Fps25,
+            Self::Fps29_97,
+            Self::Fps29_97Drop,
+            Self::Fps30,
+            Self::Fps50,
+            Self::Fps59_94,
+            Self::Fps59_94Drop,
+            Self::Fps60,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == rate)
+    }
+
+    /// 타임코드에서 1초로 세는 프레임 수
+    fn nominal(self) -> i64 {
+        match self {
+            Self::Fps23_976 | Self::Fps24 => 24,
+            Self::Fps25 => 25,
+            Self::Fps29_97 | Self::Fps29_97Drop | Self::Fps30 => 30,
+            Self::Fps50 => 50,
+            Self::Fps59_94 | Self::Fps59_94Drop | Self::Fps60 => 60,
+        }
+    }
+
+    /// 실제 속도가 1000/1001배인지
+    fn ntsc(self) -> bool {
+        matches!(
+            self,
+            Self::Fps23_976
+                | Self::Fps29_97
+                | Self::Fps29_97Drop
+                | Self::Fps59_94
+                | Self::Fps59_94Drop
+        )
+    }
+
+    /// 드롭 프레임이면 (10의 배수가 아닌) 매 분 건너뛰는 프레임 번호 수
+    fn dropped_per_minute(self) -> i64 {
+        match self {
+            Self::Fps29_97Drop => 2,
+            Self::Fps59_94Drop => 4,
+            _ => 0,
+        }
+    }
+
+    fn frame_to_ms(self, frame: i64) -> i64 {
+        let scale = if self.ntsc() { 1001 } else { 1000 };
+        (frame * scale + self.nominal() / 2).div_euclid(self.nominal())
+    }
+
+    fn ms_to_frame(self, ms: i64) -> i64 {
+        let scale = if self.ntsc() { 1001 } else { 1000 };
+        (ms * self.nominal() + scale / 2).div_euclid(scale)
+    }
+
+    /// 가장 가까운 프레임 경계 시각 (ms)
+    pub fn snap_ms(self, ms: i64) -> i64 {
+        self.frame_to_ms(self.ms_to_frame(ms))
+    }
+}
+
+/// `[-]HH:MM:SS:FF` 타임코드를 실제 경과 시간(ms)으로 변환
+///
+/// 드롭 프레임 레이트면 건너뛴 프레임 번호를 빼고 계산한다.
+pub fn timecode_to_ms(timecode: &str, rate: FrameRate) -> Result<i64, String> {
+    let invalid = || format!("잘못된 타임코드: {} (HH:MM:SS:FF 형식)", timecode);
+    let (negative, body) = match timecode.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, timecode.trim()),
+    };
+    let parts: Vec<i64> = body
+        .split([':', ';', '.'])
+        .map(|part| part.parse::<i64>().map_err(|_| invalid()))
+        .collect::<Result<_, _>>()?;
+    let [hours, minutes, seconds, frames] = parts[..] else {
+        return Err(invalid());
+    };
+    if minutes >= 60 || seconds >= 60 || frames >= rate.nominal() || hours < 0 {
+        return Err(invalid());
+    }
+
+    let drop = rate.dropped_per_minute();
+    if drop > 0 && seconds == 0 && minutes % 10 != 0 && frames < drop {
+        return Err(format!(
+            "드롭 프레임 타임코드에 없는 프레임입니다: {}",
+            timecode
+        ));
+    }
+
+    let total_minutes = hours * 60 + minutes;
+    let frame = (total_minutes * 60 + seconds) * rate.nominal() + frames
+        - drop * (total_minutes - total_minutes / 10);
+    let ms = rate.frame_to_ms(frame);
+    Ok(if negative { -ms } else { ms })
+}
+
+/// 타임코드 구분자로 프레임 레이트 짐작 (`;`는 29.97 드롭 프레임 관례)
+fn guess_rate(timecode: &str) -> Option<FrameRate> {
+    timecode.contains(';').then_some(FrameRate::Fps29_97Drop)
+}
+
+/// 따로 녹화한 영상에 전사 시간을 맞춤
+///
+/// `offset`은 전사 0ms가 영상에서 놓이는 위치의 타임코드다 (영상이 늦게 시작했으면 `-`).
+/// 저장한 뒤로 SRT/VTT 내보내기는 이 오프셋을 더하고 프레임 경계에 맞춘 시각을 쓴다.
+#[tauri::command]
+pub fn align_transcript_to_media(
+    storage: State<'_, Storage>,
+    session_id: String,
+    media_path: String,
+    offset: String,
+    frame_rate: Option<FrameRate>,
+) -> Result<MediaAlignment, TeuImError> {
+    if !Path::new(&media_path).is_file() {
+        return Err(TeuImError::invalid_argument(format!(
+            "영상 파일을 찾을 수 없습니다: {}",
+            media_path
+        )));
+    }
+    let rate = frame_rate
+        .or_else(|| guess_rate(&offset))
+        .ok_or_else(|| TeuImError::invalid_argument("프레임 레이트를 지정하세요"))?;
+    let offset_ms = timecode_to_ms(&offset, rate).map_err(TeuImError::invalid_argument)?;
+
+    let alignment = storage.set_media_alignment(&MediaAlignment {
+        session_id,
+        media_path,
+        offset: offset.trim().to_string(),
+        frame_rate: rate,
+        offset_ms,
+    })?;
+    log::info!(
+        "영상 타임코드 정렬: {} ({} @ {})",
+        alignment.session_id,
+        alignment.offset,
+        rate.as_str()
+    );
+    Ok(alignment)
+}
+
+/// 세션의 영상 정렬 조회 (없으면 `None`)
+#[tauri::command]
+pub fn get_media_alignment(
+    storage: State<'_, Storage>,
+    session_id: String,
+) -> Result<Option<MediaAlignment>, TeuImError> {
+    storage.media_alignment(&session_id)
+}
+
+/// 영상 정렬 해제 (내보내기가 원래 시간으로 돌아감)
+#[tauri::command]
+pub fn clear_media_alignment(
+    storage: State<'_, Storage>,
+    session_id: String,
+) -> Result<(), TeuImError> {
+    storage.clear_media_alignment(&session_id)
+}
@@ -0,0 +1,286 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::error::TeuImError;
+use crate::llm::{self, ChatRequest};
+use crate::storage::{SessionSummary, Storage};
+
+/// 제목에 넣을 최대 핵심어 수
+const MAX_KEYWORDS: usize = 3;
+
+/// 핵심어가 없을 때 첫 문장으로 만드는 제목의 최대 길이 (글자 수)
+const MAX_FALLBACK_CHARS: usize = 40;
+
+/// LLM에 넘길 전사 앞부분 길이 (글자 수, 제목에는 도입부로 충분)
+const LLM_TRANSCRIPT_CHARS: usize = 8_000;
+
+/// 제목 후보에서 뺄 흔한 단어
+const STOPWORDS: &[&str] = &[
+    "그리고",
+    "그래서",
+    "그런데",
+    "하지만",
+    "그러면",
+    "그러니까",
+    "이제",
+    "지금",
+    "정말",
+    "진짜",
+    "약간",
+    "조금",
+    "많이",
+    "그냥",
+    "일단",
+    "우리",
+    "저희",
+    "제가",
+    "이거",
+    "그거",
+    "저거",
+    "이것",
+    "그것",
+    "여기",
+    "거기",
+    "뭔가",
+    "이런",
+    "그런",
+    "있는",
+    "없는",
+    "하는",
+    "했는데",
+    "합니다",
+    "있습니다",
+    "됩니다",
+    "the",
+    "and",
+    "that",
+    "this",
+    "with",
+    "for",
+    "are",
+    "was",
+    "you",
+    "have",
+    "but",
+    "not",
+    "what",
+    "just",
+    "like",
+    "can",
+    "will",
+    "about",
+    "there",
+    "they",
+    "our",
+    "from",
+    "yeah",
+    "okay",
+    "so",
+    "um",
+    "uh",
+];
+
+/// 한국어 어절 끝에서 떼어 낼 조사 (긴 것부터)
+const PARTICLES: &[&str] = &[
+    "에서는",
+    "으로는",
+    "에서",
+    "으로",
+    "에게",
+    "까지",
+    "부터",
+    "이랑",
+    "하고",
+    "은",
+    "는",
+    "이",
+    "가",
+    "을",
+    "를",
+    "에",
+    "의",
+    "도",
+    "로",
+    "와",
+    "과",
+    "만",
+];
+
+/// 제목을 만든 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleSource {
+    /// 자주 나온 단어
+    Keywords,
+    /// 설정된 LLM
+    Llm,
+}
+
+/// 제목 제안 (`session-title-suggested` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct TitleSuggestion {
+    pub session_id: String,
+    pub title: String,
+    /// 제목을 만든 핵심어 (빈도순, LLM 제목이면 비어 있음)
+    pub keywords: Vec<String>,
+    pub source: TitleSource,
+}
+
+/// 전사 텍스트를 비교용 단어로 나눔 (조사를 떼고 라틴 문자는 소문자로)
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter_map(|word| {
+            let word = PARTICLES
+                .iter()
+                .find_map(|particle| {
+                    word.strip_suffix(particle)
+                        .filter(|stem| stem.chars().count() >= 2)
+                })
+                .unwrap_or(word);
+            let word = word.to_lowercase();
+            let keep = word.chars().count() >= 2
+                && !word.chars().all(|c| c.is_numeric())
+                && !STOPWORDS.contains(&word.as_str());
+            keep.then_some(word)
+        })
+}
+
+/// 자주 나온 단어로 제목 만들기 (여러 번 나온 단어가 없으면 첫 문장)
+fn keyword_title(texts: &[String]) -> (String, Vec<String>) {
+    // 단어별 (횟수, 처음 나온 순서)
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    for (order, word) in texts.iter().flat_map(|text| words(text)).enumerate() {
+        counts.entry(word).or_insert((0, order)).0 += 1;
+    }
+
+    let mut ranked: Vec<_> = counts.into_iter().filter(|(_, (n, _))| *n >= 2).collect();
+    ranked.sort_by(|(_, (a, a_order)), (_, (b, b_order))| b.cmp(a).then(a_order.cmp(b_order)));
+    let keywords: Vec<String> = ranked
+        .into_iter()
+        .take(MAX_KEYWORDS)
+        .map(|(word, _)| word)
+        .collect();
+    if !keywords.is_empty() {
+        return (keywords.join(", "), keywords);
+    }
+
+    let first = texts
+        .iter()
+        .map(|text| text.trim())
+        .find(|text| !text.is_empty())
+        .unwrap_or_default();
+    let sentence = first
+        .split_inclusive(['.', '?', '!'])
+        .next()
+        .unwrap_or(first)
+        .trim();
+    let mut title: String = sentence.chars().take(MAX_FALLBACK_CHARS).collect();
+    if sentence.chars().count() > MAX_FALLBACK_CHARS {
+        title.push('…');
+    }
+    (title, Vec::new())
+}
+
+fn keyword_suggestion(storage: &Storage, session_id: &str) -> Result<TitleSuggestion, TeuImError> {
+    let detail = storage.get_session(session_id)?;
+    let texts: Vec<String> = detail.segments.into_iter().map(|s| s.text).collect();
+    let (title, keywords) = keyword_title(&texts);
+    if title.is_empty() {
+        return Err(TeuImError::invalid_argument(
+            "전사 내용이 없어 제목을 제안할 수 없습니다",
+        ));
+    }
+    Ok(TitleSuggestion {
+        session_id: session_id.to_string(),
+        title,
+        keywords,
+        source: TitleSource::Keywords,
+    })
+}
+
+/// 설정된 LLM으로 한 줄 제목 생성
+async fn llm_title(app: &AppHandle, session_id: &str) -> Result<Option<String>, TeuImError> {
+    let Some(provider) = llm::provider(app) else {
+        return Ok(None);
+    };
+    let (transcript, _) =
+        crate::summary::transcript_text(&app.state::<Storage>(), session_id, LLM_TRANSCRIPT_CHARS)?;
+    let request = ChatRequest {
+        system: "회의 전사를 읽고 전사와 같은 언어로 30자 이내의 제목 하나만 답하세요. \
+                 따옴표나 설명은 붙이지 마세요."
+            .to_string(),
+        user: transcript,
+        max_tokens: Some(40),
+    };
+    let answer = provider.complete(request, Box::new(|_| {})).await?;
+    let title = answer
+        .lines()
+        .map(|line| line.trim().trim_start_matches('#').trim())
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+        .trim_matches(|c| matches!(c, '"' | '\'' | '“' | '”' | '「' | '」'))
+        .trim()
+        .to_string();
+    Ok((!title.is_empty()).then_some(title))
+}
+
+/// LLM이 설정돼 있으면 LLM으로, 아니거나 실패하면 핵심어로 제목 제안
+async fn suggest(app: &AppHandle, session_id: &str) -> Result<TitleSuggestion, TeuImError> {
+    match llm_title(app, session_id).await {
+        Ok(Some(title)) => {
+            return Ok(TitleSuggestion {
+                session_id: session_id.to_string(),
+                title,
+                keywords: Vec::new(),
+                source: TitleSource::Llm,
+            })
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("LLM 제목 생성 실패, 핵심어로 대신함: {}", e),
+    }
+    keyword_suggestion(&app.state::<Storage>(), session_id)
+}
+
+/// 세션이 끝난 뒤 제목이 없으면 제목을 제안해 `session-title-suggested`로 알림
+///
+/// 제안만 보내고 저장은 사용자가 `set_session_title`로 받아들였을 때 한다.
+pub fn suggest_after_session(app: &AppHandle, session_id: &str) {
+    let app = app.clone();
+    let session_id = session_id.to_string();
+    tauri::async_runtime::spawn(async move {
+        let untitled = app
+            .state::<Storage>()
+            .get_session(&session_id)
+            .is_ok_and(|detail| detail.session.title.is_none());
+        if !untitled {
+            return;
+        }
+        match suggest(&app, &session_id).await {
+            Ok(suggestion) => {
+                log::info!("세션 제목 제안: {} ({})", suggestion.title, session_id);
+                let _ = app.emit("session-title-suggested", suggestion);
+            }
+            Err(e) => log::debug!("세션 제목 제안 생략 ({}): {}", session_id, e),
+        }
+    });
+}
+
+/// 전사 내용으로 세션 제목 제안 (저장하지 않음)
+#[tauri::command]
+pub async fn suggest_session_title(
+    app: AppHandle,
+    session_id: String,
+) -> Result<TitleSuggestion, TeuImError> {
+    suggest(&app, &session_id).await
+}
+
+/// 세션 제목 변경 (제안을 받아들일 때도 사용)
+#[tauri::command]
+pub fn set_session_title(
+    storage: State<'_, Storage>,
+    session_id: String,
+    title: String,
+) -> Result<SessionSummary, TeuImError> {
+    storage.set_session_title(&session_id, title.trim())
+}
@@ -0,0 +1,1162 @@
+use cpal::Sample;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext};
+
+use crate::audio::{CaptureManager, MonoResampler};
+use crate::compute::{ComputeBackend, ComputeSettings};
+use crate::error::TeuImError;
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::stream_server::{self, StreamMessage};
+use crate::stt::{self, SttConfig, SttInput, SttProvider, STT_SAMPLE_RATE};
+
+mod diarize;
+mod punctuate;
+mod stabilize;
+
+use diarize::Diarizer;
+use punctuate::punctuate;
+use stabilize::PartialStabilizer;
+
+/// Whisper 모델 입력 샘플레이트
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
+/// 부분 결과를 다시 계산하는 기본 간격 (ms)
+const DEFAULT_STEP_MS: u32 = 2_000;
+
+/// 스트리밍 모드에서 부분 결과를 다시 계산하는 기본 간격 (ms)
+const STREAMING_STEP_MS: u32 = 300;
+
+/// 확정 결과를 내보내는 기본 구간 길이 (ms)
+const DEFAULT_SEGMENT_MS: u32 = 10_000;
+
+/// 파일 전사에서 한 번에 인식하는 구간 길이 (ms, Whisper 입력 창 크기)
+const FILE_SEGMENT_MS: u32 = 30_000;
+
+/// 파일 전사가 취소됐을 때의 에러 메시지
+pub(crate) const CANCELLED: &str = "작업이 취소되었습니다";
+
+/// 화자 구분 시 기본 최대 화자 수
+const DEFAULT_MAX_SPEAKERS: usize = 4;
+
+/// 전사 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TranscriptionOptions {
+    /// 전사할 캡처 세션 (생략하면 처음 들어오는 세션)
+    pub session_id: Option<String>,
+    /// 인식 언어 코드 (생략하면 자동 감지)
+    pub language: Option<String>,
+    /// 영어로 번역하며 전사
+    pub translate: bool,
+    /// 추론 스레드 수
+    pub threads: Option<u32>,
+    pub step_ms: Option<u32>,
+    pub segment_ms: Option<u32>,
+    /// 확정 결과를 이어서 저장할 저장소 세션 (생략하면 새로 생성)
+    pub storage_session_id: Option<String>,
+    /// 확정 구간에 화자 라벨 붙이기 (로컬 엔진)
+    pub diarize: bool,
+    pub max_speakers: Option<usize>,
+    /// 확정 전에 구간 언어를 감지해 `language-detected`로 알림 (다국어 모델)
+    pub detect_language: bool,
+    /// 감지할 언어 후보 (예: `["ko", "en"]`, 비우면 모든 언어)
+    pub candidate_languages: Vec<String>,
+    /// 감지한 언어로 인식 언어를 자동 전환
+    pub auto_switch_language: bool,
+    /// 확정 구간에 문장 부호·대소문자 보정 적용
+    pub punctuate: bool,
+    /// 저지연 스트리밍: 부분 결과를 약 300ms마다 안정 구간과 함께 보냄
+    ///
+    /// 로컬 엔진은 그만큼 자주 추론하므로 작은 모델에 알맞다.
+    pub streaming: bool,
+    /// 용어집에서 불러온 인식 힌트 (전사 시작 시 채움)
+    #[serde(skip)]
+    pub glossary: Vec<String>,
+}
+
+impl TranscriptionOptions {
+    /// 저장·알림 전에 확정 구간 텍스트 후처리
+    fn finalize_text(&self, text: &str) -> String {
+        if self.punctuate {
+            punctuate(text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// `transcript-partial` / `transcript-final` 이벤트 페이로드
+///
+/// 시간은 전사 시작 시점부터의 경과 시간(ms)이다.
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptSegment {
+    pub id: String,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub is_final: bool,
+    /// 화자 라벨 (화자 구분을 켠 확정 결과에만 있음)
+    pub speaker: Option<String>,
+    /// 구간 전체 인식 확신도 (0~1, 엔진이 주지 않으면 없음)
+    pub confidence: Option<f32>,
+    /// 단어별 결과 (엔진이 단어 단위 정보를 줄 때만 채움)
+    pub words: Vec<TranscriptWord>,
+    /// 스트리밍 모드 부분 결과에서 더는 바뀌지 않을 앞부분 (`text`의 접두어)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stable_text: Option<String>,
+}
+
+/// 구간 안의 단어 하나
+///
+/// 시간은 구간과 같은 기준(전사 시작부터의 경과 시간, ms)이다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptWord {
+    pub text: String,
+    /// 인식 확신도 (0~1)
+    pub confidence: Option<f32>,
+    /// 엔진이 단어 시간을 주지 않으면 없음
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+}
+
+impl TranscriptWord {
+    /// 단어 시간을 `offset_ms`만큼 옮김
+    pub fn shifted(&self, offset_ms: i64) -> Self {
+        Self {
+            start_ms: self.start_ms.map(|ms| ms + offset_ms),
+            end_ms: self.end_ms.map(|ms| ms + offset_ms),
+            ..self.clone()
+        }
+    }
+}
+
+/// 단어 목록 시간을 `offset_ms`만큼 옮김
+fn shift_words(words: &[TranscriptWord], offset_ms: i64) -> Vec<TranscriptWord> {
+    words.iter().map(|word| word.shifted(offset_ms)).collect()
+}
+
+/// Whisper 추론 결과 구간 (시간은 입력 오디오 시작 기준, ms)
+struct Inferred {
+    text: String,
+    start_ms: i64,
+    end_ms: i64,
+    confidence: Option<f32>,
+    words: Vec<TranscriptWord>,
+}
+
+/// `language-detected` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageDetection {
+    pub language: String,
+    pub probability: f32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// `transcription-error` 이벤트 페이로드 (클라우드 제공자 연결 오류)
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionError {
+    pub session_id: String,
+    pub provider: String,
+    pub message: String,
+    /// 재연결 시도 횟수
+    pub attempt: u32,
+    /// 다시 연결하는 중인지 (false면 전사가 멈춤)
+    pub recovering: bool,
+}
+
+/// 로드된 모델 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelInfo {
+    pub path: String,
+    pub multilingual: bool,
+    /// 실제로 쓰는 연산 장치 (고른 GPU를 쓸 수 없으면 CPU)
+    pub backend: ComputeBackend,
+}
+
+enum FeedMessage {
+    Audio { sample_rate: u32, samples: Vec<i16> },
+    /// 발화가 끝났으니 쌓인 오디오를 모두 확정
+    Flush,
+    /// VAD가 넘기지 않은 무음 (그 길이만큼 타임라인을 옮김)
+    Gap { sample_rate: u32, samples: usize },
+    Stop,
+}
+
+struct FeedTarget {
+    session_id: Option<String>,
+    tx: Sender<FeedMessage>,
+    /// 결과를 기록하는 저장소 세션과 그 세션에서 이번 전사가 시작한 위치 (ms)
+    storage_session_id: String,
+    offset_ms: i64,
+    /// 지금까지 작업자로 넘긴 오디오 길이 (ms)
+    fed_ms: f64,
+}
+
+/// 캡처 콜백에서 전사 작업자로 오디오를 넘기는 통로
+#[derive(Clone, Default)]
+pub struct TranscriptionFeed {
+    target: Arc<Mutex<Option<FeedTarget>>>,
+}
+
+impl TranscriptionFeed {
+    /// 전사 중인 세션의 샘플만 작업자로 전달
+    pub fn push(&self, session_id: &str, sample_rate: u32, samples: &[i16]) {
+        self.send(session_id, sample_rate, samples.len(), || FeedMessage::Audio {
+            sample_rate,
+            samples: samples.to_vec(),
+        });
+    }
+
+    /// VAD가 무음이라 넘기지 않은 샘플 수를 알림 (타임라인이 실제 녹음과 맞도록)
+    pub fn skip(&self, session_id: &str, sample_rate: u32, samples: usize) {
+        if samples == 0 {
+            return;
+        }
+        self.send(session_id, sample_rate, samples, || FeedMessage::Gap {
+            sample_rate,
+            samples,
+        });
+    }
+
+    /// 전사 중인 세션이면 `samples`만큼 위치를 옮기고 메시지 전달
+    fn send(
+        &self,
+        session_id: &str,
+        sample_rate: u32,
+        samples: usize,
+        message: impl FnOnce() -> FeedMessage,
+    ) {
+        let mut target = self.target.lock().unwrap();
+        let Some(feed) = target.as_mut() else {
+            return;
+        };
+
+        match &feed.session_id {
+            Some(id) if id != session_id => return,
+            Some(_) => {}
+            None => feed.session_id = Some(session_id.to_string()),
+        }
+        feed.fed_ms += samples as f64 * 1000.0 / sample_rate as f64;
+
+        let _ = feed.tx.send(message());
+    }
+
+    /// 발화 종료 시 현재 구간을 바로 확정하도록 요청
+    pub fn flush(&self, session_id: &str) {
+        let target = self.target.lock().unwrap();
+        if let Some(feed) = target.as_ref() {
+            if feed.session_id.as_deref() == Some(session_id) {
+                let _ = feed.tx.send(FeedMessage::Flush);
+            }
+        }
+    }
+
+    /// 이 캡처 세션을 전사 중이면 저장소 세션 ID와 그 타임라인상의 현재 위치 (ms)
+    pub fn position(&self, session_id: &str) -> Option<(String, i64)> {
+        let target = self.target.lock().unwrap();
+        let feed = target.as_ref()?;
+        if feed.session_id.as_deref().is_some_and(|id| id != session_id) {
+            return None;
+        }
+        Some((
+            feed.storage_session_id.clone(),
+            feed.offset_ms + feed.fed_ms as i64,
+        ))
+    }
+
+    fn attach(
+        &self,
+        session_id: Option<String>,
+        storage_session_id: String,
+        offset_ms: i64,
+        tx: Sender<FeedMessage>,
+    ) {
+        *self.target.lock().unwrap() = Some(FeedTarget {
+            session_id,
+            tx,
+            storage_session_id,
+            offset_ms,
+            fed_ms: 0.0,
+        });
+    }
+
+    fn detach(&self) {
+        self.target.lock().unwrap().take();
+    }
+}
+
+struct LoadedModel {
+    path: PathBuf,
+    context: Arc<WhisperContext>,
+    backend: ComputeBackend,
+}
+
+/// 16kHz로 변환하는 리샘플러 (입력 레이트가 바뀌면 새로 만듦)
+#[derive(Default)]
+struct WhisperRateConverter {
+    resampler: Option<(u32, MonoResampler)>,
+}
+
+impl WhisperRateConverter {
+    fn convert(&mut self, sample_rate: u32, samples: &[i16]) -> Result<Vec<f32>, String> {
+        let input: Vec<f32> = samples.iter().map(|s| *s as f32 / 32768.0).collect();
+        if sample_rate == WHISPER_SAMPLE_RATE {
+            return Ok(input);
+        }
+
+        if self.resampler.as_ref().map(|(rate, _)| *rate) != Some(sample_rate) {
+            let resampler = MonoResampler::new(sample_rate, WHISPER_SAMPLE_RATE)?;
+            self.resampler = Some((sample_rate, resampler));
+        }
+        Ok(self
+            .resampler
+            .as_mut()
+            .map(|(_, r)| r.process(&input))
+            .unwrap_or_default())
+    }
+}
+
+/// 전사를 수행할 엔진
+enum Backend {
+    Whisper(Arc<WhisperContext>),
+    Cloud(Arc<dyn SttProvider>),
+}
+
+struct Worker {
+    tx: Sender<FeedMessage>,
+    thread: JoinHandle<()>,
+}
+
+/// whisper.cpp 전사 엔진 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct TranscriptionEngine {
+    model: Mutex<Option<LoadedModel>>,
+    worker: Mutex<Option<Worker>>,
+}
+
+impl TranscriptionEngine {
+    pub fn is_running(&self) -> bool {
+        self.worker.lock().unwrap().is_some()
+    }
+
+    /// 로드된 Whisper 모델
+    pub(crate) fn context(&self) -> Option<Arc<WhisperContext>> {
+        self.model
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|model| model.context.clone())
+    }
+
+    /// 로드된 Whisper 모델 파일 경로
+    pub(crate) fn model_path(&self) -> Option<PathBuf> {
+        self.model.lock().unwrap().as_ref().map(|model| model.path.clone())
+    }
+
+    /// 전사 옵션에 스레드 수가 없을 때 쓸 값 (로드된 모델의 연산 장치 기준)
+    pub(crate) fn default_threads(&self, compute: &ComputeSettings) -> u32 {
+        let backend = self.model.lock().unwrap().as_ref().map(|model| model.backend);
+        compute.threads(backend.unwrap_or_default())
+    }
+
+    /// 작업자를 멈추고 남은 오디오를 확정할 때까지 대기 (실행 중이었으면 `true`)
+    pub(crate) fn stop(&self, feed: &TranscriptionFeed) -> bool {
+        feed.detach();
+
+        let Some(worker) = self.worker.lock().unwrap().take() else {
+            return false;
+        };
+        let _ = worker.tx.send(FeedMessage::Stop);
+        if worker.thread.join().is_err() {
+            log::error!("전사 스레드가 비정상 종료됨");
+        }
+        true
+    }
+}
+
+/// Whisper 모델 로드 (GGML/GGUF 파일)
+#[tauri::command]
+pub async fn load_model(
+    engine: State<'_, TranscriptionEngine>,
+    store: State<'_, SettingsStore>,
+    path: String,
+) -> Result<ModelInfo, TeuImError> {
+    let model_path = PathBuf::from(&path);
+    if !model_path.is_file() {
+        return Err(TeuImError::ModelLoad {
+            message: format!("모델 파일을 찾을 수 없습니다: {}", path),
+            path,
+        });
+    }
+
+    let compute = store.get().compute;
+    let backend = compute.resolve();
+
+    // 모델 로드는 수 초가 걸릴 수 있어 블로킹 스레드에서 실행
+    let load_path = model_path.clone();
+    let context = tauri::async_runtime::spawn_blocking(move || {
+        WhisperContext::new_with_params(&load_path, compute.context_parameters(backend))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| TeuImError::ModelLoad {
+        path: path.clone(),
+        message: e.to_string(),
+    })?;
+
+    let info = ModelInfo {
+        path,
+        multilingual: context.is_multilingual(),
+        backend,
+    };
+
+    *engine.model.lock().unwrap() = Some(LoadedModel {
+        path: model_path,
+        context: Arc::new(context),
+        backend,
+    });
+
+    log::info!("Whisper 모델 로드됨: {} ({:?})", info.path, backend);
+    Ok(info)
+}
+
+/// 로드된 모델과 별개로 쓸 Whisper 모델 열기 (블로킹)
+///
+/// 다시 듣기나 세션 다시 전사처럼 더 큰 모델로 잠깐 돌릴 때 쓴다. `model`은
+/// 모델 목록의 이름이나 파일 경로이고, 연산 장치 설정에 맞는 스레드 수를 함께 반환한다.
+pub(crate) fn open_model(
+    app: &AppHandle,
+    model: &str,
+) -> Result<(WhisperContext, u32), TeuImError> {
+    let path =
+        crate::models::resolve_model_path(app, model).map_err(TeuImError::invalid_argument)?;
+    let compute = app.state::<SettingsStore>().get().compute;
+    let backend = compute.resolve();
+    let context = WhisperContext::new_with_params(&path, compute.context_parameters(backend))
+        .map_err(|e| TeuImError::ModelLoad {
+            path: path.to_string_lossy().into_owned(),
+            message: e.to_string(),
+        })?;
+    log::info!("추가 Whisper 모델 열림: {} ({:?})", path.display(), backend);
+    Ok((context, compute.threads(backend)))
+}
+
+/// 캡처 스트림 전사 시작
+///
+/// 확정 결과가 저장되는 저장소 세션 ID를 반환한다.
+#[tauri::command]
+pub fn start_transcription(
+    app: AppHandle,
+    engine: State<'_, TranscriptionEngine>,
+    manager: State<'_, CaptureManager>,
+    storage: State<'_, Storage>,
+    stt: State<'_, SttConfig>,
+    options: Option<TranscriptionOptions>,
+) -> Result<String, TeuImError> {
+    let mut options = options.unwrap_or_default();
+    options.glossary = crate::glossary::hints(&storage);
+    let compute = app.state::<SettingsStore>().get().compute;
+    options.threads = Some(options.threads.unwrap_or(engine.default_threads(&compute)));
+
+    if let Some(code) = options
+        .candidate_languages
+        .iter()
+        .find(|code| whisper_rs::get_lang_id(code).is_none())
+    {
+        return Err(TeuImError::invalid_argument(format!(
+            "지원하지 않는 언어 코드: {}",
+            code
+        )));
+    }
+
+    let backend = match stt.current() {
+        Some(provider) => {
+            log::info!("전사 시작: {}", provider.name());
+            Backend::Cloud(provider)
+        }
+        None => {
+            let model = engine.model.lock().unwrap();
+            let model = model.as_ref().ok_or(TeuImError::ModelNotLoaded)?;
+            log::info!("전사 시작: {}", model.path.display());
+            Backend::Whisper(model.context.clone())
+        }
+    };
+
+    let mut worker = engine.worker.lock().unwrap();
+    if worker.is_some() {
+        return Err(TeuImError::TranscriptionRunning);
+    }
+
+    // 이어서 저장하는 세션이면 기존 길이 뒤로 타임스탬프를 붙임
+    let (storage_session_id, offset_ms) = match options.storage_session_id.clone() {
+        Some(id) => {
+            let offset_ms = storage.session_duration(&id)?;
+            (id, offset_ms)
+        }
+        None => {
+            let device_name = options.session_id.as_ref().and_then(|id| {
+                manager
+                    .list()
+                    .into_iter()
+                    .find(|session| &session.id == id)
+                    .map(|session| session.device_name)
+            });
+            (storage.create_session(None, device_name)?.id, 0)
+        }
+    };
+    options.storage_session_id = Some(storage_session_id.clone());
+    crate::journal::begin(&app, &storage_session_id, offset_ms);
+
+    let (tx, rx) = mpsc::channel();
+    let session_id = options.session_id.clone();
+    let app_handle = app.clone();
+    let thread = thread::spawn(move || match backend {
+        Backend::Whisper(context) => run_transcription(app, context, options, offset_ms, rx),
+        Backend::Cloud(provider) => run_cloud_transcription(app, provider, options, offset_ms, rx),
+    });
+
+    manager.transcription_feed().attach(
+        session_id,
+        storage_session_id.clone(),
+        offset_ms,
+        tx.clone(),
+    );
+    *worker = Some(Worker { tx, thread });
+    drop(worker);
+
+    crate::tray::refresh(&app_handle);
+    Ok(storage_session_id)
+}
+
+/// 전사 중지 (남은 오디오는 확정 결과로 내보냄)
+#[tauri::command]
+pub fn stop_transcription(
+    app: AppHandle,
+    engine: State<'_, TranscriptionEngine>,
+    manager: State<'_, CaptureManager>,
+) -> Result<(), TeuImError> {
+    if engine.stop(&manager.transcription_feed()) {
+        log::info!("전사 중지됨");
+        crate::tray::refresh(&app);
+    }
+    Ok(())
+}
+
+/// 부분 결과 간격 (성능 절약 모드면 더 긴 간격)
+fn partial_step(app: &AppHandle, step_len: usize) -> usize {
+    let samples_per_ms = WHISPER_SAMPLE_RATE as usize / 1000;
+    match crate::performance::saving_step_ms(app) {
+        Some(step_ms) => step_len.max(step_ms as usize * samples_per_ms),
+        None => step_len,
+    }
+}
+
+/// 전사 작업자 루프
+///
+/// 오디오를 모아 `step_ms`마다 현재 구간을 다시 인식해 부분 결과를 보내고,
+/// 구간이 `segment_ms`를 넘으면 마지막 문장을 제외한 부분을 확정한다.
+/// 확정 결과는 저장소 세션에 바로 기록된다.
+fn run_transcription(
+    app: AppHandle,
+    context: Arc<WhisperContext>,
+    mut options: TranscriptionOptions,
+    offset_ms: i64,
+    rx: Receiver<FeedMessage>,
+) {
+    let storage = app.state::<Storage>();
+    let storage_session_id = options.storage_session_id.clone().unwrap_or_default();
+
+    let mut state = match context.create_state() {
+        Ok(state) => state,
+        Err(e) => {
+            log::error!("Whisper 상태 생성 실패: {}", e);
+            return;
+        }
+    };
+
+    let samples_per_ms = WHISPER_SAMPLE_RATE as usize / 1000;
+    let default_step_ms = if options.streaming {
+        STREAMING_STEP_MS
+    } else {
+        DEFAULT_STEP_MS
+    };
+    let step_len = options.step_ms.unwrap_or(default_step_ms) as usize * samples_per_ms;
+    let segment_len = options.segment_ms.unwrap_or(DEFAULT_SEGMENT_MS) as usize * samples_per_ms;
+
+    let mut converter = WhisperRateConverter::default();
+    let mut pending: Vec<f32> = Vec::new();
+    let mut pending_offset_ms = offset_ms;
+    // ms 단위로 옮기고 남은 무음 길이
+    let mut gap_remainder_ms = 0.0;
+    let mut since_partial = 0usize;
+    let mut window_id = Uuid::new_v4().to_string();
+    let mut stabilizer = PartialStabilizer::default();
+    let detect_language = options.detect_language && context.is_multilingual();
+    if options.detect_language && !detect_language {
+        log::warn!("영어 전용 모델이라 언어 감지를 건너뜁니다");
+    }
+    let mut diarizer = options
+        .diarize
+        .then(|| Diarizer::new(options.max_speakers.unwrap_or(DEFAULT_MAX_SPEAKERS)));
+
+    loop {
+        let mut flushing = false;
+        let stopping = match rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(FeedMessage::Audio {
+                sample_rate,
+                samples,
+            }) => {
+                let converted = match converter.convert(sample_rate, &samples) {
+                    Ok(converted) => converted,
+                    Err(e) => {
+                        log::error!("{}", e);
+                        continue;
+                    }
+                };
+                since_partial += converted.len();
+                pending.extend(converted);
+                false
+            }
+            Ok(FeedMessage::Flush) => {
+                flushing = !pending.is_empty();
+                false
+            }
+            Ok(FeedMessage::Gap {
+                sample_rate,
+                samples,
+            }) => {
+                // 모아 둔 오디오가 있으면 그 뒤를 무음으로 채워 시간을 맞춤
+                let gap_ms = samples as f64 * 1000.0 / sample_rate as f64;
+                if pending.is_empty() {
+                    gap_remainder_ms += gap_ms;
+                    pending_offset_ms += gap_remainder_ms.trunc() as i64;
+                    gap_remainder_ms = gap_remainder_ms.fract();
+                } else {
+                    let silence = (gap_ms * samples_per_ms as f64) as usize;
+                    pending.resize(pending.len() + silence, 0.0);
+                }
+                false
+            }
+            Ok(FeedMessage::Stop) | Err(RecvTimeoutError::Disconnected) => true,
+            Err(RecvTimeoutError::Timeout) => false,
+        };
+
+        if stopping || flushing || pending.len() >= segment_len {
+            let keep_tail = !stopping && !flushing;
+
+            if detect_language && pending.len() >= 1000 * samples_per_ms {
+                match detect(&mut state, &options, &pending) {
+                    Ok((language, probability)) => {
+                        let _ = app.emit(
+                            "language-detected",
+                            LanguageDetection {
+                                language: language.clone(),
+                                probability,
+                                start_ms: pending_offset_ms,
+                                end_ms: pending_offset_ms + (pending.len() / samples_per_ms) as i64,
+                            },
+                        );
+                        if options.auto_switch_language
+                            && options.language.as_deref() != Some(language.as_str())
+                        {
+                            log::info!("인식 언어 전환: {}", language);
+                            options.language = Some(language);
+                        }
+                    }
+                    Err(e) => log::warn!("언어 감지 실패: {}", e),
+                }
+            }
+
+            let segments = match infer(&mut state, &options, &pending) {
+                Ok(segments) => segments,
+                Err(e) => {
+                    log::error!("전사 실패: {}", e);
+                    Vec::new()
+                }
+            };
+
+            // 마지막 문장은 잘렸을 수 있으니 다음 구간에서 다시 인식
+            let finalize_count = if keep_tail && segments.len() > 1 {
+                segments.len() - 1
+            } else {
+                segments.len()
+            };
+
+            for inferred in &segments[..finalize_count] {
+                let speaker = diarizer.as_mut().and_then(|diarizer| {
+                    let start = (inferred.start_ms as usize * samples_per_ms).min(pending.len());
+                    let end =
+                        (inferred.end_ms as usize * samples_per_ms).clamp(start, pending.len());
+                    diarizer.assign(&pending[start..end])
+                });
+                let segment = TranscriptSegment {
+                    id: Uuid::new_v4().to_string(),
+                    text: options.finalize_text(&inferred.text),
+                    start_ms: pending_offset_ms + inferred.start_ms,
+                    end_ms: pending_offset_ms + inferred.end_ms,
+                    is_final: true,
+                    speaker,
+                    confidence: inferred.confidence,
+                    words: shift_words(&inferred.words, pending_offset_ms),
+                    stable_text: None,
+                };
+                emit_final(&app, &storage, &storage_session_id, segment);
+            }
+
+            let consumed_ms = if finalize_count < segments.len() {
+                segments[finalize_count].start_ms
+            } else {
+                (pending.len() / samples_per_ms) as i64
+            };
+            let consumed = (consumed_ms as usize * samples_per_ms).min(pending.len());
+            pending.drain(..consumed);
+            pending_offset_ms += consumed_ms;
+            since_partial = 0;
+            window_id = Uuid::new_v4().to_string();
+            stabilizer.reset();
+
+            if stopping {
+                if let Err(e) = storage.finish_session(&storage_session_id, pending_offset_ms) {
+                    log::error!("세션 종료 기록 실패: {}", e);
+                }
+                crate::journal::end(&app, &storage_session_id);
+                crate::titling::suggest_after_session(&app, &storage_session_id);
+                crate::refine::after_session(&app, &storage_session_id);
+                break;
+            }
+        } else if since_partial >= partial_step(&app, step_len) && !pending.is_empty() {
+            since_partial = 0;
+            match infer(&mut state, &options, &pending) {
+                Ok(segments) if !segments.is_empty() => {
+                    let text = segments
+                        .iter()
+                        .map(|s| s.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let stable_text = options.streaming.then(|| stabilizer.update(&text));
+                    emit_partial(
+                        &app,
+                        &storage_session_id,
+                        TranscriptSegment {
+                            id: window_id.clone(),
+                            text,
+                            start_ms: pending_offset_ms + segments[0].start_ms,
+                            end_ms: pending_offset_ms + segments[segments.len() - 1].end_ms,
+                            is_final: false,
+                            speaker: None,
+                            confidence: None,
+                            words: Vec::new(),
+                            stable_text,
+                        },
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("부분 전사 실패: {}", e),
+            }
+        }
+    }
+}
+
+/// 클라우드 전사 작업자
+///
+/// 캡처 오디오를 16kHz로 맞춰 제공자에 흘려보내고, 돌아온 결과를
+/// 로컬 엔진과 같은 이벤트로 내보낸다.
+fn run_cloud_transcription(
+    app: AppHandle,
+    provider: Arc<dyn SttProvider>,
+    options: TranscriptionOptions,
+    offset_ms: i64,
+    rx: Receiver<FeedMessage>,
+) {
+    let storage_session_id = options.storage_session_id.clone().unwrap_or_default();
+
+    let (audio_tx, audio_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (result_tx, mut result_rx) = tokio::sync::mpsc::unbounded_channel();
+    let error_app = app.clone();
+    let error_session_id = storage_session_id.clone();
+    let provider_name = provider.name();
+    let report = move |message: &str, attempt: u32, recovering: bool| {
+        let error = TranscriptionError {
+            session_id: error_session_id.clone(),
+            provider: provider_name.to_string(),
+            message: message.to_string(),
+            attempt,
+            recovering,
+        };
+        let _ = error_app.emit("transcription-error", error);
+    };
+    let stream = tauri::async_runtime::spawn(stt::stream_with_retry(
+        provider.clone(),
+        audio_rx,
+        result_tx,
+        options.glossary.clone(),
+        report,
+    ));
+
+    // 제공자 시간 → 그 시점까지 건너뛴 무음 길이 (제공자는 무음을 받지 않음)
+    let gaps: Arc<Mutex<Vec<(i64, i64)>>> = Arc::default();
+    let results_gaps = gaps.clone();
+    let results_app = app.clone();
+    let results_session_id = storage_session_id.clone();
+    let results_options = options.clone();
+    let results = tauri::async_runtime::spawn(async move {
+        let storage = results_app.state::<Storage>();
+        let mut window_id = Uuid::new_v4().to_string();
+        let mut stabilizer = PartialStabilizer::default();
+        while let Some(result) = result_rx.recv().await {
+            let stable_text = (results_options.streaming && !result.is_final)
+                .then(|| stabilizer.update(&result.text));
+            let middle_ms = (result.start_ms + result.end_ms) / 2;
+            let offset_ms = offset_ms + gap_before(&results_gaps.lock().unwrap(), middle_ms);
+            let segment = TranscriptSegment {
+                id: if result.is_final {
+                    Uuid::new_v4().to_string()
+                } else {
+                    window_id.clone()
+                },
+                text: if result.is_final {
+                    results_options.finalize_text(&result.text)
+                } else {
+                    result.text
+                },
+                start_ms: offset_ms + result.start_ms,
+                end_ms: offset_ms + result.end_ms,
+                is_final: result.is_final,
+                speaker: None,
+                confidence: result.confidence,
+                words: shift_words(&result.words, offset_ms),
+                stable_text,
+            };
+            if segment.is_final {
+                window_id = Uuid::new_v4().to_string();
+                stabilizer.reset();
+                emit_final(&results_app, &storage, &results_session_id, segment);
+            } else {
+                emit_partial(&results_app, &results_session_id, segment);
+            }
+        }
+    });
+
+    let mut converter = WhisperRateConverter::default();
+    let mut sent_samples = 0usize;
+    let mut skipped_ms = 0.0;
+    loop {
+        let input = match rx.recv() {
+            Ok(FeedMessage::Audio {
+                sample_rate,
+                samples,
+            }) => match converter.convert(sample_rate, &samples) {
+                Ok(converted) => {
+                    sent_samples += converted.len();
+                    SttInput::Audio(
+                        converted
+                            .iter()
+                            .map(|s| i16::from_sample(s.clamp(-1.0, 1.0)))
+                            .collect(),
+                    )
+                }
+                Err(e) => {
+                    log::error!("{}", e);
+                    continue;
+                }
+            },
+            Ok(FeedMessage::Flush) => SttInput::Flush,
+            Ok(FeedMessage::Gap {
+                sample_rate,
+                samples,
+            }) => {
+                skipped_ms += samples as f64 * 1000.0 / sample_rate as f64;
+                let sent_ms = (sent_samples * 1000 / STT_SAMPLE_RATE as usize) as i64;
+                let mut gaps = gaps.lock().unwrap();
+                match gaps.last_mut() {
+                    Some(last) if last.0 == sent_ms => last.1 = skipped_ms as i64,
+                    _ => gaps.push((sent_ms, skipped_ms as i64)),
+                }
+                continue;
+            }
+            Ok(FeedMessage::Stop) | Err(_) => break,
+        };
+        // 제공자가 먼저 끝났으면 입력은 버림 (오류는 아래에서 기록)
+        let _ = audio_tx.send(input);
+    }
+    drop(audio_tx);
+
+    match tauri::async_runtime::block_on(stream) {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => log::error!("{} 전사 실패: {}", provider.name(), e),
+        Err(e) => log::error!("{} 전사 작업 중단: {}", provider.name(), e),
+    }
+    let _ = tauri::async_runtime::block_on(results);
+
+    let duration_ms =
+        offset_ms + (sent_samples * 1000 / STT_SAMPLE_RATE as usize) as i64 + skipped_ms as i64;
+    if let Err(e) = app
+        .state::<Storage>()
+        .finish_session(&storage_session_id, duration_ms)
+    {
+        log::error!("세션 종료 기록 실패: {}", e);
+    }
+    crate::journal::end(&app, &storage_session_id);
+    crate::titling::suggest_after_session(&app, &storage_session_id);
+    crate::refine::after_session(&app, &storage_session_id);
+}
+
+/// 제공자 시간 `at_ms`까지 건너뛴 무음 길이 (`gaps`는 보낸 위치 순)
+fn gap_before(gaps: &[(i64, i64)], at_ms: i64) -> i64 {
+    gaps.iter()
+        .rev()
+        .find(|(sent_ms, _)| *sent_ms <= at_ms)
+        .map_or(0, |(_, skipped_ms)| *skipped_ms)
+}
+
+/// 16kHz 모노 오디오 전체를 구간별로 인식 (파일 가져오기용)
+///
+/// 실시간 작업자와 같이 구간 끝의 잘렸을 수 있는 문장은 다음 구간에서 다시
+/// 인식한다. `on_progress`에는 처리한 비율(0~1)을 넘기고, `cancel`이 켜지면
+/// 다음 구간 전에 멈춘다.
+pub(crate) fn transcribe_buffer(
+    context: &WhisperContext,
+    options: &TranscriptionOptions,
+    audio: &[f32],
+    cancel: &AtomicBool,
+    mut on_segment: impl FnMut(TranscriptSegment),
+    mut on_progress: impl FnMut(f32),
+) -> Result<(), String> {
+    let mut state = context
+        .create_state()
+        .map_err(|e| format!("Whisper 상태 생성 실패: {}", e))?;
+
+    let samples_per_ms = WHISPER_SAMPLE_RATE as usize / 1000;
+    let segment_len = options.segment_ms.unwrap_or(FILE_SEGMENT_MS) as usize * samples_per_ms;
+    let mut diarizer = options
+        .diarize
+        .then(|| Diarizer::new(options.max_speakers.unwrap_or(DEFAULT_MAX_SPEAKERS)));
+
+    let mut position = 0usize;
+    while position < audio.len() {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(CANCELLED.to_string());
+        }
+        let end = (position + segment_len).min(audio.len());
+        let window = &audio[position..end];
+        let last = end == audio.len();
+
+        let segments = infer(&mut state, options, window)?;
+        let finalize_count = if !last && segments.len() > 1 {
+            segments.len() - 1
+        } else {
+            segments.len()
+        };
+
+        let window_offset_ms = (position / samples_per_ms) as i64;
+        for inferred in &segments[..finalize_count] {
+            let speaker = diarizer.as_mut().and_then(|diarizer| {
+                let start = (inferred.start_ms as usize * samples_per_ms).min(window.len());
+                let end = (inferred.end_ms as usize * samples_per_ms).clamp(start, window.len());
+                diarizer.assign(&window[start..end])
+            });
+            on_segment(TranscriptSegment {
+                id: Uuid::new_v4().to_string(),
+                text: options.finalize_text(&inferred.text),
+                start_ms: window_offset_ms + inferred.start_ms,
+                end_ms: window_offset_ms + inferred.end_ms,
+                is_final: true,
+                speaker,
+                confidence: inferred.confidence,
+                words: shift_words(&inferred.words, window_offset_ms),
+                stable_text: None,
+            });
+        }
+
+        // 남긴 문장이 구간 맨 앞에서 시작하면 더 나아갈 수 없으니 구간 전체를 넘김
+        let consumed = segments
+            .get(finalize_count)
+            .map(|inferred| inferred.start_ms as usize * samples_per_ms)
+            .filter(|consumed| *consumed > 0)
+            .unwrap_or(window.len())
+            .min(window.len());
+        position += consumed;
+        on_progress(position as f32 / audio.len() as f32);
+    }
+
+    Ok(())
+}
+
+/// 확정 구간을 저장소에 기록하고 `transcript-final`로 알림 (번역이 켜져 있으면 번역 요청)
+fn emit_final(
+    app: &AppHandle,
+    storage: &Storage,
+    storage_session_id: &str,
+    segment: TranscriptSegment,
+) {
+    if let Err(e) = storage.insert_segment(storage_session_id, &segment) {
+        log::error!("전사 구간 저장 실패: {}", e);
+    }
+    crate::journal::record_final(app, storage_session_id, &segment);
+    crate::translate::enqueue(app, &segment);
+    crate::captions::push(app, &segment);
+    stream_server::publish(app, StreamMessage::Final(segment.clone()));
+    let _ = app.emit("transcript-final", segment);
+}
+
+/// 부분 결과를 `transcript-partial`로 알림 (저널에도 기록)
+fn emit_partial(app: &AppHandle, storage_session_id: &str, segment: TranscriptSegment) {
+    crate::journal::record_partial(app, storage_session_id, &segment);
+    crate::captions::push(app, &segment);
+    stream_server::publish(app, StreamMessage::Partial(segment.clone()));
+    let _ = app.emit("transcript-partial", segment);
+}
+
+/// 단어 하나로 묶는 중인 토큰들
+#[derive(Default)]
+struct WordTokens {
+    bytes: Vec<u8>,
+    probabilities: Vec<f32>,
+    start: Option<i64>,
+    end: Option<i64>,
+}
+
+/// 후보 언어 중 가장 가능성이 높은 언어와 확률
+fn detect(
+    state: &mut whisper_rs::WhisperState,
+    options: &TranscriptionOptions,
+    audio: &[f32],
+) -> Result<(String, f32), String> {
+    let threads = options.threads.map(|t| t as usize).unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|n| n.get().min(4))
+            .unwrap_or(1)
+    });
+
+    state
+        .pcm_to_mel(audio, threads)
+        .map_err(|e| e.to_string())?;
+    let (_, probs) = state.lang_detect(0, threads).map_err(|e| e.to_string())?;
+
+    let candidates: Vec<i32> = options
+        .candidate_languages
+        .iter()
+        .filter_map(|code| whisper_rs::get_lang_id(code))
+        .collect();
+
+    probs
+        .iter()
+        .enumerate()
+        .filter(|(id, _)| candidates.is_empty() || candidates.contains(&(*id as i32)))
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .and_then(|(id, p)| whisper_rs::get_lang_str(id as i32).map(|code| (code.to_string(), *p)))
+        .ok_or_else(|| "감지된 언어가 없습니다".to_string())
+}
+
+/// 16kHz 모노 오디오를 인식해 (텍스트, 시작 ms, 끝 ms) 목록 반환
+fn infer(
+    state: &mut whisper_rs::WhisperState,
+    options: &TranscriptionOptions,
+    audio: &[f32],
+) -> Result<Vec<Inferred>, String> {
+    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+    params.set_language(Some(options.language.as_deref().unwrap_or("auto")));
+    params.set_translate(options.translate);
+    params.set_no_context(true);
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(false);
+    // 단어 시간용 토큰 타임스탬프
+    params.set_token_timestamps(true);
+    if let Some(threads) = options.threads {
+        params.set_n_threads(threads as i32);
+    }
+    // 용어집 용어를 앞 문맥으로 넣어 고유명사 표기를 유도
+    if let Some(prompt) = crate::glossary::whisper_prompt(&options.glossary) {
+        params.set_initial_prompt(&prompt);
+    }
+
+    state.full(params, audio).map_err(|e| e.to_string())?;
+
+    let segments = state
+        .as_iter()
+        .filter_map(|segment| {
+            let text = segment.to_str_lossy().ok()?.trim().to_string();
+            if text.is_empty() {
+                return None;
+            }
+            let (confidence, words) = token_words(&segment);
+            // whisper 타임스탬프는 10ms 단위
+            Some(Inferred {
+                text,
+                start_ms: segment.start_timestamp() * 10,
+                end_ms: segment.end_timestamp() * 10,
+                confidence,
+                words,
+            })
+        })
+        .collect();
+
+    Ok(segments)
+}
+
+/// 토큰을 단어 단위로 묶어 구간·단어 확신도와 단어 시간 계산
+///
+/// 공백으로 시작하는 토큰에서 새 단어가 시작되고, 단어 확신도는 토큰 확률의 평균,
+/// 단어 시간은 첫 토큰 시작부터 마지막 토큰 끝까지다. 한글처럼 한 글자가 여러
+/// 토큰에 걸칠 수 있어 바이트로 모은 뒤 문자열로 바꾼다.
+fn token_words(segment: &whisper_rs::WhisperSegment) -> (Option<f32>, Vec<TranscriptWord>) {
+    let mut words = Vec::new();
+    let mut word = WordTokens::default();
+    let mut total = 0.0f32;
+    let mut count = 0usize;
+
+    let mut flush = |word: &mut WordTokens| {
+        let word = std::mem::take(word);
+        let text = String::from_utf8_lossy(&word.bytes).trim().to_string();
+        if !text.is_empty() && !word.probabilities.is_empty() {
+            let confidence =
+                word.probabilities.iter().sum::<f32>() / word.probabilities.len() as f32;
+            words.push(TranscriptWord {
+                text,
+                confidence: Some(confidence),
+                // whisper 타임스탬프는 10ms 단위
+                start_ms: word.start.map(|t| t * 10),
+                end_ms: word.end.map(|t| t * 10),
+            });
+        }
+    };
+
+    for index in 0..segment.n_tokens() {
+        let Some(token) = segment.get_token(index) else {
+            continue;
+        };
+        let Ok(token_bytes) = token.to_bytes() else {
+            continue;
+        };
+        // [_BEG_], [_TT_123], <|endoftext|> 같은 특수 토큰 제외
+        if token_bytes.starts_with(b"[_") || token_bytes.starts_with(b"<|") {
+            continue;
+        }
+        if token_bytes.starts_with(b" ") {
+            flush(&mut word);
+        }
+        let data = token.token_data();
+        word.bytes.extend_from_slice(token_bytes);
+        word.probabilities.push(data.p);
+        word.start.get_or_insert(data.t0);
+        word.end = Some(data.t1);
+        total += data.p;
+        count += 1;
+    }
+    flush(&mut word);
+
+    let confidence = (count > 0).then(|| total / count as f32);
+    (confidence, words)
+}
@@ -0,0 +1,217 @@
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+
+use super::WHISPER_SAMPLE_RATE;
+
+/// 분석 프레임 길이 (25ms)
+const FRAME_LEN: usize = 400;
+/// 프레임 간격 (10ms)
+const HOP_LEN: usize = 160;
+const FFT_LEN: usize = 512;
+const MEL_BANDS: usize = 24;
+/// 임베딩에 쓰는 MFCC 계수 (음량에 좌우되는 c0 제외)
+const CEPSTRA: usize = 12;
+
+/// 이보다 짧은 구간은 임베딩이 불안정해 직전 화자로 간주 (ms)
+const MIN_SEGMENT_MS: usize = 500;
+/// 같은 화자로 볼 최소 코사인 유사도
+const SIMILARITY_THRESHOLD: f32 = 0.9;
+/// 가장 큰 프레임 에너지 대비 이보다 작은 프레임은 무음으로 보고 제외
+const SILENCE_RATIO: f32 = 0.01;
+
+struct Speaker {
+    centroid: Vec<f32>,
+    count: usize,
+}
+
+/// 구간별 MFCC 통계 임베딩을 온라인 클러스터링해 화자 라벨을 붙이는 단계
+pub struct Diarizer {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    filters: Vec<Vec<f32>>,
+    speakers: Vec<Speaker>,
+    max_speakers: usize,
+    last: Option<usize>,
+}
+
+impl Diarizer {
+    pub fn new(max_speakers: usize) -> Self {
+        let window = (0..FRAME_LEN)
+            .map(|i| {
+                0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LEN - 1) as f32).cos()
+            })
+            .collect();
+
+        Self {
+            fft: FftPlanner::new().plan_fft_forward(FFT_LEN),
+            window,
+            filters: mel_filters(),
+            speakers: Vec::new(),
+            max_speakers: max_speakers.max(1),
+            last: None,
+        }
+    }
+
+    /// 16kHz 구간 오디오의 화자 라벨 (예: `화자 1`)
+    pub fn assign(&mut self, audio: &[f32]) -> Option<String> {
+        let min_len = MIN_SEGMENT_MS * WHISPER_SAMPLE_RATE as usize / 1000;
+        let index = match self.embed(audio).filter(|_| audio.len() >= min_len) {
+            Some(embedding) => self.cluster(embedding),
+            None => self.last?,
+        };
+
+        self.last = Some(index);
+        Some(format!("화자 {}", index + 1))
+    }
+
+    /// 가장 비슷한 화자에 배정하거나 새 화자 추가
+    fn cluster(&mut self, embedding: Vec<f32>) -> usize {
+        let best = self
+            .speakers
+            .iter()
+            .enumerate()
+            .map(|(i, speaker)| (i, cosine(&speaker.centroid, &embedding)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match best {
+            Some((index, similarity))
+                if similarity >= SIMILARITY_THRESHOLD
+                    || self.speakers.len() >= self.max_speakers =>
+            {
+                let speaker = &mut self.speakers[index];
+                speaker.count += 1;
+                let weight = 1.0 / speaker.count as f32;
+                for (c, e) in speaker.centroid.iter_mut().zip(&embedding) {
+                    *c += (e - *c) * weight;
+                }
+                index
+            }
+            _ => {
+                self.speakers.push(Speaker {
+                    centroid: embedding,
+                    count: 1,
+                });
+                self.speakers.len() - 1
+            }
+        }
+    }
+
+    /// 유성 프레임 MFCC의 평균과 표준편차를 이어 붙인 벡터
+    fn embed(&self, audio: &[f32]) -> Option<Vec<f32>> {
+        let mut frames: Vec<(f32, Vec<f32>)> = Vec::new();
+        let mut buffer = vec![Complex::new(0.0, 0.0); FFT_LEN];
+
+        for start in (0..audio.len().saturating_sub(FRAME_LEN)).step_by(HOP_LEN) {
+            let frame = &audio[start..start + FRAME_LEN];
+            let energy = frame.iter().map(|s| s * s).sum::<f32>();
+
+            buffer.fill(Complex::new(0.0, 0.0));
+            for (slot, (sample, w)) in buffer.iter_mut().zip(frame.iter().zip(&self.window)) {
+                slot.re = sample * w;
+            }
+            self.fft.process(&mut buffer);
+
+            let power: Vec<f32> = buffer[..FFT_LEN / 2 + 1]
+                .iter()
+                .map(|c| c.norm_sqr())
+                .collect();
+            let log_mel: Vec<f32> = self
+                .filters
+                .iter()
+                .map(|filter| {
+                    let e: f32 = filter.iter().zip(&power).map(|(f, p)| f * p).sum();
+                    (e + 1e-10).ln()
+                })
+                .collect();
+
+            frames.push((energy, dct(&log_mel)));
+        }
+
+        let max_energy = frames.iter().map(|(e, _)| *e).fold(0.0, f32::max);
+        let voiced: Vec<&Vec<f32>> = frames
+            .iter()
+            .filter(|(e, _)| *e >= max_energy * SILENCE_RATIO && *e > 0.0)
+            .map(|(_, mfcc)| mfcc)
+            .collect();
+        if voiced.len() < 10 {
+            return None;
+        }
+
+        let n = voiced.len() as f32;
+        let mut mean = vec![0.0; CEPSTRA];
+        for mfcc in &voiced {
+            for (m, c) in mean.iter_mut().zip(mfcc.iter()) {
+                *m += c / n;
+            }
+        }
+        let mut std = vec![0.0; CEPSTRA];
+        for mfcc in &voiced {
+            for ((s, c), m) in std.iter_mut().zip(mfcc.iter()).zip(&mean) {
+                *s += (c - m) * (c - m) / n;
+            }
+        }
+
+        Some(
+            mean.into_iter()
+                .chain(std.into_iter().map(f32::sqrt))
+                .collect(),
+        )
+    }
+}
+
+/// 로그 멜 에너지의 DCT-II 중 c1..=c12
+fn dct(log_mel: &[f32]) -> Vec<f32> {
+    let n = log_mel.len() as f32;
+    (1..=CEPSTRA)
+        .map(|k| {
+            log_mel
+                .iter()
+                .enumerate()
+                .map(|(i, v)| v * (std::f32::consts::PI * k as f32 * (i as f32 + 0.5) / n).cos())
+                .sum()
+        })
+        .collect()
+}
+
+/// 80Hz~7.6kHz 삼각 멜 필터뱅크
+fn mel_filters() -> Vec<Vec<f32>> {
+    let to_mel = |hz: f32| 2595.0 * (1.0 + hz / 700.0).log10();
+    let to_hz = |mel: f32| 700.0 * (10f32.powf(mel / 2595.0) - 1.0);
+
+    let (low, high) = (to_mel(80.0), to_mel(7600.0));
+    let bins: Vec<f32> = (0..MEL_BANDS + 2)
+        .map(|i| {
+            let hz = to_hz(low + (high - low) * i as f32 / (MEL_BANDS + 1) as f32);
+            hz * FFT_LEN as f32 / WHISPER_SAMPLE_RATE as f32
+        })
+        .collect();
+
+    (0..MEL_BANDS)
+        .map(|band| {
+            let (left, center, right) = (bins[band], bins[band + 1], bins[band + 2]);
+            (0..FFT_LEN / 2 + 1)
+                .map(|bin| {
+                    let bin = bin as f32;
+                    if bin <= left || bin >= right {
+                        0.0
+                    } else if bin <= center {
+                        (bin - left) / (center - left)
+                    } else {
+                        (right - bin) / (right - center)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
@@ -0,0 +1,74 @@
+/// 문장을 끝내는 부호
+const SENTENCE_END: &[char] = &['.', '?', '!', '…', '。', '？', '！'];
+
+/// 이 단어로 시작하는 영어 문장은 의문문으로 봄
+const QUESTION_WORDS: &[&str] = &[
+    "who", "what", "when", "where", "why", "how", "which", "whose", "is", "are", "was", "were",
+    "do", "does", "did", "can", "could", "will", "would", "should", "shall", "may", "have", "has",
+];
+
+/// 이 어미로 끝나는 한국어 문장은 의문문으로 봄
+const KOREAN_QUESTION_ENDINGS: &[&str] = &["까", "니", "냐", "나요", "가요", "는지요", "을까요"];
+
+/// 영어에서 항상 대문자로 쓰는 1인칭 대명사
+const PRONOUN_I: &[&str] = &["i", "i'm", "i'll", "i've", "i'd"];
+
+/// 규칙 기반 문장 부호·대소문자 보정
+///
+/// 공백을 정리하고, 문장 첫 글자와 1인칭 대명사 `I`를 대문자로 바꾸고,
+/// 끝에 부호가 없으면 의문문 여부에 따라 `?`나 `.`를 붙인다.
+/// 이미 부호가 있는 Whisper 출력은 거의 그대로 둔다.
+pub fn punctuate(text: &str) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let Some(first) = words.first() else {
+        return String::new();
+    };
+    let question = is_question(first, words[words.len() - 1]);
+
+    let mut output = String::with_capacity(text.len() + 1);
+    let mut sentence_start = true;
+    for word in &words {
+        // 부호 앞 공백 제거 ("안녕 ." → "안녕.")
+        let attached = word
+            .chars()
+            .all(|c| matches!(c, ',' | '.' | '?' | '!' | ';' | ':'));
+        if !output.is_empty() && !attached {
+            output.push(' ');
+        }
+
+        if PRONOUN_I.contains(&word.to_lowercase().as_str()) {
+            output.push('I');
+            output.push_str(&word[1..]);
+        } else if sentence_start {
+            output.push_str(&capitalize(word));
+        } else {
+            output.push_str(word);
+        }
+        sentence_start = word.ends_with(SENTENCE_END);
+    }
+
+    if !output.ends_with(SENTENCE_END) {
+        output.push(if question { '?' } else { '.' });
+    }
+    output
+}
+
+fn is_question(first: &str, last: &str) -> bool {
+    let first = first
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    let last = last.trim_end_matches(|c: char| !c.is_alphanumeric());
+    QUESTION_WORDS.contains(&first.as_str())
+        || KOREAN_QUESTION_ENDINGS
+            .iter()
+            .any(|ending| last.ends_with(ending))
+}
+
+/// 첫 글자가 라틴 문자면 대문자로 (한글 등 대소문자가 없는 문자는 그대로)
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) if first.is_lowercase() => first.to_uppercase().chain(chars).collect(),
+        _ => word.to_string(),
+    }
+}
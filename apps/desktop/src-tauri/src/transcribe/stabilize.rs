@@ -0,0 +1,56 @@
+/// 스트리밍 부분 결과의 안정 구간 판정
+///
+/// 연속한 두 가설이 똑같이 내놓은 앞부분 단어는 이후에도 거의 바뀌지 않으므로
+/// 안정 구간으로 본다. 자막은 안정 구간을 그대로 두고 나머지만 다시 그리면 된다.
+#[derive(Default)]
+pub struct PartialStabilizer {
+    previous: Vec<String>,
+    stable_words: usize,
+}
+
+impl PartialStabilizer {
+    /// 새 가설을 반영하고 안정 구간(`text`의 접두어) 반환
+    pub fn update(&mut self, text: &str) -> String {
+        let words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+        let agreed = words
+            .iter()
+            .zip(&self.previous)
+            .take_while(|(current, previous)| current == previous)
+            .count();
+
+        // 안정 구간이 고쳐졌으면 두 가설이 합의한 곳까지 되돌림
+        self.stable_words = agreed;
+        self.previous = words;
+
+        stable_prefix(text, self.stable_words)
+    }
+
+    /// 구간이 확정돼 새 가설이 시작될 때 초기화
+    pub fn reset(&mut self) {
+        self.previous.clear();
+        self.stable_words = 0;
+    }
+}
+
+/// `text`에서 앞 `count`개 단어까지의 원문 (단어 사이 공백 유지)
+fn stable_prefix(text: &str, count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    let mut seen = 0;
+    let mut in_word = false;
+    for (index, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if in_word {
+                seen += 1;
+                if seen == count {
+                    return text[..index].trim_start().to_string();
+                }
+            }
+            in_word = false;
+        } else {
+            in_word = true;
+        }
+    }
+    text.trim().to_string()
+}
@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::credentials;
+use crate::llm::{self, ChatRequest};
+use crate::settings::SettingsStore;
+use crate::storage::Storage;
+use crate::stream_server::{self, StreamMessage};
+use crate::transcribe::TranscriptSegment;
+
+mod deepl;
+mod google;
+
+pub type TranslateFuture = Pin<Box<dyn Future<Output = Result<String, String>> + Send>>;
+
+/// 기계 번역 제공자
+pub trait Translator: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// `source`가 없으면 제공자가 원문 언어를 감지
+    fn translate(&self, text: String, source: Option<String>, target: String) -> TranslateFuture;
+}
+
+/// 번역 제공자 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationProviderKind {
+    Deepl,
+    Google,
+}
+
+impl TranslationProviderKind {
+    /// 키체인에 API 키를 저장하는 이름
+    fn credential_name(self) -> &'static str {
+        match self {
+            TranslationProviderKind::Deepl => "deepl",
+            TranslationProviderKind::Google => "google",
+        }
+    }
+}
+
+/// 번역 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TranslationOptions {
+    /// 생략하면 키체인에 저장된 키 사용
+    pub api_key: Option<String>,
+    /// 원문 언어 코드 (생략하면 자동 감지)
+    pub source_language: Option<String>,
+    /// 설정된 LLM으로 기계 번역을 다듬어 저장
+    pub refine: bool,
+}
+
+/// `translation` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationEvent {
+    pub segment_id: String,
+    pub language: String,
+    pub text: String,
+}
+
+struct ActiveTranslation {
+    translator: Arc<dyn Translator>,
+    source_language: Option<String>,
+    target_language: String,
+    refine: bool,
+}
+
+/// 실시간 번역 설정 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct TranslationService {
+    active: Mutex<Option<ActiveTranslation>>,
+}
+
+/// 확정된 전사 구간을 비동기로 번역해 저장하고 `translation` 이벤트로 알림
+///
+/// 번역이 설정되지 않았으면 아무것도 하지 않는다.
+pub fn enqueue(app: &AppHandle, segment: &TranscriptSegment) {
+    let (translator, source, target, refine) = {
+        let service = app.state::<TranslationService>();
+        let active = service.active.lock().unwrap();
+        let Some(active) = active.as_ref() else {
+            return;
+        };
+        (
+            active.translator.clone(),
+            active.source_language.clone(),
+            active.target_language.clone(),
+            active.refine,
+        )
+    };
+
+    let app = app.clone();
+    let segment_id = segment.id.clone();
+    let text = segment.text.clone();
+    tauri::async_runtime::spawn(async move {
+        let translated = match translator
+            .translate(text.clone(), source, target.clone())
+            .await
+        {
+            Ok(translated) => translated,
+            Err(e) => {
+                log::error!("{} 번역 실패: {}", translator.name(), e);
+                return;
+            }
+        };
+        let translated = if refine {
+            refine_translation(&app, &text, &target, translated).await
+        } else {
+            translated
+        };
+
+        if let Err(e) = app
+            .state::<Storage>()
+            .insert_translation(&segment_id, &target, &translated)
+        {
+            log::error!("번역 저장 실패: {}", e);
+        }
+
+        let event = TranslationEvent {
+            segment_id,
+            language: target,
+            text: translated,
+        };
+        stream_server::publish(&app, StreamMessage::Translation(event.clone()));
+        let _ = app.emit("translation", event);
+    });
+}
+
+/// 설정된 LLM으로 기계 번역을 자연스럽게 다듬음 (실패하면 기계 번역 그대로)
+async fn refine_translation(app: &AppHandle, source: &str, target: &str, draft: String) -> String {
+    let Some(provider) = llm::provider(app) else {
+        return draft;
+    };
+    let request = ChatRequest {
+        system: format!(
+            "원문의 뜻을 바꾸지 말고 기계 번역을 자연스러운 '{}' 문장으로 다듬으세요. \
+             다듬은 번역만 답하세요.",
+            target
+        ),
+        user: format!("원문: {}\n기계 번역: {}", source, draft),
+        max_tokens: None,
+    };
+    match provider.complete(request, Box::new(|_| {})).await {
+        Ok(refined) if !refined.trim().is_empty() => refined.trim().to_string(),
+        Ok(_) => draft,
+        Err(e) => {
+            log::warn!("{} 번역 다듬기 실패: {}", provider.name(), e);
+            draft
+        }
+    }
+}
+
+/// 실시간 번역 설정
+///
+/// `target_language`를 생략하면 설정의 번역 대상 언어를 사용한다.
+/// `refine`을 켜려면 LLM 제공자가 설정돼 있어야 한다.
+#[tauri::command]
+pub fn configure_translation(
+    app: AppHandle,
+    service: State<'_, TranslationService>,
+    settings: State<'_, SettingsStore>,
+    provider: TranslationProviderKind,
+    target_language: Option<String>,
+    options: Option<TranslationOptions>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_default();
+
+    let target_language = target_language
+        .or_else(|| settings.get().target_language)
+        .ok_or("번역 대상 언어를 지정하세요")?;
+
+    if options.refine && llm::provider(&app).is_none() {
+        return Err("번역을 다듬으려면 먼저 LLM 제공자를 설정하세요".to_string());
+    }
+
+    let api_key = match options.api_key.filter(|key| !key.is_empty()) {
+        Some(key) => key,
+        None => credentials::api_key(provider.credential_name())?
+            .ok_or_else(|| "API 키가 필요합니다".to_string())?,
+    };
+
+    let translator: Arc<dyn Translator> = match provider {
+        TranslationProviderKind::Deepl => Arc::new(deepl::Deepl::new(api_key)),
+        TranslationProviderKind::Google => Arc::new(google::Google::new(api_key)),
+    };
+
+    log::info!("번역 설정: {} -> {}", translator.name(), target_language);
+    *service.active.lock().unwrap() = Some(ActiveTranslation {
+        translator,
+        source_language: options.source_language,
+        target_language,
+        refine: options.refine,
+    });
+    Ok(())
+}
+
+/// 실시간 번역 끄기
+#[tauri::command]
+pub fn disable_translation(service: State<'_, TranslationService>) {
+    if service.active.lock().unwrap().take().is_some() {
+        log::info!("번역 꺼짐");
+    }
+}
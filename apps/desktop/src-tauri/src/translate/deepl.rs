@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{TranslateFuture, Translator};
+
+const FREE_URL: &str = "https://api-free.deepl.com/v2/translate";
+const PRO_URL: &str = "https://api.deepl.com/v2/translate";
+
+/// DeepL 번역 API
+pub struct Deepl {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl Deepl {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Response {
+    translations: Vec<Translation>,
+}
+
+#[derive(Deserialize)]
+struct Translation {
+    text: String,
+}
+
+impl Translator for Deepl {
+    fn name(&self) -> &'static str {
+        "DeepL"
+    }
+
+    fn translate(&self, text: String, source: Option<String>, target: String) -> TranslateFuture {
+        // 무료 플랜 키는 `:fx`로 끝나며 별도 엔드포인트를 사용
+        let url = if self.api_key.ends_with(":fx") {
+            FREE_URL
+        } else {
+            PRO_URL
+        };
+
+        let mut body = json!({
+            "text": [text],
+            "target_lang": target.to_uppercase(),
+        });
+        if let Some(source) = source {
+            body["source_lang"] = json!(source.to_uppercase());
+        }
+
+        let request = self
+            .client
+            .post(url)
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .json(&body);
+
+        Box::pin(async move {
+            let response = request
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("DeepL 요청 실패: {}", e))?
+                .json::<Response>()
+                .await
+                .map_err(|e| format!("DeepL 응답 해석 실패: {}", e))?;
+
+            response
+                .translations
+                .into_iter()
+                .next()
+                .map(|t| t.text)
+                .ok_or_else(|| "DeepL 응답에 번역이 없습니다".to_string())
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{TranslateFuture, Translator};
+
+const TRANSLATE_URL: &str = "https://translation.googleapis.com/language/translate/v2";
+
+/// Google Cloud Translation (v2 REST)
+pub struct Google {
+    api_key: String,
+    client: reqwest::Client,
+}
+
+impl Google {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            api_key,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Response {
+    data: Data,
+}
+
+#[derive(Deserialize)]
+struct Data {
+    translations: Vec<Translation>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Translation {
+    translated_text: String,
+}
+
+impl Translator for Google {
+    fn name(&self) -> &'static str {
+        "Google"
+    }
+
+    fn translate(&self, text: String, source: Option<String>, target: String) -> TranslateFuture {
+        let mut body = json!({
+            "q": [text],
+            "target": target,
+            "format": "text",
+        });
+        if let Some(source) = source {
+            body["source"] = json!(source);
+        }
+
+        let request = self
+            .client
+            .post(TRANSLATE_URL)
+            .header("x-goog-api-key", &self.api_key)
+            .json(&body);
+
+        Box::pin(async move {
+            let response = request
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("Google 번역 요청 실패: {}", e.without_url()))?
+                .json::<Response>()
+                .await
+                .map_err(|e| format!("Google 번역 응답 해석 실패: {}", e.without_url()))?;
+
+            response
+                .data
+                .translations
+                .into_iter()
+                .next()
+                .map(|t| t.translated_text)
+                .ok_or_else(|| "Google 번역 응답에 번역이 없습니다".to_string())
+        })
+    }
+}
@@ -0,0 +1,204 @@
+use std::sync::Mutex;
+use tauri::image::Image;
+use tauri::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tauri::tray::{TrayIcon, TrayIconBuilder};
+use tauri::{AppHandle, Manager, Wry};
+
+use crate::audio::{self, CaptureManager};
+use crate::i18n::t;
+use crate::settings::SettingsStore;
+use crate::transcribe::TranscriptionEngine;
+
+/// 트레이에 표시하는 캡처 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaptureState {
+    Idle,
+    Recording,
+    Transcribing,
+}
+
+impl CaptureState {
+    fn tooltip(self) -> &'static str {
+        match self {
+            CaptureState::Idle => t("tray.idle"),
+            CaptureState::Recording => t("tray.recording"),
+            CaptureState::Transcribing => t("tray.transcribing"),
+        }
+    }
+
+    /// 아이콘 우하단에 찍는 상태 점 색상
+    fn badge_color(self) -> Option<[u8; 3]> {
+        match self {
+            CaptureState::Idle => None,
+            CaptureState::Recording => Some([0xE5, 0x39, 0x35]),
+            CaptureState::Transcribing => Some([0x1E, 0x88, 0xE5]),
+        }
+    }
+}
+
+/// 트레이 아이콘과 메뉴 (Tauri 관리 상태)
+pub struct Tray {
+    icon: TrayIcon,
+    toggle_capture: MenuItem<Wry>,
+    show: MenuItem<Wry>,
+    quit: MenuItem<Wry>,
+    base_icon: Image<'static>,
+    state: Mutex<CaptureState>,
+}
+
+/// 트레이 아이콘 생성
+pub fn create(app: &AppHandle) -> tauri::Result<()> {
+    let toggle_capture = MenuItem::with_id(
+        app,
+        "toggle_capture",
+        t("tray.start_capture"),
+        true,
+        None::<&str>,
+    )?;
+    let show = MenuItem::with_id(app, "show", t("tray.show"), true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", t("tray.quit"), true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[
+            &toggle_capture,
+            &PredefinedMenuItem::separator(app)?,
+            &show,
+            &quit,
+        ],
+    )?;
+
+    let base_icon = app
+        .default_window_icon()
+        .cloned()
+        .ok_or_else(|| tauri::Error::AssetNotFound("앱 아이콘".to_string()))?
+        .to_owned();
+
+    let icon = TrayIconBuilder::with_id("main")
+        .icon(base_icon.clone())
+        .tooltip(CaptureState::Idle.tooltip())
+        .menu(&menu)
+        .on_menu_event(handle_menu_event)
+        .build(app)?;
+
+    app.manage(Tray {
+        icon,
+        toggle_capture,
+        show,
+        quit,
+        base_icon,
+        state: Mutex::new(CaptureState::Idle),
+    });
+    Ok(())
+}
+
+/// 캡처/전사 상태가 바뀌었을 수 있을 때 호출해 아이콘과 메뉴 갱신
+pub fn refresh(app: &AppHandle) {
+    crate::power::refresh(app);
+
+    let Some(tray) = app.try_state::<Tray>() else {
+        return;
+    };
+
+    let capturing = !app.state::<CaptureManager>().list().is_empty();
+    let state = if app.state::<TranscriptionEngine>().is_running() {
+        CaptureState::Transcribing
+    } else if capturing {
+        CaptureState::Recording
+    } else {
+        CaptureState::Idle
+    };
+
+    let mut current = tray.state.lock().unwrap();
+    if *current == state {
+        return;
+    }
+    *current = state;
+
+    let icon = match state.badge_color() {
+        Some(color) => with_badge(&tray.base_icon, color),
+        None => tray.base_icon.clone(),
+    };
+    let _ = tray.icon.set_icon(Some(icon));
+    let _ = tray.icon.set_tooltip(Some(state.tooltip()));
+    let _ = tray.toggle_capture.set_text(toggle_label(capturing));
+}
+
+/// 표시 언어가 바뀌었을 때 메뉴와 툴팁 문자열을 다시 설정
+pub fn relabel(app: &AppHandle) {
+    let Some(tray) = app.try_state::<Tray>() else {
+        return;
+    };
+    let state = *tray.state.lock().unwrap();
+    let capturing = !app.state::<CaptureManager>().list().is_empty();
+    let _ = tray.icon.set_tooltip(Some(state.tooltip()));
+    let _ = tray.toggle_capture.set_text(toggle_label(capturing));
+    let _ = tray.show.set_text(t("tray.show"));
+    let _ = tray.quit.set_text(t("tray.quit"));
+}
+
+fn toggle_label(capturing: bool) -> &'static str {
+    if capturing {
+        t("tray.stop_capture")
+    } else {
+        t("tray.start_capture")
+    }
+}
+
+fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    match event.id.as_ref() {
+        "toggle_capture" => toggle_capture(app),
+        "show" => show_main_window(app),
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+/// 메인 창을 복원해 앞으로 가져옴
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// 캡처 중이면 모두 중지, 아니면 설정의 기본 장치로 마이크 캡처 시작
+fn toggle_capture(app: &AppHandle) {
+    let manager = app.state::<CaptureManager>();
+    if !manager.list().is_empty() {
+        manager.stop_all();
+        refresh(app);
+        return;
+    }
+
+    let device_id = app
+        .state::<SettingsStore>()
+        .get()
+        .default_device_id
+        .unwrap_or_else(|| "default".to_string());
+    if let Err(e) =
+        audio::start_audio_capture(app.clone(), manager, device_id, None, None, None, None)
+    {
+        log::error!("트레이에서 캡처 시작 실패: {}", e);
+    }
+}
+
+/// 아이콘 우하단에 상태 점을 그린 사본
+fn with_badge(base: &Image<'_>, color: [u8; 3]) -> Image<'static> {
+    let (width, height) = (base.width(), base.height());
+    let mut rgba = base.rgba().to_vec();
+
+    let radius = width.min(height) as f32 / 4.0;
+    let (cx, cy) = (width as f32 - radius, height as f32 - radius);
+    for y in 0..height {
+        for x in 0..width {
+            let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+            if dx * dx + dy * dy <= radius * radius {
+                let offset = ((y * width + x) * 4) as usize;
+                rgba[offset..offset + 4].copy_from_slice(&[color[0], color[1], color[2], 0xFF]);
+            }
+        }
+    }
+
+    Image::new_owned(rgba, width, height)
+}
@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State, Url};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use crate::error::TeuImError;
+use crate::jobs::JobQueue;
+use crate::settings::SettingsStore;
+
+/// 안정 채널 업데이트 매니페스트 (가장 최근 정식 릴리스)
+const STABLE_ENDPOINT: &str =
+    "https://github.com/eonofpixel/Teu-Im/releases/latest/download/latest.json";
+
+/// 베타 채널 업데이트 매니페스트 (`beta` 태그 릴리스)
+const BETA_ENDPOINT: &str =
+    "https://github.com/eonofpixel/Teu-Im/releases/download/beta/latest.json";
+
+/// 업데이트 채널
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    /// 정식 릴리스 전 미리보기 빌드
+    Beta,
+}
+
+impl UpdateChannel {
+    fn endpoint(self) -> &'static str {
+        match self {
+            Self::Stable => STABLE_ENDPOINT,
+            Self::Beta => BETA_ENDPOINT,
+        }
+    }
+}
+
+/// 새 버전 정보 (`update-available` 이벤트 페이로드)
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    pub channel: UpdateChannel,
+    /// 릴리스 노트
+    pub notes: Option<String>,
+    pub date: Option<String>,
+}
+
+/// `update-download-progress` 이벤트 페이로드
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateDownloadProgress {
+    pub version: String,
+    pub downloaded: u64,
+    /// 전체 크기 (서버가 알려 주지 않으면 생략)
+    pub total: Option<u64>,
+}
+
+/// 확인·내려받은 업데이트 (Tauri 관리 상태)
+#[derive(Default)]
+pub struct UpdateState {
+    available: Mutex<Option<Update>>,
+    downloaded: Mutex<Option<(Update, Vec<u8>)>>,
+}
+
+fn update_error(e: tauri_plugin_updater::Error) -> TeuImError {
+    TeuImError::from(format!("업데이트 실패: {}", e))
+}
+
+/// 이 빌드에 업데이트 서명 공개 키가 들어 있는지
+///
+/// 키는 릴리스 빌드에서 `plugins.updater.pubkey` 설정으로 넣는다.
+fn has_pubkey(app: &AppHandle) -> bool {
+    app.config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|config| config.get("pubkey"))
+        .and_then(|pubkey| pubkey.as_str())
+        .is_some_and(|pubkey| !pubkey.trim().is_empty())
+}
+
+/// 설정한 채널에서 새 버전 확인 (없으면 `None`)
+#[tauri::command]
+pub async fn check_for_updates(
+    app: AppHandle,
+    state: State<'_, UpdateState>,
+) -> Result<Option<UpdateInfo>, TeuImError> {
+    if !has_pubkey(&app) {
+        return Err(TeuImError::invalid_argument(
+            "이 빌드는 업데이트 서명 키 없이 빌드되어 자동 업데이트를 쓸 수 없습니다",
+        ));
+    }
+
+    let channel = app.state::<SettingsStore>().get().update_channel;
+    let endpoint = Url::parse(channel.endpoint()).map_err(|e| e.to_string())?;
+    let update = app
+        .updater_builder()
+        .endpoints(vec![endpoint])
+        .map_err(update_error)?
+        .build()
+        .map_err(update_error)?
+        .check()
+        .await
+        .map_err(update_error)?;
+
+    let Some(update) = update else {
+        log::info!("최신 버전 사용 중 ({:?} 채널)", channel);
+        *state.available.lock().unwrap() = None;
+        return Ok(None);
+    };
+    let info = UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        channel,
+        notes: update.body.clone(),
+        date: update.date.map(|date| date.to_string()),
+    };
+    log::info!(
+        "새 버전 있음: {} -> {} ({:?} 채널)",
+        info.current_version,
+        info.version,
+        channel
+    );
+    *state.available.lock().unwrap() = Some(update);
+    let _ = app.emit("update-available", &info);
+    Ok(Some(info))
+}
+
+/// 확인한 업데이트 내려받기 (서명을 검증하고 설치 전까지 보관)
+///
+/// 진행률은 `update-download-progress`, 끝나면 `update-downloaded`로 알린다.
+#[tauri::command]
+pub async fn download_update(
+    app: AppHandle,
+    state: State<'_, UpdateState>,
+) -> Result<(), TeuImError> {
+    let update = state
+        .available
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or_else(|| TeuImError::invalid_argument("먼저 업데이트를 확인하세요"))?;
+
+    let version = update.version.clone();
+    let mut downloaded = 0u64;
+    let mut last_percent = None;
+    let result = update
+        .download(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                // 전체 크기를 알면 1% 단위로만 알림
+                let percent = total.map(|total| downloaded * 100 / total.max(1));
+                if percent.is_some() && percent == last_percent {
+                    return;
+                }
+                last_percent = percent;
+                let _ = app.emit(
+                    "update-download-progress",
+                    UpdateDownloadProgress {
+                        version: version.clone(),
+                        downloaded,
+                        total,
+                    },
+                );
+            },
+            || log::info!("업데이트 내려받기 완료: {}", version),
+        )
+        .await;
+
+    match result {
+        Ok(bytes) => {
+            *state.downloaded.lock().unwrap() = Some((update, bytes));
+            let _ = app.emit("update-downloaded", &version);
+            Ok(())
+        }
+        Err(e) => {
+            // 다시 시도할 수 있게 확인한 업데이트를 돌려놓음
+            *state.available.lock().unwrap() = Some(update);
+            Err(update_error(e))
+        }
+    }
+}
+
+/// 내려받은 업데이트를 설치하고 앱 다시 시작
+#[tauri::command]
+pub fn install_update(app: AppHandle, state: State<'_, UpdateState>) -> Result<(), TeuImError> {
+    if crate::shutdown::is_busy(&app) || app.state::<JobQueue>().is_busy() {
+        return Err(TeuImError::invalid_argument(
+            "캡처·녹음·전사를 마친 뒤 업데이트를 설치하세요",
+        ));
+    }
+    let (update, bytes) = state
+        .downloaded
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or_else(|| TeuImError::invalid_argument("먼저 업데이트를 내려받으세요"))?;
+
+    log::info!("업데이트 설치: {}", update.version);
+    update.install(&bytes).map_err(update_error)?;
+    app.restart()
+}
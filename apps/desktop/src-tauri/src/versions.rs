@@ -0,0 +1,451 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::State;
+
+use crate::error::TeuImError;
+use crate::storage::{Storage, TranscriptVersion, VersionSegment};
+
+/// 전체 표로 LCS를 계산할 최대 칸 수 (넘으면 남은 구간을 통째로 교체로 봄)
+const MAX_TABLE_CELLS: usize = 4_000_000;
+
+/// 비교 결과 묶음 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffKind {
+    Equal,
+    /// `b`에만 있는 단어
+    Insert,
+    /// `a`에만 있는 단어
+    Delete,
+    /// `a`의 단어가 `b`의 단어로 바뀜
+    Replace,
+}
+
+/// 비교한 단어 하나
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffWord {
+    pub text: String,
+    /// 단어가 속한 구간 (버전 안의 구간 ID)
+    pub segment_id: String,
+    /// 단어 시각을 모르면 구간 시각
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// 같은 종류로 이어진 단어 묶음
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffChunk {
+    pub kind: DiffKind,
+    /// `a` 쪽 단어 (`insert`면 비어 있음)
+    pub a: Vec<DiffWord>,
+    /// `b` 쪽 단어 (`delete`면 비어 있음)
+    pub b: Vec<DiffWord>,
+}
+
+/// `diff_transcript_versions` 응답
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptDiff {
+    pub a: TranscriptVersion,
+    pub b: TranscriptVersion,
+    pub chunks: Vec<DiffChunk>,
+    /// 바뀐 단어 비율 (단어 오류율과 같이 `a` 단어 수 기준, 1을 넘을 수 있음)
+    pub change_rate: f32,
+}
+
+/// 버전 구간을 단어로 나눔
+///
+/// 띄어쓰기로 나누되, 띄어 쓰지 않는 한자·가나는 글자 하나를 단어로 본다.
+/// 엔진이 준 단어 수와 맞으면 단어 시각을, 아니면 구간 시각을 쓴다.
+fn words(segments: &[VersionSegment]) -> Vec<DiffWord> {
+    let mut words = Vec::new();
+    for segment in segments {
+        let tokens: Vec<&str> = segment
+            .text
+            .split_whitespace()
+            .flat_map(split_cjk)
+            .collect();
+        let timed = segment.words.len() == tokens.len();
+        for (index, token) in tokens.into_iter().enumerate() {
+            let word = timed.then(|| &segment.words[index]);
+            words.push(DiffWord {
+                text: token.to_string(),
+                segment_id: segment.id.clone(),
+                start_ms: word.and_then(|w| w.start_ms).unwrap_or(segment.start_ms),
+                end_ms: word.and_then(|w| w.end_ms).unwrap_or(segment.end_ms),
+            });
+        }
+    }
+    words
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // 히라가나·가타카나
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}')
+}
+
+/// 한자·가나는 한 글자씩, 나머지는 이어진 채로 자름
+fn split_cjk(token: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (index, c) in token.char_indices() {
+        if is_cjk(c) {
+            if start < index {
+                parts.push(&token[start..index]);
+            }
+            parts.push(&token[index..index + c.len_utf8()]);
+            start = index + c.len_utf8();
+        }
+    }
+    if start < token.len() {
+        parts.push(&token[start..]);
+    }
+    parts
+}
+
+/// 단어 하나의 비교 결과
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// 두 단어열의 차이 (공통 앞뒤를 떼고 양쪽에 한 번씩만 나오는 단어를 기준점으로 나눔)
+fn diff(a: &[&str], b: &[&str], a_offset: usize, b_offset: usize, ops: &mut Vec<Op>) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    ops.extend((0..prefix).map(|i| Op::Equal(a_offset + i, b_offset + i)));
+
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+    let (a_start, b_start) = (a_offset + prefix, b_offset + prefix);
+    if a_mid.is_empty() || b_mid.is_empty() {
+        ops.extend((0..a_mid.len()).map(|i| Op::Delete(a_start + i)));
+        ops.extend((0..b_mid.len()).map(|j| Op::Insert(b_start + j)));
+    } else {
+        let anchors = unique_anchors(a_mid, b_mid);
+        if anchors.is_empty() {
+            lcs(a_mid, b_mid, a_start, b_start, ops);
+        } else {
+            let (mut i, mut j) = (0, 0);
+            for (ai, bj) in anchors {
+                diff(&a_mid[i..ai], &b_mid[j..bj], a_start + i, b_start + j, ops);
+                ops.push(Op::Equal(a_start + ai, b_start + bj));
+                (i, j) = (ai + 1, bj + 1);
+            }
+            diff(&a_mid[i..], &b_mid[j..], a_start + i, b_start + j, ops);
+        }
+    }
+
+    let (a_end, b_end) = (a_offset + a.len() - suffix, b_offset + b.len() - suffix);
+    ops.extend((0..suffix).map(|i| Op::Equal(a_end + i, b_end + i)));
+}
+
+/// 양쪽에 한 번씩만 나오는 단어 중 순서가 맞는 가장 긴 짝 (a 위치, b 위치)
+fn unique_anchors(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    let mut counts: HashMap<&str, (usize, usize, usize)> = HashMap::new();
+    for (i, word) in a.iter().enumerate() {
+        let entry = counts.entry(word).or_insert((0, 0, 0));
+        entry.0 += 1;
+        entry.2 = i;
+    }
+    for word in b {
+        if let Some(entry) = counts.get_mut(word) {
+            entry.1 += 1;
+        }
+    }
+    let pairs: Vec<(usize, usize)> = b
+        .iter()
+        .enumerate()
+        .filter_map(|(j, word)| {
+            let (in_a, in_b, i) = counts.get(word)?;
+            (*in_a == 1 && *in_b == 1).then_some((*i, j))
+        })
+        .collect();
+
+    // b 순서로 놓인 짝에서 a 위치가 증가하는 가장 긴 부분열
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; pairs.len()];
+    for (index, (i, _)) in pairs.iter().enumerate() {
+        let position = tails.partition_point(|&tail| pairs[tail].0 < *i);
+        if position > 0 {
+            previous[index] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(index);
+        } else {
+            tails[position] = index;
+        }
+    }
+    let mut anchors = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        anchors.push(pairs[index]);
+        current = previous[index];
+    }
+    anchors.reverse();
+    anchors
+}
+
+/// 표로 구하는 최장 공통 부분열 (너무 크면 통째로 교체)
+fn lcs(a: &[&str], b: &[&str], a_offset: usize, b_offset: usize, ops: &mut Vec<Op>) {
+    let (n, m) = (a.len(), b.len());
+    if n * m > MAX_TABLE_CELLS {
+        ops.extend((0..n).map(|i| Op::Delete(a_offset + i)));
+        ops.extend((0..m).map(|j| Op::Insert(b_offset + j)));
+        return;
+    }
+
+    // table[i][j] = a[i..]와 b[j..]의 공통 부분열 길이
+    let width = m + 1;
+    let mut table = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i * width + j] = if a[i] == b[j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            ops.push(Op::Equal(a_offset + i, b_offset + j));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+            ops.push(Op::Delete(a_offset + i));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(b_offset + j));
+            j += 1;
+        }
+    }
+    ops.extend((i..n).map(|i| Op::Delete(a_offset + i)));
+    ops.extend((j..m).map(|j| Op::Insert(b_offset + j)));
+}
+
+/// 단어별 결과를 같은 종류끼리 묶음 (이어진 삭제·삽입은 교체 하나로)
+fn chunks(ops: &[Op], a: &[DiffWord], b: &[DiffWord]) -> Vec<DiffChunk> {
+    let mut chunks: Vec<DiffChunk> = Vec::new();
+    for op in ops {
+        let (kind, a_word, b_word) = match *op {
+            Op::Equal(i, j) => (DiffKind::Equal, Some(&a[i]), Some(&b[j])),
+            Op::Delete(i) => (DiffKind::Delete, Some(&a[i]), None),
+            Op::Insert(j) => (DiffKind::Insert, None, Some(&b[j])),
+        };
+        let chunk = match chunks.last_mut() {
+            Some(last) if last.kind == kind => last,
+            Some(last) if kind != DiffKind::Equal && last.kind != DiffKind::Equal => {
+                last.kind = DiffKind::Replace;
+                last
+            }
+            _ => {
+                chunks.push(DiffChunk {
+                    kind,
+                    a: Vec::new(),
+                    b: Vec::new(),
+                });
+                chunks.last_mut().unwrap()
+            }
+        };
+        chunk.a.extend(a_word.cloned());
+        chunk.b.extend(b_word.cloned());
+    }
+    chunks
+}
+
+/// 세션의 전사 버전 목록 (실시간·다시 전사·편집, 만든 순)
+#[tauri::command]
+pub fn list_transcript_versions(
+    storage: State<'_, Storage>,
+    session_id: String,
+) -> Result<Vec<TranscriptVersion>, TeuImError> {
+    storage.transcript_versions(&session_id)
+}
+
+/// 두 전사 버전의 단어 단위 비교 (`a`가 기준)
+///
+/// 버전 ID는 `list_transcript_versions`의 `id`다. 같은 단어가 이어진 묶음과
+/// 삽입·삭제·교체 묶음을 순서대로 반환하므로 UI는 묶음을 차례로 그리면 된다.
+#[tauri::command]
+pub fn diff_transcript_versions(
+    storage: State<'_, Storage>,
+    a: String,
+    b: String,
+) -> Result<TranscriptDiff, TeuImError> {
+    let (a_version, a_segments) = storage.transcript_version(&a)?;
+    let (b_version, b_segments) = storage.transcript_version(&b)?;
+    let a_words = words(&a_segments);
+    let b_words = words(&b_segments);
+
+    let a_text: Vec<&str> = a_words.iter().map(|w| w.text.as_str()).collect();
+    let b_text: Vec<&str> = b_words.iter().map(|w| w.text.as_str()).collect();
+    let mut ops = Vec::with_capacity(a_text.len().max(b_text.len()));
+    diff(&a_text, &b_text, 0, 0, &mut ops);
+    let chunks = chunks(&ops, &a_words, &b_words);
+
+    // 교체는 짧은 쪽만큼 대체, 나머지는 삽입이나 삭제로 셈
+    let changed: usize = chunks
+        .iter()
+        .filter(|chunk| chunk.kind != DiffKind::Equal)
+        .map(|chunk| chunk.a.len().max(chunk.b.len()))
+        .sum();
+    let change_rate = match (a_words.len(), changed) {
+        (_, 0) => 0.0,
+        (0, _) => 1.0,
+        (total, changed) => changed as f32 / total as f32,
+    };
+
+    log::debug!(
+        "전사 버전 비교: {} / {} (묶음 {}개, 변경 {:.1}%)",
+        a,
+        b,
+        chunks.len(),
+        change_rate * 100.0
+    );
+    Ok(TranscriptDiff {
+        a: a_version,
+        b: b_version,
+        chunks,
+        change_rate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcribe::TranscriptWord;
+
+    fn segment(id: &str, text: &str) -> VersionSegment {
+        VersionSegment {
+            id: id.to_string(),
+            text: text.to_string(),
+            start_ms: 0,
+            end_ms: 1_000,
+            words: Vec::new(),
+        }
+    }
+
+    /// 두 문장을 비교해 (종류, a 쪽 글, b 쪽 글) 목록으로
+    fn compare(a: &str, b: &str) -> Vec<(DiffKind, String, String)> {
+        let a_words = words(&[segment("a", a)]);
+        let b_words = words(&[segment("b", b)]);
+        let a_text: Vec<&str> = a_words.iter().map(|w| w.text.as_str()).collect();
+        let b_text: Vec<&str> = b_words.iter().map(|w| w.text.as_str()).collect();
+        let mut ops = Vec::new();
+        diff(&a_text, &b_text, 0, 0, &mut ops);
+        let join = |words: &[DiffWord]| {
+            words
+                .iter()
+                .map(|w| w.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        chunks(&ops, &a_words, &b_words)
+            .iter()
+            .map(|chunk| (chunk.kind, join(&chunk.a), join(&chunk.b)))
+            .collect()
+    }
+
+    fn chunk(kind: DiffKind, a: &str, b: &str) -> (DiffKind, String, String) {
+        (kind, a.to_string(), b.to_string())
+    }
+
+    #[test]
+    fn identical_text_is_one_equal_chunk() {
+        assert_eq!(
+            compare("오늘 회의를 시작합니다", "오늘 회의를 시작합니다"),
+            vec![chunk(
+                DiffKind::Equal,
+                "오늘 회의를 시작합니다",
+                "오늘 회의를 시작합니다"
+            )]
+        );
+    }
+
+    #[test]
+    fn changed_word_is_a_replace() {
+        assert_eq!(
+            compare("the quick brown fox", "the quick red fox"),
+            vec![
+                chunk(DiffKind::Equal, "the quick", "the quick"),
+                chunk(DiffKind::Replace, "brown", "red"),
+                chunk(DiffKind::Equal, "fox", "fox"),
+            ]
+        );
+    }
+
+    #[test]
+    fn inserts_and_deletes_are_kept_apart() {
+        assert_eq!(
+            compare("a b c", "a c d"),
+            vec![
+                chunk(DiffKind::Equal, "a", "a"),
+                chunk(DiffKind::Delete, "b", ""),
+                chunk(DiffKind::Equal, "c", "c"),
+                chunk(DiffKind::Insert, "", "d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_words_fall_back_to_lcs() {
+        assert_eq!(
+            compare("a b a b", "b a b a"),
+            vec![
+                chunk(DiffKind::Delete, "a", ""),
+                chunk(DiffKind::Equal, "b a b", "b a b"),
+                chunk(DiffKind::Insert, "", "a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_side_is_all_inserts() {
+        assert_eq!(
+            compare("", "새 문장"),
+            vec![chunk(DiffKind::Insert, "", "새 문장")]
+        );
+    }
+
+    #[test]
+    fn cjk_is_split_per_character() {
+        assert_eq!(split_cjk("東京に行く"), vec!["東", "京", "に", "行", "く"]);
+        assert_eq!(split_cjk("abc漢字def"), vec!["abc", "漢", "字", "def"]);
+        // 한글은 띄어 쓰므로 그대로 둠
+        assert_eq!(split_cjk("안녕하세요"), vec!["안녕하세요"]);
+    }
+
+    #[test]
+    fn word_times_are_used_when_counts_match() {
+        let timed = |text: &str, start_ms, end_ms| TranscriptWord {
+            text: text.to_string(),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        };
+        let mut matched = segment("s", "hello world");
+        matched.words = vec![timed("hello", 100, 400), timed("world", 500, 900)];
+        let mut mismatched = segment("t", "hello there world");
+        mismatched.words = matched.words.clone();
+
+        let words = words(&[matched, mismatched]);
+        let times: Vec<(i64, i64)> = words.iter().map(|w| (w.start_ms, w.end_ms)).collect();
+        assert_eq!(
+            times,
+            vec![(100, 400), (500, 900), (0, 1_000), (0, 1_000), (0, 1_000)]
+        );
+        assert_eq!(words[2].segment_id, "t");
+    }
+}